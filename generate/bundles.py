@@ -0,0 +1,71 @@
+"""Bundles of test files, so that the crate can download a whole directory at once.
+
+Each bundle is a `.tar.zst` archive of the stored files of one directory
+(such as `WG04/JPLL`), with their stored names as member paths.
+Archives are built reproducibly, and require the `zstd` command.
+They are written as release assets to `release-assets/`,
+named `bundles.<directory with / replaced by ->.tar.zst`,
+and listed in `generate/bundles.txt`:
+
+    <sha256 of the archive> <size in bytes> <stored name> <member prefix>
+
+which ./generate/generate.py compiles into the crate.
+The archives are then uploaded with the other release assets,
+see ./generate/release_assets.py.
+"""
+import io
+import os
+import subprocess
+import tarfile
+import tempfile
+
+import generate
+import release_assets
+
+BUNDLES_LIST = './generate/bundles.txt'
+# directories of test files which are bundled
+BUNDLED_DIRS = [
+    'WG04/J2KI', 'WG04/J2KR', 'WG04/JLSL', 'WG04/JLSN', 'WG04/JPLL', 'WG04/JPLY',
+    'WG04/REF', 'WG04/RLE', 'pydicom',
+]
+
+
+def build_tar(members):
+    """Returns a reproducible tar archive of (stored name, path) members"""
+    out = io.BytesIO()
+    with tarfile.open(fileobj=out, mode='w', format=tarfile.USTAR_FORMAT) as tar:
+        for member_name, path in sorted(members):
+            info = tarfile.TarInfo(member_name)
+            info.size = os.path.getsize(path)
+            info.mode = 0o644
+            with open(path, 'rb') as f:
+                tar.addfile(info, f)
+    return out.getvalue()
+
+
+def main():
+    os.makedirs(release_assets.OUT_DIR, exist_ok=True)
+    test_files = list(generate.test_file_paths())
+    lines = []
+    for directory in BUNDLED_DIRS:
+        prefix = directory + '/'
+        members = [
+            (generate.stored_name(name, compression), path)
+            for name, path, compression in test_files if name.startswith(prefix)
+        ]
+        name = f'bundles/{directory.replace("/", "-")}.tar.zst'
+        out_path = os.path.join(release_assets.OUT_DIR, release_assets.asset_name(name))
+        with tempfile.NamedTemporaryFile(suffix='.tar') as tar:
+            tar.write(build_tar(members))
+            tar.flush()
+            subprocess.run(['zstd', '-19', '-q', '-f', tar.name, '-o', out_path], check=True)
+        lines.append(f'{generate.get_hash(out_path)} {os.path.getsize(out_path)} {name} {prefix}\n')
+        print(f'Bundled {len(members)} files into {out_path}')
+
+    with open(BUNDLES_LIST, 'w') as f:
+        f.writelines(lines)
+    print(f'Generated {BUNDLES_LIST}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,45 @@
+"""Stored test files by their hash, for the `content-addressed` backend of the crate.
+
+Each stored file is copied to `content-addressed/sha256/<sha256 of the file>`,
+along with the bundles of ./generate/bundles.py if they were built,
+and the directory is then synced to the gateway:
+
+    python3 generate/content_addressed.py
+    aws s3 sync content-addressed/ s3://dicom-test-files-cas/
+
+Files with the same contents share one copy.
+"""
+import os
+import shutil
+
+import bundles
+import generate
+import release_assets
+
+OUT_DIR = './content-addressed'
+
+
+def main():
+    out_dir = os.path.join(OUT_DIR, 'sha256')
+    os.makedirs(out_dir, exist_ok=True)
+    paths = [path for (_, path, _) in generate.test_file_paths()]
+    paths += [path for (_, path, _) in generate.test_file_paths(generate.GOLDEN_DIR, ())]
+    bundle_names = [name for (_, _, name, _) in generate.bundles(bundles.BUNDLES_LIST)]
+    paths += [
+        path for path in (
+            os.path.join(release_assets.OUT_DIR, release_assets.asset_name(name))
+            for name in bundle_names
+        )
+        if os.path.exists(path)
+    ]
+    hashes = set()
+    for path in sorted(paths):
+        hash = generate.get_hash(path)
+        if hash not in hashes:
+            hashes.add(hash)
+            shutil.copyfile(path, os.path.join(out_dir, hash))
+    print(f'Copied {len(hashes)} files to {out_dir}')
+
+
+if __name__ == '__main__':
+    main()
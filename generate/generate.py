@@ -1,22 +1,71 @@
 import hashlib
 import os
 
-
-def test_files():
-    """Returns a generator of file names and their info"""
-    for root, directories, file_names in os.walk('./data'):
+import groups
+import hosted
+import malformed
+import metadata
+import pixels
+import provenance
+
+
+# folder of the golden dumps, which are not test files themselves
+GOLDEN_DIR = './data/golden'
+# signed list of the test files, see ./generate/manifest.py
+MANIFEST = './data/manifest.txt'
+MANIFEST_SIGNATURE = './data/manifest.txt.sig'
+# extensions of the stored names of compressed test files,
+# by compression ('zstd', 'gzip' or 'xz', which map to constructor function names)
+COMPRESSED_EXTENSIONS = {'zstd': '.zst', 'gzip': '.gz', 'xz': '.xz'}
+
+
+def test_file_paths(top='./data', exclude=(GOLDEN_DIR, MANIFEST, MANIFEST_SIGNATURE)):
+    """Returns a generator of file names, paths and compression"""
+    for root, directories, file_names in os.walk(top):
+        directories[:] = [d for d in directories if os.path.join(root, d) not in exclude]
         for file_name in file_names:
             path = os.path.join(root, file_name)
+            if path in exclude:
+                continue
             name = path.replace('./data/', '')
-            hash = get_hash(path)
-            # 'zstd' or 'none', which map to constructor function names
-            if file_name.endswith('.zst'):
-                compression = 'zstd'
-                # remove extension to abstract compression away
-                name = name[:-4]
+            for compression, extension in COMPRESSED_EXTENSIONS.items():
+                if file_name.endswith(extension):
+                    # remove extension to abstract compression away
+                    name = name[:-len(extension)]
+                    break
             else:
                 compression = 'none'
-            yield (name, compression, hash)
+            yield (name, path, compression)
+
+
+def stored_name(name, compression):
+    """Returns the name of a test file as stored in the data repository"""
+    return name + COMPRESSED_EXTENSIONS.get(compression, '')
+
+
+def test_files():
+    """Returns a generator of file names and their info"""
+    paths = sorted(test_file_paths())
+    pixel_hashes = pixels.pixel_hashes(
+        (name, path, compression) for (name, path, compression) in paths
+        if not malformed.defect(name)
+    )
+    for name, path, compression in paths:
+        hash = get_hash(path)
+        size = os.path.getsize(path)
+        info = None if malformed.defect(name) else metadata.info(path, compression)
+        if info:
+            info['pixel_hash'] = pixel_hashes.get(name)
+        yield (
+            name, compression, hash, size, info,
+            groups.file_groups(path, name, compression), decompressed_hash(path, compression),
+        )
+
+
+def golden_files():
+    """Returns a generator of golden file names, hashes and sizes"""
+    for name, path, compression in test_file_paths(GOLDEN_DIR, ()):
+        yield (name, compression, get_hash(path), os.path.getsize(path))
 
 
 def get_hash(path):
@@ -32,6 +81,15 @@ def get_hash(path):
             sha256.update(data)
 
 
+def decompressed_hash(path, compression):
+    """Returns the sha256 hash of the contents of a test file stored with zstd,
+    once decompressed"""
+    if compression != 'zstd':
+        return None
+    with open(path, 'rb') as f:
+        return hashlib.sha256(metadata.decompress(f.read(), compression)).hexdigest()
+
+
 class MultiGeneator:
     def __init__(self, *generators):
         self.generators = generators
@@ -45,26 +103,130 @@ class MultiGeneator:
             generator.finish()
 
 
-def generate_rust(test_files):
+def rust_option(value):
+    if value is None:
+        return 'None'
+    if isinstance(value, str):
+        return f'Some("{value}")'
+    return f'Some({value})'
+
+
+def rust_entry(name, compression, hash, size, info, groups=(), decompressed=None, collection=None):
+    """Returns the Rust expression describing a test file"""
+    entry = f'TestFile::{compression}("{name}", "{hash}").size({size})'
+    if collection:
+        entry += f'.collection(&{hosted.constant(collection)})'
+    if decompressed:
+        entry += f'.decompressed("{decompressed}")'
+    if not name.startswith('golden/'):
+        entry += f'.provenance({provenance.constant(name)})'
+        entry += f'.anonymization(Anonymization::{provenance.anonymization(name)})'
+        upstream_url = provenance.upstream_url(name)
+        if upstream_url:
+            entry += f'.upstream("{upstream_url}")'
+    defect = malformed.defect(name)
+    if defect:
+        entry += f'.defect(Defect::{defect})'
+    if info:
+        fields = ', '.join(f'{key}: {rust_option(value)}' for key, value in info.items())
+        entry += f'.info(TestFileInfo {{ {fields} }})'
+    if groups:
+        names = ', '.join(f'"{group}"' for group in groups)
+        entry += f'.groups(&[{names}])'
+    return entry
+
+
+def manifest_feature(name):
+    """Returns the cargo feature compiling in the entry of a test file,
+    after the top directory of the test file (or the one it is a dump of)"""
+    if name.startswith('golden/'):
+        name = name[len('golden/'):]
+    return 'manifest-' + name.split('/')[0].lower()
+
+
+def rust_entry_line(entry):
+    """Returns the lines of a Rust entry in an array, gated by its feature"""
+    return f'    #[cfg(feature = "{manifest_feature(entry[0])}")]\n    {rust_entry(*entry)},\n'
+
+
+def bundles(path='./generate/bundles.txt'):
+    """Returns the bundles listed by ./generate/bundles.py,
+    as (hash, size, stored name, member prefix)"""
+    if not os.path.exists(path):
+        return []
+    with open(path) as f:
+        return [tuple(line.split()) for line in f if line.strip()]
+
+
+def generate_rust(test_files, golden_files, bundles):
     out_filename = './rust/src/entries.rs'
+
+    # save to list and sort by name
+    test_files = list(test_files)
+    test_files.extend(
+        (name, compression, hash, size, None, (), None, collection)
+        for (name, compression, hash, size, collection) in hosted.test_files()
+    )
+    test_files.sort(key=lambda x: x[0])
+    # constants are only written for directories with test files
+    top_dirs = {test_file[0].split('/')[0] for test_file in test_files}
+
     with open(out_filename, 'w+') as f:
         # write generated file heading      
         f.writelines([
             '// THIS FILE IS GENERATED BY ./generate/generate.py\n',
             '// DO NOT MANUALLY EDIT THIS FILE\n\n',
-            'use crate::test_file::TestFile;\n\n',
+            '// some are unused without any manifest feature\n',
+            '#[allow(unused_imports)]\n',
+            'use crate::test_file::{\n',
+            '    Anonymization, Bundle, Collection, Defect, Provenance, TestFile, TestFileInfo,\n',
+            '};\n\n',
 
+            '// provenance of the test files, see ./generate/provenance.py\n',
+        ])
+        f.writelines(
+            f'#[cfg(feature = "{manifest_feature(top_dir)}")]\n{provenance.rust_constant(top_dir)}\n'
+            for top_dir in provenance.SOURCES if top_dir in top_dirs
+        )
+        f.writelines([
+            '\n',
+            '// collections hosted elsewhere, see ./generate/hosted.py\n',
+        ])
+        f.writelines(
+            f'#[cfg(feature = "{manifest_feature(collection)}")]\n{hosted.rust_constant(collection)}\n'
+            for collection in hosted.COLLECTIONS if collection in top_dirs
+        )
+        f.writelines([
+            '\n',
             '/// all test file entries\n',
             'pub static FILE_ENTRIES: &[TestFile] = &[\n',
         ])
 
-        # save to list and sort by name
-        test_files = list(test_files)
-        test_files.sort(key=lambda x: x[0])
-
         # write each test file entry
+        f.writelines(rust_entry_line(test_file) for test_file in test_files)
+        f.write('];\n')
+
+        f.writelines([
+            '\n',
+            '/// golden JSON dumps of the test files, see ./generate/golden.py\n',
+            'pub static GOLDEN_ENTRIES: &[TestFile] = &[\n',
+        ])
+        f.writelines(
+            rust_entry_line((name, compression, hash, size, None))
+            for (name, compression, hash, size) in sorted(golden_files)
+        )
+        f.write('];\n')
+
+        f.writelines([
+            '\n',
+            '/// bundles of test files, see ./generate/bundles.py\n',
+            '#[cfg_attr(not(feature = "bundles"), allow(dead_code))]\n',
+            'pub static BUNDLES: &[Bundle] = &[\n',
+        ])
         f.writelines(
-            f'    TestFile::{compression}("{name}", "{hash}"),\n' for (name, compression, hash) in test_files
+            f'    #[cfg(feature = "{manifest_feature(prefix)}")]\n'
+            f'    Bundle {{ name: "{name}", hash: "{hash}", size: {size}, prefix: "{prefix}" }},\n'
+            for (hash, size, name, prefix) in bundles
         )
         f.write('];\n')
         print(f'Generated {out_filename}')
@@ -73,7 +235,7 @@ def generate_rust(test_files):
 def main():
     hashes = sorted(test_files())
 
-    generate_rust(hashes)
+    generate_rust(hashes, golden_files(), bundles())
 
 
 if __name__ == '__main__':
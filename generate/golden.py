@@ -0,0 +1,292 @@
+"""Golden JSON dumps of the test files, for differential parser testing.
+
+Each dump is the main data set of a test file (without the file meta group)
+in the DICOM JSON model (PS3.18 Annex F),
+with object keys sorted and an indentation of 2 spaces.
+Pixel data is not inlined,
+but referenced with a `BulkDataURI` holding the name of the test file.
+
+Dumps are only produced for files with explicit value representations,
+so that they do not depend on a data dictionary.
+Files in an implicit VR transfer syntax,
+with ISO 2022 character set extensions,
+or which fail to parse, are skipped.
+
+    python3 generate/golden.py
+"""
+import base64
+import json
+import os
+import struct
+import sys
+import zlib
+
+import malformed
+import metadata
+
+IMPLICIT_VR_LE = '1.2.840.10008.1.2'
+EXPLICIT_VR_BE = '1.2.840.10008.1.2.2'
+DEFLATED = '1.2.840.10008.1.2.1.99'
+
+LONG_VRS = metadata.LONG_VRS
+UNDEFINED = metadata.UNDEFINED
+PIXEL_DATA = (0x7FE0, 0x0010)
+SPECIFIC_CHARACTER_SET = (0x0008, 0x0005)
+
+BINARY_VRS = {'OB', 'OD', 'OF', 'OL', 'OV', 'OW', 'UN'}
+# text VRs which do not support multiple values
+SINGLE_VALUED = {'LT', 'ST', 'UT', 'UR'}
+# text VRs affected by the specific character set
+CHARSET_VRS = {'LO', 'LT', 'PN', 'SH', 'ST', 'UC', 'UT'}
+NUMERIC_FORMATS = {
+    'US': 'H', 'SS': 'h', 'UL': 'I', 'SL': 'i',
+    'FL': 'f', 'FD': 'd', 'UV': 'Q', 'SV': 'q',
+}
+
+CHARSETS = {
+    '': 'ascii',
+    'ISO_IR 6': 'ascii',
+    'ISO_IR 100': 'latin_1',
+    'ISO_IR 101': 'iso8859_2',
+    'ISO_IR 109': 'iso8859_3',
+    'ISO_IR 110': 'iso8859_4',
+    'ISO_IR 144': 'iso8859_5',
+    'ISO_IR 127': 'iso8859_6',
+    'ISO_IR 126': 'iso8859_7',
+    'ISO_IR 138': 'iso8859_8',
+    'ISO_IR 148': 'iso8859_9',
+    'ISO_IR 203': 'iso8859_15',
+    'ISO_IR 166': 'tis_620',
+    'ISO_IR 192': 'utf_8',
+    'GB18030': 'gb18030',
+    'GBK': 'gbk',
+}
+
+
+class Unsupported(Exception):
+    pass
+
+
+class Parser:
+    def __init__(self, data, name, explicit, big_endian):
+        if not explicit:
+            raise Unsupported('implicit VR')
+        self.data = data
+        self.name = name
+        self.prefix = '>' if big_endian else '<'
+        self.encoding = 'ascii'
+
+    def unpack(self, fmt, pos):
+        return struct.unpack_from(self.prefix + fmt, self.data, pos)
+
+    def header(self, pos):
+        """Returns (tag, vr, length, value position)"""
+        group, elem = self.unpack('HH', pos)
+        if group == 0xFFFE:
+            # items and delimiters have no VR
+            return (group, elem), None, self.unpack('I', pos + 4)[0], pos + 8
+        vr = self.data[pos + 4:pos + 6]
+        if not (vr.isalpha() and vr.isupper()):
+            raise ValueError(f'invalid VR {vr!r}')
+        if vr in LONG_VRS:
+            return (group, elem), vr.decode(), self.unpack('I', pos + 8)[0], pos + 12
+        return (group, elem), vr.decode(), self.unpack('H', pos + 6)[0], pos + 8
+
+    def data_set(self, pos, end, top_level=False):
+        """Parses elements until `end` or an item delimiter,
+        returning (JSON object, position after the data set)"""
+        out = {}
+        previous = None
+        while pos < end:
+            tag, vr, length, value_pos = self.header(pos)
+            if tag == (0xFFFE, 0xE00D):
+                return out, value_pos
+            if previous is not None and tag <= previous:
+                raise ValueError(f'elements out of order at {tag}')
+            previous = tag
+            if tag[0] == 0x0002 and top_level:
+                # file meta group without preamble
+                pos = value_pos + length
+                continue
+            key = f'{tag[0]:04X}{tag[1]:04X}'
+            if vr == 'SQ':
+                out[key], pos = self.sequence(value_pos, length)
+            elif length == UNDEFINED:
+                if tag != PIXEL_DATA:
+                    raise Unsupported(f'undefined length {vr} at {key}')
+                out[key] = {'vr': vr, 'BulkDataURI': self.name}
+                pos = self.skip_fragments(value_pos)
+            else:
+                if value_pos + length > len(self.data):
+                    raise ValueError('value past the end of the file')
+                raw = self.data[value_pos:value_pos + length]
+                if tag == PIXEL_DATA:
+                    out[key] = {'vr': vr, 'BulkDataURI': self.name}
+                else:
+                    out[key] = self.element(tag, vr, raw)
+                pos = value_pos + length
+        if pos > end:
+            raise ValueError('data set past its end')
+        return out, pos
+
+    def sequence(self, pos, length):
+        end = len(self.data) if length == UNDEFINED else pos + length
+        items = []
+        while pos < end:
+            tag, _, item_len, value_pos = self.header(pos)
+            if tag == (0xFFFE, 0xE0DD):
+                pos = value_pos
+                break
+            if tag != (0xFFFE, 0xE000):
+                raise ValueError(f'unexpected tag {tag} in sequence')
+            item_end = end if item_len == UNDEFINED else value_pos + item_len
+            item, pos = self.data_set(value_pos, item_end)
+            items.append(item)
+        element = {'vr': 'SQ'}
+        if items:
+            element['Value'] = items
+        return element, pos
+
+    def skip_fragments(self, pos):
+        while True:
+            tag, _, length, value_pos = self.header(pos)
+            pos = value_pos + length
+            if tag == (0xFFFE, 0xE0DD):
+                return value_pos
+            if tag != (0xFFFE, 0xE000):
+                raise ValueError(f'unexpected tag {tag} in pixel data')
+
+    def element(self, tag, vr, raw):
+        element = {'vr': vr}
+        value = self.value(tag, vr, raw)
+        if value and (vr in BINARY_VRS or any(v is not None for v in value)):
+            if vr in BINARY_VRS:
+                element['InlineBinary'] = value
+            else:
+                element['Value'] = value
+        return element
+
+    def value(self, tag, vr, raw):
+        if not raw:
+            return None
+        if vr in BINARY_VRS:
+            return base64.b64encode(raw).decode()
+        if vr in NUMERIC_FORMATS:
+            fmt = NUMERIC_FORMATS[vr]
+            size = struct.calcsize(fmt)
+            if len(raw) % size:
+                raise ValueError(f'bad length for {vr}')
+            values = struct.unpack(f'{self.prefix}{len(raw) // size}{fmt}', raw)
+            return [json_number(v) for v in values]
+        if vr == 'AT':
+            values = struct.unpack(f'{self.prefix}{len(raw) // 2}H', raw)
+            return [f'{g:04X}{e:04X}' for g, e in zip(values[::2], values[1::2])]
+
+        encoding = self.encoding if vr in CHARSET_VRS else 'ascii'
+        try:
+            text = raw.decode(encoding)
+        except UnicodeDecodeError:
+            raise Unsupported(f'cannot decode {vr} value as {encoding}')
+        if tag == SPECIFIC_CHARACTER_SET:
+            self.set_charset(text)
+
+        if vr in SINGLE_VALUED:
+            values = [text.rstrip(' \0')]
+        else:
+            values = [v.strip(' \0') for v in text.split('\\')]
+        if vr == 'PN':
+            return [person_name(v) for v in values]
+        if vr == 'IS':
+            return [int(v) if v else None for v in values]
+        if vr == 'DS':
+            return [json_number(float(v)) if v else None for v in values]
+        return [v if v else None for v in values]
+
+    def set_charset(self, text):
+        terms = [t.strip() for t in text.split('\\')]
+        if len(terms) > 1 or any(t.startswith('ISO 2022') for t in terms):
+            raise Unsupported(f'character set {text!r}')
+        if terms[0] not in CHARSETS:
+            raise Unsupported(f'character set {text!r}')
+        self.encoding = CHARSETS[terms[0]]
+
+
+def json_number(value):
+    if isinstance(value, float) and value.is_integer():
+        return int(value)
+    return value
+
+
+def person_name(value):
+    if not value:
+        return None
+    groups = value.split('=')
+    name = {}
+    for key, group in zip(['Alphabetic', 'Ideographic', 'Phonetic'], groups):
+        if group:
+            name[key] = group
+    return name
+
+
+def dump(path, name, compression):
+    """Returns the golden JSON dump of the given test file"""
+    with open(path, 'rb') as f:
+        data = f.read()
+    data = metadata.decompress(data, compression)
+
+    if data[128:132] == b'DICM':
+        meta = Parser(data, name, True, False)
+        pos = 132
+        transfer_syntax = None
+        while pos + 8 <= len(data) and meta.unpack('H', pos)[0] == 0x0002:
+            tag, vr, length, value_pos = meta.header(pos)
+            if tag == (0x0002, 0x0010):
+                transfer_syntax = data[value_pos:value_pos + length].rstrip(b'\0 ').decode()
+            pos = value_pos + length
+        if transfer_syntax is None:
+            raise Unsupported('no transfer syntax')
+        if transfer_syntax == DEFLATED:
+            data = data[:pos] + zlib.decompress(data[pos:], -15)
+        explicit = transfer_syntax != IMPLICIT_VR_LE
+        big_endian = transfer_syntax == EXPLICIT_VR_BE
+    else:
+        pos = 0
+        explicit = metadata.looks_explicit(data, pos)
+        big_endian = data[0] == 0 and data[1] != 0
+
+    parser = Parser(data, name, explicit, big_endian)
+    out, _ = parser.data_set(pos, len(data), top_level=True)
+    return out
+
+
+def golden_name(name):
+    return os.path.join('golden', name + '.json')
+
+
+def golden_files(test_files):
+    """Yields the name of the golden file for each test file which has one"""
+    for name, path, compression in test_files:
+        if malformed.defect(name):
+            continue
+        try:
+            out = dump(path, name, compression)
+            text = json.dumps(out, indent=2, sort_keys=True, ensure_ascii=False, allow_nan=False)
+        except (Unsupported, ValueError, struct.error, UnicodeDecodeError, zlib.error) as e:
+            print(f'Skipping {name}: {e}', file=sys.stderr)
+            continue
+        yield name, text
+
+
+def main():
+    import generate
+
+    for name, text in golden_files(sorted(generate.test_file_paths())):
+        target = os.path.join('./data', golden_name(name))
+        os.makedirs(os.path.dirname(target), exist_ok=True)
+        with open(target, 'w', encoding='utf-8') as f:
+            f.write(text + '\n')
+        print(f'Generated {target}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,82 @@
+"""Named groups of test files, by semantic category.
+
+Most groups are derived from the contents of each file,
+so that new files join them automatically.
+`CURATED` lists additional members which cannot be detected that way.
+"""
+import malformed
+import metadata
+from metadata import (ICON_IMAGE_SEQUENCE, NUMBER_OF_FRAMES, ODD_LENGTH, PRIVATE,
+                      SAMPLES_PER_PIXEL, SOP_CLASS_UID, SPECIFIC_CHARACTER_SET, UN_VR,
+                      UNDEFINED_LENGTH)
+
+TRANSFER_SYNTAX_GROUPS = {
+    'implicit-vr': {'1.2.840.10008.1.2'},
+    'big-endian': {'1.2.840.10008.1.2.2'},
+    'deflate': {'1.2.840.10008.1.2.1.99'},
+    'jpeg': {
+        '1.2.840.10008.1.2.4.50',
+        '1.2.840.10008.1.2.4.51',
+        '1.2.840.10008.1.2.4.57',
+        '1.2.840.10008.1.2.4.70',
+    },
+    'jpeg-ls': {'1.2.840.10008.1.2.4.80', '1.2.840.10008.1.2.4.81'},
+    'jpeg2000': {
+        '1.2.840.10008.1.2.4.90',
+        '1.2.840.10008.1.2.4.91',
+        '1.2.840.10008.1.2.4.201',
+        '1.2.840.10008.1.2.4.202',
+        '1.2.840.10008.1.2.4.203',
+    },
+    'rle': {'1.2.840.10008.1.2.5'},
+}
+
+# character sets which are not worth a special mention
+BASIC_CHARSETS = {'', 'ISO_IR 6', 'ISO_IR 100'}
+
+# group name -> additional test file names
+CURATED = {
+    # not understood by the metadata parser
+    'no-preamble': {'pydicom/no_meta.dcm'},
+}
+
+
+def groups(name, parsed):
+    """Returns the sorted group names of a test file"""
+    found = set()
+    for group, names in CURATED.items():
+        if name in names:
+            found.add(group)
+    if malformed.defect(name):
+        # not to be mistaken for valid files of the other groups
+        return ['malformed']
+    if parsed is None or not (parsed.found.get(SOP_CLASS_UID) or parsed.transfer_syntax):
+        return sorted(found)
+
+    for group, uids in TRANSFER_SYNTAX_GROUPS.items():
+        if parsed.transfer_syntax in uids:
+            found.add(group)
+    if not parsed.has_preamble:
+        found.add('no-preamble')
+    charset = parsed.found.get(SPECIFIC_CHARACTER_SET)
+    if charset is not None and charset not in BASIC_CHARSETS:
+        found.add('charset')
+    if parsed.found.get(PRIVATE):
+        found.add('private-tags')
+    if parsed.found.get(UNDEFINED_LENGTH):
+        found.add('undefined-length')
+    if parsed.found.get(UN_VR):
+        found.add('un-vr')
+    if parsed.found.get(ODD_LENGTH):
+        found.add('odd-length')
+    if parsed.found.get(ICON_IMAGE_SEQUENCE):
+        found.add('icon')
+    if (parsed.number(NUMBER_OF_FRAMES) or 1) > 1:
+        found.add('multi-frame')
+    if (parsed.number(SAMPLES_PER_PIXEL) or 1) > 1:
+        found.add('color')
+    return sorted(found)
+
+
+def file_groups(path, name, compression):
+    return groups(name, metadata.parse(path, compression))
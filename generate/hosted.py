@@ -0,0 +1,88 @@
+"""Collections of test files hosted outside of this project,
+compiled in as entries pinned to the hashes of their files.
+
+The files of a collection are listed in ./generate/<collection>.txt,
+one `<sha256> <size> <path>` line per file,
+where the path is relative to the base URL of the collection
+and is the name of the test file after the collection's directory.
+Update the list from a local checkout of the collection with
+
+    python3 generate/hosted.py <collection> <checkout> [<path>...]
+
+which pins the given paths, and the ones already listed again,
+at their contents in the checkout.
+"""
+
+import hashlib
+import os
+import sys
+
+# collection -> base URL of its files
+COLLECTIONS = {
+    'gdcm': 'https://raw.githubusercontent.com/malaterre/GDCM-data/master/',
+    # a curated subset, for a conformance set of codecs
+    'clunie': 'https://www.dclunie.com/images/compressed/',
+}
+
+
+def pin_file(collection):
+    """Returns the path of the list of pinned files of a collection"""
+    return f'./generate/{collection}.txt'
+
+
+def pinned(collection):
+    """Returns the pinned files of a collection, as (path, hash, size)"""
+    path = pin_file(collection)
+    if not os.path.exists(path):
+        return []
+    files = []
+    with open(path) as f:
+        for line in f:
+            if not line.strip() or line.startswith('#'):
+                continue
+            hash, size, file_path = line.split()
+            files.append((file_path, hash, int(size)))
+    return files
+
+
+def test_files():
+    """Returns the test files of all collections,
+    as (name, compression, hash, size, collection)"""
+    for collection in COLLECTIONS:
+        for path, hash, size in pinned(collection):
+            yield (f'{collection}/{path}', 'none', hash, size, collection)
+
+
+def rust_constant(collection):
+    """Returns the Rust constant of a collection"""
+    return (
+        f'pub static {constant(collection)}: Collection = Collection {{ '
+        f'name: "{collection}", base_url: "{COLLECTIONS[collection]}" }};'
+    )
+
+
+def constant(collection):
+    """Returns the name of the Rust constant of a collection"""
+    return f'{collection.upper()}_COLLECTION'
+
+
+def pin(collection, checkout, paths):
+    """Pins the given files of a local checkout of a collection,
+    along with the ones already pinned"""
+    paths = set(paths) | {path for path, _, _ in pinned(collection)}
+    lines = []
+    for path in sorted(paths):
+        with open(os.path.join(checkout, path), 'rb') as f:
+            data = f.read()
+        lines.append(f'{hashlib.sha256(data).hexdigest()} {len(data)} {path}\n')
+    with open(pin_file(collection)) as f:
+        header = [line for line in f if line.startswith('#')]
+    with open(pin_file(collection), 'w') as f:
+        f.writelines(header + lines)
+    print(f'Pinned {len(lines)} files of {collection}, run `make generate` to compile them in')
+
+
+if __name__ == '__main__':
+    if len(sys.argv) < 3 or sys.argv[1] not in COLLECTIONS:
+        sys.exit(f'usage: {sys.argv[0]} <{"|".join(COLLECTIONS)}> <checkout> [<path>...]')
+    pin(sys.argv[1], sys.argv[2], sys.argv[3:])
@@ -0,0 +1,114 @@
+"""Curated malformed DICOM files for negative parser tests.
+
+Each file in `data/invalid` is derived from a valid file of the collection
+by applying a single, well-known defect.
+Running this module regenerates them deterministically:
+
+    python3 generate/malformed.py
+"""
+import os
+import struct
+
+# base file from which all malformed files are derived
+# (Explicit VR Little Endian, with file meta group and pixel data at the end)
+SOURCE = './data/pydicom/CT_small.dcm'
+
+OUT_DIR = './data/invalid'
+
+# 4-byte length VRs in explicit VR encoding
+LONG_VRS = {b'OB', b'OD', b'OF', b'OL', b'OV', b'OW', b'SQ', b'SV', b'UC', b'UN', b'UR', b'UT', b'UV'}
+
+
+def elements(data):
+    """Yields (offset, tag, vr, header length, value length)
+    for each top level element in an explicit VR little endian file."""
+    pos = 132
+    while pos < len(data):
+        group, elem = struct.unpack_from('<HH', data, pos)
+        vr = data[pos + 4:pos + 6]
+        if vr in LONG_VRS:
+            header_len = 12
+            value_len = struct.unpack_from('<I', data, pos + 8)[0]
+        else:
+            header_len = 8
+            value_len = struct.unpack_from('<H', data, pos + 6)[0]
+        yield (pos, (group, elem), vr, header_len, value_len)
+        pos += header_len + value_len
+
+
+def find(data, tag):
+    for element in elements(data):
+        if element[1] == tag:
+            return element
+    raise KeyError(tag)
+
+
+def short_preamble(data):
+    # only 64 bytes of preamble before the magic code
+    return data[64:]
+
+
+def bad_magic_code(data):
+    return data[:128] + b'DICX' + data[132:]
+
+
+def wrong_group_length(data):
+    pos, _, _, header_len, _ = find(data, (0x0002, 0x0000))
+    value_pos = pos + header_len
+    length = struct.unpack_from('<I', data, value_pos)[0]
+    return data[:value_pos] + struct.pack('<I', length + 12) + data[value_pos + 4:]
+
+
+def element_length_overflow(data):
+    # Patient's Name claiming 65520 bytes
+    pos, _, _, _, _ = find(data, (0x0010, 0x0010))
+    return data[:pos + 6] + struct.pack('<H', 0xFFF0) + data[pos + 8:]
+
+
+def invalid_vr(data):
+    pos, _, _, _, _ = find(data, (0x0010, 0x0010))
+    return data[:pos + 4] + b'$$' + data[pos + 6:]
+
+
+def truncated_data_set(data):
+    # cut in the middle of the Contrast/Bolus Agent value
+    pos, _, _, header_len, value_len = find(data, (0x0018, 0x0010))
+    return data[:pos + header_len + value_len // 2]
+
+
+def truncated_pixel_data(data):
+    pos, _, _, header_len, value_len = find(data, (0x7FE0, 0x0010))
+    return data[:pos + header_len + value_len // 2]
+
+
+# file name -> (Rust `Defect` variant, function producing the file)
+MALFORMED = {
+    'invalid/short_preamble.dcm': ('ShortPreamble', short_preamble),
+    'invalid/bad_magic_code.dcm': ('BadMagicCode', bad_magic_code),
+    'invalid/wrong_group_length.dcm': ('WrongGroupLength', wrong_group_length),
+    'invalid/element_length_overflow.dcm': ('ElementLengthOverflow', element_length_overflow),
+    'invalid/invalid_vr.dcm': ('InvalidVr', invalid_vr),
+    'invalid/truncated_data_set.dcm': ('TruncatedDataSet', truncated_data_set),
+    'invalid/truncated_pixel_data.dcm': ('TruncatedPixelData', truncated_pixel_data),
+}
+
+
+def defect(name):
+    """Returns the name of the `Defect` variant for the given entry, if any"""
+    entry = MALFORMED.get(name)
+    return entry[0] if entry else None
+
+
+def main():
+    with open(SOURCE, 'rb') as f:
+        data = f.read()
+    os.makedirs(OUT_DIR, exist_ok=True)
+    for name, (_, corrupt) in MALFORMED.items():
+        path = os.path.join('./data', name)
+        with open(path, 'wb') as f:
+            f.write(corrupt(data))
+        print(f'Generated {path}')
+
+
+if __name__ == '__main__':
+    main()
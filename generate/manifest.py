@@ -0,0 +1,72 @@
+"""Signed manifest of the test files, for the remote manifest mode of the crate.
+
+The manifest lets released versions of the crate
+resolve test files added to the data repository after their release.
+It lists one test file per line,
+sorted by name and separated by single spaces:
+
+    <sha256 of the stored file> <size in bytes> <stored file name>
+
+with the stored file name ending in `.zst`, `.gz` or `.xz` for compressed files.
+Lines starting with `#` are comments.
+
+The project has no signing key yet, so the manifest is left unsigned
+and the crate refuses remote manifests until a key is embedded in it:
+
+    python3 generate/manifest.py
+
+External manifests are signed with an Ed25519 key given as a PEM file,
+with the signature in hexadecimal written next to them with a `.sig` extension:
+
+    DICOM_TEST_FILES_SIGNING_KEY=path/to/key.pem python3 generate/manifest.py sign files.toml
+"""
+import os
+import sys
+
+import generate
+
+HEADER = '# dicom-test-files manifest v1\n'
+
+
+def manifest(test_files):
+    """Returns the manifest text from an iterable of (name, path, compression)"""
+    lines = [HEADER]
+    for name, path, compression in sorted(test_files):
+        stored_name = generate.stored_name(name, compression)
+        if any(c.isspace() for c in stored_name):
+            raise ValueError(f'unsupported file name {stored_name!r}')
+        lines.append(f'{generate.get_hash(path)} {os.path.getsize(path)} {stored_name}\n')
+    return ''.join(lines)
+
+
+def sign(data, key_path):
+    """Returns the Ed25519 signature of `data` in hexadecimal"""
+    from cryptography.hazmat.primitives import serialization
+
+    with open(key_path, 'rb') as f:
+        key = serialization.load_pem_private_key(f.read(), password=None)
+    return key.sign(data).hex()
+
+
+def main():
+    if sys.argv[1:2] == ['sign']:
+        key_path = os.environ.get('DICOM_TEST_FILES_SIGNING_KEY')
+        if not key_path:
+            print('DICOM_TEST_FILES_SIGNING_KEY is required to sign manifests', file=sys.stderr)
+            sys.exit(1)
+        for path in sys.argv[2:]:
+            with open(path, 'rb') as f:
+                signature = sign(f.read(), key_path)
+            with open(path + '.sig', 'w') as f:
+                f.write(signature + '\n')
+            print(f'Signed {path}')
+        return
+
+    data = manifest(generate.test_file_paths()).encode()
+    with open(generate.MANIFEST, 'wb') as f:
+        f.write(data)
+    print(f'Generated {generate.MANIFEST}, unsigned')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,260 @@
+"""Expected values of each test file, read from its DICOM header.
+
+Only the few attributes exposed by `TestFileInfo` are extracted,
+so this is a minimal parser which stops at the pixel data.
+"""
+import ctypes
+import ctypes.util
+import gzip
+import lzma
+import struct
+import zlib
+
+IMPLICIT_VR_LE = '1.2.840.10008.1.2'
+EXPLICIT_VR_BE = '1.2.840.10008.1.2.2'
+DEFLATED = '1.2.840.10008.1.2.1.99'
+
+# 4-byte length VRs in explicit VR encoding
+LONG_VRS = {b'OB', b'OD', b'OF', b'OL', b'OV', b'OW', b'SQ', b'SV', b'UC', b'UN', b'UR', b'UT', b'UV'}
+UNDEFINED = 0xFFFFFFFF
+
+SPECIFIC_CHARACTER_SET = (0x0008, 0x0005)
+SOP_CLASS_UID = (0x0008, 0x0016)
+SOP_INSTANCE_UID = (0x0008, 0x0018)
+SAMPLES_PER_PIXEL = (0x0028, 0x0002)
+PHOTOMETRIC_INTERPRETATION = (0x0028, 0x0004)
+PLANAR_CONFIGURATION = (0x0028, 0x0006)
+NUMBER_OF_FRAMES = (0x0028, 0x0008)
+ROWS = (0x0028, 0x0010)
+COLUMNS = (0x0028, 0x0011)
+BITS_ALLOCATED = (0x0028, 0x0100)
+PIXEL_REPRESENTATION = (0x0028, 0x0103)
+PIXEL_DATA = (0x7FE0, 0x0010)
+# attributes with the US value representation
+US_TAGS = {
+    SAMPLES_PER_PIXEL, PLANAR_CONFIGURATION, ROWS, COLUMNS, BITS_ALLOCATED, PIXEL_REPRESENTATION,
+}
+WANTED = US_TAGS | {
+    SPECIFIC_CHARACTER_SET, SOP_CLASS_UID, SOP_INSTANCE_UID,
+    PHOTOMETRIC_INTERPRETATION, NUMBER_OF_FRAMES,
+}
+ICON_IMAGE_SEQUENCE = (0x0088, 0x0200)
+# markers of structural quirks of the main data set
+PRIVATE = 'private'
+UNDEFINED_LENGTH = 'undefined-length'
+UN_VR = 'un'
+ODD_LENGTH = 'odd-length'
+
+
+def zstd_decompress(data):
+    lib = ctypes.CDLL(ctypes.util.find_library('zstd') or 'libzstd.so.1')
+    lib.ZSTD_getFrameContentSize.restype = ctypes.c_ulonglong
+    lib.ZSTD_decompress.restype = ctypes.c_size_t
+    lib.ZSTD_isError.restype = ctypes.c_uint
+    size = lib.ZSTD_getFrameContentSize(data, len(data))
+    if size >= 2 ** 63:
+        raise ValueError('zstd frame without content size')
+    out = ctypes.create_string_buffer(size)
+    written = lib.ZSTD_decompress(out, size, data, len(data))
+    if lib.ZSTD_isError(written):
+        raise ValueError('invalid zstd frame')
+    return out.raw[:written]
+
+
+def decompress(data, compression):
+    """Returns the contents of a stored test file"""
+    if compression == 'zstd':
+        return zstd_decompress(data)
+    if compression == 'gzip':
+        return gzip.decompress(data)
+    if compression == 'xz':
+        return lzma.decompress(data)
+    return data
+
+
+class Reader:
+    def __init__(self, data, pos, explicit, big_endian):
+        self.data = data
+        self.pos = pos
+        self.explicit = explicit
+        self.prefix = '>' if big_endian else '<'
+
+    def unpack(self, fmt, size):
+        value = struct.unpack_from(self.prefix + fmt, self.data, self.pos)
+        self.pos += size
+        return value
+
+    def header(self):
+        """Reads an element header, returning (tag, vr, length)"""
+        group, elem = self.unpack('HH', 4)
+        if group == 0xFFFE:
+            return (group, elem), None, self.unpack('I', 4)[0]
+        if not self.explicit:
+            return (group, elem), None, self.unpack('I', 4)[0]
+        vr = self.data[self.pos:self.pos + 2]
+        self.pos += 2
+        if vr in LONG_VRS:
+            self.pos += 2
+            return (group, elem), vr, self.unpack('I', 4)[0]
+        return (group, elem), vr, self.unpack('H', 2)[0]
+
+    def skip_undefined(self):
+        """Skips the items of a sequence with undefined length"""
+        while True:
+            tag, _, length = self.header()
+            if tag == (0xFFFE, 0xE0DD):
+                return
+            if tag != (0xFFFE, 0xE000):
+                raise ValueError(f'unexpected tag {tag} in sequence')
+            if length == UNDEFINED:
+                self.skip_item()
+            else:
+                self.pos += length
+
+    def skip_item(self):
+        """Skips the contents of an item with undefined length"""
+        while True:
+            tag, _, length = self.header()
+            if tag == (0xFFFE, 0xE00D):
+                return
+            if length == UNDEFINED:
+                self.skip_undefined()
+            else:
+                self.pos += length
+
+    def value(self, length, us=False):
+        raw = self.data[self.pos:self.pos + length]
+        self.pos += length
+        if us:
+            return struct.unpack(self.prefix + 'H', raw[:2])[0] if length >= 2 else None
+        return raw.rstrip(b'\0 ').decode('ascii', 'replace')
+
+
+def data_set(data, pos, explicit, big_endian):
+    """Collects the wanted attributes of a data set"""
+    found = {}
+    reader = Reader(data, pos, explicit, big_endian)
+    while reader.pos + 8 <= len(data):
+        tag, vr, length = reader.header()
+        if tag == PIXEL_DATA:
+            # value position and length
+            found[PIXEL_DATA] = (reader.pos, length)
+            break
+        if tag[0] % 2 == 1:
+            found[PRIVATE] = True
+        if tag == ICON_IMAGE_SEQUENCE:
+            found[ICON_IMAGE_SEQUENCE] = True
+        if vr == b'UN':
+            found[UN_VR] = True
+        if length == UNDEFINED:
+            found[UNDEFINED_LENGTH] = True
+        elif length % 2 == 1:
+            found[ODD_LENGTH] = True
+        if length == UNDEFINED:
+            # sequences of unknown VR are always in implicit VR
+            reader.explicit = explicit and vr != b'UN'
+            reader.skip_undefined()
+            reader.explicit = explicit
+        elif tag in WANTED:
+            found[tag] = reader.value(length, us=tag in US_TAGS)
+        else:
+            reader.pos += length
+    return found
+
+
+def looks_explicit(data, pos):
+    vr = data[pos + 4:pos + 6]
+    return len(vr) == 2 and vr.isalpha() and vr.isupper()
+
+
+class Parsed:
+    """The wanted attributes of a test file"""
+
+    def __init__(self, data, has_preamble, transfer_syntax, meta_sop_class, found, big_endian):
+        self.data = data
+        self.has_preamble = has_preamble
+        self.transfer_syntax = transfer_syntax
+        self.meta_sop_class = meta_sop_class
+        self.found = found
+        self.big_endian = big_endian
+
+    def number(self, tag):
+        value = self.found.get(tag)
+        if isinstance(value, str):
+            try:
+                return int(value.split('\\')[0])
+            except ValueError:
+                return None
+        return value
+
+
+def parse(path, compression):
+    """Returns the wanted attributes of the given test file,
+    or `None` if it could not be read as DICOM"""
+    with open(path, 'rb') as f:
+        data = f.read()
+    data = decompress(data, compression)
+
+    transfer_syntax = None
+    meta_sop_class = None
+    try:
+        if data[128:132] == b'DICM':
+            meta = Reader(data, 132, True, False)
+            while meta.pos + 8 <= len(data):
+                start = meta.pos
+                tag, vr, length = meta.header()
+                if tag[0] != 0x0002:
+                    meta.pos = start
+                    break
+                if tag == (0x0002, 0x0010):
+                    transfer_syntax = meta.value(length)
+                elif tag == (0x0002, 0x0002):
+                    meta_sop_class = meta.value(length)
+                else:
+                    meta.pos += length
+            pos = meta.pos
+            if transfer_syntax == DEFLATED:
+                data = data[:pos] + zlib.decompress(data[pos:], -15)
+            explicit = transfer_syntax != IMPLICIT_VR_LE
+            big_endian = transfer_syntax == EXPLICIT_VR_BE
+        else:
+            # data set without file meta information
+            pos = 0
+            explicit = looks_explicit(data, pos)
+            # group 0008 read as 0x0800
+            big_endian = data[0] == 0 and data[1] != 0
+        found = data_set(data, pos, explicit, big_endian)
+    except (ValueError, struct.error, zlib.error):
+        return None
+    return Parsed(data, pos > 0, transfer_syntax, meta_sop_class, found, big_endian)
+
+
+def info(path, compression):
+    """Returns a dict with the expected values of the given test file,
+    or `None` if it could not be read as DICOM"""
+    parsed = parse(path, compression)
+    if parsed is None:
+        return None
+    found = parsed.found
+    transfer_syntax = parsed.transfer_syntax
+    meta_sop_class = parsed.meta_sop_class
+    number = parsed.number
+
+    sop_class = found.get(SOP_CLASS_UID) or meta_sop_class or None
+    if sop_class is None and transfer_syntax is None:
+        return None
+
+    frames = number(NUMBER_OF_FRAMES)
+    if frames is None and PIXEL_DATA in found:
+        frames = 1
+    return {
+        'sop_class_uid': sop_class,
+        'transfer_syntax_uid': transfer_syntax,
+        'rows': number(ROWS),
+        'columns': number(COLUMNS),
+        'frames': frames,
+        'bits_allocated': number(BITS_ALLOCATED),
+        'pixel_representation': number(PIXEL_REPRESENTATION),
+        'samples_per_pixel': number(SAMPLES_PER_PIXEL),
+        'photometric_interpretation': found.get(PHOTOMETRIC_INTERPRETATION) or None,
+    }
@@ -0,0 +1,209 @@
+"""SHA-256 digests of the decoded pixel data of each test file.
+
+The decoded pixel buffer is defined as all frames one after the other,
+with samples interleaved (planar configuration 0),
+each sample stored in little endian over Bits Allocated / 8 bytes,
+and no trailing padding.
+Color images keep their photometric interpretation.
+
+Native pixel data and RLE Lossless are decoded here.
+Other lossless encodings take the digest of their uncompressed counterpart,
+as lossless decoders must reproduce it exactly:
+`WG04/REF/<image>_UNC` for the WG04 files,
+or else an uncompressed or RLE file of the collection
+with the same SOP Instance UID, photometric interpretation and geometry.
+Lossy encodings have no digest,
+as their output depends on the decoder.
+"""
+import hashlib
+import re
+import struct
+import sys
+
+import metadata
+from metadata import (BITS_ALLOCATED, COLUMNS, PHOTOMETRIC_INTERPRETATION, PIXEL_DATA,
+                      PLANAR_CONFIGURATION, ROWS, SAMPLES_PER_PIXEL, SOP_INSTANCE_UID,
+                      UNDEFINED)
+
+NATIVE = {
+    None,
+    '1.2.840.10008.1.2',
+    '1.2.840.10008.1.2.1',
+    '1.2.840.10008.1.2.1.99',
+    '1.2.840.10008.1.2.2',
+}
+RLE_LOSSLESS = '1.2.840.10008.1.2.5'
+LOSSLESS = {
+    # JPEG Lossless, Non-Hierarchical (Process 14)
+    '1.2.840.10008.1.2.4.57',
+    # JPEG Lossless, Non-Hierarchical, First-Order Prediction
+    '1.2.840.10008.1.2.4.70',
+    # JPEG-LS Lossless
+    '1.2.840.10008.1.2.4.80',
+    # JPEG 2000 Lossless Only
+    '1.2.840.10008.1.2.4.90',
+    # High-Throughput JPEG 2000 Lossless Only
+    '1.2.840.10008.1.2.4.201',
+}
+
+
+class Image:
+    def __init__(self, parsed):
+        number = parsed.number
+        self.rows = number(ROWS)
+        self.columns = number(COLUMNS)
+        self.frames = number(metadata.NUMBER_OF_FRAMES) or 1
+        self.samples = number(SAMPLES_PER_PIXEL) or 1
+        self.planar = number(PLANAR_CONFIGURATION) or 0
+        bits = number(BITS_ALLOCATED)
+        if None in (self.rows, self.columns, bits) or bits % 8:
+            raise ValueError('unsupported image')
+        self.bytes = bits // 8
+        self.pixels = self.rows * self.columns
+        self.frame_len = self.pixels * self.samples * self.bytes
+
+    def geometry(self):
+        return (self.rows, self.columns, self.frames, self.samples, self.bytes)
+
+    def key(self, parsed):
+        uid = parsed.found.get(SOP_INSTANCE_UID)
+        photometric = parsed.found.get(PHOTOMETRIC_INTERPRETATION)
+        return (uid, photometric) + self.geometry()
+
+
+def wg04_reference(name):
+    """Returns the name of the uncompressed WG04 file for the given WG04 file"""
+    match = re.fullmatch(r'WG04/[A-Z0-9]+/([A-Z]+[0-9]+)_[A-Z0-9]+', name)
+    return match and f'WG04/REF/{match.group(1)}_UNC'
+
+
+def native(parsed, image, pos, length):
+    data = parsed.data[pos:pos + length]
+    if len(data) < image.frame_len * image.frames:
+        raise ValueError('pixel data too short')
+    out = bytearray()
+    for frame in range(image.frames):
+        frame_data = data[frame * image.frame_len:(frame + 1) * image.frame_len]
+        if parsed.big_endian and image.bytes > 1:
+            fmt = {2: 'H', 4: 'I', 8: 'Q'}[image.bytes]
+            count = len(frame_data) // image.bytes
+            frame_data = struct.pack(f'<{count}{fmt}', *struct.unpack(f'>{count}{fmt}', frame_data))
+        if image.planar == 1 and image.samples > 1:
+            plane_len = image.pixels * image.bytes
+            planes = [frame_data[s * plane_len:(s + 1) * plane_len] for s in range(image.samples)]
+            frame_data = interleave(planes, image)
+        out += frame_data
+    return bytes(out)
+
+
+def interleave(planes, image):
+    out = bytearray(image.frame_len)
+    step = image.bytes
+    for s, plane in enumerate(planes):
+        for b in range(step):
+            out[(s * step + b)::image.samples * step] = plane[b::step]
+    return bytes(out)
+
+
+def fragments(data, pos):
+    """Returns the fragments of encapsulated pixel data, without the offset table"""
+    items = []
+    while True:
+        group, elem, length = struct.unpack_from('<HHI', data, pos)
+        pos += 8
+        if (group, elem) == (0xFFFE, 0xE0DD):
+            return items[1:]
+        if (group, elem) != (0xFFFE, 0xE000):
+            raise ValueError('unexpected tag in pixel data')
+        items.append(data[pos:pos + length])
+        pos += length
+
+
+def rle_segment(data, length):
+    out = bytearray()
+    pos = 0
+    while len(out) < length and pos < len(data):
+        n = data[pos]
+        pos += 1
+        if n < 128:
+            out += data[pos:pos + n + 1]
+            pos += n + 1
+        elif n > 128:
+            out += data[pos:pos + 1] * (257 - n)
+            pos += 1
+    if len(out) < length:
+        raise ValueError('RLE segment too short')
+    return bytes(out[:length])
+
+
+def rle(parsed, image, pos):
+    frames = fragments(parsed.data, pos)
+    if len(frames) != image.frames:
+        raise ValueError('RLE frames must be in one fragment each')
+    out = bytearray()
+    for frame in frames:
+        count = struct.unpack_from('<I', frame, 0)[0]
+        if count != image.samples * image.bytes:
+            raise ValueError('unexpected number of RLE segments')
+        offsets = list(struct.unpack_from('<15I', frame, 4)[:count]) + [len(frame)]
+        # segments go from the most significant byte of the first sample
+        segments = [rle_segment(frame[offsets[i]:offsets[i + 1]], image.pixels)
+                    for i in range(count)]
+        planes = []
+        for s in range(image.samples):
+            sample_segments = segments[s * image.bytes:(s + 1) * image.bytes][::-1]
+            plane = bytearray(image.pixels * image.bytes)
+            for b, segment in enumerate(sample_segments):
+                plane[b::image.bytes] = segment
+            planes.append(bytes(plane))
+        out += interleave(planes, image)
+    return bytes(out)
+
+
+def decode(parsed):
+    """Returns (image, decoded pixel buffer or None) of a test file"""
+    if parsed is None or PIXEL_DATA not in parsed.found:
+        return None, None
+    image = Image(parsed)
+    pos, length = parsed.found[PIXEL_DATA]
+    if parsed.transfer_syntax in NATIVE and length != UNDEFINED:
+        return image, native(parsed, image, pos, length)
+    if parsed.transfer_syntax == RLE_LOSSLESS:
+        return image, rle(parsed, image, pos)
+    return image, None
+
+
+def pixel_hashes(test_files):
+    """Returns a dict of test file name to decoded pixel data digest,
+    from an iterable of (name, path, compression)"""
+    hashes = {}
+    by_key = {}
+    geometries = {}
+    pending = []
+    for name, path, compression in test_files:
+        parsed = metadata.parse(path, compression)
+        try:
+            image, decoded = decode(parsed)
+        except (ValueError, struct.error) as e:
+            print(f'No pixel hash for {name}: {e}', file=sys.stderr)
+            continue
+        if image is None:
+            continue
+        key = image.key(parsed)
+        geometries[name] = image.geometry()
+        if decoded is not None:
+            digest = hashlib.sha256(decoded).hexdigest()
+            hashes[name] = digest
+            if key[0] and by_key.setdefault(key, digest) != digest:
+                print(f'Decoded pixel data of {name} differs from its counterpart',
+                      file=sys.stderr)
+        elif parsed.transfer_syntax in LOSSLESS:
+            pending.append((name, key))
+
+    for name, key in pending:
+        reference = wg04_reference(name)
+        if reference in hashes and geometries[reference] == geometries[name]:
+            hashes[name] = hashes[reference]
+        elif key in by_key:
+            hashes[name] = by_key[key]
+    return hashes
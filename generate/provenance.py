@@ -0,0 +1,88 @@
+"""Where the test files come from, under what terms they are redistributed
+and how they are known to be free of patient data,
+by top directory of the data repository.
+
+Each top directory must be listed here,
+so that a new collection cannot be added without its license.
+"""
+
+# top directory -> (source, source URL, license),
+# licenses are SPDX license expressions where one applies
+SOURCES = {
+    'pydicom': (
+        'pydicom test files',
+        'https://github.com/pydicom/pydicom/tree/main/src/pydicom/data/test_files',
+        'MIT',
+    ),
+    'WG04': (
+        'DICOM WG-04 compression test images, published by NEMA',
+        'ftp://medical.nema.org/medical/dicom/DataSets/WG04/',
+        'freely redistributable DICOM test data',
+    ),
+    'invalid': (
+        'dicom-test-files, derived from pydicom/CT_small.dcm by ./generate/malformed.py',
+        'https://github.com/robyoung/dicom-test-files',
+        'MIT',
+    ),
+    'gdcm': (
+        'GDCM test data',
+        'https://github.com/malaterre/GDCM-data',
+        'freely redistributable DICOM test data',
+    ),
+    'clunie': (
+        'compression test images of David Clunie',
+        'https://www.dclunie.com/images/compressed/',
+        'freely redistributable DICOM test data',
+    ),
+}
+
+# top directory -> anonymization status, as the variant of `Anonymization`
+ANONYMIZATION = {
+    'pydicom': 'FullyAnonymized',
+    # contributed by modality vendors, not vetted by the working group
+    'WG04': 'VendorSample',
+    'invalid': 'Synthetic',
+    # mostly contributed by modality vendors
+    'gdcm': 'VendorSample',
+    'clunie': 'VendorSample',
+}
+
+# top directory -> base URL of the files at their canonical upstream host,
+# for the collections with one served over HTTP
+UPSTREAM = {
+    'pydicom': 'https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/',
+}
+
+
+def constant(name):
+    """Returns the name of the Rust constant of the provenance of a test file,
+    after its top directory"""
+    top_dir = name.split('/')[0]
+    if top_dir not in SOURCES:
+        raise ValueError(f'no provenance for {name}, list {top_dir} in ./generate/provenance.py')
+    return top_dir.upper()
+
+
+def anonymization(name):
+    """Returns the anonymization status of a test file, after its top directory"""
+    top_dir = name.split('/')[0]
+    if top_dir not in ANONYMIZATION:
+        raise ValueError(f'no anonymization status for {name}, list {top_dir} in ./generate/provenance.py')
+    return ANONYMIZATION[top_dir]
+
+
+def upstream_url(name):
+    """Returns the URL of a test file at its canonical upstream host, if any"""
+    top_dir, _, path = name.partition('/')
+    if top_dir not in UPSTREAM:
+        return None
+    return UPSTREAM[top_dir] + path
+
+
+def rust_constant(top_dir):
+    """Returns the Rust constant of the provenance of a top directory"""
+    source, url, license = SOURCES[top_dir]
+    return (
+        f'pub const {constant(top_dir)}: Provenance = Provenance {{ '
+        f'source: "{source}", url: Some("{url}"), license: "{license}" }};'
+    )
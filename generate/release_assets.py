@@ -0,0 +1,39 @@
+"""Stored test files as flat release assets, for the `github-releases` backend of the crate.
+
+Release assets cannot hold directories,
+so each stored file is copied to `release-assets/`
+with the `/` of its path replaced by `.`,
+and are then uploaded to the release of the crate version:
+
+    python3 generate/release_assets.py
+    gh release upload v0.3.0 release-assets/*
+"""
+import os
+import shutil
+
+import generate
+
+OUT_DIR = './release-assets'
+
+
+def asset_name(stored_name):
+    """Returns the release asset name of a stored file name"""
+    return stored_name.replace('/', '.')
+
+
+def main():
+    os.makedirs(OUT_DIR, exist_ok=True)
+    names = set()
+    paths = list(generate.test_file_paths()) + list(generate.test_file_paths(generate.GOLDEN_DIR, ()))
+    for name, path, compression in sorted(paths):
+        stored_name = generate.stored_name(name, compression)
+        asset = asset_name(stored_name)
+        if asset in names:
+            raise ValueError(f'asset name {asset!r} is not unique')
+        names.add(asset)
+        shutil.copyfile(path, os.path.join(OUT_DIR, asset))
+    print(f'Copied {len(names)} assets to {OUT_DIR}')
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,338 @@
+//! Procedural macros for [`dicom-test-files`][1].
+//!
+//! These are re-exported by the main crate when its `macros` feature is
+//! enabled. Use them through `dicom_test_files` rather than depending on
+//! this crate directly.
+//!
+//! [1]: https://docs.rs/dicom-test-files
+
+#![deny(missing_docs)]
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Turn a function into a test which receives DICOM test files as arguments.
+///
+/// Each `file = "..."` argument names a test file, which is fetched
+/// (and cached) with `dicom_test_files::path` before the body runs.
+/// The resulting `PathBuf`s are passed to the function parameters
+/// in the same order.
+///
+/// ```ignore
+/// use std::path::PathBuf;
+/// use dicom_test_files::dicom_test;
+///
+/// #[dicom_test(file = "pydicom/CT_small.dcm", file = "pydicom/MR_small.dcm")]
+/// fn opens_ct_and_mr(ct: PathBuf, mr: PathBuf) {
+///     assert!(ct.exists());
+///     assert!(mr.exists());
+/// }
+/// ```
+///
+/// A `#[test]` attribute is added unless the function already has one
+/// (such as `#[tokio::test]`), in which case `async` functions are supported too.
+/// Failing to fetch a file makes the test panic.
+#[proc_macro_attribute]
+pub fn dicom_test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match expand(attr, item) {
+        Ok(tokens) => tokens,
+        Err((span, message)) => compile_error(span, &message),
+    }
+}
+
+type ExpandResult<T> = Result<T, (Span, String)>;
+
+fn expand(attr: TokenStream, item: TokenStream) -> ExpandResult<TokenStream> {
+    let files = parse_files(attr)?;
+    let item = parse_fn(item)?;
+
+    if files.len() != item.num_params {
+        return Err((
+            item.name.span(),
+            format!(
+                "`{}` takes {} parameter(s) but {} file(s) were given to `dicom_test`",
+                item.name,
+                item.num_params,
+                files.len()
+            ),
+        ));
+    }
+
+    if item.is_async && !item.has_test_attr {
+        return Err((
+            item.name.span(),
+            "async `dicom_test` functions need an async test attribute, such as `#[tokio::test]`"
+                .to_string(),
+        ));
+    }
+
+    let mut out = TokenStream::new();
+    out.extend(item.attrs);
+    if !item.has_test_attr {
+        out.extend(parse("#[test]"));
+    }
+    out.extend(item.signature_prefix);
+    out.extend(Some(TokenTree::Ident(item.name)));
+    out.extend(Some(TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        TokenStream::new(),
+    ))));
+    out.extend(item.ret.clone());
+
+    // inner function holding the original parameters and body
+    let mut body = TokenStream::new();
+    if item.is_async {
+        body.extend(parse("async"));
+    }
+    body.extend(parse("fn __dicom_test_body"));
+    body.extend(Some(TokenTree::Group(item.params)));
+    body.extend(item.ret);
+    body.extend(Some(TokenTree::Group(item.body)));
+
+    let mut args = TokenStream::new();
+    for file in files {
+        let fetch = format!(
+            "::dicom_test_files::path({file}).unwrap_or_else(|e| panic!(\"failed to fetch DICOM test file {{}}: {{:?}}\", {file}, e)),",
+            file = file
+        );
+        args.extend(parse(&fetch));
+    }
+    body.extend(parse("__dicom_test_body"));
+    body.extend(Some(TokenTree::Group(Group::new(Delimiter::Parenthesis, args))));
+    if item.is_async {
+        body.extend(parse(".await"));
+    }
+
+    out.extend(Some(TokenTree::Group(Group::new(Delimiter::Brace, body))));
+    Ok(out)
+}
+
+/// Parse `file = "..."` pairs separated by commas.
+fn parse_files(attr: TokenStream) -> ExpandResult<Vec<Literal>> {
+    let mut files = Vec::new();
+    let mut tokens = attr.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match &token {
+            TokenTree::Ident(ident) if ident.to_string() == "file" => {}
+            other => {
+                return Err((
+                    other.span(),
+                    "expected `file = \"...\"` in `dicom_test` arguments".to_string(),
+                ))
+            }
+        }
+        match tokens.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '=' => {}
+            _ => return Err((token.span(), "expected `=` after `file`".to_string())),
+        }
+        match tokens.next() {
+            Some(TokenTree::Literal(lit)) if is_str_literal(&lit) => files.push(lit),
+            Some(other) => {
+                return Err((other.span(), "expected a string literal".to_string()));
+            }
+            None => return Err((token.span(), "expected a string literal".to_string())),
+        }
+        match tokens.next() {
+            None => break,
+            Some(TokenTree::Punct(p)) if p.as_char() == ',' => {}
+            Some(other) => return Err((other.span(), "expected `,`".to_string())),
+        }
+    }
+
+    if files.is_empty() {
+        return Err((
+            Span::call_site(),
+            "`dicom_test` requires at least one `file = \"...\"` argument".to_string(),
+        ));
+    }
+    Ok(files)
+}
+
+fn is_str_literal(lit: &Literal) -> bool {
+    let repr = lit.to_string();
+    repr.starts_with('"') || repr.starts_with("r\"") || repr.starts_with("r#")
+}
+
+struct TestFn {
+    attrs: Vec<TokenTree>,
+    has_test_attr: bool,
+    /// visibility and qualifiers up to and including the `fn` keyword
+    signature_prefix: Vec<TokenTree>,
+    is_async: bool,
+    name: Ident,
+    params: Group,
+    num_params: usize,
+    /// return type (`-> T`) and where clause, if any
+    ret: Vec<TokenTree>,
+    body: Group,
+}
+
+fn parse_fn(item: TokenStream) -> ExpandResult<TestFn> {
+    let mut tokens = item.into_iter().peekable();
+
+    let mut attrs = Vec::new();
+    let mut has_test_attr = false;
+    while let Some(TokenTree::Punct(p)) = tokens.peek() {
+        if p.as_char() != '#' {
+            break;
+        }
+        attrs.extend(tokens.next());
+        match tokens.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+                has_test_attr |= is_test_attr(&group);
+                attrs.push(TokenTree::Group(group));
+            }
+            other => {
+                let span = other.map_or_else(Span::call_site, |t| t.span());
+                return Err((span, "malformed attribute".to_string()));
+            }
+        }
+    }
+
+    let mut signature_prefix = Vec::new();
+    let mut is_async = false;
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "fn" => {
+                signature_prefix.push(TokenTree::Ident(ident));
+                break;
+            }
+            Some(TokenTree::Ident(ident)) => {
+                is_async |= ident.to_string() == "async";
+                signature_prefix.push(TokenTree::Ident(ident));
+            }
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                // e.g. `pub(crate)`
+                signature_prefix.push(TokenTree::Group(group));
+            }
+            Some(other) => {
+                return Err((other.span(), "`dicom_test` can only be applied to functions".to_string()))
+            }
+            None => {
+                return Err((
+                    Span::call_site(),
+                    "`dicom_test` can only be applied to functions".to_string(),
+                ))
+            }
+        }
+    }
+
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident,
+        other => {
+            let span = other.map_or_else(Span::call_site, |t| t.span());
+            return Err((span, "expected function name".to_string()));
+        }
+    };
+
+    let params = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => group,
+        Some(TokenTree::Punct(p)) if p.as_char() == '<' => {
+            return Err((p.span(), "`dicom_test` functions cannot be generic".to_string()))
+        }
+        other => {
+            let span = other.map_or_else(Span::call_site, |t| t.span());
+            return Err((span, "expected function parameters".to_string()));
+        }
+    };
+    let num_params = count_params(params.stream());
+
+    let mut rest: Vec<TokenTree> = tokens.collect();
+    let body = match rest.pop() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group,
+        other => {
+            let span = other.map_or_else(Span::call_site, |t| t.span());
+            return Err((span, "expected function body".to_string()));
+        }
+    };
+
+    Ok(TestFn {
+        attrs,
+        has_test_attr,
+        signature_prefix,
+        is_async,
+        name,
+        params,
+        num_params,
+        ret: rest,
+        body,
+    })
+}
+
+/// Whether the attribute's path ends with `test`,
+/// as in `#[test]` or `#[tokio::test]`.
+fn is_test_attr(attr: &Group) -> bool {
+    let mut last_ident = None;
+    for token in attr.stream() {
+        match token {
+            TokenTree::Ident(ident) => last_ident = Some(ident.to_string()),
+            TokenTree::Punct(p) if p.as_char() == ':' => {}
+            _ => break,
+        }
+    }
+    last_ident.as_deref() == Some("test")
+}
+
+/// Count the comma separated parameters of a function,
+/// ignoring commas nested in generic arguments.
+fn count_params(params: TokenStream) -> usize {
+    let mut count = 0;
+    let mut depth = 0usize;
+    let mut pending = false;
+    let mut prev_dash = false;
+    for token in params {
+        let mut is_dash = false;
+        match &token {
+            TokenTree::Punct(p) if p.as_char() == ',' && depth == 0 => {
+                if pending {
+                    count += 1;
+                }
+                pending = false;
+                continue;
+            }
+            TokenTree::Punct(p) if p.as_char() == '<' => depth += 1,
+            // `->` in function pointer types is not a closing bracket
+            TokenTree::Punct(p) if p.as_char() == '>' && !prev_dash => {
+                depth = depth.saturating_sub(1)
+            }
+            TokenTree::Punct(p) if p.as_char() == '-' => is_dash = true,
+            _ => {}
+        }
+        prev_dash = is_dash;
+        pending = true;
+    }
+    if pending {
+        count += 1;
+    }
+    count
+}
+
+fn parse(code: &str) -> TokenStream {
+    code.parse().expect("valid generated tokens")
+}
+
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let tokens = vec![
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("core", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            Some(TokenTree::Literal(Literal::string(message))).into_iter().collect(),
+        )),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ];
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
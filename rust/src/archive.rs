@@ -0,0 +1,144 @@
+//! Test files stored as members of ZIP archives
+
+use crate::{get_data_path, http, stored_url, Error, Result, TestFile};
+use sha2::{Digest, Sha256};
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// Serializes the downloads of archives,
+/// so that an archive shared by several files is only downloaded once
+static ARCHIVES: Mutex<()> = Mutex::new(());
+
+/// Fetch the member of a ZIP archive which is the given entry
+/// into its cached path.
+///
+/// The archive is downloaded on first use
+/// and kept in the cache for the other members,
+/// which are verified against their own hash once extracted.
+pub(crate) fn fetch_member(
+    entry: &TestFile,
+    archive: &str,
+    member: &str,
+    cached_path: &Path,
+) -> Result<()> {
+    let url = stored_url(entry.base_url, archive)?;
+    let archive_path = cached_archive(&url)?;
+    extract_member(entry, &archive_path, &url, member, cached_path)
+}
+
+/// The path of the archive at the given URL in the cache,
+/// downloading it if needed
+fn cached_archive(url: &str) -> Result<PathBuf> {
+    // one file per URL, as archives of different sources may share a name
+    let hash = format!("{:x}", Sha256::digest(url.as_bytes()));
+    let archives_dir = get_data_path().join(".archives");
+    let archive_path = archives_dir.join(format!("{}.zip", &hash[..16]));
+
+    let _guard = ARCHIVES.lock().unwrap_or_else(|e| e.into_inner());
+    if !archive_path.exists() {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(url, "archive cache miss");
+        std::fs::create_dir_all(&archives_dir)?;
+        let mut resp = http::get(url, &[])?;
+        let tempfile_path = crate::write_temp(&mut resp.body, &archives_dir)?;
+        std::fs::rename(tempfile_path, &archive_path)?;
+    }
+    Ok(archive_path)
+}
+
+#[cfg(feature = "zip")]
+fn extract_member(
+    entry: &TestFile,
+    archive_path: &Path,
+    url: &str,
+    member: &str,
+    cached_path: &Path,
+) -> Result<()> {
+    use std::fs;
+    use zip::{result::ZipError, ZipArchive};
+
+    let invalid = |e: ZipError| {
+        // the archive is downloaded again on the next attempt
+        let _ = fs::remove_file(archive_path);
+        Error::Download(format!("Invalid archive {}: {}", url, e))
+    };
+    let mut archive = ZipArchive::new(fs::File::open(archive_path)?).map_err(invalid)?;
+    let mut file = match archive.by_name(member) {
+        Ok(file) => file,
+        Err(ZipError::FileNotFound) => {
+            return Err(Error::Download(format!(
+                "Archive {} has no member {}",
+                url, member
+            )))
+        }
+        Err(e) => return Err(invalid(e)),
+    };
+
+    let parent_dir = cached_path.parent().unwrap();
+    fs::create_dir_all(parent_dir)?;
+    let tempfile_path = crate::write_temp(&mut file, parent_dir)?;
+    crate::install(entry, tempfile_path, cached_path).map_err(|e| e.downloaded_from(url))
+}
+
+#[cfg(not(feature = "zip"))]
+fn extract_member(
+    _entry: &TestFile,
+    _archive_path: &Path,
+    _url: &str,
+    _member: &str,
+    _cached_path: &Path,
+) -> Result<()> {
+    Err(Error::ZipRequired)
+}
+
+#[cfg(all(test, feature = "zip"))]
+mod tests {
+    use super::*;
+    use crate::{lookup, path, Compression};
+    use std::{fs, io::Write};
+    use zip::{write::FileOptions, ZipWriter};
+
+    #[test]
+    fn extract_zip_member() {
+        let mr = lookup("pydicom/MR_small.dcm").unwrap();
+        let contents = fs::read(path(mr.name).unwrap()).unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let archive_path = dir.path().join("collection.zip");
+        let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        writer
+            .start_file("images/mr.dcm", FileOptions::default())
+            .unwrap();
+        writer.write_all(&contents).unwrap();
+        writer
+            .start_file("images/other.dcm", FileOptions::default())
+            .unwrap();
+        writer.write_all(b"something else").unwrap();
+        fs::write(&archive_path, writer.finish().unwrap().into_inner()).unwrap();
+
+        let compression = Compression::ZipMember {
+            archive: "collection.zip",
+            member: "images/mr.dcm",
+        };
+        let entry = TestFile::new("myorg/mr.dcm", compression, mr.hash);
+        assert_eq!(entry.real_file_name(), "collection.zip");
+        let cached_path = dir.path().join("cache/myorg/mr.dcm");
+        let url = "https://example.org/collection.zip";
+        extract_member(&entry, &archive_path, url, "images/mr.dcm", &cached_path).unwrap();
+        assert_eq!(fs::read(&cached_path).unwrap(), contents);
+
+        // a member with other contents, or no member at all
+        let other_path = dir.path().join("cache/myorg/other.dcm");
+        assert!(matches!(
+            extract_member(&entry, &archive_path, url, "images/other.dcm", &other_path),
+            Err(Error::InvalidHash { .. })
+        ));
+        assert!(matches!(
+            extract_member(&entry, &archive_path, url, "images/nope.dcm", &other_path),
+            Err(Error::Download(_))
+        ));
+        assert!(archive_path.exists());
+    }
+}
@@ -0,0 +1,204 @@
+//! Hosting backends of the data set
+
+use crate::DigestAlgorithm;
+
+const GITHUB_URL: &str = "https://github.com";
+
+/// Where the test files of this project are downloaded from,
+/// see the [crate documentation](crate#hosting-backends).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// The raw contents of the git repository at the git ref (the default)
+    #[default]
+    RawGit,
+    /// The assets of the GitHub release tagged with the git ref,
+    /// one asset per stored test file
+    GithubReleases,
+    /// A content-addressed gateway serving each stored test file by its hash,
+    /// set by `DICOM_TEST_FILES_GATEWAY_URL`
+    ContentAddressed,
+    /// A generic artifact repository, such as Artifactory or Nexus,
+    /// with the URL template set by `DICOM_TEST_FILES_ARTIFACT_URL`
+    /// and the headers set by `DICOM_TEST_FILES_ARTIFACT_HEADERS`
+    Artifact,
+}
+
+impl Backend {
+    /// Parse the value of `DICOM_TEST_FILES_BACKEND`
+    pub(crate) fn parse(value: &str) -> Option<Backend> {
+        match value {
+            "raw-git" => Some(Backend::RawGit),
+            "github-releases" => Some(Backend::GithubReleases),
+            "content-addressed" => Some(Backend::ContentAddressed),
+            "artifact" => Some(Backend::Artifact),
+            _ => None,
+        }
+    }
+}
+
+/// The name of the release asset of a stored test file.
+///
+/// Asset names cannot hold directories,
+/// so the `/` of the path are replaced with `.`,
+/// see ./generate/release_assets.py
+pub(crate) fn release_asset_name(real_file_name: &str) -> String {
+    real_file_name.replace('/', ".")
+}
+
+/// The URL of the release asset of a stored test file
+pub(crate) fn release_asset_url(repository: &str, git_ref: &str, real_file_name: &str) -> String {
+    format!(
+        "{}/{}/releases/download/{}/{}",
+        GITHUB_URL,
+        repository,
+        git_ref,
+        release_asset_name(real_file_name)
+    )
+}
+
+/// The URL of a stored file with the given hash
+/// at the content-addressed gateway with the given base URL,
+/// see ./generate/content_addressed.py
+pub(crate) fn content_url(gateway_url: &str, digest: DigestAlgorithm, hash: &str) -> String {
+    format!("{}{}/{}", gateway_url, digest.name(), hash)
+}
+
+/// The URL of a stored file of this project
+/// from the URL template of an artifact repository, replacing
+///
+/// - `{path}` with the path of the file, such as `WG04/REF/CT1_UNC.zst`,
+/// - `{dir}` and `{file}` with its directory and file name,
+/// - `{asset}` with its release asset name, such as `WG04.REF.CT1_UNC.zst`,
+/// - `{ref}` with the git ref of the data set.
+///
+/// A template without placeholders is the base URL of the files.
+pub(crate) fn artifact_url(template: &str, git_ref: &str, real_file_name: &str) -> String {
+    if !template.contains('{') {
+        return format!("{}/{}", template.trim_end_matches('/'), real_file_name);
+    }
+    let (dir, file) = real_file_name
+        .rsplit_once('/')
+        .unwrap_or(("", real_file_name));
+    template
+        .replace("{path}", real_file_name)
+        .replace("{dir}", dir)
+        .replace("{file}", file)
+        .replace("{asset}", &release_asset_name(real_file_name))
+        .replace("{ref}", git_ref)
+}
+
+/// Whether the given URL is one of the artifact repository
+/// with the given URL template,
+/// by the part of the template before its first placeholder
+pub(crate) fn is_artifact_url(template: &str, url: &str) -> bool {
+    let prefix = template.split('{').next().unwrap_or_default();
+    match prefix.split_once("://") {
+        Some((_, host)) if !host.is_empty() => url.starts_with(prefix),
+        _ => false,
+    }
+}
+
+/// Parse the value of `DICOM_TEST_FILES_ARTIFACT_HEADERS`,
+/// comma-separated `name=value` pairs such as `X-JFrog-Art-Api=AKCp8…`
+pub(crate) fn parse_headers(value: &str) -> Result<Vec<(String, String)>, String> {
+    value
+        .split(',')
+        .filter(|header| !header.trim().is_empty())
+        .map(|header| match header.split_once('=') {
+            Some((name, value))
+                if !name.trim().is_empty() && !name.trim().contains(char::is_whitespace) =>
+            {
+                Ok((name.trim().to_string(), value.trim().to_string()))
+            }
+            _ => Err(format!("invalid header {:?}", header.trim())),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn release_assets() {
+        assert_eq!(
+            release_asset_url("robyoung/dicom-test-files", "v0.3.0", "WG04/REF/CT1_UNC.zst"),
+            "https://github.com/robyoung/dicom-test-files/releases/download/v0.3.0/WG04.REF.CT1_UNC.zst"
+        );
+        assert_eq!(
+            Backend::parse("github-releases"),
+            Some(Backend::GithubReleases)
+        );
+        assert_eq!(Backend::parse("raw-git"), Some(Backend::RawGit));
+        assert_eq!(
+            Backend::parse("content-addressed"),
+            Some(Backend::ContentAddressed)
+        );
+        assert_eq!(Backend::parse("artifact"), Some(Backend::Artifact));
+        assert_eq!(Backend::parse("s3"), None);
+    }
+
+    #[test]
+    fn content_addressed_urls() {
+        assert_eq!(
+            content_url("https://cas.example.org/", DigestAlgorithm::Sha256, "ee5c"),
+            "https://cas.example.org/sha256/ee5c"
+        );
+        assert_eq!(
+            content_url("http://127.0.0.1/cas/", DigestAlgorithm::Blake3, "af13"),
+            "http://127.0.0.1/cas/blake3/af13"
+        );
+    }
+
+    #[test]
+    fn artifact_urls() {
+        let template = "https://artifactory.example.org/artifactory/dicom/{ref}/{path}";
+        assert_eq!(
+            artifact_url(template, "v0.3.0", "WG04/REF/CT1_UNC.zst"),
+            "https://artifactory.example.org/artifactory/dicom/v0.3.0/WG04/REF/CT1_UNC.zst"
+        );
+        assert_eq!(
+            artifact_url(
+                "https://nexus.example.org/repository/raw/{dir}/{ref}/{file}?flat={asset}",
+                "main",
+                "pydicom/CT_small.dcm"
+            ),
+            "https://nexus.example.org/repository/raw/pydicom/main/CT_small.dcm?flat=pydicom.CT_small.dcm"
+        );
+        assert_eq!(
+            artifact_url(
+                "https://nexus.example.org/raw/",
+                "main",
+                "pydicom/CT_small.dcm"
+            ),
+            "https://nexus.example.org/raw/pydicom/CT_small.dcm"
+        );
+
+        assert!(is_artifact_url(
+            template,
+            "https://artifactory.example.org/artifactory/dicom/v0.3.0/bundles/wg04.tar.zst"
+        ));
+        assert!(!is_artifact_url(
+            template,
+            "https://raw.githubusercontent.com/robyoung/dicom-test-files/master/data/x.dcm"
+        ));
+        assert!(!is_artifact_url(
+            "https://{ref}.example.org/{path}",
+            "https://evil.example.com/"
+        ));
+    }
+
+    #[test]
+    fn artifact_headers() {
+        assert_eq!(
+            parse_headers("X-JFrog-Art-Api=AKCp8, Authorization=Basic dTpw=="),
+            Ok(vec![
+                ("X-JFrog-Art-Api".to_string(), "AKCp8".to_string()),
+                ("Authorization".to_string(), "Basic dTpw==".to_string()),
+            ])
+        );
+        assert_eq!(parse_headers(""), Ok(vec![]));
+        assert!(parse_headers("X-Api-Key").is_err());
+        assert!(parse_headers("X Api=1").is_err());
+    }
+}
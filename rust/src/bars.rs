@@ -0,0 +1,117 @@
+//! Progress bars of prefetches, with the `progress` feature
+//!
+//! A prefetch shows a bar of the test files processed so far,
+//! and a bar for each file being downloaded meanwhile.
+//! Bars are drawn on standard error, only when it is a terminal.
+
+use crate::DownloadEvent;
+#[cfg(feature = "progress")]
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+#[cfg(feature = "progress")]
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+/// The bars of the downloads in progress by URL,
+/// and the number of prefetches showing them
+#[cfg(feature = "progress")]
+static DOWNLOADS: Mutex<(usize, Option<HashMap<String, ProgressBar>>)> = Mutex::new((0, None));
+
+/// All bars, drawn together
+#[cfg(feature = "progress")]
+fn multi() -> &'static MultiProgress {
+    static MULTI: OnceLock<MultiProgress> = OnceLock::new();
+    MULTI.get_or_init(MultiProgress::new)
+}
+
+/// The progress bar of a prefetch, removed when dropped
+pub(crate) struct PrefetchBar {
+    #[cfg(feature = "progress")]
+    files: ProgressBar,
+}
+
+impl PrefetchBar {
+    /// Show the bar of a prefetch of the given number of test files
+    #[cfg(feature = "progress")]
+    pub(crate) fn start(total: usize) -> Self {
+        let files = multi().add(ProgressBar::new(total as u64));
+        files.set_style(
+            ProgressStyle::with_template(
+                "[{elapsed_precise}] {bar:40.cyan/blue} {pos}/{len} files (ETA {eta}) {wide_msg}",
+            )
+            .expect("valid template")
+            .progress_chars("=> "),
+        );
+        DOWNLOADS.lock().unwrap_or_else(|e| e.into_inner()).0 += 1;
+        PrefetchBar { files }
+    }
+
+    #[cfg(not(feature = "progress"))]
+    pub(crate) fn start(_total: usize) -> Self {
+        PrefetchBar {}
+    }
+
+    /// Count the given test file as processed
+    #[cfg(feature = "progress")]
+    pub(crate) fn processed(&self, name: &str) {
+        self.files.set_message(name.to_string());
+        self.files.inc(1);
+    }
+
+    #[cfg(not(feature = "progress"))]
+    pub(crate) fn processed(&self, _name: &str) {}
+}
+
+#[cfg(feature = "progress")]
+impl Drop for PrefetchBar {
+    fn drop(&mut self) {
+        self.files.finish_and_clear();
+        multi().remove(&self.files);
+        let mut downloads = DOWNLOADS.lock().unwrap_or_else(|e| e.into_inner());
+        downloads.0 -= 1;
+        if downloads.0 == 0 {
+            for (_, bar) in downloads.1.take().into_iter().flatten() {
+                bar.finish_and_clear();
+                multi().remove(&bar);
+            }
+        }
+    }
+}
+
+/// Update the bar of a download, while a prefetch is showing them
+#[cfg(feature = "progress")]
+pub(crate) fn on_download(event: DownloadEvent) {
+    let mut downloads = DOWNLOADS.lock().unwrap_or_else(|e| e.into_inner());
+    if downloads.0 == 0 {
+        return;
+    }
+    let bars = downloads.1.get_or_insert_with(HashMap::new);
+    match event {
+        DownloadEvent::Started { url, total } => {
+            let bar = multi().add(ProgressBar::new(total.unwrap_or(0)));
+            bar.set_style(
+                ProgressStyle::with_template("  {bytes:>10}/{total_bytes:<10} {wide_msg}")
+                    .expect("valid template"),
+            );
+            bar.set_message(url.to_string());
+            bars.insert(url.to_string(), bar);
+        }
+        DownloadEvent::Progress {
+            url, downloaded, ..
+        } => {
+            if let Some(bar) = bars.get(url) {
+                bar.set_position(downloaded);
+            }
+        }
+        DownloadEvent::Completed { url, .. } => {
+            if let Some(bar) = bars.remove(url) {
+                bar.finish_and_clear();
+                multi().remove(&bar);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "progress"))]
+pub(crate) fn on_download(_event: DownloadEvent) {}
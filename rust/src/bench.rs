@@ -0,0 +1,80 @@
+//! Support for benchmarks
+
+use crate::{fetch_verified, find_entry, Error};
+use std::{
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Whether benchmark data has been prepared,
+/// meaning that measurements may be underway.
+static MEASURING: AtomicBool = AtomicBool::new(false);
+
+/// Fetch and verify the given DICOM test files for a benchmark,
+/// returning their local paths.
+///
+/// Call this once in the benchmark's setup,
+/// outside of the measured code,
+/// so that download time does not leak into the measurements.
+/// Files which were already cached and are stored without compression
+/// are checked against their hash, and fetched again if corrupted.
+///
+/// Once this function has been called,
+/// any test file which still needs to be downloaded
+/// produces a loud warning on standard error,
+/// as it is likely to distort the measurements.
+///
+/// ```no_run
+/// let inputs = dicom_test_files::bench_data(&["pydicom/CT_small.dcm", "WG04/REF/CT1_UNC"]);
+/// // c.bench_function("open CT_small", |b| b.iter(|| open(&inputs[0])));
+/// # let _ = inputs;
+/// ```
+///
+/// # Panics
+///
+/// Panics if any of the files is unknown or cannot be fetched.
+pub fn bench_data(names: &[&str]) -> Vec<PathBuf> {
+    let paths = names
+        .iter()
+        .map(|name| prepare(name).unwrap_or_else(|e| {
+            panic!("failed to prepare benchmark data {}: {:?}", name, e)
+        }))
+        .collect();
+    MEASURING.store(true, Ordering::SeqCst);
+    paths
+}
+
+fn prepare(name: &str) -> Result<PathBuf, Error> {
+    let entry = find_entry(name)?;
+    fetch_verified(entry)
+}
+
+/// Warn if a test file is downloaded while benchmarks may be measuring.
+pub(crate) fn warn_if_measuring(name: &str) {
+    if MEASURING.load(Ordering::SeqCst) {
+        eprintln!(
+            "[dicom-test-files] WARNING: downloading {} after benchmark data was prepared! \
+             Timings may include the download, add it to `bench_data` instead.",
+            name
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_hash;
+
+    // `bench_data` itself is not called here,
+    // as it would flag the whole test process as measuring
+    #[test]
+    fn prepare_caches_and_verifies() {
+        for name in ["pydicom/MR_small.dcm", "pydicom/MR_small_RLE.dcm"] {
+            let path = prepare(name).unwrap();
+            assert!(path.ends_with(name));
+            check_hash(&path, find_entry(name).unwrap()).unwrap();
+            // second time around it is verified from the cache
+            assert_eq!(prepare(name).unwrap(), path);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! `dicom-test-files add <path> --category <dir> [--zstd]`,
+//! for maintainers of the data repository
+
+use crate::{option_value, usage, Failure, Outcome};
+use sha2::{Digest, Sha256};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Minimum share of the size saved by compression to keep it, in tenths
+#[cfg(feature = "zstd")]
+const MIN_SAVED_TENTHS: usize = 1;
+
+/// Add a file to the data repository in the current directory,
+/// compressed when it is worth it,
+/// and list it in the compiled entries
+pub fn run(mut args: impl Iterator<Item = String>) -> Outcome {
+    let mut source = None;
+    let mut category = None;
+    let mut zstd = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--category" => category = Some(option_value(&arg, &mut args)?),
+            "--zstd" => zstd = true,
+            _ if arg.starts_with('-') => {
+                return Err(usage(format_args!("unknown option {:?}", arg)))
+            }
+            _ if source.is_none() => source = Some(PathBuf::from(arg)),
+            _ => return Err(usage(format_args!("unexpected argument {:?}", arg))),
+        }
+    }
+    let source = source.ok_or_else(|| usage("missing file to add"))?;
+    let category = category.ok_or_else(|| usage("missing --category"))?;
+    let category = category.trim_matches('/');
+    if category.is_empty()
+        || category
+            .split('/')
+            .any(|dir| dir.is_empty() || dir == "." || dir == "..")
+    {
+        return Err(usage(format_args!("invalid category {:?}", category)));
+    }
+    let file_name = source
+        .file_name()
+        .and_then(|name| name.to_str())
+        .filter(|name| !name.contains(|c: char| c == '"' || c == '\\' || c.is_whitespace()))
+        .ok_or_else(|| usage(format_args!("unsupported file name {:?}", source)))?;
+    let name = format!("{}/{}", category, file_name);
+
+    let repository = repository_root()?;
+    let data_dir = repository.join("data");
+    // each top directory has a manifest feature
+    let top_dir = category.split('/').next().unwrap();
+    if !data_dir.join(top_dir).is_dir() {
+        return Err(usage(format_args!(
+            "unknown category {:?}, add a manifest feature to create one",
+            top_dir
+        )));
+    }
+    for extension in ["", ".zst", ".gz", ".xz"] {
+        if data_dir.join(format!("{}{}", name, extension)).exists() {
+            return Err(usage(format_args!(
+                "{} is already in the data repository",
+                name
+            )));
+        }
+    }
+
+    let contents = fs::read(&source)?;
+    let decompressed_hash = format!("{:x}", Sha256::digest(&contents));
+    let (stored, compression) = compress(contents, zstd)?;
+    let stored_name = match compression {
+        "zstd" => format!("{}.zst", name),
+        _ => name.clone(),
+    };
+    let hash = format!("{:x}", Sha256::digest(&stored));
+    let stored_path = data_dir.join(&stored_name);
+    fs::create_dir_all(stored_path.parent().unwrap())?;
+    fs::write(&stored_path, &stored)?;
+
+    let entries_path = entries_file(&repository);
+    let entries = fs::read_to_string(&entries_path)?;
+    let decompressed = match compression {
+        "zstd" => format!(".decompressed(\"{}\")", decompressed_hash),
+        _ => String::new(),
+    };
+    // with the provenance of its top directory, see ./generate/provenance.py
+    let entry = format!(
+        "    #[cfg(feature = \"manifest-{}\")]\n    TestFile::{}(\"{}\", \"{}\").size({}){}.provenance({}),\n",
+        top_dir.to_lowercase(),
+        compression,
+        name,
+        hash,
+        stored.len(),
+        decompressed,
+        top_dir.to_uppercase()
+    );
+    fs::write(&entries_path, insert_entry(&entries, &name, &entry)?)?;
+
+    println!(
+        "added {} as data/{} ({} bytes, sha256 {})",
+        name,
+        stored_name,
+        stored.len(),
+        hash
+    );
+    println!(
+        "run `make generate` to fill in its file info, groups and anonymization status, \
+         and `make manifest` to sign the manifest again"
+    );
+    Ok(())
+}
+
+/// The root of the data repository,
+/// holding the current directory
+fn repository_root() -> Result<PathBuf, Failure> {
+    let current_dir = env::current_dir()?;
+    current_dir
+        .ancestors()
+        .find(|dir| dir.join("data").is_dir() && entries_file(dir).is_file())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| {
+            let message = "not in the dicom-test-files repository";
+            io::Error::new(io::ErrorKind::NotFound, message).into()
+        })
+}
+
+/// The compiled entries in the data repository
+fn entries_file(repository: &Path) -> PathBuf {
+    repository.join("rust").join("src").join("entries.rs")
+}
+
+/// Compress the contents of a file with zstd
+/// if it saves enough space or if asked to,
+/// returning the stored contents and their compression
+#[cfg(feature = "zstd")]
+fn compress(contents: Vec<u8>, zstd: bool) -> Result<(Vec<u8>, &'static str), Failure> {
+    // with the content size in the frame, unlike a streamed encoding
+    let compressed = zstd::bulk::compress(&contents, 19)?;
+    if zstd || compressed.len() <= contents.len() - contents.len() * MIN_SAVED_TENTHS / 10 {
+        Ok((compressed, "zstd"))
+    } else {
+        Ok((contents, "none"))
+    }
+}
+
+#[cfg(not(feature = "zstd"))]
+fn compress(contents: Vec<u8>, zstd: bool) -> Result<(Vec<u8>, &'static str), Failure> {
+    if zstd {
+        return Err(dicom_test_files::Error::ZstdRequired.into());
+    }
+    Ok((contents, "none"))
+}
+
+/// Insert the lines of an entry among the compiled entries,
+/// in the order of the names as generated
+fn insert_entry(entries: &str, name: &str, entry: &str) -> Result<String, Failure> {
+    let start = entries
+        .find("pub static FILE_ENTRIES")
+        .and_then(|start| entries[start..].find('\n').map(|end| start + end + 1));
+    let end = start.and_then(|start| entries[start..].find("\n];").map(|end| start + end + 1));
+    let (start, end) = match (start, end) {
+        (Some(start), Some(end)) => (start, end),
+        _ => {
+            let message = "no FILE_ENTRIES in entries.rs";
+            return Err(io::Error::new(io::ErrorKind::InvalidData, message).into());
+        }
+    };
+
+    // before the attributes of the first entry with a greater name
+    let mut at = end;
+    let mut attributes = None;
+    let mut offset = start;
+    for line in entries[start..end].split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let line = line.trim_start();
+        if line.starts_with("#[") {
+            attributes.get_or_insert(line_start);
+        } else if let Some(entry) = line.strip_prefix("TestFile::") {
+            if entry.split('"').nth(1).unwrap_or_default() > name {
+                at = attributes.unwrap_or(line_start);
+                break;
+            }
+            attributes = None;
+        }
+    }
+    Ok(format!("{}{}{}", &entries[..at], entry, &entries[at..]))
+}
@@ -0,0 +1,39 @@
+//! `dicom-test-files clean [--all | --stale | --temp]`
+
+use crate::{usage, Outcome};
+
+/// What to remove from the cache
+enum Removal {
+    All,
+    Stale,
+    Temp,
+}
+
+/// Remove the whole cache,
+/// only the files which are not test files of the current manifest,
+/// or only the temporary files left over by crashed runs
+pub fn run(args: impl Iterator<Item = String>) -> Outcome {
+    let mut removal = Removal::All;
+    for arg in args {
+        match arg.as_str() {
+            "--all" => removal = Removal::All,
+            "--stale" => removal = Removal::Stale,
+            "--temp" => removal = Removal::Temp,
+            _ => return Err(usage(format_args!("unknown argument {:?}", arg))),
+        }
+    }
+
+    let removed = match removal {
+        Removal::All => {
+            dicom_test_files::clear_cache()?;
+            println!("removed {}", dicom_test_files::cache_dir().display());
+            return Ok(());
+        }
+        Removal::Stale => dicom_test_files::remove_stale_files()?,
+        Removal::Temp => dicom_test_files::clean_temp()?,
+    };
+    for path in removed {
+        println!("removed {}", path.display());
+    }
+    Ok(())
+}
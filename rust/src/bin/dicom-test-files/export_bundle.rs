@@ -0,0 +1,31 @@
+//! `dicom-test-files export-bundle [--glob <pattern>]... --out <path>`
+
+use crate::{option_value, usage, Outcome};
+use dicom_test_files::Filter;
+use std::path::PathBuf;
+
+/// Write the selected test files, verified,
+/// along with a manifest of their hashes into one archive,
+/// to unpack into the cache of a machine without network access
+pub fn run(mut args: impl Iterator<Item = String>) -> Outcome {
+    let mut filter: Option<Filter> = None;
+    let mut out = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--glob" => {
+                let glob = Filter::glob(option_value(&arg, &mut args)?);
+                filter = Some(match filter {
+                    Some(filter) => filter.or(glob),
+                    None => glob,
+                });
+            }
+            "--out" => out = Some(PathBuf::from(option_value(&arg, &mut args)?)),
+            _ => return Err(usage(format_args!("unknown argument {:?}", arg))),
+        }
+    }
+    let out = out.ok_or_else(|| usage("missing --out"))?;
+
+    let names = dicom_test_files::bundle_cache(filter.unwrap_or_default(), &out)?;
+    println!("{} test files written to {}", names.len(), out.display());
+    Ok(())
+}
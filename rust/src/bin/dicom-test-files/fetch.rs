@@ -0,0 +1,46 @@
+//! `dicom-test-files fetch [--raw] <name>...`
+
+use crate::{usage, Failure, Outcome};
+
+/// Fetch the test files of the given names into the cache,
+/// or their stored files with `--raw`,
+/// printing the path of each one,
+/// and fail if any of them could not be fetched
+pub fn run(args: impl Iterator<Item = String>) -> Outcome {
+    let mut raw = false;
+    let mut names = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--raw" => raw = true,
+            option if option.starts_with('-') => {
+                return Err(usage(format_args!("unknown option {:?}", option)))
+            }
+            _ => names.push(arg),
+        }
+    }
+    if names.is_empty() {
+        return Err(usage("missing test file names to fetch"));
+    }
+
+    let mut failed = false;
+    for name in &names {
+        // downloaded files are verified against their hash
+        let fetched = if raw {
+            dicom_test_files::raw_path(name)
+        } else {
+            dicom_test_files::path(name)
+        };
+        match fetched {
+            Ok(path) => println!("{}", path.display()),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        Err(Failure::Partial)
+    } else {
+        Ok(())
+    }
+}
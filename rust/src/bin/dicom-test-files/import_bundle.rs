@@ -0,0 +1,29 @@
+//! `dicom-test-files import-bundle <path>`
+
+use crate::{usage, Outcome};
+use dicom_test_files::cache_dir;
+use std::path::PathBuf;
+
+/// Unpack an archive written by `export-bundle` into the cache,
+/// checking each file against the manifest of the archive
+pub fn run(args: impl Iterator<Item = String>) -> Outcome {
+    let mut src = None;
+    for arg in args {
+        match arg.as_str() {
+            _ if arg.starts_with('-') => {
+                return Err(usage(format_args!("unknown option {:?}", arg)))
+            }
+            _ if src.is_none() => src = Some(PathBuf::from(arg)),
+            _ => return Err(usage(format_args!("unexpected argument {:?}", arg))),
+        }
+    }
+    let src = src.ok_or_else(|| usage("missing archive to import"))?;
+
+    let names = dicom_test_files::unbundle_cache(&src)?;
+    println!(
+        "{} test files unpacked into {}",
+        names.len(),
+        cache_dir().display()
+    );
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! `dicom-test-files list [--glob <pattern>] [--transfer-syntax <uid>]
+//! [--sop-class <uid>] [--json]`
+
+use crate::{human_size, option_value, usage, Outcome};
+use dicom_test_files::Filter;
+use std::io::{self, Write};
+
+/// List the test files selected by the options,
+/// with their sizes and whether they are cached
+pub fn run(mut args: impl Iterator<Item = String>) -> Outcome {
+    let mut filter = Filter::all();
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--glob" => filter = filter.and(Filter::glob(option_value(&arg, &mut args)?)),
+            "--transfer-syntax" => {
+                filter = filter.and(Filter::transfer_syntax(option_value(&arg, &mut args)?))
+            }
+            "--sop-class" => filter = filter.and(Filter::sop_class(option_value(&arg, &mut args)?)),
+            "--json" => json = true,
+            _ => return Err(usage(format_args!("unknown argument {:?}", arg))),
+        }
+    }
+
+    let mut entries = dicom_test_files::entries(filter)?;
+    entries.sort_by_key(|entry| entry.name);
+    let mut listed = Vec::with_capacity(entries.len());
+    for entry in entries {
+        listed.push((entry, dicom_test_files::cached_path(entry.name)?));
+    }
+
+    let mut out = io::stdout().lock();
+    if json {
+        let listed: Vec<serde_json::Value> = listed
+            .iter()
+            .map(|(entry, cached_path)| {
+                let cached_path = cached_path.as_ref().map(|path| path.display().to_string());
+                serde_json::json!({
+                    "name": entry.name,
+                    "size": entry.size,
+                    "transfer_syntax_uid": entry.info.transfer_syntax_uid,
+                    "sop_class_uid": entry.info.sop_class_uid,
+                    "license": entry.provenance.map(|provenance| provenance.license),
+                    "cached": cached_path.is_some(),
+                    "path": cached_path,
+                })
+            })
+            .collect();
+        writeln!(out, "{:#}", serde_json::Value::Array(listed))?;
+        return Ok(());
+    }
+
+    let width = listed
+        .iter()
+        .map(|(entry, _)| entry.name.len())
+        .max()
+        .unwrap_or(0);
+    for (entry, cached_path) in &listed {
+        let status = if cached_path.is_some() {
+            "cached"
+        } else {
+            "not cached"
+        };
+        writeln!(
+            out,
+            "{:width$}  {:>10}  {}",
+            entry.name,
+            human_size(entry.size),
+            status,
+            width = width
+        )?;
+    }
+    Ok(())
+}
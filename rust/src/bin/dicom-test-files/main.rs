@@ -0,0 +1,173 @@
+//! Command line interface to the DICOM test files,
+//! to manage the cache from shell scripts and Makefiles
+
+use std::{env, fmt, io, process::ExitCode};
+
+mod add;
+mod clean;
+mod export_bundle;
+mod fetch;
+mod import_bundle;
+mod list;
+mod prefetch;
+mod serve;
+mod stats;
+mod verify;
+
+const USAGE: &str = "\
+Usage: dicom-test-files <command> [arguments]
+
+Commands:
+  fetch <name>...  download and verify test files into the cache,
+                   printing their paths
+    --raw                      the stored files as served, such as .zst files,
+                               without decompressing them
+  list             list the available test files,
+                   with their sizes and whether they are cached
+    --glob <pattern>           only the files matching the glob pattern
+    --transfer-syntax <uid>    only the files in the transfer syntax
+    --sop-class <uid>          only the files of the SOP class
+    --json                     as a JSON array
+  prefetch         download the test files which are not cached yet,
+                   several at a time
+    --glob <pattern>           only the files matching the glob pattern,
+                               may be repeated (default: all test files)
+    --jobs <n>                 the number of files to download at a time
+    --decompress-jobs <n>      the number of files to decompress at a time,
+                               on workers of their own (default: as downloaded)
+    --max-size <size>          skip the files larger than the size,
+                               in bytes or with a K, M or G suffix
+  verify           hash the cached test files again, reporting corrupted ones
+  stats            report the location and disk usage of the cache,
+                   the test files cached by category and the largest of them
+    --top <n>                  the number of largest files to report (default: 10)
+    --json                     as a JSON object
+  clean            remove the cache
+    --all                      the whole cache (default)
+    --stale                    only the files missing from the current manifest
+    --temp                     only the temporary files left over by crashed runs
+  export-bundle    write verified test files along with a manifest of their hashes
+                   into one archive, for machines without network access
+    --glob <pattern>           only the files matching the glob pattern,
+                               may be repeated (default: all test files)
+    --out <path>               the archive to write, compressed with zstd
+                               if its name ends with .zst
+  import-bundle <path>
+                   unpack an archive written by export-bundle into the cache,
+                   checking each file against the manifest of the archive
+  serve            serve the data directory over HTTP, as a mirror
+                   for DICOM_TEST_FILES_URL on machines without internet access
+    --dir <dir>                the data directory to serve (default: data)
+    --port <port>              the port to listen on (default: 8080)
+    --bind <addr>              the address to listen on (default: 0.0.0.0)
+  add <path>       add a file to the data repository in the current directory,
+                   compressed with zstd if it saves space, and list it
+                   in the compiled entries, for maintainers
+    --category <dir>           the directory of the file in the data repository
+    --zstd                     compress the file in any case
+";
+
+/// Why a command did not succeed
+enum Failure {
+    /// wrong arguments, reported along with the usage
+    Usage(String),
+    /// an error which stopped the command
+    Error(Box<dyn std::error::Error>),
+    /// some of the work failed, and was reported as such
+    Partial,
+    /// the output was closed early, such as by `head`
+    Closed,
+}
+
+impl From<dicom_test_files::Error> for Failure {
+    fn from(e: dicom_test_files::Error) -> Self {
+        Failure::Error(e.into())
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(e: io::Error) -> Self {
+        if e.kind() == io::ErrorKind::BrokenPipe {
+            return Failure::Closed;
+        }
+        Failure::Error(e.into())
+    }
+}
+
+/// The outcome of a command
+type Outcome = Result<(), Failure>;
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let outcome = match args.next().as_deref() {
+        Some("fetch") => fetch::run(args),
+        Some("list") => list::run(args),
+        Some("prefetch") => prefetch::run(args),
+        Some("verify") => verify::run(args),
+        Some("stats") => stats::run(args),
+        Some("clean") => clean::run(args),
+        Some("export-bundle") => export_bundle::run(args),
+        Some("import-bundle") => import_bundle::run(args),
+        Some("serve") => serve::run(args),
+        Some("add") => add::run(args),
+        Some("-h" | "--help" | "help") => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+        Some("-V" | "--version") => {
+            println!("dicom-test-files {}", env!("CARGO_PKG_VERSION"));
+            Ok(())
+        }
+        Some(command) => Err(usage(format_args!("unknown command {:?}", command))),
+        None => Err(usage("missing command")),
+    };
+    match outcome {
+        Ok(()) | Err(Failure::Closed) => ExitCode::SUCCESS,
+        Err(Failure::Usage(message)) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+        Err(Failure::Error(e)) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+        Err(Failure::Partial) => ExitCode::FAILURE,
+    }
+}
+
+/// A failure due to wrong arguments
+fn usage(message: impl fmt::Display) -> Failure {
+    Failure::Usage(message.to_string())
+}
+
+/// The value following an option on the command line
+fn option_value(option: &str, args: &mut impl Iterator<Item = String>) -> Result<String, Failure> {
+    args.next()
+        .ok_or_else(|| usage(format_args!("missing value for {}", option)))
+}
+
+/// A size in bytes in a human readable unit
+fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.;
+    let mut unit = 0;
+    while size >= 1024. && unit + 1 < UNITS.len() {
+        size /= 1024.;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Parse a number of bytes with an optional binary unit suffix
+fn parse_size(value: &str) -> Option<u64> {
+    let (digits, unit) = match value.char_indices().last()? {
+        (i, 'K') | (i, 'k') => (&value[..i], 1 << 10),
+        (i, 'M') | (i, 'm') => (&value[..i], 1 << 20),
+        (i, 'G') | (i, 'g') => (&value[..i], 1 << 30),
+        _ => (value, 1),
+    };
+    digits.parse::<u64>().ok()?.checked_mul(unit)
+}
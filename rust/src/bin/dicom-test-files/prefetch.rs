@@ -0,0 +1,82 @@
+//! `dicom-test-files prefetch [--glob <pattern>]... [--jobs <n>] [--decompress-jobs <n>] [--max-size <size>]`
+
+use crate::{option_value, parse_size, usage, Failure, Outcome};
+use dicom_test_files::{Config, FetchOutcome, Filter, Prefetch};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Fetch the selected test files into the cache,
+/// reporting the progress on standard error,
+/// and fail if any of them could not be fetched
+pub fn run(mut args: impl Iterator<Item = String>) -> Outcome {
+    let mut filter: Option<Filter> = None;
+    let mut jobs = None;
+    let mut decompress_jobs = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--glob" => {
+                let glob = Filter::glob(option_value(&arg, &mut args)?);
+                filter = Some(match filter {
+                    Some(filter) => filter.or(glob),
+                    None => glob,
+                });
+            }
+            "--jobs" => {
+                let value = option_value(&arg, &mut args)?;
+                match value.parse::<usize>() {
+                    Ok(n) if n > 0 => jobs = Some(n),
+                    _ => return Err(usage(format_args!("invalid number of jobs {:?}", value))),
+                }
+            }
+            "--decompress-jobs" => {
+                let value = option_value(&arg, &mut args)?;
+                match value.parse::<usize>() {
+                    Ok(n) if n > 0 => decompress_jobs = Some(n),
+                    _ => return Err(usage(format_args!("invalid number of jobs {:?}", value))),
+                }
+            }
+            "--max-size" => {
+                let value = option_value(&arg, &mut args)?;
+                let max_size = parse_size(&value)
+                    .ok_or_else(|| usage(format_args!("invalid size {:?}", value)))?;
+                Config::new().max_size(max_size).apply()?;
+            }
+            _ => return Err(usage(format_args!("unknown argument {:?}", arg))),
+        }
+    }
+
+    static FAILED: AtomicUsize = AtomicUsize::new(0);
+    let mut prefetch = Prefetch::new(filter.unwrap_or_default()).on_progress(|p| {
+        let outcome = match &p.outcome {
+            FetchOutcome::Cached => "cached".to_string(),
+            FetchOutcome::Fetched => "fetched".to_string(),
+            FetchOutcome::Skipped => "skipped, over the size limit".to_string(),
+            FetchOutcome::Failed(e) => {
+                FAILED.fetch_add(1, Ordering::SeqCst);
+                format!("failed: {}", e)
+            }
+        };
+        eprintln!("[{}/{}] {}: {}", p.completed, p.total, p.name, outcome);
+    });
+    if let Some(jobs) = jobs {
+        prefetch = prefetch.jobs(jobs);
+    }
+    if let Some(jobs) = decompress_jobs {
+        prefetch = prefetch.decompress_jobs(jobs);
+    }
+    let summary = match prefetch.run() {
+        Ok(summary) => summary,
+        // already reported
+        Err(_) if FAILED.load(Ordering::SeqCst) > 0 => {
+            eprintln!("{} test files failed", FAILED.load(Ordering::SeqCst));
+            return Err(Failure::Partial);
+        }
+        Err(e) => return Err(e.into()),
+    };
+    println!(
+        "{} fetched, {} cached, {} skipped",
+        summary.fetched.len(),
+        summary.cached.len(),
+        summary.skipped.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,208 @@
+//! `dicom-test-files serve [--dir <dir>] [--port <port>] [--bind <addr>]`
+
+use crate::{option_value, usage, Outcome};
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Write},
+    net::{IpAddr, TcpListener, TcpStream},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+    time::UNIX_EPOCH,
+};
+
+const DEFAULT_PORT: u16 = 8080;
+
+/// Serve the files of a data directory over HTTP,
+/// as a mirror of the data repository for `DICOM_TEST_FILES_URL`
+pub fn run(mut args: impl Iterator<Item = String>) -> Outcome {
+    let mut dir = PathBuf::from("data");
+    let mut port = DEFAULT_PORT;
+    let mut bind = IpAddr::from([0, 0, 0, 0]);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dir" => dir = PathBuf::from(option_value(&arg, &mut args)?),
+            "--port" => {
+                let value = option_value(&arg, &mut args)?;
+                port = value
+                    .parse()
+                    .map_err(|_| usage(format_args!("invalid port {:?}", value)))?;
+            }
+            "--bind" => {
+                let value = option_value(&arg, &mut args)?;
+                bind = value
+                    .parse()
+                    .map_err(|_| usage(format_args!("invalid address {:?}", value)))?;
+            }
+            _ => return Err(usage(format_args!("unknown argument {:?}", arg))),
+        }
+    }
+    if !dir.is_dir() {
+        return Err(usage(format_args!(
+            "{} is not a directory, pass the data directory with --dir",
+            dir.display()
+        )));
+    }
+
+    let listener = TcpListener::bind((bind, port))?;
+    let addr = listener.local_addr()?;
+    println!("serving {} at http://{}/", dir.display(), addr);
+    println!(
+        "point DICOM_TEST_FILES_URL at http://<this host>:{}/",
+        addr.port()
+    );
+    io::stdout().flush()?;
+
+    let dir = Arc::new(dir);
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("error: {}", e);
+                continue;
+            }
+        };
+        let dir = Arc::clone(&dir);
+        std::thread::spawn(move || {
+            if let Err(e) = serve_connection(stream, &dir) {
+                eprintln!("error: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Answer the requests of a connection until it is closed
+fn serve_connection(mut stream: TcpStream, dir: &Path) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    loop {
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line)? == 0 {
+            return Ok(());
+        }
+        let mut etag_match = None;
+        let mut close = false;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                let value = value.trim();
+                if name.eq_ignore_ascii_case("If-None-Match") {
+                    etag_match = Some(value.to_string());
+                } else if name.eq_ignore_ascii_case("Connection") {
+                    close = value.eq_ignore_ascii_case("close");
+                }
+            }
+        }
+
+        let mut fields = request_line.split_whitespace();
+        let (method, target) = match (fields.next(), fields.next()) {
+            (Some(method), Some(target)) => (method, target),
+            _ => return respond(&mut stream, "400 Bad Request", "", true),
+        };
+        let head = method == "HEAD";
+        if !head && method != "GET" {
+            respond(
+                &mut stream,
+                "405 Method Not Allowed",
+                "Allow: GET, HEAD\r\n",
+                close,
+            )?;
+        } else if let Some(path) = file_path(dir, target).filter(|path| path.is_file()) {
+            let status = serve_file(&mut stream, &path, head, etag_match.as_deref(), close)?;
+            eprintln!("{} {} {}", method, target, status);
+        } else {
+            respond(&mut stream, "404 Not Found", "", close)?;
+            eprintln!("{} {} 404", method, target);
+        }
+        if close {
+            return Ok(());
+        }
+    }
+}
+
+/// The file of the data directory at the path of a request target,
+/// unless it leaves the directory
+fn file_path(dir: &Path, target: &str) -> Option<PathBuf> {
+    let path = target.split(['?', '#']).next()?;
+    let path = percent_decode(path.strip_prefix('/')?)?;
+    let path = Path::new(&path);
+    if path.components().all(|c| matches!(c, Component::Normal(_))) {
+        Some(dir.join(path))
+    } else {
+        None
+    }
+}
+
+/// Decode the escaped bytes of a path
+fn percent_decode(path: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut rest = path.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Send a file, or only its headers,
+/// returning the status code sent
+fn serve_file(
+    stream: &mut TcpStream,
+    path: &Path,
+    head: bool,
+    etag_match: Option<&str>,
+    close: bool,
+) -> io::Result<u16> {
+    let mut file = fs::File::open(path)?;
+    let metadata = file.metadata()?;
+    let modified = metadata
+        .modified()?
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    // changes along with the file, which is enough to revalidate cached copies
+    let etag = format!("\"{:x}-{:x}\"", metadata.len(), modified.as_secs());
+    if etag_match == Some(etag.as_str()) {
+        let headers = format!("ETag: {}\r\n", etag);
+        respond(stream, "304 Not Modified", &headers, close)?;
+        return Ok(304);
+    }
+    let headers = format!(
+        "Content-Type: application/octet-stream\r\nContent-Length: {}\r\nETag: {}\r\n",
+        metadata.len(),
+        etag
+    );
+    write_head(stream, "200 OK", &headers, close)?;
+    if !head {
+        io::copy(&mut file, stream)?;
+    }
+    stream.flush()?;
+    Ok(200)
+}
+
+/// Send a response without a body
+fn respond(stream: &mut TcpStream, status: &str, headers: &str, close: bool) -> io::Result<()> {
+    let headers = format!("{}Content-Length: 0\r\n", headers);
+    write_head(stream, status, &headers, close)?;
+    stream.flush()
+}
+
+fn write_head(stream: &mut TcpStream, status: &str, headers: &str, close: bool) -> io::Result<()> {
+    let connection = if close { "Connection: close\r\n" } else { "" };
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\n{}{}\r\n",
+        status, headers, connection
+    )
+}
@@ -0,0 +1,129 @@
+//! `dicom-test-files stats [--top <n>] [--json]`
+
+use crate::{human_size, option_value, usage, Outcome};
+use dicom_test_files::{cache_dir, Filter};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+/// Number of the largest cached files reported by default
+const DEFAULT_TOP: usize = 10;
+
+/// The test files of a category, as cached
+#[derive(Default)]
+struct Category {
+    cached: usize,
+    total: usize,
+    bytes: u64,
+}
+
+/// Report the location and disk usage of the cache,
+/// the test files cached in each category
+/// and the largest of them
+pub fn run(mut args: impl Iterator<Item = String>) -> Outcome {
+    let mut top = DEFAULT_TOP;
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--top" => {
+                let value = option_value(&arg, &mut args)?;
+                top = value
+                    .parse()
+                    .map_err(|_| usage(format_args!("invalid number of files {:?}", value)))?;
+            }
+            "--json" => json = true,
+            _ => return Err(usage(format_args!("unknown argument {:?}", arg))),
+        }
+    }
+
+    let mut categories: BTreeMap<&str, Category> = BTreeMap::new();
+    let mut cached = Vec::new();
+    for entry in dicom_test_files::entries(Filter::all())? {
+        let name = entry.name.split('/').next().unwrap_or_default();
+        let category = categories.entry(name).or_default();
+        category.total += 1;
+        if let Some(path) = dicom_test_files::cached_path(entry.name)? {
+            let bytes = fs::metadata(&path)?.len();
+            category.cached += 1;
+            category.bytes += bytes;
+            cached.push((entry.name, bytes));
+        }
+    }
+    cached.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then(a_name.cmp(b_name)));
+    cached.truncate(top);
+    // generated files and partial downloads take space as well
+    let cache_dir = cache_dir();
+    let used = dir_size(&cache_dir)?;
+
+    let mut out = io::stdout().lock();
+    if json {
+        let categories: Vec<serde_json::Value> = categories
+            .iter()
+            .map(|(name, category)| {
+                serde_json::json!({
+                    "name": *name,
+                    "cached": category.cached,
+                    "total": category.total,
+                    "bytes": category.bytes,
+                })
+            })
+            .collect();
+        let largest: Vec<serde_json::Value> = cached
+            .iter()
+            .map(|(name, bytes)| serde_json::json!({ "name": *name, "bytes": *bytes }))
+            .collect();
+        let stats = serde_json::json!({
+            "path": cache_dir.display().to_string(),
+            "bytes": used,
+            "categories": categories,
+            "largest": largest,
+        });
+        writeln!(out, "{:#}", stats)?;
+        return Ok(());
+    }
+
+    writeln!(out, "cache: {} ({})", cache_dir.display(), human_size(used))?;
+    let width = categories.keys().map(|name| name.len()).max().unwrap_or(0);
+    writeln!(out)?;
+    for (name, category) in &categories {
+        writeln!(
+            out,
+            "{:width$}  {:>5} of {:<5} cached  {:>10}",
+            name,
+            category.cached,
+            category.total,
+            human_size(category.bytes),
+            width = width
+        )?;
+    }
+    if !cached.is_empty() {
+        writeln!(out, "\nlargest cached files:")?;
+        for (name, bytes) in &cached {
+            writeln!(out, "{:>10}  {}", human_size(*bytes), name)?;
+        }
+    }
+    Ok(())
+}
+
+/// The number of bytes taken by the files in a directory, recursively
+fn dir_size(dir: &Path) -> io::Result<u64> {
+    let files = match fs::read_dir(dir) {
+        Ok(files) => files,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+    let mut size = 0;
+    for file in files {
+        let file = file?;
+        let file_type = file.file_type()?;
+        if file_type.is_dir() {
+            size += dir_size(&file.path())?;
+        } else if file_type.is_file() {
+            size += file.metadata()?.len();
+        }
+    }
+    Ok(size)
+}
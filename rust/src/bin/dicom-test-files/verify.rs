@@ -0,0 +1,49 @@
+//! `dicom-test-files verify`
+
+use crate::{usage, Failure, Outcome};
+use dicom_test_files::HashCheck;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Hash the cached test files again,
+/// reporting those which do not match
+pub fn run(mut args: impl Iterator<Item = String>) -> Outcome {
+    if let Some(arg) = args.next() {
+        return Err(usage(format_args!("unknown argument {:?}", arg)));
+    }
+
+    let (valid, mismatches, unchecked) = (
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+        AtomicUsize::new(0),
+    );
+    // reported as soon as hashed, in parallel with the `rayon` feature
+    dicom_test_files::verify_cache_with(|entry, check| match check {
+        HashCheck::Valid => {
+            valid.fetch_add(1, Ordering::Relaxed);
+        }
+        HashCheck::Mismatch => {
+            let path = dicom_test_files::cache_dir().join(entry.name);
+            eprintln!(
+                "error: {} does not match its hash: {}",
+                entry.name,
+                path.display()
+            );
+            mismatches.fetch_add(1, Ordering::Relaxed);
+        }
+        HashCheck::Unchecked => {
+            unchecked.fetch_add(1, Ordering::Relaxed);
+        }
+    })?;
+    let mismatches = mismatches.into_inner();
+    println!(
+        "{} valid, {} corrupted, {} unchecked (cached decompressed)",
+        valid.into_inner(),
+        mismatches,
+        unchecked.into_inner()
+    );
+    if mismatches > 0 {
+        eprintln!("remove the corrupted files to fetch them again");
+        return Err(Failure::Partial);
+    }
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! Helpers for use in downstream build scripts.
+//!
+//! Add `dicom-test-files` to your `[build-dependencies]`
+//! (in addition to `[dev-dependencies]`)
+//! to fetch test files while the crate is being built,
+//! instead of on the first test run.
+
+use crate::{entry_path, path, Error, Result};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Environment variables which affect where test files are fetched from.
+const ENV_VARS: &[&str] = &[
+    "DICOM_TEST_FILES_URL",
+    "DICOM_TEST_FILES_REF",
+    "DICOM_TEST_FILES_BACKEND",
+    "DICOM_TEST_FILES_MAX_SIZE",
+    "DICOM_TEST_FILES_REMOTE_MANIFEST",
+    "DICOM_TEST_FILES_MANIFEST",
+    "DICOM_TEST_FILES_LOCK",
+    "DICOM_TEST_FILES_LOCKFILE",
+    "DICOM_TEST_FILES_REFRESH",
+    "DICOM_TEST_FILES_MAX_DOWNLOADS",
+    "DICOM_TEST_FILES_GCS_ENDPOINT",
+    "DICOM_TEST_FILES_AZURE_ENDPOINT",
+    "DICOM_TEST_FILES_TRUSTED_KEYS",
+    "DICOM_TEST_FILES_REQUIRE_SIGNATURES",
+    "DICOM_TEST_FILES_GDCM_URL",
+    "DICOM_TEST_FILES_CLUNIE_URL",
+    "DICOM_TEST_FILES_MAX_RATE",
+    "DICOM_TEST_FILES_MAX_CACHE_BYTES",
+    "DICOM_TEST_FILES_KEEP_COMPRESSED",
+    "DICOM_TEST_FILES_CDN_URL",
+    "DICOM_TEST_FILES_LFS_URL",
+    "DICOM_TEST_FILES_GATEWAY_URL",
+    "DICOM_TEST_FILES_ARTIFACT_URL",
+    "DICOM_TEST_FILES_ARTIFACT_HEADERS",
+    "DICOM_TEST_FILES_NAMESPACE",
+    "DICOM_TEST_FILES_FALLBACK_DIR",
+];
+
+/// Fetch the given DICOM test files into the local cache
+/// from a build script,
+/// returning their paths.
+///
+/// Cargo is told to rerun the build script
+/// if any of the cached files goes missing
+/// or the data source is reconfigured.
+/// Note that, as with any `rerun-if` instruction,
+/// the build script will then no longer rerun
+/// on every change to the package sources,
+/// so add `cargo:rerun-if-changed=build.rs` if you rely on that.
+///
+/// ```no_run
+/// // in build.rs
+/// dicom_test_files::build_support::prefetch(&[
+///     "WG04/REF/CT1_UNC",
+///     "WG04/REF/MR1_UNC",
+/// ])
+/// .expect("failed to fetch DICOM test files");
+/// ```
+pub fn prefetch(names: &[&str]) -> Result<Vec<PathBuf>> {
+    for var in ENV_VARS {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+    names
+        .iter()
+        .map(|name| {
+            let cached_path = path(name)?;
+            println!("cargo:rerun-if-changed={}", cached_path.display());
+            Ok(cached_path)
+        })
+        .collect()
+}
+
+/// Fetch the given DICOM test files and copy them into the build script's
+/// output directory, so that they can be embedded with [`include_dicom_bytes!`].
+///
+/// This must be called from a build script,
+/// as it relies on the `OUT_DIR` environment variable.
+///
+/// ```no_run
+/// // in build.rs
+/// dicom_test_files::build_support::embed(&["pydicom/CT_small.dcm"])
+///     .expect("failed to fetch DICOM test files");
+/// ```
+///
+/// [`include_dicom_bytes!`]: crate::include_dicom_bytes
+pub fn embed(names: &[&str]) -> Result<()> {
+    let out_dir = env::var_os("OUT_DIR").ok_or_else(|| {
+        Error::Io(io::Error::new(
+            io::ErrorKind::NotFound,
+            "OUT_DIR is not set, `embed` must be called from a build script",
+        ))
+    })?;
+    embed_into(names, Path::new(&out_dir))?;
+    Ok(())
+}
+
+/// Copy the given test files into `out_dir/dicom_test_files`,
+/// returning the paths of the copies.
+fn embed_into(names: &[&str], out_dir: &Path) -> Result<Vec<PathBuf>> {
+    let embed_dir = out_dir.join("dicom_test_files");
+    let cached_paths = prefetch(names)?;
+    names
+        .iter()
+        .zip(cached_paths)
+        .map(|(name, cached_path)| {
+            let target = entry_path(&embed_dir, name);
+            fs::create_dir_all(target.parent().unwrap())?;
+            fs::copy(&cached_path, &target)?;
+            println!("cargo:rerun-if-changed={}", target.display());
+            Ok(target)
+        })
+        .collect::<Result<Vec<PathBuf>, Error>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefetch_returns_cached_paths() {
+        let paths = prefetch(&["pydicom/liver.dcm", "WG04/JPLY/NM1_JPLY"]).unwrap();
+
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with("pydicom/liver.dcm"));
+        assert!(paths[1].ends_with("WG04/JPLY/NM1_JPLY"));
+        assert!(paths.iter().all(|p| p.exists()));
+    }
+
+    #[test]
+    fn embed_into_out_dir() {
+        let out_dir = tempfile::tempdir().unwrap();
+
+        let paths = embed_into(&["pydicom/liver.dcm"], out_dir.path()).unwrap();
+
+        assert_eq!(
+            paths,
+            vec![out_dir.path().join("dicom_test_files/pydicom/liver.dcm")]
+        );
+        assert_eq!(
+            fs::read(&paths[0]).unwrap(),
+            fs::read(path("pydicom/liver.dcm").unwrap()).unwrap(),
+        );
+    }
+}
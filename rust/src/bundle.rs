@@ -0,0 +1,163 @@
+//! Bundles of test files, downloaded as a single archive
+
+use crate::TestFile;
+#[cfg(feature = "bundles")]
+use crate::{
+    config, entries::BUNDLES, entry_path, gateway_url, get_data_path, http, install, max_size,
+    stored_url, test_file::Bundle, verify_hash, write_temp, Backend, DigestAlgorithm, Error,
+    Result,
+};
+#[cfg(feature = "bundles")]
+use std::{fs, path::Path};
+
+/// Fetch the bundles holding more than one of the given entries
+/// which are not cached yet,
+/// unpacking these entries into the cache.
+/// Returns the names of the unpacked entries.
+///
+/// Bundles which cannot be fetched are left out,
+/// their members are then downloaded one by one.
+#[cfg(feature = "bundles")]
+pub(crate) fn fetch_bundles(entries: &[&'static TestFile]) -> Vec<&'static str> {
+    let data_path = get_data_path();
+    let mut unpacked = Vec::new();
+    for bundle in BUNDLES {
+        let members: Vec<&'static TestFile> = entries
+            .iter()
+            .copied()
+            .filter(|entry| {
+                entry.base_url.is_none()
+                    && entry.real_file_name().starts_with(bundle.prefix)
+                    && !entry_path(&data_path, entry.name).exists()
+            })
+            .collect();
+        if members.len() < 2 || max_size().is_some_and(|max_size| bundle.size > max_size) {
+            continue;
+        }
+        match fetch_bundle(bundle, &members, &data_path) {
+            Ok(names) => {
+                #[cfg(feature = "tracing")]
+                tracing::info!(bundle = bundle.name, files = names.len(), "unpacked bundle");
+                unpacked.extend(names)
+            }
+            // not hosted by this data source
+            Err(Error::Download(_)) => {}
+            Err(e) => eprintln!(
+                "[dicom-test-files] Failed to fetch bundle {}: {:?}",
+                bundle.name, e
+            ),
+        }
+    }
+    unpacked
+}
+
+#[cfg(not(feature = "bundles"))]
+pub(crate) fn fetch_bundles(_entries: &[&'static TestFile]) -> Vec<&'static str> {
+    Vec::new()
+}
+
+/// Download and verify a bundle, then unpack the given members
+#[cfg(feature = "bundles")]
+fn fetch_bundle(
+    bundle: &Bundle,
+    members: &[&'static TestFile],
+    data_path: &Path,
+) -> Result<Vec<&'static str>> {
+    let url = match config::backend() {
+        Backend::ContentAddressed => gateway_url(DigestAlgorithm::Sha256, bundle.hash)?,
+        _ => stored_url(None, bundle.name)?,
+    };
+    let mut resp = http::get(&url, &[])?;
+
+    fs::create_dir_all(data_path)?;
+    let archive_path =
+        write_temp(&mut resp.body, data_path).map_err(|e| e.downloading(bundle.name))?;
+    verify_hash(&archive_path, bundle.name, bundle.hash).map_err(|e| e.downloaded_from(&url))?;
+    let unpacked = unpack(&archive_path, members, data_path);
+    fs::remove_file(&archive_path).unwrap_or_else(|e| {
+        eprintln!("[dicom-test-files] Failed to remove temporary file: {}", e);
+    });
+    unpacked
+}
+
+/// Unpack the given members of a `.tar.zst` archive into the cache
+/// at the given path, verifying each of them.
+///
+/// Members of the archive are only written to the cached paths
+/// of known entries,
+/// and members which do not match their entry are left out.
+#[cfg(feature = "bundles")]
+fn unpack(
+    archive_path: &Path,
+    members: &[&'static TestFile],
+    data_path: &Path,
+) -> Result<Vec<&'static str>> {
+    let decoder = zstd::Decoder::new(fs::File::open(archive_path)?)?;
+    let mut archive = tar::Archive::new(decoder);
+    let mut unpacked = Vec::new();
+    for member in archive.entries()? {
+        let mut member = member?;
+        if !member.header().entry_type().is_file() {
+            continue;
+        }
+        let stored_name = member.path()?.to_string_lossy().into_owned();
+        let entry = match members
+            .iter()
+            .find(|entry| entry.real_file_name() == stored_name)
+        {
+            Some(entry) => *entry,
+            None => continue,
+        };
+
+        let cached_path = entry_path(data_path, entry.name);
+        let parent_dir = cached_path.parent().unwrap();
+        fs::create_dir_all(parent_dir)?;
+        let tempfile_path = write_temp(&mut member, parent_dir)?;
+        match install(entry, tempfile_path, &cached_path) {
+            Ok(()) => unpacked.push(entry.name),
+            Err(Error::InvalidHash { .. }) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(unpacked)
+}
+
+#[cfg(all(test, feature = "bundles"))]
+mod tests {
+    use super::*;
+    use crate::{lookup, path};
+
+    #[test]
+    fn unpack_bundle() {
+        let names = ["pydicom/MR_small.dcm", "pydicom/emri_small.dcm"];
+        let members: Vec<_> = names.iter().map(|name| lookup(name).unwrap()).collect();
+
+        let dir = tempfile::tempdir().unwrap();
+        let archive_path = dir.path().join("pydicom.tar.zst");
+        let encoder = zstd::Encoder::new(fs::File::create(&archive_path).unwrap(), 3).unwrap();
+        let mut builder = tar::Builder::new(encoder);
+        let mut append = |name: &str, data: &[u8]| {
+            let mut header = tar::Header::new_ustar();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            builder.append_data(&mut header, name, data).unwrap();
+        };
+        for name in names {
+            append(name, &fs::read(path(name).unwrap()).unwrap());
+        }
+        append("pydicom/unknown.dcm", b"not a known entry");
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let data_path = dir.path().join("cache");
+        let mut unpacked = unpack(&archive_path, &members, &data_path).unwrap();
+        unpacked.sort_unstable();
+        assert_eq!(unpacked, names);
+        for name in names {
+            assert_eq!(
+                fs::read(data_path.join(name)).unwrap(),
+                fs::read(path(name).unwrap()).unwrap()
+            );
+        }
+        assert!(!data_path.join("pydicom/unknown.dcm").exists());
+    }
+}
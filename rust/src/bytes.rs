@@ -0,0 +1,240 @@
+//! Contents of test files in memory,
+//! which in WebAssembly are fetched by the browser
+
+use crate::{config, Error, Result};
+use std::sync::Arc;
+
+/// Fetch a DICOM file by its relative path (`name`) like [`path`](crate::path),
+/// and return its contents.
+///
+/// Natively, the file is fetched into the cache and read from there,
+/// blocking the current thread.
+/// In WebAssembly with the `wasm` feature,
+/// where there is no file system to cache files in,
+/// the file is downloaded with the fetch API of the browser or worker
+/// and kept in memory for the rest of the session,
+/// so that the same tests run in both.
+///
+/// Files over the [limit of the files read into memory](crate#size-limit)
+/// fail with [`Error::TooLargeForMemory`],
+/// see [`size`](crate::size) and [`reader`](crate::reader) for those.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), dicom_test_files::Error> {
+/// let liver = dicom_test_files::bytes("pydicom/liver.dcm").await?;
+/// assert_eq!(&liver[128..132], b"DICM");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn bytes(name: &str) -> Result<Arc<[u8]>> {
+    let entry = crate::resolve(name)?;
+    // files stored with compression are larger once decompressed
+    check_in_memory(entry.name, entry.size)?;
+    let path = crate::path(name)?;
+    check_in_memory(entry.name, std::fs::metadata(&path)?.len())?;
+    Ok(std::fs::read(path)?.into())
+}
+
+/// Fetch a DICOM file by its relative path (`name`) like [`path`](crate::path),
+/// and return its contents.
+///
+/// Natively, the file is fetched into the cache and read from there,
+/// blocking the current thread.
+/// In WebAssembly with the `wasm` feature,
+/// where there is no file system to cache files in,
+/// the file is downloaded with the fetch API of the browser or worker
+/// and kept in memory for the rest of the session,
+/// so that the same tests run in both.
+///
+/// Files over the [limit of the files read into memory](crate#size-limit)
+/// fail with [`Error::TooLargeForMemory`],
+/// see [`size`](crate::size) and [`reader`](crate::reader) for those.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), dicom_test_files::Error> {
+/// let liver = dicom_test_files::bytes("pydicom/liver.dcm").await?;
+/// assert_eq!(&liver[128..132], b"DICM");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub async fn bytes(name: &str) -> Result<Arc<[u8]>> {
+    wasm::bytes(name).await
+}
+
+/// Fail with [`Error::TooLargeForMemory`]
+/// if a file of the given size is over the limit of the files read into memory
+fn check_in_memory(name: &str, size: u64) -> Result<()> {
+    let max = config::max_in_memory();
+    if size > max {
+        return Err(Error::TooLargeForMemory {
+            name: name.to_string(),
+            size,
+            max,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+mod wasm {
+    use super::check_in_memory;
+    use crate::{
+        digest::digest, http, resolve, source_url, Compression, DigestAlgorithm, Error, Result,
+        TestFile,
+    };
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+
+    /// Contents of the test files fetched so far, by name
+    static MEMORY: Mutex<Option<HashMap<&'static str, Arc<[u8]>>>> = Mutex::new(None);
+
+    pub(super) async fn bytes(name: &str) -> Result<Arc<[u8]>> {
+        let entry = resolve(name)?;
+        if let Some(contents) = MEMORY
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert_with(HashMap::new)
+            .get(entry.name)
+        {
+            return Ok(contents.clone());
+        }
+
+        check_in_memory(entry.name, entry.size)?;
+        let url = source_url(entry)?;
+        let stored = fetch(&url).await?;
+        verify(entry, &stored, entry.hash, entry.digest, &url)?;
+        let contents = decode(entry, stored)?;
+        check_in_memory(entry.name, contents.len() as u64)?;
+        if let Some(hash) = entry.decompressed_hash {
+            verify(entry, &contents, hash, DigestAlgorithm::Sha256, &url)?;
+        }
+        let contents: Arc<[u8]> = contents.into();
+        MEMORY
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert_with(HashMap::new)
+            .insert(entry.name, contents.clone());
+        Ok(contents)
+    }
+
+    /// Download a file with the fetch API
+    async fn fetch(url: &str) -> Result<Vec<u8>> {
+        let failed =
+            |e: JsValue| Error::Unreachable(format!("Failed to download {}: {:?}", url, e));
+        // the global scope of a page or of a web worker
+        let global = js_sys::global();
+        let promise = match global.dyn_ref::<web_sys::Window>() {
+            Some(window) => window.fetch_with_str(url),
+            None => global
+                .unchecked_into::<web_sys::WorkerGlobalScope>()
+                .fetch_with_str(url),
+        };
+        let resp: web_sys::Response = JsFuture::from(promise)
+            .await
+            .map_err(failed)?
+            .unchecked_into();
+        if !resp.ok() {
+            let reason = format!("Failed to download {}: status code {}", url, resp.status());
+            return Err(http::status_failure(resp.status(), reason).into());
+        }
+        let buffer = JsFuture::from(resp.array_buffer().map_err(failed)?)
+            .await
+            .map_err(failed)?;
+        Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+
+    /// Check the stored or decompressed contents of a file
+    /// against the given hash of its entry
+    fn verify(
+        entry: &TestFile,
+        data: &[u8],
+        expected: &str,
+        algorithm: DigestAlgorithm,
+        url: &str,
+    ) -> Result<()> {
+        let actual = digest(data, algorithm)?;
+        if actual != expected {
+            return Err(Error::InvalidHash {
+                name: entry.name.to_string(),
+                expected: expected.to_string(),
+                actual,
+                bytes: data.len() as u64,
+                url: Some(url.to_string()),
+            });
+        }
+        Ok(())
+    }
+
+    /// Decompress a stored file in memory
+    fn decode(entry: &TestFile, stored: Vec<u8>) -> Result<Vec<u8>> {
+        match entry.compression {
+            Compression::None => Ok(stored),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Ok(zstd::decode_all(&stored[..])?),
+            #[cfg(not(feature = "zstd"))]
+            Compression::Zstd => Err(Error::ZstdRequired),
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => read_all(flate2::read::GzDecoder::new(&stored[..])),
+            #[cfg(not(feature = "gzip"))]
+            Compression::Gzip => Err(Error::GzipRequired),
+            #[cfg(feature = "xz")]
+            Compression::Xz => read_all(xz2::read::XzDecoder::new(&stored[..])),
+            #[cfg(not(feature = "xz"))]
+            Compression::Xz => Err(Error::XzRequired),
+            Compression::ZipMember { .. } => Err(Error::Download(format!(
+                "{} is in a ZIP archive, which is not supported in WebAssembly",
+                entry.name
+            ))),
+            Compression::DicomWeb { .. } => Err(Error::Download(format!(
+                "{} is on a DICOMweb server, which is not supported in WebAssembly",
+                entry.name
+            ))),
+        }
+    }
+
+    #[cfg(any(feature = "gzip", feature = "xz"))]
+    fn read_all(mut decoder: impl std::io::Read) -> Result<Vec<u8>> {
+        let mut contents = Vec::new();
+        decoder.read_to_end(&mut contents)?;
+        Ok(contents)
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    #[test]
+    fn bytes_of_cached_file() {
+        // natively, the contents are ready at once
+        let mut bytes = pin!(super::bytes("pydicom/liver.dcm"));
+        let contents = match bytes.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(contents) => contents.unwrap(),
+            Poll::Pending => panic!("bytes should not be pending natively"),
+        };
+        assert_eq!(&contents[128..132], b"DICM");
+        let path = crate::path("pydicom/liver.dcm").unwrap();
+        assert_eq!(&contents[..], &std::fs::read(path).unwrap()[..]);
+    }
+
+    #[test]
+    fn too_large_for_memory() {
+        let error = super::check_in_memory("WG04/huge.dcm", u64::MAX).unwrap_err();
+        assert!(matches!(
+            error,
+            crate::Error::TooLargeForMemory { size: u64::MAX, .. }
+        ));
+        assert!(error.to_string().contains("dicom_test_files::reader"));
+        super::check_in_memory("pydicom/liver.dcm", 1024).unwrap();
+    }
+}
@@ -0,0 +1,541 @@
+//! Inspection and cleaning of the local cache
+
+use crate::{
+    all_entries, config, digest::file_digest, entries::GOLDEN_ENTRIES, entry_path, file_hash,
+    get_data_path, namespace, remote, resolve, revalidate, space, Compression, Error, HashCheck,
+    Result, TestFile,
+};
+use std::{
+    collections::HashSet,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Mutex, Once},
+    time::{Duration, SystemTime},
+};
+
+/// Directories of the cache holding files generated on the fly,
+/// rather than test file entries
+const GENERATED_DIRS: &[&str] = &["corrupted", "studies", "synth"];
+
+/// Time after which temporary files which are not written to any more
+/// are left over from a crashed run
+const TEMP_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Return the directory where test files are cached,
+/// see the [cache location](crate#cache-location).
+pub fn cache_dir() -> PathBuf {
+    get_data_path()
+}
+
+/// Return the path of the given test file in the cache
+/// if it is already there, without fetching it.
+///
+/// ```no_run
+/// if dicom_test_files::cached_path("pydicom/liver.dcm")?.is_none() {
+///     println!("liver.dcm is not cached yet");
+/// }
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn cached_path(name: &str) -> Result<Option<PathBuf>> {
+    let entry = resolve(name)?;
+    let cached_path = entry_path(&get_data_path(), entry.name);
+    Ok(Some(cached_path).filter(|path| path.exists()))
+}
+
+/// Hash the cached copies of all known test files again,
+/// returning each cached test file along with its hash check.
+///
+/// Test files stored with compression are cached decompressed,
+/// so their copies are checked against the hash of their decompressed contents,
+/// or reported as [`HashCheck::Unchecked`] when it is not known,
+/// as are copies hashed with BLAKE3 without the `blake3` feature.
+/// Those [kept compressed](crate#compressed-cache) without a decompressed copy
+/// are checked against the hash of the stored file.
+/// Copies which do not match are left in place,
+/// see [`remove_stale_files`] and [`clear_cache`] to remove them.
+pub fn verify_cache() -> Result<Vec<(&'static TestFile, HashCheck)>> {
+    let checked = Mutex::new(Vec::new());
+    verify_indexed(|i, entry, check| {
+        let mut checked = checked.lock().unwrap_or_else(|e| e.into_inner());
+        checked.push((i, entry, check));
+    })?;
+    let mut checked = checked.into_inner().unwrap_or_else(|e| e.into_inner());
+    // in the order of the entries, whichever was hashed first
+    checked.sort_unstable_by_key(|(i, ..)| *i);
+    Ok(checked
+        .into_iter()
+        .map(|(_, entry, check)| (entry, check))
+        .collect())
+}
+
+/// Hash the cached copies of all known test files again like [`verify_cache`],
+/// passing each cached test file along with its hash check
+/// to the given function as soon as it is hashed.
+///
+/// With the `rayon` feature,
+/// test files are hashed in parallel on all cores,
+/// so that an audit of a cache of several gigabytes takes seconds.
+/// The function is then called from several threads,
+/// in no particular order.
+/// Hashing stops at the first file which cannot be read.
+///
+/// ```no_run
+/// use dicom_test_files::HashCheck;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let corrupted = AtomicUsize::new(0);
+/// dicom_test_files::verify_cache_with(|entry, check| {
+///     if check == HashCheck::Mismatch {
+///         eprintln!("{} is corrupted", entry.name);
+///         corrupted.fetch_add(1, Ordering::Relaxed);
+///     }
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_cache_with(on_check: impl Fn(&'static TestFile, HashCheck) + Sync) -> Result<()> {
+    verify_indexed(|_, entry, check| on_check(entry, check))
+}
+
+/// Hash the cached copies of all known test files again,
+/// passing each one to the given function along with its index in the entries
+fn verify_indexed(on_check: impl Fn(usize, &'static TestFile, HashCheck) + Sync) -> Result<()> {
+    let data_path = get_data_path();
+    let entries = all_entries()?;
+    let check = |(i, entry): (usize, &&'static TestFile)| -> Result<()> {
+        if let Some(check) = check_cached(&data_path, entry)? {
+            on_check(i, entry, check);
+        }
+        Ok(())
+    };
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        entries.par_iter().enumerate().try_for_each(check)
+    }
+    #[cfg(not(feature = "rayon"))]
+    entries.iter().enumerate().try_for_each(check)
+}
+
+/// Hash the cached copy of the given entry again, if there is one
+fn check_cached(data_path: &Path, entry: &TestFile) -> Result<Option<HashCheck>> {
+    let cached_path = entry_path(data_path, entry.name);
+    if !cached_path.exists() {
+        let stored_path = entry_path(data_path, &entry.real_file_name());
+        if entry.compression.is_stream() && stored_path.exists() {
+            return check_stored(&stored_path, entry).map(Some);
+        }
+        return Ok(None);
+    }
+    let check = match entry.compression {
+        Compression::None | Compression::ZipMember { .. } | Compression::DicomWeb { .. } => {
+            check_stored(&cached_path, entry)?
+        }
+        _ => match entry.decompressed_hash {
+            Some(hash) if file_hash(&cached_path)?.0 == hash => HashCheck::Valid,
+            Some(_) => HashCheck::Mismatch,
+            None => HashCheck::Unchecked,
+        },
+    };
+    Ok(Some(check))
+}
+
+/// Check the given file against the hash of the given entry
+fn check_stored(path: &Path, entry: &TestFile) -> Result<HashCheck> {
+    match file_digest(path, entry.digest) {
+        Ok((hash, _)) if hash == entry.hash => Ok(HashCheck::Valid),
+        Ok(_) => Ok(HashCheck::Mismatch),
+        // without the feature of the algorithm
+        Err(Error::Blake3Required) => Ok(HashCheck::Unchecked),
+        Err(e) => Err(e),
+    }
+}
+
+/// Remove the whole cache directory,
+/// so that test files are downloaded again on their next use.
+pub fn clear_cache() -> Result<()> {
+    match fs::remove_dir_all(get_data_path()) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Remove the cached files which are not test files of the current manifest,
+/// such as the ones renamed or dropped since they were cached,
+/// returning the paths removed.
+///
+/// Files generated on the fly,
+/// such as [synthetic files](crate::synth) and [studies](crate::study),
+/// are kept.
+pub fn remove_stale_files() -> Result<Vec<PathBuf>> {
+    let entries = all_entries()?;
+    // copies kept compressed, see `Config::keep_compressed`
+    let stored: Vec<_> = entries
+        .iter()
+        .filter(|entry| entry.compression.is_stream())
+        .map(|entry| entry.real_file_name())
+        .collect();
+    let known: HashSet<&str> = entries
+        .iter()
+        .copied()
+        .chain(GOLDEN_ENTRIES)
+        .map(|entry| entry.name)
+        .chain(stored.iter().map(|name| name.as_ref()))
+        .collect();
+    remove_stale(&get_data_path(), &known)
+}
+
+/// Remove the temporary files and directories of downloads
+/// left over in the cache by crashed runs,
+/// returning the paths removed.
+///
+/// Temporary files which were written to in the last hour
+/// are kept, as they may belong to downloads in progress.
+/// This is done once per process before the first download.
+pub fn clean_temp() -> Result<Vec<PathBuf>> {
+    clean_temp_in(&get_data_path(), TEMP_MAX_AGE)
+}
+
+/// Remove the temporary files left over in the cache,
+/// once per process
+pub(crate) fn clean_temp_once() {
+    static CLEANED: Once = Once::new();
+    CLEANED.call_once(|| {
+        if let Err(e) = clean_temp() {
+            eprintln!(
+                "[dicom-test-files] Failed to remove left over temporary files: {}",
+                e
+            );
+        }
+    });
+}
+
+/// Remove the temporary files and directories of the given directory
+/// and its subdirectories not written to for longer than the given age
+fn clean_temp_in(dir: &Path, max_age: Duration) -> Result<Vec<PathBuf>> {
+    let files = match fs::read_dir(dir) {
+        Ok(files) => files,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut removed = Vec::new();
+    for file in files {
+        let file = file?;
+        let path = file.path();
+        let is_dir = file.file_type()?.is_dir();
+        if !file.file_name().to_string_lossy().starts_with(".tmp") {
+            if is_dir {
+                removed.extend(clean_temp_in(&path, max_age)?);
+            }
+            continue;
+        }
+        // removed by another process in the meantime
+        let age = match last_modified(&path) {
+            Ok(modified) => modified.elapsed().unwrap_or_default(),
+            Err(_) => continue,
+        };
+        if age <= max_age {
+            continue;
+        }
+        let removal = if is_dir {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        };
+        match removal {
+            Ok(()) => removed.push(path),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(removed)
+}
+
+/// When the given file,
+/// or the given directory or any of its files, was last modified
+fn last_modified(path: &Path) -> io::Result<SystemTime> {
+    let metadata = fs::metadata(path)?;
+    let mut modified = metadata.modified()?;
+    if metadata.is_dir() {
+        for file in fs::read_dir(path)? {
+            modified = modified.max(last_modified(&file?.path())?);
+        }
+    }
+    Ok(modified)
+}
+
+/// Fail with [`Error::QuotaExceeded`]
+/// if downloading the given entry into the cache
+/// would take it over the quota set by [`Config::max_cache_bytes`](crate::Config::max_cache_bytes),
+/// if any.
+pub(crate) fn check_quota(entry: &TestFile) -> Result<()> {
+    match config::max_cache_bytes() {
+        Some(quota) => check_quota_at(entry, &get_data_path(), quota),
+        None => Ok(()),
+    }
+}
+
+/// Fail if downloading the given entry into the given cache directory
+/// would take it over the given number of bytes.
+///
+/// The usage of the cache is measured once,
+/// then kept as a running total of the downloads checked since,
+/// and only measured again before refusing one,
+/// as some of these downloads may have failed or been removed.
+fn check_quota_at(entry: &TestFile, data_path: &Path, quota: u64) -> Result<()> {
+    static USAGE: Mutex<Option<(PathBuf, u64)>> = Mutex::new(None);
+
+    let needed = space::needed(entry);
+    let mut usage = USAGE.lock().unwrap_or_else(|e| e.into_inner());
+    let used = match &*usage {
+        Some((path, used)) if path == data_path && used.saturating_add(needed) <= quota => *used,
+        _ => dir_size(data_path)?,
+    };
+    let total = used.saturating_add(needed);
+    if total > quota {
+        *usage = Some((data_path.to_path_buf(), used));
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            name = entry.name,
+            needed,
+            used,
+            quota,
+            "over the cache quota"
+        );
+        return Err(Error::QuotaExceeded {
+            name: entry.name.to_string(),
+            needed,
+            used,
+            quota,
+        });
+    }
+    *usage = Some((data_path.to_path_buf(), total));
+    Ok(())
+}
+
+/// The total size of the files in the given directory, in bytes
+fn dir_size(dir: &Path) -> io::Result<u64> {
+    let files = match fs::read_dir(dir) {
+        Ok(files) => files,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+    let mut size = 0;
+    for file in files {
+        let file = file?;
+        // links to the cached files, see `Config::namespace`
+        if file.file_name() == namespace::VIEWS_DIR {
+            continue;
+        }
+        match entry_size(&file) {
+            Ok(file_size) => size += file_size,
+            // renamed or removed during the walk, such as by a download
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(size)
+}
+
+/// The size of the given file, or of the files of the given directory
+fn entry_size(file: &fs::DirEntry) -> io::Result<u64> {
+    let file_type = file.file_type()?;
+    if file_type.is_dir() {
+        dir_size(&file.path())
+    } else if file_type.is_file() {
+        Ok(file.metadata()?.len())
+    } else {
+        Ok(0)
+    }
+}
+
+/// Remove the files of the given cache directory which are not known test files
+fn remove_stale(data_path: &Path, known: &HashSet<&str>) -> Result<Vec<PathBuf>> {
+    let mut stale = Vec::new();
+    let top_dirs = match fs::read_dir(data_path) {
+        Ok(dirs) => dirs,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    for dir in top_dirs {
+        let dir = dir?;
+        let dir_name = dir.file_name().to_string_lossy().into_owned();
+        // files at the top are kept along with the cache, such as the remote manifest
+        if dir_name.starts_with('.')
+            || GENERATED_DIRS.contains(&dir_name.as_str())
+            || !dir.file_type()?.is_dir()
+        {
+            continue;
+        }
+        find_stale(&dir.path(), &dir_name, known, &mut stale)?;
+    }
+
+    let mut removed = Vec::new();
+    for (name, path) in stale {
+        if remote::enabled() && remote::lookup(&name)?.is_some() {
+            continue;
+        }
+        fs::remove_file(&path)?;
+        revalidate::store_etag(&name, None);
+        namespace::remove_views(data_path, &name);
+        // directories left empty
+        for dir in path.ancestors().skip(1) {
+            if dir == data_path || fs::remove_dir(dir).is_err() {
+                break;
+            }
+        }
+        removed.push(path);
+    }
+    Ok(removed)
+}
+
+/// Collect the files of the given cache directory
+/// which are not known test files, along with their names
+fn find_stale(
+    dir: &Path,
+    prefix: &str,
+    known: &HashSet<&str>,
+    stale: &mut Vec<(String, PathBuf)>,
+) -> Result<()> {
+    for file in fs::read_dir(dir)? {
+        let file = file?;
+        let file_name = file.file_name().to_string_lossy().into_owned();
+        // files being downloaded and their locks
+        if file_name.starts_with('.') {
+            continue;
+        }
+        let name = format!("{}/{}", prefix, file_name);
+        if file.file_type()?.is_dir() {
+            find_stale(&file.path(), &name, known, stale)?;
+        } else if !known.contains(name.as_str()) {
+            stale.push((name, file.path()));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_paths() {
+        let path = crate::path("pydicom/liver.dcm").unwrap();
+        assert!(path.starts_with(cache_dir()));
+        assert_eq!(cached_path("pydicom/liver.dcm").unwrap(), Some(path));
+        assert!(cached_path("pydicom/livr.dcm").is_err());
+    }
+
+    #[test]
+    fn verify_cached_files() {
+        crate::path("pydicom/CT_small.dcm").unwrap();
+        crate::path("WG04/REF/CT1_UNC").unwrap();
+        let checked = verify_cache().unwrap();
+        let check = |name| {
+            checked
+                .iter()
+                .find(|(entry, _)| entry.name == name)
+                .map(|(_, check)| *check)
+        };
+        assert_eq!(check("pydicom/CT_small.dcm"), Some(HashCheck::Valid));
+        assert_eq!(check("WG04/REF/CT1_UNC"), Some(HashCheck::Valid));
+        // in the order of the entries
+        let entries = all_entries().unwrap();
+        let index = |name| entries.iter().position(|entry| entry.name == name);
+        assert!(checked
+            .windows(2)
+            .all(|pair| index(pair[0].0.name) < index(pair[1].0.name)));
+
+        // or as they are hashed
+        let streamed = Mutex::new(Vec::new());
+        verify_cache_with(|entry, check| streamed.lock().unwrap().push((entry.name, check)))
+            .unwrap();
+        let streamed = streamed.into_inner().unwrap();
+        assert!(streamed.contains(&("WG04/REF/CT1_UNC", HashCheck::Valid)));
+        assert!(streamed.len() >= checked.len());
+    }
+
+    #[test]
+    fn cache_quota() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("pydicom")).unwrap();
+        fs::write(dir.path().join("pydicom/liver.dcm"), [0; 600]).unwrap();
+        fs::write(dir.path().join("manifest.txt"), [0; 100]).unwrap();
+        assert_eq!(dir_size(dir.path()).unwrap(), 700);
+        assert_eq!(dir_size(&dir.path().join("nope")).unwrap(), 0);
+
+        let entry = TestFile::none("quota/file.dcm", "").size(300);
+        check_quota_at(&entry, dir.path(), 1000).unwrap();
+        let error = check_quota_at(&entry, dir.path(), 999).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::QuotaExceeded {
+                needed: 300,
+                used: 700,
+                quota: 999,
+                ..
+            }
+        ));
+        assert!(error.to_string().contains("remove_stale_files"));
+
+        // a running total of the downloads, measured again when over the quota
+        let entry = TestFile::none("quota/file.dcm", "").size(200);
+        check_quota_at(&entry, dir.path(), 1000).unwrap();
+        fs::write(dir.path().join("pydicom/file.dcm"), [0; 200]).unwrap();
+        assert!(check_quota_at(&entry, dir.path(), 1000).is_err());
+        fs::remove_file(dir.path().join("pydicom/liver.dcm")).unwrap();
+        check_quota_at(&entry, dir.path(), 1000).unwrap();
+    }
+
+    #[test]
+    fn clean_left_over_temp() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str| {
+            let path = dir.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, name).unwrap();
+            path
+        };
+        let kept = [write("pydicom/liver.dcm"), write("synth/v1/abc.dcm")];
+        write(".tmpAbc123/tmpfile");
+        write("pydicom/.tmpXyz789/tmpfile");
+        fs::create_dir_all(dir.path().join("WG04/REF/.tmpEmpty1")).unwrap();
+
+        // possibly in progress
+        assert_eq!(clean_temp_in(dir.path(), TEMP_MAX_AGE).unwrap().len(), 0);
+
+        std::thread::sleep(Duration::from_millis(20));
+        let mut removed = clean_temp_in(dir.path(), Duration::from_millis(10)).unwrap();
+        removed.sort();
+        let expected = [".tmpAbc123", "WG04/REF/.tmpEmpty1", "pydicom/.tmpXyz789"];
+        let expected: Vec<PathBuf> = expected.iter().map(|name| dir.path().join(name)).collect();
+        assert_eq!(removed, expected);
+        assert!(removed.iter().all(|path| !path.exists()));
+        assert!(kept.iter().all(|path| path.exists()));
+        assert!(dir.path().join("WG04/REF").exists());
+    }
+
+    #[test]
+    fn remove_stale_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name| {
+            let path = entry_path(dir.path(), name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, name).unwrap();
+            path
+        };
+        let kept = [
+            write("pydicom/liver.dcm"),
+            write("pydicom/.tmp123-4"),
+            write("synth/v1/abc.dcm"),
+            write("manifest.txt"),
+        ];
+        let stale = write("pydicom/renamed/liver.dcm");
+
+        let known: HashSet<&str> = ["pydicom/liver.dcm"].iter().copied().collect();
+        assert_eq!(remove_stale(dir.path(), &known).unwrap(), vec![stale.clone()]);
+        assert!(!stale.parent().unwrap().exists());
+        assert!(kept.iter().all(|path| path.exists()));
+    }
+}
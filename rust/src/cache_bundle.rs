@@ -0,0 +1,339 @@
+//! Shipping of the local cache to machines without network access
+
+#[cfg(feature = "tar")]
+use crate::{
+    digest::file_digest, entry_path, fetch_verified, file_hash, get_data_path, prefetch, resolve,
+    write_temp, DigestAlgorithm, Error,
+};
+use crate::{Filter, Result};
+use std::path::Path;
+#[cfg(feature = "tar")]
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, BufRead, Read, Write},
+};
+
+/// Name of the archive member listing the hash and size of the others
+#[cfg(feature = "tar")]
+const MANIFEST_NAME: &str = "dicom-test-files-bundle.txt";
+
+#[cfg(feature = "tar")]
+const MANIFEST_HEADER: &str = "# dicom-test-files bundle v1\n";
+
+/// The magic number starting a zstd frame
+#[cfg(feature = "tar")]
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Fetch the test files selected by the given filter
+/// and write them as they are cached into a tar archive at `dest`,
+/// returning the names of the files written.
+///
+/// The files are verified before they are written,
+/// and the archive starts with a manifest of their hashes,
+/// against which [`unbundle_cache`] checks them again.
+/// A `dest` path ending in `.zst` is compressed with zstd.
+///
+/// The archive can be shipped to another machine,
+/// such as the target of a cross-compiled test suite
+/// or a runner of an air-gapped network,
+/// and unpacked into its cache with [`unbundle_cache`],
+/// after which [`path`](crate::path) works without network access.
+/// Requires the `tar` feature, enabled by default.
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// // on the host
+/// dicom_test_files::bundle_cache("WG04/**", "target/dicom-test-files.tar.zst")?;
+/// // on the target
+/// dicom_test_files::unbundle_cache("dicom-test-files.tar.zst")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn bundle_cache(
+    filter: impl Into<Filter>,
+    dest: impl AsRef<Path>,
+) -> Result<Vec<&'static str>> {
+    write_bundle(filter.into(), dest.as_ref())
+}
+
+#[cfg(feature = "tar")]
+fn write_bundle(filter: Filter, dest: &Path) -> Result<Vec<&'static str>> {
+    let compressed = dest.extension() == Some("zst".as_ref());
+    #[cfg(not(feature = "zstd"))]
+    if compressed {
+        return Err(Error::ZstdRequired);
+    }
+    let summary = prefetch(filter)?;
+    let mut names: Vec<&'static str> = summary.fetched.into_iter().chain(summary.cached).collect();
+    names.sort_unstable();
+
+    let mut manifest = MANIFEST_HEADER.to_string();
+    let mut paths = Vec::with_capacity(names.len());
+    for name in &names {
+        let cached_path = fetch_verified(resolve(name)?)?;
+        let (hash, size) = file_hash(&cached_path)?;
+        manifest.push_str(&format!("{} {} {}\n", hash, size, name));
+        paths.push(cached_path);
+    }
+
+    let file = io::BufWriter::new(fs::File::create(dest)?);
+    #[cfg(feature = "zstd")]
+    if compressed {
+        let encoder = zstd::Encoder::new(file, 19)?;
+        write_tar(encoder, &manifest, &names, &paths)?.finish()?;
+        return Ok(names);
+    }
+    write_tar(file, &manifest, &names, &paths)?;
+    Ok(names)
+}
+
+#[cfg(not(feature = "tar"))]
+fn write_bundle(_filter: Filter, _dest: &Path) -> Result<Vec<&'static str>> {
+    Err(crate::Error::TarRequired)
+}
+
+/// Write the manifest and the files of a bundle as a tar archive,
+/// returning the writer
+#[cfg(feature = "tar")]
+fn write_tar<W: Write>(
+    writer: W,
+    manifest: &str,
+    names: &[&str],
+    paths: &[std::path::PathBuf],
+) -> Result<W> {
+    let mut builder = tar::Builder::new(writer);
+    let mut header = tar::Header::new_gnu();
+    header.set_size(manifest.len() as u64);
+    header.set_mode(0o644);
+    builder.append_data(&mut header, MANIFEST_NAME, manifest.as_bytes())?;
+    for (name, path) in names.iter().zip(paths) {
+        let file = fs::File::open(path)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(file.metadata()?.len());
+        header.set_mode(0o644);
+        builder.append_data(&mut header, name, file)?;
+    }
+    Ok(builder.into_inner()?)
+}
+
+/// Unpack into the local cache a tar archive written by [`bundle_cache`],
+/// compressed with zstd or not,
+/// returning the names of the test files unpacked.
+///
+/// Each file is checked against the hash listed in the manifest of the archive,
+/// and the whole unpacking fails with [`Error::InvalidHash`](crate::Error::InvalidHash)
+/// at the first file which does not match.
+/// Files unknown to this version are left out,
+/// as are those of compressed files without the hash of their decompressed contents,
+/// which cannot be checked and are fetched again on first use.
+/// Requires the `tar` feature, enabled by default.
+pub fn unbundle_cache(src: impl AsRef<Path>) -> Result<Vec<&'static str>> {
+    read_bundle(src.as_ref())
+}
+
+#[cfg(feature = "tar")]
+fn read_bundle(src: &Path) -> Result<Vec<&'static str>> {
+    let mut file = io::BufReader::new(fs::File::open(src)?);
+    if file.fill_buf()?.starts_with(&ZSTD_MAGIC) {
+        #[cfg(feature = "zstd")]
+        return read_tar(zstd::Decoder::with_buffer(file)?);
+        #[cfg(not(feature = "zstd"))]
+        return Err(Error::ZstdRequired);
+    }
+    read_tar(file)
+}
+
+#[cfg(not(feature = "tar"))]
+fn read_bundle(_src: &Path) -> Result<Vec<&'static str>> {
+    Err(crate::Error::TarRequired)
+}
+
+/// Unpack the files of a bundle, checking them against its manifest
+#[cfg(feature = "tar")]
+fn read_tar(reader: impl Read) -> Result<Vec<&'static str>> {
+    let data_path = get_data_path();
+    let mut archive = tar::Archive::new(reader);
+    let mut manifest: Option<HashMap<String, String>> = None;
+    let mut unpacked = Vec::new();
+    for member in archive.entries()? {
+        let mut member = member?;
+        if !member.header().entry_type().is_file() {
+            continue;
+        }
+        let name = member.path()?.to_string_lossy().into_owned();
+        if name == MANIFEST_NAME {
+            let mut text = String::new();
+            member.read_to_string(&mut text)?;
+            manifest = Some(parse_manifest(&text)?);
+            continue;
+        }
+        let manifest = manifest.as_ref().ok_or_else(|| {
+            Error::Manifest(format!(
+                "{} is not the first member of the bundle",
+                MANIFEST_NAME
+            ))
+        })?;
+        let expected = match manifest.get(&name) {
+            Some(hash) => hash,
+            None => {
+                eprintln!(
+                    "[dicom-test-files] Leaving out {}: not in the bundle manifest",
+                    name
+                );
+                continue;
+            }
+        };
+        let entry = match resolve(&name) {
+            Ok(entry) => entry,
+            Err(e) => {
+                eprintln!("[dicom-test-files] Leaving out {}: {}", name, e);
+                continue;
+            }
+        };
+        // only the hash of the compressed stream is known, it is fetched again
+        if entry.compression.is_stream() && entry.decompressed_hash.is_none() {
+            eprintln!(
+                "[dicom-test-files] Leaving out {}: the hash of its decompressed contents is unknown",
+                name
+            );
+            continue;
+        }
+
+        let cached_path = entry_path(&data_path, entry.name);
+        let parent_dir = cached_path.parent().unwrap();
+        fs::create_dir_all(parent_dir)?;
+        let tempfile_path = write_temp(&mut member, parent_dir)?;
+        let (actual, bytes) = file_hash(&tempfile_path)?;
+        // copies also have to match their entry, once decompressed
+        let matches_entry = if entry.compression.is_stream() {
+            entry.decompressed_hash == Some(actual.as_str())
+        } else if entry.digest == DigestAlgorithm::Sha256 {
+            actual == entry.hash
+        } else {
+            file_digest(&tempfile_path, entry.digest)?.0 == entry.hash
+        };
+        if actual != *expected || !matches_entry {
+            fs::remove_file(&tempfile_path)?;
+            return Err(Error::InvalidHash {
+                name: entry.name.to_string(),
+                expected: expected.clone(),
+                actual,
+                bytes,
+                url: None,
+            });
+        }
+        fs::rename(&tempfile_path, &cached_path)?;
+        unpacked.push(entry.name);
+    }
+    Ok(unpacked)
+}
+
+/// Parse the manifest of a bundle into the hashes of its files by name
+#[cfg(feature = "tar")]
+fn parse_manifest(text: &str) -> Result<HashMap<String, String>> {
+    let mut lines = text.lines();
+    if lines.next() != Some(MANIFEST_HEADER.trim_end()) {
+        return Err(Error::Manifest(
+            "unsupported version of the bundle manifest".to_string(),
+        ));
+    }
+    let mut hashes = HashMap::new();
+    for line in lines.filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let mut fields = line.splitn(3, ' ');
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(hash), Some(_size), Some(name)) if crate::manifest::is_sha256(hash) => {
+                hashes.insert(name.to_string(), hash.to_string());
+            }
+            _ => {
+                return Err(Error::Manifest(format!(
+                    "invalid line in the bundle manifest: {:?}",
+                    line
+                )))
+            }
+        }
+    }
+    Ok(hashes)
+}
+
+#[cfg(all(test, feature = "tar"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ship_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive_path = dir.path().join("cache.tar");
+        let names = bundle_cache("pydicom/MR_small_padded.dcm", &archive_path).unwrap();
+        assert_eq!(names, ["pydicom/MR_small_padded.dcm"]);
+
+        let cached_path = crate::path("pydicom/MR_small_padded.dcm").unwrap();
+        let contents = fs::read(&cached_path).unwrap();
+        fs::remove_file(&cached_path).unwrap();
+        assert_eq!(unbundle_cache(&archive_path).unwrap(), names);
+        assert_eq!(fs::read(&cached_path).unwrap(), contents);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn ship_compressed_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive_path = dir.path().join("cache.tar.zst");
+        let names = bundle_cache("WG04/REF/NM1_UNC", &archive_path).unwrap();
+        assert_eq!(names, ["WG04/REF/NM1_UNC"]);
+        let mut magic = [0; 4];
+        fs::File::open(&archive_path)
+            .unwrap()
+            .read_exact(&mut magic)
+            .unwrap();
+        assert_eq!(magic, ZSTD_MAGIC);
+
+        let cached_path = crate::path("WG04/REF/NM1_UNC").unwrap();
+        let contents = fs::read(&cached_path).unwrap();
+        fs::remove_file(&cached_path).unwrap();
+        assert_eq!(unbundle_cache(&archive_path).unwrap(), names);
+        assert_eq!(fs::read(&cached_path).unwrap(), contents);
+    }
+
+    #[test]
+    fn leave_out_unchecked_files() {
+        use crate::{entry_path, register_registry, Compression, Registry, RegistryEntry};
+
+        let contents = b"decompressed, but from which stream?";
+        register_registry(Registry {
+            base_url: "https://example.org/bundles/".to_string(),
+            entries: vec![RegistryEntry::new("bundle/unchecked.dcm", "0".repeat(64))
+                .compression(Compression::Zstd)],
+        })
+        .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let member_path = dir.path().join("unchecked.dcm");
+        fs::write(&member_path, contents).unwrap();
+        let (hash, size) = file_hash(&member_path).unwrap();
+        let archive_path = dir.path().join("cache.tar");
+        let manifest = format!(
+            "{}{} {} bundle/unchecked.dcm\n",
+            MANIFEST_HEADER, hash, size
+        );
+        write_tar(
+            fs::File::create(&archive_path).unwrap(),
+            &manifest,
+            &["bundle/unchecked.dcm"],
+            &[member_path],
+        )
+        .unwrap();
+
+        assert!(unbundle_cache(&archive_path).unwrap().is_empty());
+        assert!(!entry_path(&get_data_path(), "bundle/unchecked.dcm").exists());
+    }
+
+    #[test]
+    fn bundle_manifest() {
+        let hash = "0".repeat(64);
+        let text = format!("{}{} 12 pydicom/liver.dcm\n", MANIFEST_HEADER, hash);
+        let hashes = parse_manifest(&text).unwrap();
+        assert_eq!(hashes.get("pydicom/liver.dcm"), Some(&hash));
+        assert!(parse_manifest("pydicom/liver.dcm").is_err());
+        assert!(parse_manifest(&format!("{}abc 12 pydicom/liver.dcm\n", MANIFEST_HEADER)).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! Cooperative cancellation of downloads
+
+use std::{
+    cell::RefCell,
+    fmt, io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+thread_local! {
+    /// The token of the operation running on this thread, if any
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// A token to cancel the downloads of an operation in progress,
+/// such as when a test harness gives up on a test which timed out.
+///
+/// The downloads of the operations run with [`CancelToken::run`]
+/// stop at the next chunk they receive once the token is cancelled,
+/// failing with [`Error::Cancelled`](crate::Error::Cancelled).
+/// Their partial files are removed,
+/// so that the cache is left as if they were never attempted.
+/// Dropping the future of [`path_async`](crate::path_async)
+/// cancels its download the same way.
+///
+/// ```no_run
+/// use dicom_test_files::CancelToken;
+/// use std::{thread, time::Duration};
+///
+/// let token = CancelToken::new();
+/// let watchdog = token.clone();
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_secs(60));
+///     watchdog.cancel();
+/// });
+/// let path = token.run(|| dicom_test_files::path("WG04/J2KR/MR1_J2KR"));
+/// # let _ = path;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Create a token which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the downloads of the operations run with this token,
+    /// including those of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Run the given operation on the current thread,
+    /// with its downloads cancelled once this token is.
+    /// The downloads of [`prefetch`](crate::prefetch) workers
+    /// started by the operation are cancelled as well.
+    pub fn run<T>(&self, operation: impl FnOnce() -> T) -> T {
+        within(Some(self.clone()), operation)
+    }
+}
+
+/// Restores the token of the thread when dropped,
+/// even if the operation panics
+struct Restore(Option<CancelToken>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Run the given operation with the given token, if any,
+/// as the token of the current thread
+pub(crate) fn within<T>(token: Option<CancelToken>, operation: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|current| current.replace(token));
+    let _restore = Restore(previous);
+    operation()
+}
+
+/// The token of the current thread, if any,
+/// for the threads it starts
+pub(crate) fn current() -> Option<CancelToken> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Fail if the token of the current thread was cancelled
+pub(crate) fn check() -> io::Result<()> {
+    let cancelled = CURRENT.with(|current| match &*current.borrow() {
+        Some(token) => token.is_cancelled(),
+        None => false,
+    });
+    if cancelled {
+        return Err(io::Error::other(Cancelled));
+    }
+    Ok(())
+}
+
+/// A cancelled download,
+/// carried by I/O errors until it becomes an [`Error::Cancelled`](crate::Error::Cancelled)
+#[derive(Debug)]
+pub(crate) struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("download cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        entry_path, get_data_path, path, register_registry,
+        testing::{Fault, MockServer},
+        Error,
+    };
+    use std::{
+        fs, thread,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn cancel_download() {
+        // a server sending one byte at a time, slowly
+        let server = MockServer::start().unwrap();
+        server.blob("cancel/slow.dcm", vec![b'x'; 100_000]);
+        server.fault(
+            "cancel/slow.dcm",
+            Fault::Throttle(Duration::from_millis(10)),
+        );
+        register_registry(server.registry()).unwrap();
+
+        let token = CancelToken::new();
+        let watchdog = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            watchdog.cancel();
+        });
+        let start = Instant::now();
+        let error = token.run(|| path("cancel/slow.dcm")).unwrap_err();
+        assert!(matches!(error, Error::Cancelled), "{:?}", error);
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert!(!error.is_transient());
+        assert!(current().is_none());
+
+        // neither the file nor its partial download are left behind
+        let cached_path = entry_path(&get_data_path(), "cancel/slow.dcm");
+        assert!(!cached_path.exists());
+        let leftovers = fs::read_dir(cached_path.parent().unwrap()).unwrap().count();
+        assert_eq!(leftovers, 0);
+
+        // later attempts with the cancelled token fail before any request
+        assert!(matches!(
+            token.run(|| path("cancel/slow.dcm")),
+            Err(Error::Cancelled)
+        ));
+    }
+}
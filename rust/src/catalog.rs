@@ -0,0 +1,582 @@
+//! Queries over the test file entries
+
+use crate::{
+    all_entries, entries::FILE_ENTRIES, resolve, suggest, Defect, Feature, Filter, Result,
+    TestFile, TestFileInfo, Tier,
+};
+use std::collections::BTreeMap;
+
+/// Directory of the test files from pydicom
+const PYDICOM_DIR: &str = "pydicom/";
+
+/// Return all known test files selected by the given filter or glob pattern,
+/// without fetching them.
+///
+/// Entries of the external manifest and of the registered registries
+/// are listed along with those compiled in.
+///
+/// ```
+/// for entry in dicom_test_files::entries("pydicom/*")? {
+///     println!("{}: {} bytes", entry.name, entry.size);
+/// }
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn entries(filter: impl Into<Filter>) -> Result<Vec<&'static TestFile>> {
+    let filter = filter.into();
+    Ok(all_entries()?
+        .into_iter()
+        .filter(|entry| filter.matches(entry))
+        .collect())
+}
+
+/// Return all deliberately malformed test files,
+/// along with what is wrong with each of them.
+///
+/// These live in the `invalid` category
+/// and are meant for negative tests of DICOM parsers.
+/// Only the compiled entries record their defects.
+///
+/// ```
+/// for (entry, defect) in dicom_test_files::malformed() {
+///     println!("{}: {}", entry.name, defect);
+/// }
+/// ```
+pub fn malformed() -> Vec<(&'static TestFile, Defect)> {
+    FILE_ENTRIES
+        .iter()
+        .filter_map(|entry| entry.defect.map(|defect| (entry, defect)))
+        .collect()
+}
+
+/// Return the expected values of the given test file,
+/// so that assertions can be driven by data
+/// instead of hardcoding the properties of each file.
+///
+/// ```
+/// let info = dicom_test_files::info("pydicom/CT_small.dcm")?;
+/// assert_eq!(info.rows, Some(128));
+/// assert_eq!(info.transfer_syntax_uid, Some("1.2.840.10008.1.2.1"));
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn info(name: &str) -> Result<&'static TestFileInfo> {
+    resolve(name).map(|entry| &entry.info)
+}
+
+/// Return the name of a test file from its identifier in pydicom,
+/// the file name given to pydicom's `get_testdata_file`,
+/// for tests ported from pydicom.
+///
+/// ```
+/// let name = dicom_test_files::pydicom_name("CT_small.dcm")?;
+/// assert_eq!(name, "pydicom/CT_small.dcm");
+/// let path = dicom_test_files::path(name)?;
+/// # let _ = path;
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+///
+/// Fails with [`Error::NotFound`](crate::Error::NotFound)
+/// for files not in this collection,
+/// suggesting the closest pydicom identifiers,
+/// or without the `manifest-pydicom` feature.
+pub fn pydicom_name(name: &str) -> Result<&'static str> {
+    FILE_ENTRIES
+        .iter()
+        .map(|entry| entry.name)
+        .find(|known| known.strip_prefix(PYDICOM_DIR) == Some(name))
+        .ok_or_else(|| {
+            let pydicom_names = FILE_ENTRIES
+                .iter()
+                .filter_map(|entry| entry.name.strip_prefix(PYDICOM_DIR));
+            suggest::not_found(name, pydicom_names)
+        })
+}
+
+/// Return the SHA-256 hash of the decoded pixel data of the given test file,
+/// as a lowercase hexadecimal string,
+/// so that decoders can be checked for correctness.
+///
+/// The decoded pixel data is expected to contain all frames in order,
+/// with interleaved samples (planar configuration 0),
+/// each sample in little endian over _Bits Allocated_ / 8 bytes,
+/// without any padding,
+/// and in the photometric interpretation of the uncompressed image.
+///
+/// The hash is `None` for files without pixel data,
+/// for lossy encodings, whose output depends on the decoder,
+/// and for lossless encodings without an uncompressed counterpart.
+///
+/// ```
+/// let hash = dicom_test_files::pixel_hash("WG04/RLE/CT1_RLE")?;
+/// assert_eq!(hash, dicom_test_files::pixel_hash("WG04/REF/CT1_UNC")?);
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn pixel_hash(name: &str) -> Result<Option<&'static str>> {
+    info(name).map(|info| info.pixel_hash)
+}
+
+/// Return all test files encoded in the given transfer syntax.
+///
+/// Use [`Filter::transfer_syntax`] to prefetch them.
+///
+/// ```
+/// // every JPEG Lossless (first-order prediction) file
+/// for entry in dicom_test_files::by_transfer_syntax("1.2.840.10008.1.2.4.70")? {
+///     println!("{}", entry.name);
+/// }
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn by_transfer_syntax(uid: &str) -> Result<Vec<&'static TestFile>> {
+    select(&Filter::transfer_syntax(uid))
+}
+
+/// Return all test files of the given SOP class,
+/// such as CT Image Storage,
+/// for code which handles the objects of one modality.
+/// Entries of an external manifest are included
+/// when they declare their `sop_class_uid`.
+///
+/// Use [`Filter::sop_class`] to prefetch them.
+///
+/// ```
+/// // every CT image
+/// for entry in dicom_test_files::by_sop_class("1.2.840.10008.5.1.4.1.1.2")? {
+///     println!("{}", entry.name);
+/// }
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn by_sop_class(uid: &str) -> Result<Vec<&'static TestFile>> {
+    select(&Filter::sop_class(uid))
+}
+
+/// Start a query of test files by the characteristics of their pixel data.
+///
+/// Each criterion narrows the query down to the test files
+/// which have the given value in their DICOM header,
+/// so that pixel pipelines can be tested
+/// against exactly the combinations they handle.
+///
+/// ```
+/// // signed 16-bit grayscale images
+/// let entries = dicom_test_files::query()
+///     .bits_allocated(16)
+///     .signed(true)
+///     .samples_per_pixel(1)
+///     .run()?;
+/// # let _ = entries;
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn query() -> Query {
+    Query::default()
+}
+
+/// A query of test files by the characteristics of their pixel data,
+/// see [`query`].
+///
+/// The query can also be given to [`prefetch`](crate::prefetch)
+/// as a [`Filter`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    bits_allocated: Option<u16>,
+    signed: Option<bool>,
+    samples_per_pixel: Option<u16>,
+    photometric_interpretation: Option<String>,
+}
+
+impl Query {
+    /// Select the files with the given _Bits Allocated_
+    pub fn bits_allocated(mut self, bits: u16) -> Self {
+        self.bits_allocated = Some(bits);
+        self
+    }
+
+    /// Select the files with signed samples,
+    /// or with unsigned ones, according to their _Pixel Representation_
+    pub fn signed(mut self, signed: bool) -> Self {
+        self.signed = Some(signed);
+        self
+    }
+
+    /// Select the files with the given _Samples per Pixel_
+    pub fn samples_per_pixel(mut self, samples: u16) -> Self {
+        self.samples_per_pixel = Some(samples);
+        self
+    }
+
+    /// Select the files with the given _Photometric Interpretation_,
+    /// such as `MONOCHROME2` or `RGB`
+    pub fn photometric_interpretation(mut self, photometric: impl Into<String>) -> Self {
+        self.photometric_interpretation = Some(photometric.into());
+        self
+    }
+
+    /// Check whether the given entry is selected by this query.
+    pub fn matches(&self, entry: &TestFile) -> bool {
+        let info = &entry.info;
+        let wanted = |criterion: Option<u16>, value: Option<u16>| match criterion {
+            Some(criterion) => value == Some(criterion),
+            None => true,
+        };
+        let signed = self.signed.map(u16::from);
+        let photometric = match &self.photometric_interpretation {
+            Some(photometric) => info.photometric_interpretation == Some(photometric.as_str()),
+            None => true,
+        };
+        wanted(self.bits_allocated, info.bits_allocated)
+            && wanted(signed, info.pixel_representation)
+            && wanted(self.samples_per_pixel, info.samples_per_pixel)
+            && photometric
+    }
+
+    /// Return all known test files selected by this query,
+    /// as [`entries`] does.
+    pub fn run(&self) -> Result<Vec<&'static TestFile>> {
+        Ok(all_entries()?
+            .into_iter()
+            .filter(|entry| self.matches(entry))
+            .collect())
+    }
+}
+
+impl From<Query> for Filter {
+    fn from(query: Query) -> Self {
+        Filter::predicate(move |entry| query.matches(entry))
+    }
+}
+
+/// Return the test files of each transfer syntax
+/// which at least one of them is encoded in,
+/// according to their file meta group.
+///
+/// Parsers can check that every transfer syntax they support
+/// is covered by the test files,
+/// and find the gaps as new transfer syntaxes are implemented.
+/// Only the compiled entries record their transfer syntax.
+///
+/// ```
+/// let coverage = dicom_test_files::coverage_by_transfer_syntax();
+/// for uid in ["1.2.840.10008.1.2", "1.2.840.10008.1.2.1", "1.2.840.10008.1.2.5"] {
+///     assert!(coverage.contains_key(uid), "no test file in {}", uid);
+/// }
+/// ```
+pub fn coverage_by_transfer_syntax() -> BTreeMap<&'static str, Vec<&'static TestFile>> {
+    let mut coverage: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for entry in FILE_ENTRIES {
+        if let Some(uid) = entry.info.transfer_syntax_uid {
+            coverage.entry(uid).or_default().push(entry);
+        }
+    }
+    coverage
+}
+
+/// Return all test files in the given group.
+///
+/// Groups are semantic categories of test files,
+/// recorded in the manifest:
+///
+/// - `"implicit-vr"`, `"big-endian"`, `"deflate"`:
+///   files in these native transfer syntaxes
+/// - `"jpeg"`, `"jpeg-ls"`, `"jpeg2000"`, `"rle"`:
+///   files with pixel data in these encodings
+/// - `"charset"`: files with a specific character set
+///   other than the default repertoire and Latin-1
+/// - `"no-preamble"`: files without preamble and file meta group
+/// - `"private-tags"`: files with private elements
+/// - `"undefined-length"`: files with sequences of undefined length
+/// - `"un-vr"`: files with elements of the UN value representation
+/// - `"odd-length"`: files with values of an odd length
+/// - `"icon"`: files with an icon image sequence
+/// - `"multi-frame"`: images with more than one frame
+/// - `"color"`: images with more than one sample per pixel
+/// - `"malformed"`: deliberately malformed files, see [`malformed`]
+///
+/// Use [`Filter::group`] to prefetch them.
+///
+/// ```
+/// for entry in dicom_test_files::group("charset")? {
+///     println!("{}", entry.name);
+/// }
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn group(name: &str) -> Result<Vec<&'static TestFile>> {
+    select(&Filter::group(name))
+}
+
+/// Return all test files with the given structural quirk,
+/// the members of its [`group`].
+///
+/// Use [`Filter::feature`] to prefetch them.
+///
+/// ```
+/// use dicom_test_files::Feature;
+///
+/// for entry in dicom_test_files::with_feature(Feature::NoPreamble)? {
+///     println!("{}", entry.name);
+/// }
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn with_feature(feature: Feature) -> Result<Vec<&'static TestFile>> {
+    group(feature.group())
+}
+
+/// Return all test files of the given size tier.
+///
+/// Tiers are based on the size of the files as downloaded.
+/// To keep CI runs light,
+/// pick the small files here or with [`Filter::tier`],
+/// or set the environment variable `DICOM_TEST_FILES_MAX_SIZE`
+/// to refuse downloading larger files
+/// (see the [crate documentation](crate#size-limit)).
+///
+/// ```
+/// use dicom_test_files::Tier;
+///
+/// for entry in dicom_test_files::tier(Tier::Small)? {
+///     assert!(entry.size < 1024 * 1024);
+/// }
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn tier(tier: Tier) -> Result<Vec<&'static TestFile>> {
+    select(&Filter::tier(tier))
+}
+
+fn select(filter: &Filter) -> Result<Vec<&'static TestFile>> {
+    Ok(all_entries()?
+        .into_iter()
+        .filter(|entry| filter.matches(entry))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn select_entries() {
+        let pydicom = entries("pydicom/*").unwrap();
+        assert!(pydicom
+            .iter()
+            .any(|entry| entry.name == "pydicom/liver.dcm"));
+        assert!(pydicom
+            .iter()
+            .all(|entry| entry.name.starts_with("pydicom/")));
+        assert!(entries(Filter::all()).unwrap().len() >= FILE_ENTRIES.len());
+    }
+
+    #[test]
+    fn malformed_files_are_invalid() {
+        let malformed = malformed();
+        assert!(!malformed.is_empty());
+        for (entry, _) in &malformed {
+            assert!(entry.name.starts_with("invalid/"));
+        }
+        assert!(malformed.iter().any(|(entry, defect)| entry.name
+            == "invalid/truncated_pixel_data.dcm"
+            && *defect == Defect::TruncatedPixelData));
+    }
+
+    #[test]
+    fn pydicom_names() {
+        assert_eq!(
+            pydicom_name("CT_small.dcm").unwrap(),
+            "pydicom/CT_small.dcm"
+        );
+        assert_eq!(
+            pydicom_name("693_J2KI.dcm").unwrap(),
+            "pydicom/693_J2KI.dcm"
+        );
+        match pydicom_name("CT_smal.dcm") {
+            Err(Error::NotFound { suggestions, .. }) => {
+                assert_eq!(suggestions[0], "CT_small.dcm")
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert!(pydicom_name("pydicom/CT_small.dcm").is_err());
+    }
+
+    #[test]
+    fn file_info() {
+        let info = info("WG04/REF/US1_UNC").unwrap();
+        assert_eq!(info.sop_class_uid, Some("1.2.840.10008.5.1.4.1.1.6.1"));
+        assert_eq!(info.transfer_syntax_uid, Some("1.2.840.10008.1.2.1"));
+        assert_eq!((info.rows, info.columns), (Some(480), Some(640)));
+        assert_eq!(info.frames, Some(1));
+        assert_eq!(info.bits_allocated, Some(8));
+        assert!(info.pixel_hash.is_some());
+
+        assert_eq!(
+            *super::info("pydicom/README.txt").unwrap(),
+            TestFileInfo::default()
+        );
+        assert!(matches!(
+            super::info("nope.dcm"),
+            Err(Error::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn lossless_pixel_hashes() {
+        let reference = pixel_hash("WG04/REF/MR1_UNC").unwrap();
+        assert_eq!(reference.map(str::len), Some(64));
+        for name in [
+            "WG04/RLE/MR1_RLE",
+            "WG04/JPLL/MR1_JPLL",
+            "WG04/J2KR/MR1_J2KR",
+        ] {
+            assert_eq!(pixel_hash(name).unwrap(), reference);
+        }
+        // lossy
+        assert_eq!(pixel_hash("WG04/J2KI/MR1_J2KI").unwrap(), None);
+        // no pixel data
+        assert_eq!(pixel_hash("pydicom/rtplan.dcm").unwrap(), None);
+    }
+
+    #[test]
+    fn select_by_transfer_syntax() {
+        let rle = by_transfer_syntax("1.2.840.10008.1.2.5").unwrap();
+        assert!(rle.iter().any(|entry| entry.name == "WG04/RLE/CT1_RLE"));
+        assert!(rle
+            .iter()
+            .any(|entry| entry.name == "pydicom/MR_small_RLE.dcm"));
+        assert!(rle
+            .iter()
+            .all(|entry| entry.info.transfer_syntax_uid == Some("1.2.840.10008.1.2.5")));
+
+        assert!(by_transfer_syntax("1.2.3.4").unwrap().is_empty());
+    }
+
+    #[test]
+    fn select_by_sop_class() {
+        let ct = by_sop_class("1.2.840.10008.5.1.4.1.1.2").unwrap();
+        assert!(ct.iter().any(|entry| entry.name == "WG04/REF/CT1_UNC"));
+        assert!(ct
+            .iter()
+            .all(|entry| entry.info.sop_class_uid == Some("1.2.840.10008.5.1.4.1.1.2")));
+
+        assert!(by_sop_class("1.2.3.4").unwrap().is_empty());
+    }
+
+    #[test]
+    fn query_pixel_characteristics() {
+        let signed = query().bits_allocated(16).signed(true).run().unwrap();
+        assert!(signed
+            .iter()
+            .any(|entry| entry.name == "pydicom/CT_small.dcm"));
+        assert!(signed.iter().all(|entry| {
+            entry.info.bits_allocated == Some(16) && entry.info.pixel_representation == Some(1)
+        }));
+
+        let rgb = query().photometric_interpretation("RGB").run().unwrap();
+        assert!(!rgb.is_empty());
+        assert!(rgb
+            .iter()
+            .all(|entry| entry.info.samples_per_pixel == Some(3)));
+        assert!(query()
+            .samples_per_pixel(3)
+            .photometric_interpretation("MONOCHROME2")
+            .run()
+            .unwrap()
+            .is_empty());
+
+        let filter = Filter::from(query().bits_allocated(8));
+        assert!(!filter.matches(crate::lookup("pydicom/CT_small.dcm").unwrap()));
+    }
+
+    #[test]
+    fn transfer_syntax_coverage() {
+        let coverage = coverage_by_transfer_syntax();
+        assert!(coverage.contains_key("1.2.840.10008.1.2.4.70"));
+        assert!(!coverage.contains_key("1.2.3.4"));
+        for (uid, entries) in &coverage {
+            let names = |entries: &[&TestFile]| -> Vec<&str> {
+                entries.iter().map(|entry| entry.name).collect()
+            };
+            assert_eq!(names(entries), names(&by_transfer_syntax(uid).unwrap()));
+        }
+    }
+
+    #[test]
+    fn select_by_group() {
+        let names = |entries: Vec<&TestFile>| -> Vec<&str> {
+            entries.into_iter().map(|entry| entry.name).collect()
+        };
+        let rle = names(group("rle").unwrap());
+        assert!(rle.contains(&"WG04/RLE/CT1_RLE"));
+        assert_eq!(
+            rle,
+            names(by_transfer_syntax("1.2.840.10008.1.2.5").unwrap())
+        );
+
+        let no_preamble = group("no-preamble").unwrap();
+        assert!(no_preamble
+            .iter()
+            .any(|entry| entry.name == "pydicom/ExplVR_LitEndNoMeta.dcm"));
+
+        let malformed = group("malformed").unwrap();
+        assert_eq!(malformed.len(), super::malformed().len());
+        assert!(malformed.iter().all(|entry| entry.groups == ["malformed"]));
+
+        assert!(group("nope").unwrap().is_empty());
+    }
+
+    #[test]
+    fn select_by_feature() {
+        let un = with_feature(Feature::UnknownVr).unwrap();
+        assert!(un
+            .iter()
+            .any(|entry| entry.name == "pydicom/explicit_VR-UN.dcm"));
+        let icon = with_feature(Feature::IconImage).unwrap();
+        assert!(icon
+            .iter()
+            .any(|entry| entry.name == "pydicom/MR-SIEMENS-DICOM-WithOverlays.dcm"));
+        assert!(!with_feature(Feature::UndefinedLengthSequences)
+            .unwrap()
+            .is_empty());
+
+        let no_preamble = Filter::feature(Feature::NoPreamble);
+        assert!(with_feature(Feature::NoPreamble)
+            .unwrap()
+            .iter()
+            .all(|entry| no_preamble.matches(entry)));
+        assert_eq!(
+            with_feature(Feature::PrivateTags).unwrap().len(),
+            group("private-tags").unwrap().len()
+        );
+    }
+
+    #[test]
+    fn select_by_tier() {
+        let small = tier(Tier::Small).unwrap();
+        let medium = tier(Tier::Medium).unwrap();
+        let large = tier(Tier::Large).unwrap();
+        // along with the entries registered by other tests
+        let compiled = |entries: &[&TestFile]| {
+            entries
+                .iter()
+                .filter(|entry| crate::lookup(entry.name).is_some())
+                .count()
+        };
+        assert_eq!(
+            compiled(&small) + compiled(&medium) + compiled(&large),
+            FILE_ENTRIES.len()
+        );
+        assert!(small
+            .iter()
+            .any(|entry| entry.name == "pydicom/CT_small.dcm"));
+        assert!(large.iter().any(|entry| entry.name == "WG04/RLE/VL5_RLE"));
+        assert!(FILE_ENTRIES.iter().all(|entry| entry.size > 0));
+        assert!(medium
+            .iter()
+            .all(|entry| (1024 * 1024..10 * 1024 * 1024).contains(&entry.size)));
+    }
+
+    #[test]
+    fn native_pixel_hash() {
+        // 100x100 interleaved RGB pixel data at the end of the file
+        let data = std::fs::read(crate::path("pydicom/SC_rgb.dcm").unwrap()).unwrap();
+        let pixel_data = &data[data.len() - 100 * 100 * 3..];
+        let hash = format!("{:x}", Sha256::digest(pixel_data));
+        assert_eq!(pixel_hash("pydicom/SC_rgb.dcm").unwrap(), Some(&*hash));
+    }
+}
@@ -0,0 +1,233 @@
+//! Injection of download failures and stalls, for resilience testing
+
+use crate::{config, Error, Result};
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// How long a stalled download waits by default
+const DEFAULT_STALL: Duration = Duration::from_secs(5);
+
+/// Number of downloads which went through chaos so far in the process
+static DOWNLOADS: AtomicU64 = AtomicU64::new(0);
+
+/// A share of downloads to fail or stall on purpose,
+/// see the [crate documentation](crate#chaos-mode).
+///
+/// ```no_run
+/// use dicom_test_files::{Chaos, Config};
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// Config::new()
+///     .chaos(Chaos::new().fail(20).stall(10, Duration::from_secs(2)))
+///     .apply()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chaos {
+    fail: u8,
+    stall: u8,
+    stall_time: Duration,
+    seed: Option<u64>,
+}
+
+impl Default for Chaos {
+    fn default() -> Self {
+        Chaos {
+            fail: 0,
+            stall: 0,
+            stall_time: DEFAULT_STALL,
+            seed: None,
+        }
+    }
+}
+
+/// What happens to a download
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Proceed,
+    Fail,
+    Stall(Duration),
+}
+
+impl Chaos {
+    /// Start with no failures nor stalls.
+    pub fn new() -> Self {
+        Chaos::default()
+    }
+
+    /// Fail the given percentage of downloads
+    /// with [`Error::Unreachable`], as a network failure would.
+    pub fn fail(mut self, percent: u8) -> Self {
+        self.fail = percent.min(100);
+        self.stall = self.stall.min(100 - self.fail);
+        self
+    }
+
+    /// Wait for the given duration before the given percentage of downloads,
+    /// out of those which do not fail.
+    pub fn stall(mut self, percent: u8, duration: Duration) -> Self {
+        self.stall = percent.min(100 - self.fail);
+        self.stall_time = duration;
+        self
+    }
+
+    /// Pick the downloads to fail or stall from the given seed,
+    /// so that the same downloads are picked in each run,
+    /// instead of different ones every time.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Parse the value of `DICOM_TEST_FILES_CHAOS`,
+    /// such as `fail=20,stall=10,stall-time=2s,seed=42`
+    pub(crate) fn parse(value: &str) -> Option<Chaos> {
+        let mut chaos = Chaos::new();
+        let mut fail = 0;
+        let mut stall = 0;
+        for option in value.split(',').map(str::trim) {
+            let (key, value) = option.split_once('=')?;
+            let value = value.trim();
+            match key.trim() {
+                "fail" => fail = parse_percent(value)?,
+                "stall" => stall = parse_percent(value)?,
+                "stall-time" => chaos.stall_time = parse_duration(value)?,
+                "seed" => chaos.seed = Some(value.parse().ok()?),
+                _ => return None,
+            }
+        }
+        if fail + stall > 100 {
+            return None;
+        }
+        chaos.fail = fail;
+        chaos.stall = stall;
+        Some(chaos)
+    }
+
+    /// Pick what happens to the n-th download of the process
+    fn pick(&self, n: u64) -> Outcome {
+        if self.fail == 0 && self.stall == 0 {
+            return Outcome::Proceed;
+        }
+        let seed = self.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos() as u64
+        });
+        let roll = (splitmix64(seed ^ splitmix64(n)) % 100) as u8;
+        if roll < self.fail {
+            Outcome::Fail
+        } else if roll < self.fail + self.stall {
+            Outcome::Stall(self.stall_time)
+        } else {
+            Outcome::Proceed
+        }
+    }
+}
+
+/// A percentage, with or without a `%` sign
+fn parse_percent(value: &str) -> Option<u8> {
+    let percent: u8 = value.strip_suffix('%').unwrap_or(value).parse().ok()?;
+    Some(percent).filter(|percent| *percent <= 100)
+}
+
+/// A duration in seconds, or with an `ms` or `s` suffix
+fn parse_duration(value: &str) -> Option<Duration> {
+    if let Some(millis) = value.strip_suffix("ms") {
+        return millis.parse().ok().map(Duration::from_millis);
+    }
+    let secs: f64 = value.strip_suffix('s').unwrap_or(value).parse().ok()?;
+    Some(secs)
+        .filter(|secs| secs.is_finite() && *secs >= 0.)
+        .map(Duration::from_secs_f64)
+}
+
+/// The SplitMix64 mix of a number
+fn splitmix64(n: u64) -> u64 {
+    let mut z = n.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Fail or stall the download from the given URL,
+/// if chaos mode picks it
+pub(crate) fn inject(url: &str) -> Result<()> {
+    let chaos = match config::chaos() {
+        Some(chaos) => chaos,
+        None => return Ok(()),
+    };
+    match chaos.pick(DOWNLOADS.fetch_add(1, Ordering::SeqCst)) {
+        Outcome::Proceed => Ok(()),
+        Outcome::Fail => {
+            eprintln!(
+                "[dicom-test-files] Chaos mode: failing the download of {}",
+                url
+            );
+            Err(Error::Unreachable(format!(
+                "Failed to download {}: failure injected by chaos mode",
+                url
+            )))
+        }
+        Outcome::Stall(duration) => {
+            eprintln!(
+                "[dicom-test-files] Chaos mode: stalling the download of {} for {:?}",
+                url, duration
+            );
+            std::thread::sleep(duration);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_chaos() {
+        assert_eq!(
+            Chaos::parse("fail=20%, stall=10,stall-time=250ms,seed=42"),
+            Some(
+                Chaos::new()
+                    .fail(20)
+                    .stall(10, Duration::from_millis(250))
+                    .seed(42)
+            )
+        );
+        assert_eq!(Chaos::parse("fail=5"), Some(Chaos::new().fail(5)));
+        assert_eq!(
+            Chaos::parse("stall=100,stall-time=1.5"),
+            Some(Chaos::new().stall(100, Duration::from_millis(1500)))
+        );
+        for invalid in [
+            "20",
+            "fail=101",
+            "fail=60,stall=60",
+            "flake=3",
+            "stall-time=-1s",
+        ] {
+            assert_eq!(Chaos::parse(invalid), None, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn pick_downloads() {
+        let stall = Duration::from_secs(1);
+        let chaos = Chaos::new().fail(30).stall(20, stall).seed(7);
+        let picked: Vec<Outcome> = (0..1000).map(|n| chaos.pick(n)).collect();
+        let count = |outcome| picked.iter().filter(|o| **o == outcome).count();
+        assert!((200..400).contains(&count(Outcome::Fail)));
+        assert!((100..300).contains(&count(Outcome::Stall(stall))));
+        // the same with the same seed
+        assert!((0..1000).all(|n| chaos.pick(n) == picked[n as usize]));
+
+        assert!((0..100).all(|n| Chaos::new().fail(100).pick(n) == Outcome::Fail));
+        assert!((0..100).all(|n| Chaos::new().pick(n) == Outcome::Proceed));
+    }
+}
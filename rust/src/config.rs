@@ -0,0 +1,1032 @@
+//! Process-wide configuration
+
+use crate::{
+    backend, http, manifest, signature, Backend, Chaos, Collection, Error, LockMode, Result,
+    TestFile,
+};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+/// Entries of the external manifest, once loaded,
+/// along with whether the manifest is signed
+static MANIFEST_ENTRIES: RwLock<Option<(&'static [TestFile], bool)>> = RwLock::new(None);
+
+/// Git ref of the data set set by [`Config::git_ref`]
+static GIT_REF: RwLock<Option<String>> = RwLock::new(None);
+
+/// Hosting backend set by [`Config::backend`]
+static BACKEND: RwLock<Option<Backend>> = RwLock::new(None);
+
+/// Whether cached files are revalidated, set by [`Config::refresh`]
+static REFRESH: RwLock<Option<bool>> = RwLock::new(None);
+
+/// Download limit set by [`Config::max_downloads`]
+static MAX_DOWNLOADS: RwLock<Option<usize>> = RwLock::new(None);
+
+/// Rate limit set by [`Config::max_rate`]
+static MAX_RATE: RwLock<Option<u64>> = RwLock::new(None);
+
+/// Cache quota set by [`Config::max_cache_bytes`]
+static MAX_CACHE_BYTES: RwLock<Option<u64>> = RwLock::new(None);
+
+/// Limit of the files read into memory set by [`Config::max_in_memory`]
+static MAX_IN_MEMORY: RwLock<Option<u64>> = RwLock::new(None);
+
+/// Whether compressed files stay compressed, set by [`Config::keep_compressed`]
+static KEEP_COMPRESSED: RwLock<Option<bool>> = RwLock::new(None);
+
+/// Whether each binary has its own view of the cache, set by [`Config::namespace`]
+static NAMESPACE: RwLock<Option<bool>> = RwLock::new(None);
+
+/// Size limit set by [`Config::max_size`]
+static MAX_SIZE: RwLock<Option<u64>> = RwLock::new(None);
+
+/// Fallback cache directory set by [`Config::fallback_dir`]
+static FALLBACK_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Chaos mode set by [`Config::chaos`]
+static CHAOS: RwLock<Option<Chaos>> = RwLock::new(None);
+
+/// Keys trusted to sign manifests set by [`Config::trusted_key`]
+static TRUSTED_KEYS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Whether sources must be signed, set by [`Config::require_signatures`]
+static REQUIRE_SIGNATURES: RwLock<Option<bool>> = RwLock::new(None);
+
+/// Content-addressed gateway set by [`Config::gateway_url`]
+static GATEWAY_URL: RwLock<Option<String>> = RwLock::new(None);
+
+/// Base URL of the large files set by [`Config::cdn_url`]
+static CDN_URL: RwLock<Option<String>> = RwLock::new(None);
+
+/// Git LFS server set by [`Config::lfs_url`]
+static LFS_URL: RwLock<Option<String>> = RwLock::new(None);
+
+/// Endpoint and access token of Google Cloud Storage
+/// set by [`Config::gcs_endpoint`] and [`Config::gcs_token`]
+static GCS: RwLock<(Option<String>, Option<String>)> = RwLock::new((None, None));
+
+/// Endpoint, SAS token and access token of Azure Blob Storage set by
+/// [`Config::azure_endpoint`], [`Config::azure_sas`] and [`Config::azure_token`]
+static AZURE: RwLock<(Option<String>, Option<String>, Option<String>)> =
+    RwLock::new((None, None, None));
+
+/// URL template of the artifact repository set by [`Config::artifact_url`]
+static ARTIFACT_URL: RwLock<Option<String>> = RwLock::new(None);
+
+/// Headers of the artifact repository set by [`Config::artifact_header`], by name
+static ARTIFACT_HEADERS: RwLock<Vec<(String, String)>> = RwLock::new(Vec::new());
+
+/// Base URLs of hosted collections set by [`Config::collection_url`], by name
+static COLLECTION_URLS: RwLock<Vec<(String, String)>> = RwLock::new(Vec::new());
+
+/// Lock mode and lockfile set by [`Config::lock`] and [`Config::lockfile`]
+static LOCK: RwLock<(Option<LockMode>, Option<PathBuf>)> = RwLock::new((None, None));
+
+/// Process-wide configuration of the test file sources.
+///
+/// Options are applied to all following calls in the process,
+/// and take precedence over the equivalent environment variables.
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// dicom_test_files::Config::new()
+///     .manifest("tests/private_files.toml")
+///     .apply()?;
+/// let weird = dicom_test_files::path("myorg/weird.dcm")?;
+/// # let _ = weird;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Config {
+    manifest: Option<PathBuf>,
+    git_ref: Option<String>,
+    backend: Option<Backend>,
+    lock: Option<LockMode>,
+    lockfile: Option<PathBuf>,
+    refresh: Option<bool>,
+    max_downloads: Option<usize>,
+    max_rate: Option<u64>,
+    max_size: Option<u64>,
+    max_cache_bytes: Option<u64>,
+    keep_compressed: Option<bool>,
+    namespace: Option<bool>,
+    max_in_memory: Option<u64>,
+    fallback_dir: Option<PathBuf>,
+    chaos: Option<Chaos>,
+    trusted_keys: Vec<String>,
+    require_signatures: Option<bool>,
+    collection_urls: Vec<(String, String)>,
+    gateway_url: Option<String>,
+    cdn_url: Option<String>,
+    lfs_url: Option<String>,
+    gcs_endpoint: Option<String>,
+    gcs_token: Option<String>,
+    azure_endpoint: Option<String>,
+    azure_sas: Option<String>,
+    azure_token: Option<String>,
+    artifact_url: Option<String>,
+    artifact_headers: Vec<(String, String)>,
+}
+
+impl Config {
+    /// Start a configuration which leaves all options unchanged.
+    pub fn new() -> Self {
+        Config::default()
+    }
+
+    /// Load test file entries from the given JSON or TOML manifest,
+    /// instead of the one set by `DICOM_TEST_FILES_MANIFEST`.
+    ///
+    /// See the [crate documentation](crate#external-manifests)
+    /// for the manifest format.
+    pub fn manifest(mut self, path: impl Into<PathBuf>) -> Self {
+        self.manifest = Some(path.into());
+        self
+    }
+
+    /// Download the test files of this project as of the given git
+    /// tag, branch or commit,
+    /// instead of the one set by `DICOM_TEST_FILES_REF`
+    /// or the [`DEFAULT_REF`](crate::DEFAULT_REF) of this release.
+    ///
+    /// This has no effect when `DICOM_TEST_FILES_URL` is set.
+    pub fn git_ref(mut self, git_ref: impl Into<String>) -> Self {
+        self.git_ref = Some(git_ref.into());
+        self
+    }
+
+    /// Download the test files of this project from the given backend,
+    /// instead of the one set by `DICOM_TEST_FILES_BACKEND`.
+    ///
+    /// See the [crate documentation](crate#hosting-backends).
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Record or verify the test files used in the [lockfile](crate#lockfile),
+    /// instead of following `DICOM_TEST_FILES_LOCK`.
+    pub fn lock(mut self, mode: LockMode) -> Self {
+        self.lock = Some(mode);
+        self
+    }
+
+    /// Use the lockfile at the given path,
+    /// instead of the one set by `DICOM_TEST_FILES_LOCKFILE`
+    /// or `dicom-test-files.lock` in the workspace.
+    pub fn lockfile(mut self, path: impl Into<PathBuf>) -> Self {
+        self.lockfile = Some(path.into());
+        self
+    }
+
+    /// Confirm with the data source that cached test files are current
+    /// before using them, instead of following `DICOM_TEST_FILES_REFRESH`.
+    ///
+    /// See the [crate documentation](crate#revalidation).
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.refresh = Some(refresh);
+        self
+    }
+
+    /// Download at most the given number of files at the same time
+    /// in the process,
+    /// instead of the limit set by `DICOM_TEST_FILES_MAX_DOWNLOADS`.
+    ///
+    /// See the [crate documentation](crate#download-limit).
+    pub fn max_downloads(mut self, max_downloads: usize) -> Self {
+        self.max_downloads = Some(max_downloads.max(1));
+        self
+    }
+
+    /// Download at most the given number of bytes per second
+    /// across all downloads in the process,
+    /// instead of the limit set by `DICOM_TEST_FILES_MAX_RATE`.
+    ///
+    /// See the [crate documentation](crate#download-limit).
+    pub fn max_rate(mut self, bytes_per_second: u64) -> Self {
+        self.max_rate = Some(bytes_per_second.max(1));
+        self
+    }
+
+    /// Refuse to download test files larger than the given number of bytes,
+    /// instead of the limit set by `DICOM_TEST_FILES_MAX_SIZE`.
+    ///
+    /// See the [crate documentation](crate#size-limit).
+    pub fn max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Refuse to download test files
+    /// once the cache would hold more than the given number of bytes,
+    /// instead of the quota set by `DICOM_TEST_FILES_MAX_CACHE_BYTES`.
+    /// Cached files are never evicted to make room.
+    ///
+    /// See the [crate documentation](crate#cache-quota).
+    pub fn max_cache_bytes(mut self, max_cache_bytes: u64) -> Self {
+        self.max_cache_bytes = Some(max_cache_bytes);
+        self
+    }
+
+    /// Refuse to read test files larger than the given number of bytes into memory
+    /// with [`bytes`](crate::bytes),
+    /// instead of the limit set by `DICOM_TEST_FILES_MAX_IN_MEMORY`
+    /// or 512 MiB.
+    ///
+    /// See the [crate documentation](crate#size-limit).
+    pub fn max_in_memory(mut self, max_in_memory: u64) -> Self {
+        self.max_in_memory = Some(max_in_memory);
+        self
+    }
+
+    /// Keep the test files stored with compression compressed in the cache,
+    /// decompressing them as they are read with [`reader`](crate::reader),
+    /// instead of following `DICOM_TEST_FILES_KEEP_COMPRESSED`.
+    ///
+    /// See the [crate documentation](crate#compressed-cache).
+    pub fn keep_compressed(mut self, keep_compressed: bool) -> Self {
+        self.keep_compressed = Some(keep_compressed);
+        self
+    }
+
+    /// Give the running binary its own view of the cache,
+    /// linking to the shared cached files,
+    /// instead of following `DICOM_TEST_FILES_NAMESPACE`.
+    ///
+    /// See the [crate documentation](crate#cache-namespaces).
+    pub fn namespace(mut self, namespace: bool) -> Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    /// Cache test files in the given directory
+    /// when the cache directory cannot be written to,
+    /// instead of the one set by `DICOM_TEST_FILES_FALLBACK_DIR`.
+    ///
+    /// See the [crate documentation](crate#cache-location).
+    pub fn fallback_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.fallback_dir = Some(path.into());
+        self
+    }
+
+    /// Fail or stall a share of downloads on purpose,
+    /// instead of following `DICOM_TEST_FILES_CHAOS`.
+    ///
+    /// See the [crate documentation](crate#chaos-mode).
+    pub fn chaos(mut self, chaos: Chaos) -> Self {
+        self.chaos = Some(chaos);
+        self
+    }
+
+    /// Trust manifests signed with the given Ed25519 public key,
+    /// in hexadecimal, along with the keys set by `DICOM_TEST_FILES_TRUSTED_KEYS`.
+    ///
+    /// See the [crate documentation](crate#signatures).
+    pub fn trusted_key(mut self, public_key: impl Into<String>) -> Self {
+        self.trusted_keys.push(public_key.into());
+        self
+    }
+
+    /// Refuse test files from sources which are not signed,
+    /// instead of following `DICOM_TEST_FILES_REQUIRE_SIGNATURES`.
+    ///
+    /// See the [crate documentation](crate#signatures).
+    pub fn require_signatures(mut self, require: bool) -> Self {
+        self.require_signatures = Some(require);
+        self
+    }
+
+    /// Download the test files of the given hosted collection,
+    /// such as `gdcm`, from the given base URL,
+    /// instead of the one set by `DICOM_TEST_FILES_<NAME>_URL`
+    /// or the collection's own.
+    ///
+    /// See the [crate documentation](crate#hosted-collections).
+    pub fn collection_url(mut self, name: impl Into<String>, base_url: impl Into<String>) -> Self {
+        self.collection_urls.push((name.into(), base_url.into()));
+        self
+    }
+
+    /// Download the test files by their hash
+    /// from the content-addressed gateway with the given base URL
+    /// in the `content-addressed` [backend](Config::backend),
+    /// instead of the one set by `DICOM_TEST_FILES_GATEWAY_URL`.
+    ///
+    /// See the [crate documentation](crate#hosting-backends).
+    pub fn gateway_url(mut self, base_url: impl Into<String>) -> Self {
+        self.gateway_url = Some(base_url.into());
+        self
+    }
+
+    /// Download the large files held as pointer records
+    /// from the given base URL,
+    /// instead of the one set by `DICOM_TEST_FILES_CDN_URL`
+    /// or the release assets of the project.
+    ///
+    /// See the [crate documentation](crate#large-files).
+    pub fn cdn_url(mut self, base_url: impl Into<String>) -> Self {
+        self.cdn_url = Some(base_url.into());
+        self
+    }
+
+    /// Resolve the Git LFS pointer files served in place of test files
+    /// with the given LFS server, such as
+    /// `https://github.com/robyoung/dicom-test-files.git/info/lfs`,
+    /// instead of the one set by `DICOM_TEST_FILES_LFS_URL`
+    /// or the one of the GitHub repository serving them.
+    ///
+    /// See the [crate documentation](crate#git-lfs).
+    pub fn lfs_url(mut self, url: impl Into<String>) -> Self {
+        self.lfs_url = Some(url.into());
+        self
+    }
+
+    /// Request the objects of `gs://` URLs from the given endpoint,
+    /// such as an emulator,
+    /// instead of the one set by `DICOM_TEST_FILES_GCS_ENDPOINT`
+    /// or the one of Google Cloud Storage.
+    ///
+    /// See the [crate documentation](crate#object-stores).
+    pub fn gcs_endpoint(mut self, url: impl Into<String>) -> Self {
+        self.gcs_endpoint = Some(url.into());
+        self
+    }
+
+    /// Authorize the requests to Google Cloud Storage
+    /// with the given OAuth access token,
+    /// instead of the one set by `DICOM_TEST_FILES_GCS_TOKEN`.
+    ///
+    /// See the [crate documentation](crate#object-stores).
+    pub fn gcs_token(mut self, token: impl Into<String>) -> Self {
+        self.gcs_token = Some(token.into());
+        self
+    }
+
+    /// Request the blobs of `az://` URLs from the given endpoint of the account,
+    /// such as `http://127.0.0.1:10000/devstoreaccount1` for Azurite,
+    /// instead of the one set by `DICOM_TEST_FILES_AZURE_ENDPOINT`
+    /// or the one of Azure Blob Storage.
+    ///
+    /// See the [crate documentation](crate#object-stores).
+    pub fn azure_endpoint(mut self, url: impl Into<String>) -> Self {
+        self.azure_endpoint = Some(url.into());
+        self
+    }
+
+    /// Authorize the requests to Azure Blob Storage
+    /// with the given SAS token,
+    /// instead of the one set by `DICOM_TEST_FILES_AZURE_SAS`.
+    /// It takes precedence over an [access token](Config::azure_token).
+    ///
+    /// See the [crate documentation](crate#object-stores).
+    pub fn azure_sas(mut self, sas: impl Into<String>) -> Self {
+        self.azure_sas = Some(sas.into());
+        self
+    }
+
+    /// Authorize the requests to Azure Blob Storage
+    /// with the given OAuth access token,
+    /// instead of the one set by `DICOM_TEST_FILES_AZURE_TOKEN`.
+    ///
+    /// See the [crate documentation](crate#object-stores).
+    pub fn azure_token(mut self, token: impl Into<String>) -> Self {
+        self.azure_token = Some(token.into());
+        self
+    }
+
+    /// Download the test files of this project
+    /// in the `artifact` [backend](Config::backend)
+    /// from the URLs of the given template, such as
+    /// `https://artifactory.example.org/artifactory/dicom/{ref}/{path}`,
+    /// instead of the one set by `DICOM_TEST_FILES_ARTIFACT_URL`.
+    ///
+    /// See the [crate documentation](crate#artifact-repositories).
+    pub fn artifact_url(mut self, template: impl Into<String>) -> Self {
+        self.artifact_url = Some(template.into());
+        self
+    }
+
+    /// Send the given header with the requests to the artifact repository,
+    /// such as an API key,
+    /// in place of the header of the same name
+    /// set by `DICOM_TEST_FILES_ARTIFACT_HEADERS`.
+    ///
+    /// See the [crate documentation](crate#artifact-repositories).
+    pub fn artifact_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.artifact_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Make this configuration effective for the rest of the process.
+    ///
+    /// Fails if the manifest cannot be read or its signature is not valid,
+    /// or if a trusted key is not valid,
+    /// in which case no option is changed.
+    pub fn apply(self) -> Result<()> {
+        if let Some(key) = self
+            .trusted_keys
+            .iter()
+            .find(|key| !signature::is_public_key(key))
+        {
+            return Err(Error::Manifest(format!("invalid trusted key {:?}", key)));
+        }
+        if let Some(path) = self.manifest {
+            let mut trusted_keys = trusted_keys();
+            trusted_keys.extend(self.trusted_keys.iter().cloned());
+            let entries = load_manifest(&path, &trusted_keys)?;
+            *MANIFEST_ENTRIES.write().unwrap_or_else(|e| e.into_inner()) = Some(entries);
+        }
+        TRUSTED_KEYS
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .extend(self.trusted_keys);
+        if let Some(git_ref) = self.git_ref {
+            *GIT_REF.write().unwrap_or_else(|e| e.into_inner()) = Some(git_ref);
+        }
+        if let Some(backend) = self.backend {
+            *BACKEND.write().unwrap_or_else(|e| e.into_inner()) = Some(backend);
+        }
+        if let Some(refresh) = self.refresh {
+            *REFRESH.write().unwrap_or_else(|e| e.into_inner()) = Some(refresh);
+        }
+        if let Some(max_downloads) = self.max_downloads {
+            *MAX_DOWNLOADS.write().unwrap_or_else(|e| e.into_inner()) = Some(max_downloads);
+        }
+        if let Some(max_rate) = self.max_rate {
+            *MAX_RATE.write().unwrap_or_else(|e| e.into_inner()) = Some(max_rate);
+        }
+        if let Some(max_size) = self.max_size {
+            *MAX_SIZE.write().unwrap_or_else(|e| e.into_inner()) = Some(max_size);
+        }
+        if let Some(max_cache_bytes) = self.max_cache_bytes {
+            *MAX_CACHE_BYTES.write().unwrap_or_else(|e| e.into_inner()) = Some(max_cache_bytes);
+        }
+        if let Some(max_in_memory) = self.max_in_memory {
+            *MAX_IN_MEMORY.write().unwrap_or_else(|e| e.into_inner()) = Some(max_in_memory);
+        }
+        if let Some(keep_compressed) = self.keep_compressed {
+            *KEEP_COMPRESSED.write().unwrap_or_else(|e| e.into_inner()) = Some(keep_compressed);
+        }
+        if let Some(namespace) = self.namespace {
+            *NAMESPACE.write().unwrap_or_else(|e| e.into_inner()) = Some(namespace);
+        }
+        if let Some(path) = self.fallback_dir {
+            *FALLBACK_DIR.write().unwrap_or_else(|e| e.into_inner()) = Some(path);
+        }
+        if let Some(require) = self.require_signatures {
+            *REQUIRE_SIGNATURES
+                .write()
+                .unwrap_or_else(|e| e.into_inner()) = Some(require);
+        }
+        if let Some(chaos) = self.chaos {
+            *CHAOS.write().unwrap_or_else(|e| e.into_inner()) = Some(chaos);
+        }
+        if !self.collection_urls.is_empty() {
+            let mut urls = COLLECTION_URLS.write().unwrap_or_else(|e| e.into_inner());
+            for (name, url) in self.collection_urls {
+                urls.retain(|(configured, _)| *configured != name);
+                urls.push((name, url));
+            }
+        }
+        if let Some(url) = self.gateway_url {
+            *GATEWAY_URL.write().unwrap_or_else(|e| e.into_inner()) = Some(url);
+        }
+        if let Some(url) = self.cdn_url {
+            *CDN_URL.write().unwrap_or_else(|e| e.into_inner()) = Some(url);
+        }
+        if let Some(url) = self.lfs_url {
+            *LFS_URL.write().unwrap_or_else(|e| e.into_inner()) = Some(url);
+        }
+        let mut gcs = GCS.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(url) = self.gcs_endpoint {
+            gcs.0 = Some(url);
+        }
+        if let Some(token) = self.gcs_token {
+            gcs.1 = Some(token);
+        }
+        drop(gcs);
+        let mut azure = AZURE.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(url) = self.azure_endpoint {
+            azure.0 = Some(url);
+        }
+        if let Some(sas) = self.azure_sas {
+            azure.1 = Some(sas);
+        }
+        if let Some(token) = self.azure_token {
+            azure.2 = Some(token);
+        }
+        drop(azure);
+        if let Some(template) = self.artifact_url {
+            *ARTIFACT_URL.write().unwrap_or_else(|e| e.into_inner()) = Some(template);
+        }
+        if !self.artifact_headers.is_empty() {
+            let mut headers = ARTIFACT_HEADERS.write().unwrap_or_else(|e| e.into_inner());
+            for (name, value) in self.artifact_headers {
+                headers.retain(|(configured, _)| !configured.eq_ignore_ascii_case(&name));
+                headers.push((name, value));
+            }
+        }
+        let mut lock = LOCK.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(mode) = self.lock {
+            lock.0 = Some(mode);
+        }
+        if let Some(path) = self.lockfile {
+            lock.1 = Some(path);
+        }
+        Ok(())
+    }
+}
+
+/// The entries of the external manifest,
+/// loading the one set by `DICOM_TEST_FILES_MANIFEST` on first use.
+///
+/// Fails if signatures are required and the manifest is not signed.
+pub(crate) fn manifest_entries() -> Result<&'static [TestFile]> {
+    let (entries, signed) = loaded_manifest()?;
+    if !signed && !entries.is_empty() && require_signatures() {
+        return Err(Error::Manifest(
+            "the external manifest is not signed, as signatures are required".to_string(),
+        ));
+    }
+    Ok(entries)
+}
+
+fn loaded_manifest() -> Result<(&'static [TestFile], bool)> {
+    if let Some(loaded) = *MANIFEST_ENTRIES.read().unwrap_or_else(|e| e.into_inner()) {
+        return Ok(loaded);
+    }
+    let mut entries = MANIFEST_ENTRIES.write().unwrap_or_else(|e| e.into_inner());
+    if let Some(loaded) = *entries {
+        return Ok(loaded);
+    }
+    let loaded = match env::var_os("DICOM_TEST_FILES_MANIFEST") {
+        Some(path) if !path.is_empty() => load_manifest(Path::new(&path), &trusted_keys())?,
+        _ => (&[][..], false),
+    };
+    *entries = Some(loaded);
+    Ok(loaded)
+}
+
+/// The git ref of the data set, if one is configured
+pub(crate) fn git_ref() -> Option<String> {
+    if let Some(git_ref) = &*GIT_REF.read().unwrap_or_else(|e| e.into_inner()) {
+        return Some(git_ref.clone());
+    }
+    env::var("DICOM_TEST_FILES_REF")
+        .ok()
+        .filter(|git_ref| !git_ref.is_empty())
+}
+
+/// The base URL of a hosted collection, with a trailing slash
+pub(crate) fn collection_url(collection: &Collection) -> String {
+    let configured = COLLECTION_URLS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .find(|(name, _)| name == collection.name)
+        .map(|(_, url)| url.clone());
+    let variable = format!("DICOM_TEST_FILES_{}_URL", collection.name.to_uppercase());
+    let url = match configured.or_else(|| env::var(variable).ok()) {
+        Some(url) if !url.is_empty() => url,
+        _ => return collection.base_url.to_string(),
+    };
+    if url.ends_with('/') {
+        url
+    } else {
+        format!("{}/", url)
+    }
+}
+
+/// The base URL of the content-addressed gateway,
+/// with a trailing slash, if one is configured
+pub(crate) fn gateway_url() -> Option<String> {
+    let configured = GATEWAY_URL
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    let url = match configured.or_else(|| env::var("DICOM_TEST_FILES_GATEWAY_URL").ok()) {
+        Some(url) if !url.is_empty() => url,
+        _ => return None,
+    };
+    if url.ends_with('/') {
+        Some(url)
+    } else {
+        Some(format!("{}/", url))
+    }
+}
+
+/// The base URL of the large files held as pointer records,
+/// with a trailing slash, if one is configured
+pub(crate) fn cdn_url() -> Option<String> {
+    let configured = CDN_URL.read().unwrap_or_else(|e| e.into_inner()).clone();
+    let url = match configured.or_else(|| env::var("DICOM_TEST_FILES_CDN_URL").ok()) {
+        Some(url) if !url.is_empty() => url,
+        _ => return None,
+    };
+    if url.ends_with('/') {
+        Some(url)
+    } else {
+        Some(format!("{}/", url))
+    }
+}
+
+/// The Git LFS server, without a trailing slash, if one is configured
+#[cfg_attr(not(feature = "lfs"), allow(dead_code))]
+pub(crate) fn lfs_url() -> Option<String> {
+    let configured = LFS_URL.read().unwrap_or_else(|e| e.into_inner()).clone();
+    match configured.or_else(|| env::var("DICOM_TEST_FILES_LFS_URL").ok()) {
+        Some(url) if !url.is_empty() => Some(url.trim_end_matches('/').to_string()),
+        _ => None,
+    }
+}
+
+/// The endpoint and the access token of Google Cloud Storage, if configured
+#[cfg_attr(not(feature = "gcs"), allow(dead_code))]
+pub(crate) fn gcs() -> (Option<String>, Option<String>) {
+    let (endpoint, token) = GCS.read().unwrap_or_else(|e| e.into_inner()).clone();
+    (
+        setting(endpoint, "DICOM_TEST_FILES_GCS_ENDPOINT"),
+        setting(token, "DICOM_TEST_FILES_GCS_TOKEN"),
+    )
+}
+
+/// The endpoint, the SAS token and the access token of Azure Blob Storage,
+/// if configured
+#[cfg_attr(not(feature = "azure"), allow(dead_code))]
+pub(crate) fn azure() -> (Option<String>, Option<String>, Option<String>) {
+    let (endpoint, sas, token) = AZURE.read().unwrap_or_else(|e| e.into_inner()).clone();
+    (
+        setting(endpoint, "DICOM_TEST_FILES_AZURE_ENDPOINT"),
+        setting(sas, "DICOM_TEST_FILES_AZURE_SAS"),
+        setting(token, "DICOM_TEST_FILES_AZURE_TOKEN"),
+    )
+}
+
+/// The configured value of a setting, or else the one of the given variable,
+/// unless empty
+#[cfg_attr(not(any(feature = "gcs", feature = "azure")), allow(dead_code))]
+fn setting(configured: Option<String>, variable: &str) -> Option<String> {
+    configured
+        .or_else(|| env::var(variable).ok())
+        .filter(|value| !value.is_empty())
+}
+
+/// The URL template of the artifact repository, if one is configured
+pub(crate) fn artifact_url() -> Option<String> {
+    let configured = ARTIFACT_URL
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    configured
+        .or_else(|| env::var("DICOM_TEST_FILES_ARTIFACT_URL").ok())
+        .filter(|template| !template.is_empty())
+}
+
+/// The headers to send with a request to the given URL:
+/// those of the artifact repository in the `artifact` backend
+/// if the URL is one of its own, none otherwise
+pub(crate) fn artifact_headers(url: &str) -> Vec<(String, String)> {
+    if backend() != Backend::Artifact {
+        return Vec::new();
+    }
+    match artifact_url() {
+        Some(template) if backend::is_artifact_url(&template, url) => {}
+        _ => return Vec::new(),
+    }
+    let mut headers = match env::var("DICOM_TEST_FILES_ARTIFACT_HEADERS") {
+        Ok(value) => backend::parse_headers(&value).unwrap_or_else(|reason| {
+            eprintln!(
+                "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_ARTIFACT_HEADERS: {}",
+                reason
+            );
+            Vec::new()
+        }),
+        Err(_) => Vec::new(),
+    };
+    for (name, value) in ARTIFACT_HEADERS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+    {
+        headers.retain(|(configured, _)| !configured.eq_ignore_ascii_case(name));
+        headers.push((name.clone(), value.clone()));
+    }
+    headers
+}
+
+/// The hosting backend of the test files of this project
+pub(crate) fn backend() -> Backend {
+    if let Some(backend) = *BACKEND.read().unwrap_or_else(|e| e.into_inner()) {
+        return backend;
+    }
+    let value = match env::var("DICOM_TEST_FILES_BACKEND") {
+        Ok(value) if !value.is_empty() => value,
+        _ => return Backend::default(),
+    };
+    Backend::parse(&value).unwrap_or_else(|| {
+        eprintln!(
+            "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_BACKEND {:?}",
+            value
+        );
+        Backend::default()
+    })
+}
+
+/// Whether cached test files are revalidated with the data source
+pub(crate) fn refresh() -> bool {
+    if let Some(refresh) = *REFRESH.read().unwrap_or_else(|e| e.into_inner()) {
+        return refresh;
+    }
+    match env::var("DICOM_TEST_FILES_REFRESH") {
+        Ok(value) if value == "1" => true,
+        Ok(value) if value.is_empty() || value == "0" => false,
+        Ok(value) => {
+            eprintln!(
+                "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_REFRESH {:?}",
+                value
+            );
+            false
+        }
+        Err(_) => false,
+    }
+}
+
+/// The maximum number of simultaneous downloads in the process
+pub(crate) fn max_downloads() -> usize {
+    if let Some(max_downloads) = *MAX_DOWNLOADS.read().unwrap_or_else(|e| e.into_inner()) {
+        return max_downloads;
+    }
+    let value = match env::var("DICOM_TEST_FILES_MAX_DOWNLOADS") {
+        Ok(value) if !value.is_empty() => value,
+        _ => return http::DEFAULT_MAX_DOWNLOADS,
+    };
+    match value.trim().parse() {
+        Ok(max_downloads) if max_downloads > 0 => max_downloads,
+        _ => {
+            eprintln!(
+                "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_MAX_DOWNLOADS {:?}",
+                value
+            );
+            http::DEFAULT_MAX_DOWNLOADS
+        }
+    }
+}
+
+/// The maximum number of bytes per second across all downloads in the process,
+/// if any
+pub(crate) fn max_rate() -> Option<u64> {
+    if let Some(max_rate) = *MAX_RATE.read().unwrap_or_else(|e| e.into_inner()) {
+        return Some(max_rate);
+    }
+    let value = match env::var("DICOM_TEST_FILES_MAX_RATE") {
+        Ok(value) if !value.is_empty() => value,
+        _ => return None,
+    };
+    match crate::parse_size(&value) {
+        Some(max_rate) if max_rate > 0 => Some(max_rate),
+        _ => {
+            eprintln!(
+                "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_MAX_RATE {:?}",
+                value
+            );
+            None
+        }
+    }
+}
+
+/// The maximum number of bytes in the cache, if any
+pub(crate) fn max_cache_bytes() -> Option<u64> {
+    if let Some(max_cache_bytes) = *MAX_CACHE_BYTES.read().unwrap_or_else(|e| e.into_inner()) {
+        return Some(max_cache_bytes);
+    }
+    let value = match env::var("DICOM_TEST_FILES_MAX_CACHE_BYTES") {
+        Ok(value) if !value.is_empty() => value,
+        _ => return None,
+    };
+    let max_cache_bytes = crate::parse_size(&value);
+    if max_cache_bytes.is_none() {
+        eprintln!(
+            "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_MAX_CACHE_BYTES {:?}",
+            value
+        );
+    }
+    max_cache_bytes
+}
+
+/// The default limit of the files read into memory
+const DEFAULT_MAX_IN_MEMORY: u64 = 512 * 1024 * 1024;
+
+/// The maximum size of the files read into memory
+pub(crate) fn max_in_memory() -> u64 {
+    if let Some(max_in_memory) = *MAX_IN_MEMORY.read().unwrap_or_else(|e| e.into_inner()) {
+        return max_in_memory;
+    }
+    let value = match env::var("DICOM_TEST_FILES_MAX_IN_MEMORY") {
+        Ok(value) if !value.is_empty() => value,
+        _ => return DEFAULT_MAX_IN_MEMORY,
+    };
+    crate::parse_size(&value).unwrap_or_else(|| {
+        eprintln!(
+            "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_MAX_IN_MEMORY {:?}",
+            value
+        );
+        DEFAULT_MAX_IN_MEMORY
+    })
+}
+
+/// Whether test files stored with compression stay compressed in the cache
+pub(crate) fn keep_compressed() -> bool {
+    if let Some(keep_compressed) = *KEEP_COMPRESSED.read().unwrap_or_else(|e| e.into_inner()) {
+        return keep_compressed;
+    }
+    match env::var("DICOM_TEST_FILES_KEEP_COMPRESSED") {
+        Ok(value) if value == "1" => true,
+        Ok(value) if value.is_empty() || value == "0" => false,
+        Ok(value) => {
+            eprintln!(
+                "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_KEEP_COMPRESSED {:?}",
+                value
+            );
+            false
+        }
+        Err(_) => false,
+    }
+}
+
+/// Whether the running binary has its own view of the cache
+pub(crate) fn namespace() -> bool {
+    if let Some(namespace) = *NAMESPACE.read().unwrap_or_else(|e| e.into_inner()) {
+        return namespace;
+    }
+    match env::var("DICOM_TEST_FILES_NAMESPACE") {
+        Ok(value) if value == "1" => true,
+        Ok(value) if value.is_empty() || value == "0" => false,
+        Ok(value) => {
+            eprintln!(
+                "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_NAMESPACE {:?}",
+                value
+            );
+            false
+        }
+        Err(_) => false,
+    }
+}
+
+/// The size limit set by [`Config::max_size`], if any
+pub(crate) fn max_size() -> Option<u64> {
+    *MAX_SIZE.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The cache directory to use when the default one cannot be written to
+pub(crate) fn fallback_dir() -> Option<PathBuf> {
+    let path = FALLBACK_DIR
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    path.or_else(|| {
+        env::var_os("DICOM_TEST_FILES_FALLBACK_DIR")
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+    })
+}
+
+/// The chaos mode of downloads, if any
+pub(crate) fn chaos() -> Option<Chaos> {
+    if let Some(chaos) = *CHAOS.read().unwrap_or_else(|e| e.into_inner()) {
+        return Some(chaos);
+    }
+    let value = match env::var("DICOM_TEST_FILES_CHAOS") {
+        Ok(value) if !value.is_empty() => value,
+        _ => return None,
+    };
+    let chaos = Chaos::parse(&value);
+    if chaos.is_none() {
+        eprintln!(
+            "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_CHAOS {:?}",
+            value
+        );
+    }
+    chaos
+}
+
+/// The keys trusted to sign external manifests
+pub(crate) fn trusted_keys() -> Vec<String> {
+    let mut keys = TRUSTED_KEYS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    if let Ok(value) = env::var("DICOM_TEST_FILES_TRUSTED_KEYS") {
+        for key in value
+            .split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+        {
+            if signature::is_public_key(key) {
+                keys.push(key.to_string());
+            } else {
+                eprintln!(
+                    "[dicom-test-files] Ignoring invalid key {:?} of DICOM_TEST_FILES_TRUSTED_KEYS",
+                    key
+                );
+            }
+        }
+    }
+    keys
+}
+
+/// Whether test files from unsigned sources are refused
+pub(crate) fn require_signatures() -> bool {
+    if let Some(require) = *REQUIRE_SIGNATURES.read().unwrap_or_else(|e| e.into_inner()) {
+        return require;
+    }
+    match env::var("DICOM_TEST_FILES_REQUIRE_SIGNATURES") {
+        Ok(value) if value == "1" => true,
+        Ok(value) if value.is_empty() || value == "0" => false,
+        Ok(value) => {
+            eprintln!(
+                "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_REQUIRE_SIGNATURES {:?}",
+                value
+            );
+            false
+        }
+        Err(_) => false,
+    }
+}
+
+/// The lock mode and the path of the lockfile if one is set,
+/// if locking is enabled
+pub(crate) fn lock() -> Option<(LockMode, Option<PathBuf>)> {
+    let (mode, path) = LOCK.read().unwrap_or_else(|e| e.into_inner()).clone();
+    let mode = match mode {
+        Some(mode) => mode,
+        None => {
+            let value = env::var("DICOM_TEST_FILES_LOCK").ok()?;
+            if value.is_empty() {
+                return None;
+            }
+            let mode = LockMode::parse(&value);
+            if mode.is_none() {
+                eprintln!(
+                    "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_LOCK {:?}",
+                    value
+                );
+            }
+            mode?
+        }
+    };
+    let path = path.or_else(|| {
+        env::var_os("DICOM_TEST_FILES_LOCKFILE")
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+    });
+    Some((mode, path))
+}
+
+/// Load an external manifest,
+/// along with whether it carries a valid signature
+fn load_manifest(path: &Path, trusted_keys: &[String]) -> Result<(&'static [TestFile], bool)> {
+    let signed = signature::check_file(path, trusted_keys)?;
+    // loaded entries are kept for the rest of the process
+    Ok((Vec::leak(manifest::load(path)?), signed))
+}
+
+#[cfg(all(test, feature = "toml"))]
+mod tests {
+    use super::*;
+    use crate::{base_url, lookup, path, Error};
+    use std::fs;
+
+    #[test]
+    fn apply_external_manifest() {
+        // an existing test file, made available under another name
+        let nm = lookup("WG04/JPLY/NM1_JPLY").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let manifest_path = dir.path().join("files.toml");
+        fs::write(
+            &manifest_path,
+            format!(
+                "base_url = \"{}WG04\"\n\n[[files]]\nname = \"JPLY/NM1_JPLY\"\nhash = \"{}\"\n",
+                base_url().unwrap(),
+                nm.hash
+            ),
+        )
+        .unwrap();
+
+        Config::new().manifest(&manifest_path).apply().unwrap();
+        let external = path("JPLY/NM1_JPLY").unwrap();
+        assert!(external.ends_with("JPLY/NM1_JPLY"));
+        assert_eq!(fs::metadata(external).unwrap().len(), 9844);
+        // compiled entries are still available
+        assert!(path("pydicom/MR_small.dcm").is_ok());
+
+        let missing = Config::new().manifest(dir.path().join("nope.toml")).apply();
+        assert!(matches!(missing, Err(Error::Io(_))));
+        // the previous manifest stays in place
+        assert!(crate::resolve("JPLY/NM1_JPLY").is_ok());
+    }
+}
@@ -0,0 +1,108 @@
+//! Export of test files as fuzzing corpora
+
+use crate::{fetch, prefetch, resolve, Filter, Result};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashSet,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Copy the test files selected by the given filter
+/// into a flat directory suitable as a seed corpus
+/// for fuzzers such as libFuzzer (`cargo fuzz`) or AFL,
+/// returning the paths of the files written.
+///
+/// Files are fetched if necessary
+/// and copied in their decompressed form.
+/// Each file is named after its test file name
+/// with path separators replaced by `_`
+/// (e.g. `WG04_JPLY_NM1_JPLY`).
+/// Files with identical contents are only exported once,
+/// under the first name in alphabetical order.
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// dicom_test_files::export_corpus("pydicom/*.dcm", "fuzz/corpus/open_file")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn export_corpus(
+    filter: impl Into<Filter>,
+    dest_dir: impl AsRef<Path>,
+) -> Result<Vec<PathBuf>> {
+    let dest_dir = dest_dir.as_ref();
+    fs::create_dir_all(dest_dir)?;
+
+    let summary = prefetch(filter)?;
+    let mut names: Vec<&str> = summary.fetched.into_iter().chain(summary.cached).collect();
+    names.sort_unstable();
+
+    let mut seen = HashSet::new();
+    let mut exported = Vec::new();
+    for name in names {
+        let entry = resolve(name)?;
+        let (cached_path, _) = fetch(entry)?;
+
+        let mut hasher = Sha256::new();
+        io::copy(&mut fs::File::open(&cached_path)?, &mut hasher)?;
+        if !seen.insert(format!("{:x}", hasher.finalize())) {
+            continue;
+        }
+
+        let target = dest_dir.join(corpus_name(entry.name));
+        fs::copy(&cached_path, &target)?;
+        exported.push(target);
+    }
+    Ok(exported)
+}
+
+/// Flatten a test file name for use in a corpus directory.
+fn corpus_name(name: &str) -> String {
+    name.replace('/', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{register_registry, testing::MockServer};
+
+    #[test]
+    fn export_flat_deduplicated_corpus() {
+        let dest = tempfile::tempdir().unwrap();
+        let filter = Filter::predicate(|e| {
+            [
+                "WG04/J2KI/US1_J2KI",
+                "pydicom/US1_J2KI.dcm",
+                "pydicom/MR_small.dcm",
+            ]
+            .contains(&e.name)
+        });
+
+        let exported = export_corpus(filter, dest.path()).unwrap();
+
+        // US1_J2KI is the same file in both collections
+        assert_eq!(
+            exported,
+            vec![
+                dest.path().join("WG04_J2KI_US1_J2KI"),
+                dest.path().join("pydicom_MR_small.dcm"),
+            ]
+        );
+        assert_eq!(fs::read_dir(dest.path()).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn export_registry_entry() {
+        let server = MockServer::start().unwrap();
+        server.blob("corpus/registered.dcm", &b"DICM registered"[..]);
+        register_registry(server.registry()).unwrap();
+        let dest = tempfile::tempdir().unwrap();
+
+        let exported = export_corpus("corpus/registered.dcm", dest.path()).unwrap();
+
+        let target = dest.path().join("corpus_registered.dcm");
+        assert_eq!(exported, vec![target.clone()]);
+        assert_eq!(fs::read(target).unwrap(), b"DICM registered");
+    }
+}
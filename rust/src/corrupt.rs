@@ -0,0 +1,227 @@
+//! Deterministic corruption of test files
+
+use crate::{entry_path, fetch_verified, find_entry, get_data_path, Error, Result};
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+/// A kind of damage to inflict on a copy of a test file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CorruptionKind {
+    /// Cut the file at the given byte offset
+    TruncateAt(u64),
+    /// Cut the file at an offset chosen by the seed
+    Truncate,
+    /// Flip the given number of bits at positions chosen by the seed
+    BitFlips(u32),
+    /// Remove a run of bytes in the middle of the pixel data,
+    /// without updating any length.
+    ///
+    /// For encapsulated pixel data,
+    /// the bytes are removed from the first fragment.
+    ChopPixelFragment,
+}
+
+impl CorruptionKind {
+    fn dir_name(self) -> String {
+        match self {
+            CorruptionKind::TruncateAt(offset) => format!("truncate-at-{}", offset),
+            CorruptionKind::Truncate => "truncate".to_string(),
+            CorruptionKind::BitFlips(n) => format!("bit-flips-{}", n),
+            CorruptionKind::ChopPixelFragment => "chop-pixel-fragment".to_string(),
+        }
+    }
+}
+
+/// Create a corrupted copy of a DICOM test file,
+/// returning the path to the copy.
+///
+/// The original file is fetched and verified first.
+/// The same name, kind and seed always produce the same bytes,
+/// so failures found this way can be reproduced.
+/// Copies are kept in the cache under `corrupted`,
+/// apart from the pristine test files.
+///
+/// ```no_run
+/// use dicom_test_files::{corrupted_copy, CorruptionKind};
+///
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// for seed in 0..16 {
+///     let broken = corrupted_copy("pydicom/CT_small.dcm", CorruptionKind::BitFlips(8), seed)?;
+///     // assert that opening `broken` fails gracefully
+/// #   let _ = broken;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn corrupted_copy(name: &str, kind: CorruptionKind, seed: u64) -> Result<PathBuf> {
+    let entry = find_entry(name)?;
+    let source = fetch_verified(entry)?;
+    let mut data = fs::read(source)?;
+    corrupt(&mut data, kind, seed)?;
+
+    let corrupted_dir = get_data_path()
+        .join("corrupted")
+        .join(kind.dir_name())
+        .join(seed.to_string());
+    let target = entry_path(&corrupted_dir, entry.name);
+    let target_dir = target.parent().unwrap();
+    fs::create_dir_all(target_dir)?;
+
+    // write in full before moving into place,
+    // as other tests may be reading the same copy
+    let mut file = tempfile::NamedTempFile::new_in(target_dir)?;
+    file.write_all(&data)?;
+    file.persist(&target).map_err(io::Error::from)?;
+    Ok(target)
+}
+
+fn corrupt(data: &mut Vec<u8>, kind: CorruptionKind, seed: u64) -> Result<()> {
+    let mut rng = SplitMix64(seed);
+    match kind {
+        CorruptionKind::TruncateAt(offset) => {
+            data.truncate(offset as usize);
+        }
+        CorruptionKind::Truncate => {
+            if data.is_empty() {
+                return Ok(());
+            }
+            let offset = rng.below(data.len() as u64) as usize;
+            data.truncate(offset);
+        }
+        CorruptionKind::BitFlips(n) => {
+            if data.is_empty() {
+                return Ok(());
+            }
+            for _ in 0..n {
+                let bit = rng.below(data.len() as u64 * 8);
+                data[(bit / 8) as usize] ^= 1 << (bit % 8);
+            }
+        }
+        CorruptionKind::ChopPixelFragment => {
+            let (start, len) = pixel_fragment(data).ok_or_else(|| {
+                Error::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "test file has no pixel data to chop",
+                ))
+            })?;
+            if len < 2 {
+                return Ok(());
+            }
+            let chop_len = 1 + rng.below(len as u64 / 2) as usize;
+            let chop_start = start + (len - chop_len) / 2;
+            data.drain(chop_start..chop_start + chop_len);
+        }
+    }
+    Ok(())
+}
+
+/// Locate the pixel data value in a little endian file,
+/// or its first fragment if encapsulated,
+/// returning its offset and length.
+fn pixel_fragment(data: &[u8]) -> Option<(usize, usize)> {
+    const PIXEL_DATA: [u8; 4] = [0xE0, 0x7F, 0x10, 0x00];
+    const ITEM: [u8; 4] = [0xFE, 0xFF, 0x00, 0xE0];
+
+    let tag_pos = data.windows(4).rposition(|w| w == PIXEL_DATA)?;
+    // explicit VR (OB/OW) has 2 reserved bytes before a 4-byte length
+    let explicit = matches!(
+        data.get(tag_pos + 4..tag_pos + 6),
+        Some(b"OB") | Some(b"OW")
+    );
+    let len_pos = if explicit { tag_pos + 8 } else { tag_pos + 4 };
+    let len = read_u32(data, len_pos)?;
+    let mut pos = len_pos + 4;
+
+    if len != 0xFFFF_FFFF {
+        let len = (len as usize).min(data.len().saturating_sub(pos));
+        return Some((pos, len));
+    }
+
+    // skip the basic offset table, then take the first fragment
+    for item in 0..2 {
+        if data.get(pos..pos + 4)? != ITEM {
+            return None;
+        }
+        let item_len = read_u32(data, pos + 4)? as usize;
+        pos += 8;
+        if item == 1 {
+            return Some((pos, item_len.min(data.len().saturating_sub(pos))));
+        }
+        pos += item_len;
+    }
+    None
+}
+
+fn read_u32(data: &[u8], pos: usize) -> Option<u32> {
+    let bytes = data.get(pos..pos + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Small deterministic pseudo-random number generator
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound` (`bound` must not be zero)
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corruption_is_reproducible() {
+        let a = corrupted_copy("pydicom/MR_small.dcm", CorruptionKind::BitFlips(16), 7).unwrap();
+        let first = fs::read(&a).unwrap();
+        let b = corrupted_copy("pydicom/MR_small.dcm", CorruptionKind::BitFlips(16), 7).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(fs::read(&b).unwrap(), first);
+
+        let original = fs::read(crate::path("pydicom/MR_small.dcm").unwrap()).unwrap();
+        assert_eq!(first.len(), original.len());
+        assert_ne!(first, original);
+    }
+
+    #[test]
+    fn truncate_at_offset() {
+        let path =
+            corrupted_copy("pydicom/MR_small.dcm", CorruptionKind::TruncateAt(1000), 0).unwrap();
+        assert_eq!(fs::metadata(path).unwrap().len(), 1000);
+    }
+
+    #[test]
+    fn corrupt_empty_file() {
+        for kind in [CorruptionKind::Truncate, CorruptionKind::BitFlips(4)] {
+            let mut data = Vec::new();
+            corrupt(&mut data, kind, 1).unwrap();
+            assert!(data.is_empty());
+        }
+    }
+
+    #[test]
+    fn chop_native_and_encapsulated_pixel_data() {
+        for name in ["pydicom/MR_small.dcm", "pydicom/MR_small_RLE.dcm"] {
+            let original = fs::read(crate::path(name).unwrap()).unwrap();
+            let (start, len) = pixel_fragment(&original).unwrap();
+            assert!(start + len <= original.len());
+
+            let chopped = corrupted_copy(name, CorruptionKind::ChopPixelFragment, 3).unwrap();
+            let chopped = fs::read(chopped).unwrap();
+            assert!(chopped.len() < original.len());
+            assert_eq!(chopped[..start], original[..start]);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! DICOMDIR fixtures, fetched along with the files they reference
+
+use crate::{fetch, prefetch, resolve, Filter, Result};
+use std::{convert::TryInto, fs, io, path::PathBuf};
+
+/// Fetch the DICOMDIR test file of the given name
+/// and every file it references,
+/// returning the root of the directory tree.
+///
+/// The referenced files are test files named after their Referenced File ID
+/// relative to the directory of the DICOMDIR,
+/// such as `myorg/disc1/DICOM/ST000001/SE000001/IM000001`
+/// for `DICOM\ST000001\SE000001\IM000001` in `myorg/disc1/DICOMDIR`,
+/// so that they are cached in the layout of the medium.
+/// Files which are not cached yet are downloaded
+/// several at the same time, like [`prefetch`].
+///
+/// Fails with [`Error::NotFound`](crate::Error::NotFound)
+/// if a referenced file is not a known test file,
+/// and with [`Error::Io`](crate::Error::Io) if the DICOMDIR is malformed.
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let root = dicom_test_files::dicomdir("myorg/disc1/DICOMDIR")?;
+/// let dicomdir = root.join("DICOMDIR");
+/// # let _ = dicomdir;
+/// # Ok(())
+/// # }
+/// ```
+pub fn dicomdir(name: &str) -> Result<PathBuf> {
+    let (cached_path, _) = fetch(resolve(name)?)?;
+    let root = cached_path.parent().unwrap().to_path_buf();
+    let dir = match name.rfind('/') {
+        Some(end) => &name[..=end],
+        None => "",
+    };
+
+    let file_ids = referenced_file_ids(&fs::read(&cached_path)?).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} is not a valid DICOMDIR", name),
+        )
+    })?;
+    let mut referenced = Vec::with_capacity(file_ids.len());
+    for file_id in file_ids {
+        referenced.push(resolve(&format!("{}{}", dir, file_id.join("/")))?);
+    }
+    let names: Vec<&'static str> = referenced.iter().map(|entry| entry.name).collect();
+    prefetch(Filter::predicate(move |entry| names.contains(&entry.name)))?;
+    for entry in referenced {
+        fetch(entry)?;
+    }
+    Ok(root)
+}
+
+/// The components of the Referenced File IDs of the directory records
+/// of a DICOMDIR, in the order of the records,
+/// or none if it is not a DICOM file in explicit VR little endian
+fn referenced_file_ids(data: &[u8]) -> Option<Vec<Vec<String>>> {
+    if data.get(128..132)? != b"DICM" {
+        return None;
+    }
+    let mut file_ids = Vec::new();
+    let mut pos = 132;
+    // the contents of sequences and items are walked through as they come
+    while pos < data.len() {
+        let group = u16::from_le_bytes(data.get(pos..pos + 2)?.try_into().ok()?);
+        let element = u16::from_le_bytes(data.get(pos + 2..pos + 4)?.try_into().ok()?);
+        if group == 0xFFFE {
+            // items and delimiters have no VR
+            pos += 8;
+            continue;
+        }
+        let vr = data.get(pos + 4..pos + 6)?;
+        let (len, header) = match vr {
+            b"OB" | b"OD" | b"OF" | b"OL" | b"OV" | b"OW" | b"SQ" | b"SV" | b"UC" | b"UN"
+            | b"UR" | b"UT" | b"UV" => (
+                u32::from_le_bytes(data.get(pos + 8..pos + 12)?.try_into().ok()?),
+                12,
+            ),
+            _ => (
+                u32::from(u16::from_le_bytes(
+                    data.get(pos + 6..pos + 8)?.try_into().ok()?,
+                )),
+                8,
+            ),
+        };
+        pos += header;
+        if vr == b"SQ" {
+            continue;
+        }
+        if len == u32::MAX {
+            return None;
+        }
+        let value = data.get(pos..pos + len as usize)?;
+        if (group, element) == (0x0004, 0x1500) {
+            let value = std::str::from_utf8(value).ok()?;
+            let components = value
+                .trim_end_matches(&[' ', '\0'][..])
+                .split('\\')
+                .map(str::to_string)
+                .collect();
+            file_ids.push(components);
+        }
+        pos += len as usize;
+    }
+    Some(file_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{register_registry, study, testing::MockServer, Error, Registry};
+
+    #[test]
+    fn dicomdir_with_referenced_files() {
+        let study = study("synth/ct_mr").unwrap();
+        let file_ids = referenced_file_ids(&fs::read(study.join("DICOMDIR")).unwrap()).unwrap();
+        assert_eq!(file_ids.len(), 7);
+        assert_eq!(file_ids[0], ["DICOM", "ST000001", "SE000001", "IM000001"]);
+        assert_eq!(referenced_file_ids(b"DICM"), None);
+
+        // a medium served with its layout
+        let server = MockServer::start().unwrap();
+        let entry = |file_id: &str| {
+            server.blob(
+                &format!("disc/{}", file_id),
+                fs::read(study.join(file_id)).unwrap(),
+            )
+        };
+        let mut entries = vec![entry("DICOMDIR")];
+        entries.extend(file_ids.iter().map(|file_id| entry(&file_id.join("/"))));
+        // the last referenced file is unknown
+        let unknown = entries.pop().unwrap();
+        register_registry(Registry {
+            base_url: server.url().to_string(),
+            entries,
+        })
+        .unwrap();
+        assert!(matches!(
+            dicomdir("disc/DICOMDIR"),
+            Err(Error::NotFound { .. })
+        ));
+
+        register_registry(Registry {
+            base_url: server.url().to_string(),
+            entries: vec![unknown],
+        })
+        .unwrap();
+        let root = dicomdir("disc/DICOMDIR").unwrap();
+        assert!(root.join("DICOMDIR").exists());
+        for file_id in &file_ids {
+            let path = file_id
+                .iter()
+                .fold(root.clone(), |path, component| path.join(component));
+            assert_eq!(
+                fs::read(path).unwrap(),
+                fs::read(study.join(file_id.join("/"))).unwrap()
+            );
+        }
+    }
+}
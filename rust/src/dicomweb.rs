@@ -0,0 +1,196 @@
+//! Test files retrieved from DICOMweb servers with WADO-RS
+
+#[cfg(feature = "dicomweb")]
+use crate::{encoding, http, stored_url, write_temp_hashed};
+use crate::{report, Error, Result, TestFile};
+use std::path::Path;
+#[cfg(feature = "dicomweb")]
+use std::{
+    fs,
+    io::{self, Read},
+};
+
+/// Media types accepted for an instance,
+/// the one required of WADO-RS servers first
+#[cfg(feature = "dicomweb")]
+const ACCEPT: &str = "multipart/related; type=\"application/dicom\", application/dicom;q=0.9";
+
+/// Retrieve the instance which is the given entry
+/// from the DICOMweb server at the base URL of its source
+/// into its cached path, verifying it against its hash.
+#[cfg(feature = "dicomweb")]
+pub(crate) fn fetch_instance(
+    entry: &TestFile,
+    study: &str,
+    series: &str,
+    instance: &str,
+    cached_path: &Path,
+    attempt: &mut report::Attempt,
+) -> Result<()> {
+    let url = stored_url(entry.base_url, &instance_path(study, series, instance))?;
+    attempt.url = Some(url.clone());
+    crate::chaos::inject(&url)?;
+    let headers = [
+        ("Accept", ACCEPT),
+        ("Accept-Encoding", encoding::ACCEPT_ENCODING),
+    ];
+    let resp = http::get(&url, &headers).map_err(|failure| {
+        attempt.status = failure.status;
+        failure.error
+    })?;
+    attempt.status = Some(resp.status);
+    let boundary = resp.content_type.as_deref().and_then(multipart_boundary);
+    let mut body = encoding::decode(resp, &url)?;
+
+    let parent_dir = cached_path.parent().unwrap();
+    fs::create_dir_all(parent_dir)?;
+    let (tempfile_path, digest) = match boundary {
+        Some(boundary) => {
+            let mut multipart = Vec::new();
+            body.read_to_end(&mut multipart)?;
+            let part = first_part(&multipart, &boundary).ok_or_else(|| {
+                Error::Download(format!("Invalid multipart response from {}", url))
+            })?;
+            write_temp_hashed(&mut io::Cursor::new(part), parent_dir, entry.digest)?
+        }
+        None => write_temp_hashed(&mut body, parent_dir, entry.digest)?,
+    };
+    attempt.bytes = digest.1;
+    crate::install_hashed(entry, tempfile_path, digest, cached_path)
+        .map_err(|e| e.downloaded_from(&url))
+}
+
+#[cfg(not(feature = "dicomweb"))]
+pub(crate) fn fetch_instance(
+    _entry: &TestFile,
+    _study: &str,
+    _series: &str,
+    _instance: &str,
+    _cached_path: &Path,
+    _attempt: &mut report::Attempt,
+) -> Result<()> {
+    Err(Error::DicomWebRequired)
+}
+
+/// The path of the retrieve instance resource
+/// relative to the base URL of a DICOMweb server
+#[cfg(feature = "dicomweb")]
+fn instance_path(study: &str, series: &str, instance: &str) -> String {
+    format!("studies/{}/series/{}/instances/{}", study, series, instance)
+}
+
+/// The boundary of a `multipart/related` content type
+#[cfg(feature = "dicomweb")]
+fn multipart_boundary(content_type: &str) -> Option<String> {
+    let mut params = content_type.split(';');
+    let media_type = params.next()?.trim();
+    if !media_type.eq_ignore_ascii_case("multipart/related") {
+        return None;
+    }
+    params.find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        if !name.trim().eq_ignore_ascii_case("boundary") {
+            return None;
+        }
+        Some(value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// The content of the first part of a multipart body,
+/// which holds the instance
+#[cfg(feature = "dicomweb")]
+fn first_part<'a>(body: &'a [u8], boundary: &str) -> Option<&'a [u8]> {
+    let delimiter = format!("--{}", boundary);
+    let start = find(body, delimiter.as_bytes())? + delimiter.len();
+    let part = &body[start..];
+    // the headers of the part end with an empty line
+    let content = find(part, b"\r\n\r\n")? + 4;
+    let part = &part[content..];
+    let end = find(part, format!("\r\n{}", delimiter).as_bytes())?;
+    Some(&part[..end])
+}
+
+#[cfg(feature = "dicomweb")]
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(all(test, feature = "dicomweb"))]
+mod tests {
+    use super::*;
+    use crate::{lookup, path, testing::MockServer, Compression};
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn multipart_responses() {
+        assert_eq!(
+            multipart_boundary("multipart/related; type=\"application/dicom\"; boundary=\"b1\""),
+            Some("b1".to_string())
+        );
+        assert_eq!(
+            multipart_boundary("Multipart/Related;boundary=b2"),
+            Some("b2".to_string())
+        );
+        assert_eq!(multipart_boundary("application/dicom"), None);
+
+        let body = b"preamble\r\n--b1\r\nContent-Type: application/dicom\r\n\r\nDICM\r\n--b1--\r\n";
+        assert_eq!(first_part(body, "b1"), Some(&b"DICM"[..]));
+        assert_eq!(first_part(b"--b1\r\n\r\nDICM", "b1"), None);
+        assert_eq!(
+            instance_path("1.2", "1.2.3", "1.2.3.4"),
+            "studies/1.2/series/1.2.3/instances/1.2.3.4"
+        );
+    }
+
+    #[test]
+    fn retrieve_instance() {
+        let contents = fs::read(path("pydicom/CT_small.dcm").unwrap()).unwrap();
+        let hash = lookup("pydicom/CT_small.dcm").unwrap().hash;
+
+        // a DICOMweb server with a single instance
+        let server = MockServer::start().unwrap();
+        let server_url = format!("{}dicomweb/", server.url());
+        let instance = "dicomweb/studies/1.2/series/1.2.3/instances/1.2.3.4";
+        let mut part = b"--abc\r\nContent-Type: application/dicom\r\n\r\n".to_vec();
+        part.extend_from_slice(&contents);
+        part.extend_from_slice(b"\r\n--abc--\r\n");
+        server.blob(instance, part);
+        server.header(
+            instance,
+            "Content-Type",
+            "multipart/related; type=\"application/dicom\"; boundary=abc",
+        );
+
+        let dicomweb = |instance| Compression::DicomWeb {
+            study: "1.2",
+            series: "1.2.3",
+            instance,
+        };
+        let mut entry = TestFile::new("hospital/ct.dcm", dicomweb("1.2.3.4"), hash);
+        entry.base_url = Some(Box::leak(server_url.into_boxed_str()));
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("hospital/ct.dcm");
+        crate::download(&entry, &cached_path, None).unwrap();
+        assert_eq!(fs::read(&cached_path).unwrap(), contents);
+        let accept = server.received(instance)[0]
+            .header("accept")
+            .map(str::to_lowercase);
+        assert!(accept.unwrap().starts_with("multipart/related"));
+
+        // verified against its hash
+        let other = format!("{:x}", Sha256::digest(b"other"));
+        entry.hash = Box::leak(other.into_boxed_str());
+        let cached_path = dir.path().join("hospital/other.dcm");
+        assert!(matches!(
+            crate::download(&entry, &cached_path, None),
+            Err(Error::InvalidHash { .. })
+        ));
+        entry.compression = dicomweb("9.9");
+        assert!(matches!(
+            crate::download(&entry, &cached_path, None),
+            Err(Error::Download(_))
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+//! Hashes of test files with the algorithm of their entry
+
+use crate::{test_file::DigestAlgorithm, Result};
+use sha2::{Digest, Sha256, Sha512};
+use std::{fs, io, path::Path};
+
+/// A hasher of one of the supported algorithms
+pub(crate) enum Hasher {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    #[cfg(feature = "blake3")]
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Hasher {
+    /// Start hashing with the given algorithm.
+    ///
+    /// Fails with [`Error::Blake3Required`](crate::Error::Blake3Required) for BLAKE3
+    /// without the `blake3` feature.
+    pub(crate) fn new(algorithm: DigestAlgorithm) -> Result<Hasher> {
+        match algorithm {
+            DigestAlgorithm::Sha256 => Ok(Hasher::Sha256(Sha256::new())),
+            DigestAlgorithm::Sha512 => Ok(Hasher::Sha512(Sha512::new())),
+            #[cfg(feature = "blake3")]
+            DigestAlgorithm::Blake3 => Ok(Hasher::Blake3(Box::new(blake3::Hasher::new()))),
+            #[cfg(not(feature = "blake3"))]
+            DigestAlgorithm::Blake3 => Err(crate::Error::Blake3Required),
+        }
+    }
+
+    /// The hash of the data written in lowercase hexadecimal
+    pub(crate) fn finalize(self) -> String {
+        match self {
+            Hasher::Sha256(hasher) => format!("{:x}", hasher.finalize()),
+            Hasher::Sha512(hasher) => format!("{:x}", hasher.finalize()),
+            #[cfg(feature = "blake3")]
+            Hasher::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+        }
+    }
+}
+
+impl io::Write for Hasher {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Hasher::Sha256(hasher) => hasher.update(buf),
+            Hasher::Sha512(hasher) => hasher.update(buf),
+            #[cfg(feature = "blake3")]
+            Hasher::Blake3(hasher) => {
+                hasher.update(buf);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The hash of some data with the given algorithm
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+pub(crate) fn digest(data: &[u8], algorithm: DigestAlgorithm) -> Result<String> {
+    let mut hasher = Hasher::new(algorithm)?;
+    io::Write::write_all(&mut hasher, data)?;
+    Ok(hasher.finalize())
+}
+
+/// The hash of a file with the given algorithm and its size in bytes
+pub(crate) fn file_digest(path: &Path, algorithm: DigestAlgorithm) -> Result<(String, u64)> {
+    let mut hasher = Hasher::new(algorithm)?;
+    let bytes = io::copy(&mut fs::File::open(path)?, &mut hasher)?;
+    Ok((hasher.finalize(), bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digests_of_data() {
+        assert_eq!(
+            digest(b"abc", DigestAlgorithm::Sha256).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            digest(b"abc", DigestAlgorithm::Sha512).unwrap(),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        let blake3 = digest(b"abc", DigestAlgorithm::Blake3);
+        #[cfg(feature = "blake3")]
+        assert_eq!(
+            blake3.unwrap(),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+        #[cfg(not(feature = "blake3"))]
+        assert!(matches!(blake3, Err(crate::Error::Blake3Required)));
+
+        for algorithm in [
+            DigestAlgorithm::Sha256,
+            DigestAlgorithm::Sha512,
+            DigestAlgorithm::Blake3,
+        ] {
+            assert_eq!(
+                DigestAlgorithm::from_name(algorithm.name()),
+                Some(algorithm)
+            );
+        }
+        assert!(DigestAlgorithm::Sha512.is_hash(&"0".repeat(128)));
+        assert!(!DigestAlgorithm::Sha512.is_hash(&"0".repeat(64)));
+    }
+}
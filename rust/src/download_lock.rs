@@ -0,0 +1,280 @@
+//! Locks of the downloads of test files, across threads and processes
+
+use crate::{cancel, Result};
+use std::{
+    fs,
+    io::{self, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Interval at which the holder of a lock shows it is alive
+const HEARTBEAT: Duration = Duration::from_secs(2);
+
+/// Time without a heartbeat after which the holder of a lock is deemed dead,
+/// such as a process which crashed or was killed mid-download
+const STALE_AFTER: Duration = Duration::from_secs(20);
+
+/// Interval at which a lock held by another is checked again
+const POLL: Duration = Duration::from_millis(50);
+
+/// The lock of the download of a test file into its cached path,
+/// released when dropped.
+///
+/// The lock is a file next to the cached path,
+/// holding the process ID of its holder and a token unique to the lock,
+/// rewritten at each heartbeat so that its modification time
+/// tells a live holder from a dead one,
+/// including across containers sharing the cache.
+pub(crate) struct DownloadLock {
+    path: PathBuf,
+    token: String,
+    released: Arc<AtomicBool>,
+    heartbeat: Option<thread::JoinHandle<()>>,
+}
+
+/// The path of the lock file of the given cached path
+fn lock_path(cached_path: &Path) -> PathBuf {
+    let file_name = cached_path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    cached_path.with_file_name(format!(".{}.lock", file_name))
+}
+
+/// Take the lock of the download into the given cached path,
+/// waiting while another thread or process holds it.
+///
+/// A lock without a heartbeat for longer than [`STALE_AFTER`]
+/// was left by a holder which died,
+/// and is taken over.
+/// Fails with [`Error::Cancelled`](crate::Error::Cancelled)
+/// if the current operation is cancelled while waiting.
+pub(crate) fn acquire(cached_path: &Path) -> Result<DownloadLock> {
+    acquire_with(cached_path, HEARTBEAT, STALE_AFTER)
+}
+
+fn acquire_with(
+    cached_path: &Path,
+    heartbeat: Duration,
+    stale_after: Duration,
+) -> Result<DownloadLock> {
+    let path = lock_path(cached_path);
+    let token = new_token();
+    fs::create_dir_all(path.parent().unwrap())?;
+    let mut file = loop {
+        cancel::check()?;
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(file) => break file,
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e.into()),
+        }
+        if is_stale(&path, stale_after) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(path = %path.display(), "taking over a stale download lock");
+            take_over(&path, &token, stale_after)?;
+            continue;
+        }
+        thread::sleep(POLL);
+    };
+    beat(&mut file, &token)?;
+
+    let released = Arc::new(AtomicBool::new(false));
+    let heartbeat = {
+        let (token, released) = (token.clone(), Arc::clone(&released));
+        thread::spawn(move || {
+            let mut last_beat = SystemTime::now();
+            while !released.load(Ordering::SeqCst) {
+                thread::sleep(POLL.min(heartbeat));
+                if last_beat.elapsed().unwrap_or_default() >= heartbeat {
+                    let _ = beat(&mut file, &token);
+                    last_beat = SystemTime::now();
+                }
+            }
+        })
+    };
+    Ok(DownloadLock {
+        path,
+        token,
+        released,
+        heartbeat: Some(heartbeat),
+    })
+}
+
+/// A token unique to a lock,
+/// across the threads and processes of all machines sharing the cache
+fn new_token() -> String {
+    static LOCKS: AtomicUsize = AtomicUsize::new(0);
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    format!(
+        "{} {}.{:09}-{}",
+        std::process::id(),
+        now.as_secs(),
+        now.subsec_nanos(),
+        LOCKS.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Remove the given stale lock, unless it was taken over in the meantime.
+///
+/// The lock is first moved aside to a name of its own,
+/// so that only one of the waiters taking it over does,
+/// then checked again: another waiter may have taken it over
+/// and beaten it between the check and the move,
+/// in which case it is put back.
+fn take_over(path: &Path, token: &str, stale_after: Duration) -> io::Result<()> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let moved = path.with_file_name(format!("{}.stale-{}", file_name, token.replace(' ', "-")));
+    match fs::rename(path, &moved) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    }
+    if !is_stale(&moved, stale_after) {
+        // fails without replacing the lock of a waiter which took it since
+        let _ = fs::hard_link(&moved, path);
+    }
+    fs::remove_file(&moved)
+}
+
+/// Show that the holder of the given lock is alive,
+/// through its own file, so that a lock taken over is never recreated
+fn beat(file: &mut fs::File, token: &str) -> io::Result<()> {
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(token.as_bytes())
+}
+
+/// Whether the given lock file was not beaten for longer than the given time.
+/// A lock which is gone is not stale, it can be taken right away.
+fn is_stale(path: &Path, stale_after: Duration) -> bool {
+    match fs::metadata(path).and_then(|metadata| metadata.modified()) {
+        Ok(modified) => modified.elapsed().unwrap_or_default() > stale_after,
+        Err(_) => false,
+    }
+}
+
+impl Drop for DownloadLock {
+    fn drop(&mut self) {
+        self.released.store(true, Ordering::SeqCst);
+        if let Some(heartbeat) = self.heartbeat.take() {
+            let _ = heartbeat.join();
+        }
+        // unless it was taken over as stale, and is now the lock of another
+        if fs::read(&self.path).ok().as_deref() == Some(self.token.as_bytes()) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CancelToken, Error};
+    use std::{sync::mpsc, time::Instant};
+
+    #[test]
+    fn wait_for_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("WG04/REF/CT1_UNC");
+        let lock = acquire(&cached_path).unwrap();
+        let lock_file = dir.path().join("WG04/REF/.CT1_UNC.lock");
+        let holder = fs::read_to_string(&lock_file).unwrap();
+        assert_eq!(
+            holder.split(' ').next(),
+            Some(std::process::id().to_string().as_str())
+        );
+
+        let (sender, receiver) = mpsc::channel();
+        let waiting_path = cached_path.clone();
+        let waiting = thread::spawn(move || {
+            let lock = acquire(&waiting_path).unwrap();
+            sender.send(()).unwrap();
+            lock
+        });
+        assert!(receiver.recv_timeout(Duration::from_millis(300)).is_err());
+        drop(lock);
+        receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        drop(waiting.join().unwrap());
+        assert!(!lock_file.exists());
+    }
+
+    #[test]
+    fn take_over_stale_lock() {
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("pydicom/liver.dcm");
+        // left by a process which died
+        fs::create_dir_all(cached_path.parent().unwrap()).unwrap();
+        fs::write(lock_path(&cached_path), "4294967295").unwrap();
+        thread::sleep(Duration::from_millis(300));
+
+        let start = Instant::now();
+        let lock = acquire_with(
+            &cached_path,
+            Duration::from_millis(50),
+            Duration::from_millis(200),
+        )
+        .unwrap();
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        // a live holder keeps its lock beyond the stale time
+        let token = CancelToken::new();
+        let canceller = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(800));
+            canceller.cancel();
+        });
+        let waited = token.run(|| {
+            acquire_with(
+                &cached_path,
+                Duration::from_millis(50),
+                Duration::from_millis(200),
+            )
+        });
+        assert!(matches!(waited, Err(Error::Cancelled)));
+        drop(lock);
+    }
+
+    #[test]
+    fn keep_lock_taken_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("pydicom/CT_small.dcm");
+        let lock_file = lock_path(&cached_path);
+        let lock = acquire_with(
+            &cached_path,
+            Duration::from_millis(50),
+            Duration::from_millis(200),
+        )
+        .unwrap();
+
+        // a waiter which saw the lock as stale before it was taken over
+        take_over(&lock_file, &new_token(), Duration::from_millis(200)).unwrap();
+        assert_eq!(fs::read_to_string(&lock_file).unwrap(), lock.token.as_str());
+        assert_eq!(
+            fs::read_dir(lock_file.parent().unwrap()).unwrap().count(),
+            1
+        );
+
+        // the holder of a lock taken over leaves the new one alone
+        fs::remove_file(&lock_file).unwrap();
+        fs::write(&lock_file, "4294967295 0.000000000-0").unwrap();
+        thread::sleep(Duration::from_millis(150));
+        assert_eq!(
+            fs::read_to_string(&lock_file).unwrap(),
+            "4294967295 0.000000000-0"
+        );
+        drop(lock);
+        assert!(lock_file.exists());
+    }
+}
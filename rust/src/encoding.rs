@@ -0,0 +1,67 @@
+//! Content encodings of HTTP responses, decoded transparently
+
+use crate::{http::Response, Error, Result};
+use std::io::Read;
+
+/// The content encodings accepted for downloads.
+///
+/// `gzip` is decoded by the HTTP client itself,
+/// `zstd` is decoded here when the `zstd` feature is enabled.
+#[cfg(feature = "zstd")]
+pub(crate) const ACCEPT_ENCODING: &str = "zstd, gzip";
+#[cfg(not(feature = "zstd"))]
+pub(crate) const ACCEPT_ENCODING: &str = "gzip";
+
+/// The decoded body of a response to the given URL.
+///
+/// The hash of the file is checked against the decoded body,
+/// so the encoding of the transfer does not change the cached file.
+pub(crate) fn decode(resp: Response, url: &str) -> Result<Box<dyn Read + Send>> {
+    decode_body(resp.content_encoding.as_deref(), resp.body, url)
+}
+
+fn decode_body(
+    encoding: Option<&str>,
+    body: impl Read + Send + 'static,
+    url: &str,
+) -> Result<Box<dyn Read + Send>> {
+    match encoding.map(|encoding| encoding.trim().to_ascii_lowercase()) {
+        None => Ok(Box::new(body)),
+        // gzip is already decoded by the HTTP client
+        Some(encoding) if encoding == "identity" || encoding == "gzip" => Ok(Box::new(body)),
+        #[cfg(feature = "zstd")]
+        Some(encoding) if encoding == "zstd" => Ok(Box::new(zstd::Decoder::new(body)?)),
+        Some(encoding) => Err(Error::Download(format!(
+            "Unsupported content encoding {:?} of {}",
+            encoding, url
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_content_encoding() {
+        let url = "https://example.org/data/pydicom/CT_small.dcm";
+        let data = b"DICM and some more bytes".to_vec();
+        let read = |encoding, body: Vec<u8>| -> Result<Vec<u8>> {
+            let mut decoded = Vec::new();
+            decode_body(encoding, std::io::Cursor::new(body), url)?.read_to_end(&mut decoded)?;
+            Ok(decoded)
+        };
+
+        assert_eq!(read(None, data.clone()).unwrap(), data);
+        assert_eq!(read(Some("identity"), data.clone()).unwrap(), data);
+        #[cfg(feature = "zstd")]
+        assert_eq!(
+            read(Some("zstd"), zstd::encode_all(&data[..], 3).unwrap()).unwrap(),
+            data
+        );
+        assert!(matches!(
+            read(Some("br"), data.clone()),
+            Err(Error::Download(_))
+        ));
+    }
+}
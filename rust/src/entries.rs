@@ -1,263 +1,1029 @@
 // THIS FILE IS GENERATED BY ./generate/generate.py
 // DO NOT MANUALLY EDIT THIS FILE
 
-use crate::test_file::TestFile;
+// some are unused without any manifest feature
+#[allow(unused_imports)]
+use crate::test_file::{
+    Anonymization, Bundle, Collection, Defect, Provenance, TestFile, TestFileInfo,
+};
+
+// provenance of the test files, see ./generate/provenance.py
+#[cfg(feature = "manifest-pydicom")]
+pub const PYDICOM: Provenance = Provenance { source: "pydicom test files", url: Some("https://github.com/pydicom/pydicom/tree/main/src/pydicom/data/test_files"), license: "MIT" };
+#[cfg(feature = "manifest-wg04")]
+pub const WG04: Provenance = Provenance { source: "DICOM WG-04 compression test images, published by NEMA", url: Some("ftp://medical.nema.org/medical/dicom/DataSets/WG04/"), license: "freely redistributable DICOM test data" };
+#[cfg(feature = "manifest-invalid")]
+pub const INVALID: Provenance = Provenance { source: "dicom-test-files, derived from pydicom/CT_small.dcm by ./generate/malformed.py", url: Some("https://github.com/robyoung/dicom-test-files"), license: "MIT" };
+
+// collections hosted elsewhere, see ./generate/hosted.py
 
 /// all test file entries
 pub static FILE_ENTRIES: &[TestFile] = &[
-    TestFile::none("WG04/J2KI/CT1_J2KI", "ee5cb9fa931ac6d31a67cd8b58d05d7e6d5212f4666ba41c2b3ece9a069c2738"),
-    TestFile::none("WG04/J2KI/CT2_J2KI", "5807c6f052944339d06ef387208a5512a5a69692c814b7fb33006a7ceac99071"),
-    TestFile::none("WG04/J2KI/MG1_J2KI", "e7539dd3014746890e9c2341149523fbb1a8dfef4b6f8ae2927a070091464257"),
-    TestFile::none("WG04/J2KI/MR1_J2KI", "ee43a6b22c478a5707515119449cc2a03e43376066468cc1ff9cbc3cc215d7e4"),
-    TestFile::none("WG04/J2KI/MR2_J2KI", "8319846e6ad6dc70dbbaf61748b1987a6807fd02db3da24e7989fd5a5ce19e4e"),
-    TestFile::none("WG04/J2KI/MR3_J2KI", "f9c5cee35a52494d060176a1fddb287445a9822e18fb554982dd42afd3044a10"),
-    TestFile::none("WG04/J2KI/MR4_J2KI", "34314e1b6c43940e7621244288b8d3287080ea5a5a0e9f84ab2eab994ce43828"),
-    TestFile::none("WG04/J2KI/NM1_J2KI", "236806a555c0ccc9dc3310ff45512176e4ca4db59be44174c1b8d1fd80e2a7aa"),
-    TestFile::none("WG04/J2KI/RG1_J2KI", "744d01372fdda4e21b507bb7f97329065de961f4f263342079b369b430064d65"),
-    TestFile::none("WG04/J2KI/RG2_J2KI", "71ecab3798eeb3dc3a4c4c644f6d426c7f13674ec0fb700605c0b3115383fc71"),
-    TestFile::none("WG04/J2KI/RG3_J2KI", "c90c915c0c373eb6d244151f9476b05e50623c303ac20334ca9ce4aab0dddf19"),
-    TestFile::none("WG04/J2KI/SC1_J2KI", "d766e76d21670a7bbbd683dc908ac41e4b2c4fc3e5d37ced88925a834cfca587"),
-    TestFile::none("WG04/J2KI/US1_J2KI", "22340375674ff253196ce8a147acf0458bea3f105ff2c6af81f0eb119729605b"),
-    TestFile::none("WG04/J2KI/VL1_J2KI", "0affe7a7ebe8438548f5105f5a5f5776796e138e0b772817c06c1f8afb537854"),
-    TestFile::none("WG04/J2KI/VL2_J2KI", "dd57aef4882e008bfea74bfc86aed15a6a5b7313ff3cc809bf6202159ebc3a4b"),
-    TestFile::none("WG04/J2KI/VL3_J2KI", "aec99f1e212b9d1ce320a56d419bf2efdf1307f0c5d8b76fddcd5c33c6249378"),
-    TestFile::none("WG04/J2KI/VL4_J2KI", "2beab0612f82bbf690fd22bd71efc91a7f01ca2bb3a43b25fa63a2b282662148"),
-    TestFile::none("WG04/J2KI/VL5_J2KI", "b1ce311af7dfe6194929328b6988082d67cae6fa71ef4235f2885f0eace7a6d6"),
-    TestFile::none("WG04/J2KI/VL6_J2KI", "dea4fa96af7140da2766d689e1f1d159e0cdc1631564a6b49a2909959caf1c3a"),
-    TestFile::none("WG04/J2KI/XA1_J2KI", "24fa42e4a19ccb8f32115969fe413d5e440bcc239eb3c1da7b3b8900530890f9"),
-    TestFile::none("WG04/J2KR/CT1_J2KR", "121f77705f8e26eefaacafe0d7becc8dd42c9b5553e3dcb904283d9a96c9f16a"),
-    TestFile::none("WG04/J2KR/CT2_J2KR", "95620ff3d0e506226853507d4a44c4b7ac6dc49fd1dd13460ed97a47e184ad03"),
-    TestFile::none("WG04/J2KR/MG1_J2KR", "1caf511a11d5c592785b442f0a925fdefb670b4286deb01b9ab81f97bf620a33"),
-    TestFile::none("WG04/J2KR/MR1_J2KR", "7cc23fdb217dc81ec14aecbe6e8e0837fa2fb3710f1873417de5afe876cf4245"),
-    TestFile::none("WG04/J2KR/MR2_J2KR", "707f0a1b648b79f17b61e241af31fb9edea7fe596681a4bcab6cce890300a9a5"),
-    TestFile::none("WG04/J2KR/MR3_J2KR", "d0def9effbec263a4b2d301ba907a1d62969dde6757927cc1a0c5f4906c16cfb"),
-    TestFile::none("WG04/J2KR/MR4_J2KR", "d2f50d4f54df409c12dc50f15b4ea6f135c687e0457e2fd2ed6a0982e6c022e0"),
-    TestFile::none("WG04/J2KR/NM1_J2KR", "24552c24921274d59b0218e86d1f6c1e6ebb2a5b7716d15229ff98edc50a4ab4"),
-    TestFile::none("WG04/J2KR/RG1_J2KR", "7fbfd29360af806770102fd7c4ffcb2a133075bd00920a4bb63460d516f67ac4"),
-    TestFile::none("WG04/J2KR/RG2_J2KR", "fb86f947211873ddf6caa3b46204ad64987d9a498885d19258e072c9cee32998"),
-    TestFile::none("WG04/J2KR/RG3_J2KR", "ffde92ba154a7d5ed2ab70b7cd37892772f8bef63fb26f9080327c6a089c205b"),
-    TestFile::none("WG04/J2KR/SC1_J2KR", "6b58681001784c2bd46bd7442f9557e51b7fa1dc912c50622e8b7c3623638a20"),
-    TestFile::none("WG04/J2KR/US1_J2KR", "2427fdc82d90cd4ce8a69b5157eecb37549902dce138ac15c6456a7eae70b83d"),
-    TestFile::none("WG04/J2KR/VL1_J2KR", "eee914f936a63b9040f0955e31c284a39a97e70fa2d454a86f61e0352ede8515"),
-    TestFile::none("WG04/J2KR/VL2_J2KR", "42bd2775fcaf1401ebc71e84056b5c6a7d257e62044e3cf0344dde8ce1ada3d0"),
-    TestFile::none("WG04/J2KR/VL3_J2KR", "29720969dc6b5d090ae370488590db41af6d204b20a8d64cacb1194ec39a7cec"),
-    TestFile::none("WG04/J2KR/VL4_J2KR", "2febdb153413f2e635a7000fafb2724725336005e2e02b58bb7123110870bc58"),
-    TestFile::none("WG04/J2KR/VL5_J2KR", "f5b32747b47a1c2eb0cd1b554a25273aa6a0760d0937ecfb2e7ebd1721b90be5"),
-    TestFile::none("WG04/J2KR/VL6_J2KR", "1d19d411e953b6715e3da6f5dbd173a113bf5d46f075263badb4fe1d7f3c21cc"),
-    TestFile::none("WG04/J2KR/XA1_J2KR", "5f0539e8963b842915e22f819ec738547c4835acf128e55b19a3dea2ffe08d8c"),
-    TestFile::none("WG04/JLSL/CT1_JLSL", "9d848ee70d48e43924eb821bb28e5bbd94035473db7aad6d6e86a37d48f98ae3"),
-    TestFile::none("WG04/JLSL/CT2_JLSL", "a7da533cf2f0910b5de6cdb611a1404fd07929b0c3f6aed875dbdf5d0a17e100"),
-    TestFile::none("WG04/JLSL/MG1_JLSL", "0ebf776c9a3a4b1dbdcd46c5ce41a6c3ed883eab4ae0d3c966d951581b247141"),
-    TestFile::none("WG04/JLSL/MR1_JLSL", "4a3dea4fb4eff1faf01e93caed1832d7f9f0ea7e826d2a6dd50559da3f441d6b"),
-    TestFile::none("WG04/JLSL/MR2_JLSL", "abce0865143bd15936a9c4dd86c752a0ee55a54ae84a988d76641a4abf7ef129"),
-    TestFile::none("WG04/JLSL/MR3_JLSL", "481217d8ba42b8eafd7321316ffb8ca3cc60d2b226aa05e68c8169253f848570"),
-    TestFile::none("WG04/JLSL/MR4_JLSL", "4c5c63ec7b3877e2ed5227217aff133fc0bc5dc8bdfae94aedc0604255eb108b"),
-    TestFile::none("WG04/JLSL/NM1_JLSL", "b38d1dd5d714f318490bee83060b58296e82099d9d338007db8b6c79af68b1a0"),
-    TestFile::none("WG04/JLSL/RG1_JLSL", "ab2e7eb94e53f68352ed4d4569417cf07917c96ec7deb1710c381a5f607e0ce1"),
-    TestFile::none("WG04/JLSL/RG2_JLSL", "34e9a853fdf326d0cc89b2ae132c2b04a0384da7c37a4b3a9c98d9dcb725b009"),
-    TestFile::none("WG04/JLSL/RG3_JLSL", "4d3b746958119ab0b84f7789ea2830e141b5ba8835b3df147d4a9f19c132d476"),
-    TestFile::none("WG04/JLSL/SC1_JLSL", "4b743d0b3d82a62c3950d68d4bcefa01b17e3ab06be1436aede8ef5143b9c54f"),
-    TestFile::none("WG04/JLSL/XA1_JLSL", "2296005f4a4d57b757e164755f5baccf3ef6577d7fba6fdf06fcf5d193653c49"),
-    TestFile::none("WG04/JLSN/CT1_JLSN", "98dee161acfc65a65987f5066cd3cbe9d7f8d182645f2417f9564c262066f144"),
-    TestFile::none("WG04/JLSN/CT2_JLSN", "d9176e6d639ad835aaddd65c0d9198a296b0edf02f85b6fb62adcee9e752da66"),
-    TestFile::none("WG04/JLSN/MG1_JLSN", "74d908d8bc960c7b6f2d96a54b95288c7165e00835334bcabf867dad082f672c"),
-    TestFile::none("WG04/JLSN/MR1_JLSN", "1c0725a47387de92b32ad498cc910bcf3307756ad618901e7a8a4cd008f48ef2"),
-    TestFile::none("WG04/JLSN/MR2_JLSN", "4202ebf23ecb47cb74fac538a9365f1e511edce4f7c515e93f6dfbb4c524ea33"),
-    TestFile::none("WG04/JLSN/MR3_JLSN", "2a4ac9d2dde60831991e565d83738b4a638f0bd4ccfb592ee07904acc9ab96a9"),
-    TestFile::none("WG04/JLSN/MR4_JLSN", "c2b5e6dce8eb8be2b20073613bece70d39c16ca13b516d6ddd689bff40987d1c"),
-    TestFile::none("WG04/JLSN/NM1_JLSN", "3c974270eca06e332277d159f4353e6b0ebbd787ffd8ee04dc4556526c90f5c9"),
-    TestFile::none("WG04/JLSN/RG1_JLSN", "e04e2bdd3b433d7f76f63c60d50bb5ad9564c3cddcf2e8fcb6efb8801806af4e"),
-    TestFile::none("WG04/JLSN/RG2_JLSN", "af270ba7b1c8a221110f1eee33ef25717e763732b21621ee857fa2b8a99fd7e4"),
-    TestFile::none("WG04/JLSN/RG3_JLSN", "63f877b43c3b4d8ff557b39b39d0890e493a8b9f8d1ffae3eb2b2e5fcf5c854f"),
-    TestFile::none("WG04/JLSN/SC1_JLSN", "acc5b413b9b0cd6cdb658ffbdb8fe5d074e4d0cbc7e9635637cb104727431e1a"),
-    TestFile::none("WG04/JLSN/XA1_JLSN", "b78c433ca4445bbd47981d110114020b99b5c041301e3348b8af4a5123ebdc3f"),
-    TestFile::none("WG04/JPLL/CT1_JPLL", "cd3adca021b00a3556c8089d12bfaa7b2640419153c77961f4745125dd7219fc"),
-    TestFile::none("WG04/JPLL/CT2_JPLL", "0db74e1c44d1f1bfd54f777a8a5a3f9ecf48b3f5811fbd904160d7df32d34bea"),
-    TestFile::none("WG04/JPLL/MG1_JPLL", "74e4b966f2982194b062ad8fbf6e26ec358519df85c2e85ffcc5a7417ec340ad"),
-    TestFile::none("WG04/JPLL/MR1_JPLL", "c45e6f2a88e8d7ee343f4d7ea1d13666cb3111da43e6b5aa013e20764d4a1e87"),
-    TestFile::none("WG04/JPLL/MR2_JPLL", "43e93052b96bbfb44a7a429b189e0abee0cd146d4d362deb0c0403ed8ae5c383"),
-    TestFile::none("WG04/JPLL/MR3_JPLL", "cd00960524708cfb9517f1f4abf65e784c587aadd99ad11cfcdb34d04eb224b5"),
-    TestFile::none("WG04/JPLL/MR4_JPLL", "a01eff905b351a408f0458932440ec9979da3277bfed94f909cf82bf2abb3051"),
-    TestFile::none("WG04/JPLL/NM1_JPLL", "c9d000c75d92b143ce1c0421471a7e9a69c8996d98b2589e533e311615a10079"),
-    TestFile::none("WG04/JPLL/RG1_JPLL", "466a7fdf51f048102140fd36efff56db30e2099d424783d50e92615469abd85d"),
-    TestFile::none("WG04/JPLL/RG2_JPLL", "a147786db235221421522c9172d50b437741f0d713f2ad6252f673d453d1dbb4"),
-    TestFile::none("WG04/JPLL/RG3_JPLL", "446803e262feb66afbfe97ba60452d6726aba9b968fe5b3005f5f595cb3da762"),
-    TestFile::none("WG04/JPLL/SC1_JPLL", "949ab5b4dafd41cc3ed20aad6a1e979a516751947b879adebf1bb9e9fdb16ac8"),
-    TestFile::none("WG04/JPLL/XA1_JPLL", "94f21a860a274b9df68d2cfdafe5d5a2c93a123a369f1b7c352ba16c259af129"),
-    TestFile::none("WG04/JPLY/MG1_JPLY", "cfbccc760ad9ff851e87842b0f090caa38ca0ba623919d26a48462eb69654bb5"),
-    TestFile::none("WG04/JPLY/MR1_JPLY", "3a6d42cf5c973b0523558c0322afd135eb7d1d332a4f718bfe8549dce1399014"),
-    TestFile::none("WG04/JPLY/MR2_JPLY", "1e7c43a80392f52596a32dd5dd8bb0f7717b16a7e75e96962b8a0e6cb5776530"),
-    TestFile::none("WG04/JPLY/MR3_JPLY", "d259cdeeb7534325143344af1d6da7f215e14862f27aa5251f4b9acd75b8c154"),
-    TestFile::none("WG04/JPLY/MR4_JPLY", "ec73206c6dc99648ea43cd8a006ae226a82e11078ef14945e666021359783ba7"),
-    TestFile::none("WG04/JPLY/NM1_JPLY", "c425608e2fcda8332c75d33f890bfe3bae32700608b719046b3d9e789374c292"),
-    TestFile::none("WG04/JPLY/RG2_JPLY", "65c2f33d35968b4a59e6aa11e7c4c169674b5ed626a7b79f4bf042a8007fe54d"),
-    TestFile::none("WG04/JPLY/RG3_JPLY", "f26b5ef74e8b66d5221d69a46251e387f15ff9ba8a8d9e5bd5093216843c0eb5"),
-    TestFile::none("WG04/JPLY/SC1_JPLY", "a0c55225e496cbd71255e433b3f3292fe393d72664028563bfee60b99f6b803a"),
-    TestFile::none("WG04/JPLY/XA1_JPLY", "816cdcb324a326cbe792e5fdfbe866977bb612089c6fbb589ecc5e36344fdfd3"),
-    TestFile::zstd("WG04/REF/CT1_UNC", "292d5071958c77e67044e6b8b9947481c3912597082983bf37a8fe9a52507315"),
-    TestFile::zstd("WG04/REF/CT2_UNC", "3f7d0a7c14230d9bc0c31b5f9b02f0cf4e0167742ded2255d18c125c589b718a"),
-    TestFile::zstd("WG04/REF/MG1_UNC", "783b5456ca803f792a782dce0266e83b053b91c97f9a1c8b927466644647d842"),
-    TestFile::zstd("WG04/REF/MR1_UNC", "32e7bc7ef7b094a212d59a8a5121d48834af7a5e631abecef4e1f445df8ec236"),
-    TestFile::zstd("WG04/REF/MR2_UNC", "1b37e9f225adef1393c7c04ab6c44693f882b389d34f77d24a5a7418661a7021"),
-    TestFile::zstd("WG04/REF/MR3_UNC", "ac30d43bf8599b5edd2d76006c197ad6ffa63892558fd91146659dfad17f3426"),
-    TestFile::zstd("WG04/REF/MR4_UNC", "9c4c699749a586d11e101f4ab53f0877c84b0ca5a2574301880fc88432a48d24"),
-    TestFile::zstd("WG04/REF/NM1_UNC", "4457c66f44b37e8c530ced925da8fec7535b39508a284120f4627fa76c544faa"),
-    TestFile::zstd("WG04/REF/RG1_UNC", "a17df3ea0e4900d0d523534ac0ff69ff1c45678a57df60954fed047daf0bf3a6"),
-    TestFile::zstd("WG04/REF/RG2_UNC", "76f026b043e4089fe4ecd18ef5b8eb5f3a8e63784afd51140600c657409568ad"),
-    TestFile::zstd("WG04/REF/RG3_UNC", "7a49a81015ce78f2aedb892fd212784c76f5d03cede0d7bd6a2148e498eb9f42"),
-    TestFile::zstd("WG04/REF/SC1_UNC", "707158fc2455413a147b69117e449eb74a10171c8ae7bf8056798e9d7a2fab14"),
-    TestFile::zstd("WG04/REF/US1_UNC", "865b65bcbe70cc8f313bc3419f43d21eb6ddfa55328298a8e1ca30e08f75df16"),
-    TestFile::zstd("WG04/REF/VL1_UNC", "cecb9b1120a74dc4281d91215c312941b9f68e23a64410f3c28a0e6cc5cc7f5f"),
-    TestFile::zstd("WG04/REF/VL2_UNC", "82a81ea23856f289dba46eb040cb023cedcab88b53b3ab6bb6a92ff5f9020323"),
-    TestFile::zstd("WG04/REF/VL3_UNC", "1f66f037657216a30f533af716fbe9f106cd88ea65326532ee5dc0b664df2ce3"),
-    TestFile::zstd("WG04/REF/VL4_UNC", "6e65bb5b0f80aa9ab73a3326075726f5105b2fa9e508aace6f283309601e3b30"),
-    TestFile::zstd("WG04/REF/VL5_UNC", "911fa9dffc0b7dbfb0f090bc8340365a2e69d3826ec5dba83826b13467db6f20"),
-    TestFile::zstd("WG04/REF/VL6_UNC", "81a75f35c30c019b8d49332b597f4f203911869c493b07f7129e61409fe3c441"),
-    TestFile::zstd("WG04/REF/XA1_UNC", "f40894fa78dde9cb47e5ab3493be1d14bd150822cfde0e3ecd0428b045504a25"),
-    TestFile::none("WG04/RLE/CT1_RLE", "d87ebd3e2e728ff8257f083d0629134f5e1e5d155bafb6fa0ef4840af1d90f01"),
-    TestFile::none("WG04/RLE/CT2_RLE", "8df23792fec23d6d5e6fb9e2f8548ef5f8f7c10a0e4b8b63cedd72c340ffead3"),
-    TestFile::none("WG04/RLE/MG1_RLE", "7c867028dfb05b2bdfc596d0966298243a47354c72c302e227b8939670bbe2a9"),
-    TestFile::none("WG04/RLE/MR1_RLE", "c40c2da8d6f8d8815c0ff67bf519e71a4202d975d4d76fd3d719222010da2b57"),
-    TestFile::none("WG04/RLE/MR2_RLE", "8afb8568563895a81da870695ba2a2c54b80a1f57950c6e2507dca77891285e5"),
-    TestFile::none("WG04/RLE/MR3_RLE", "c0fc8a81a8f0fd5b352b457302f915d5bc157cb09ac4f480811d5282b3f7b7aa"),
-    TestFile::none("WG04/RLE/MR4_RLE", "c50da475afc366dd2bd194d65315731cd99665bbffe37891927d3715efcb99db"),
-    TestFile::none("WG04/RLE/NM1_RLE", "550bcb521853cf0791130de4268448c3158bd786a11f3dc6dd54b66d1de2ed77"),
-    TestFile::none("WG04/RLE/RG1_RLE", "4d3366fad1ef9bd9a1b44841d0148c40c4e2e45d16948e8642aae214c65d0dd9"),
-    TestFile::none("WG04/RLE/RG2_RLE", "d80dab6c9d05a6925d388b07093cff75284171d0cefcbcd04201a0423051aadc"),
-    TestFile::none("WG04/RLE/RG3_RLE", "6d63f282b70a964e8fb4aa084978a36305a892640314553547021baf991f58be"),
-    TestFile::none("WG04/RLE/SC1_RLE", "d9ffae0840edbd57f99da12c83237a60231c6332ebe2d952b40a0c05b97f34ba"),
-    TestFile::none("WG04/RLE/US1_RLE", "6e44f53c164a4cfb79c1d14f3a1bd085f9ba36f5d696ce1730137f5bf7ddbbc3"),
-    TestFile::none("WG04/RLE/VL1_RLE", "670ded567a804996060fb3f0c4c483f4363cbbaa42b53f958914fce079c3434b"),
-    TestFile::none("WG04/RLE/VL2_RLE", "a36ac72ffd480a0345530bb3a7999ef6037102e96d257b60789b8a87092513fe"),
-    TestFile::none("WG04/RLE/VL3_RLE", "425b1d34dea09a7f7826d7a0814b8184df646f4440d9838b2776554e42a30965"),
-    TestFile::none("WG04/RLE/VL4_RLE", "ab690160de273c844bb11eacbd80a00e86e8ffadca65afdda654a89d20d8cd47"),
-    TestFile::none("WG04/RLE/VL5_RLE", "f635d329eac85313450c48bd00cff3de77e8c83a12619321996ac311ee40f4eb"),
-    TestFile::none("WG04/RLE/VL6_RLE", "5c096e6322fe97e10d08c7301c658a76f28f6184700794f10e816ebb8bb010f1"),
-    TestFile::none("WG04/RLE/XA1_RLE", "e9c89f2bb83c73f545d4962b2de9b0f0d01771255a9f93dccfd55746f7c4384d"),
-    TestFile::none("pydicom/693_J2KI.dcm", "8d5d503fd46b9a59c628762d71d7391ea1a2a5fd8d339ac82ef9e281a15ef65f"),
-    TestFile::none("pydicom/693_J2KR.dcm", "c392d8bd1f952ed2d9387d5143d34c5a29ac9d74566688169731a50ac6a82aa2"),
-    TestFile::none("pydicom/693_UNCI.dcm", "42d6c33d6666bf569a53951211be6fca2ab04956db43c3f75a9720d976ab128c"),
-    TestFile::none("pydicom/693_UNCR.dcm", "cc4cdd599231922ecf63de2ddacf03d51c4588805c9154c2eef1ff49c23b32be"),
-    TestFile::none("pydicom/CT_small.dcm", "3dd31e5cc835b3f2cdd46c9da1982f59251e78518fefa8163d914631c66437d6"),
-    TestFile::none("pydicom/ExplVR_BigEnd.dcm", "42eb61ea5650f1064e52d48019cd87b118e52cf4dfbc8fa57427ed2ed4c036ea"),
-    TestFile::none("pydicom/ExplVR_BigEndNoMeta.dcm", "a56be8c8c52f0d1cf55d7c2ced6abc6f22b799cbf556b84b856055a7a7565949"),
-    TestFile::none("pydicom/ExplVR_LitEndNoMeta.dcm", "008e9302975d34899d89b4e3f044f8637b16acde25242eb0b36a1ffc034b9b42"),
-    TestFile::none("pydicom/JPEG-LL.dcm", "c9d000c75d92b143ce1c0421471a7e9a69c8996d98b2589e533e311615a10079"),
-    TestFile::none("pydicom/JPEG-lossy.dcm", "c425608e2fcda8332c75d33f890bfe3bae32700608b719046b3d9e789374c292"),
-    TestFile::none("pydicom/JPEG2000.dcm", "5be539024e6803029a7b73c0f8e72e88d032e3a0bc05922c0c047344780aa8e1"),
-    TestFile::none("pydicom/JPEG2000_UNC.dcm", "645ff302c7f7ee6c402d74c7c9e3cb5efdb861a828959cc2adc8775a8260688d"),
-    TestFile::none("pydicom/JPGLosslessP14SV1_1s_1f_8b.dcm", "1978d4f058e52d3239fae33f261b3dc74605fdd9f89031fffd57bea6218d0dbf"),
-    TestFile::none("pydicom/MR-SIEMENS-DICOM-WithOverlays.dcm", "094faf56c63bff84c30567e29de0c67d7c5a8ae05cf880ac12175491b6b645d2"),
-    TestFile::none("pydicom/MR2_J2KI.dcm", "8319846e6ad6dc70dbbaf61748b1987a6807fd02db3da24e7989fd5a5ce19e4e"),
-    TestFile::none("pydicom/MR2_J2KR.dcm", "707f0a1b648b79f17b61e241af31fb9edea7fe596681a4bcab6cce890300a9a5"),
-    TestFile::none("pydicom/MR2_UNCI.dcm", "7f79ac33e1ab32e1a8ca10ce62f18e5a2372e78c8a6684af17302b1a0171fc46"),
-    TestFile::none("pydicom/MR2_UNCR.dcm", "c14c7f0c6e25bd4dfbb822fe264e540fc7142bf1c9d15d4c652ec8f5f97fa9e8"),
-    TestFile::none("pydicom/MR_small.dcm", "3f27d1c22f1a66e80d7bb7c911e8610fd0bb70325a76746a7adb1c0ddefcf2bb"),
-    TestFile::none("pydicom/MR_small_RLE.dcm", "2e5cb60878dc0acc494298ccdad28fce2cf14c51096e5d8cedab40248ea02e6c"),
-    TestFile::none("pydicom/MR_small_bigendian.dcm", "3e4c8c9fe70de4f3be149bbd673fa56f211c8e8e2ff9bac63f70f9dc31b5d108"),
-    TestFile::none("pydicom/MR_small_expb.dcm", "8b3846771e1dbb4b36daf3eabbd331090a4735d2930174540458c51ec0808aeb"),
-    TestFile::none("pydicom/MR_small_implicit.dcm", "6077442c42a56fc7fcc7db8411a657dded9fc109e6d3275765c4de358292b299"),
-    TestFile::none("pydicom/MR_small_jp2klossless.dcm", "4c0049e0355b560c8c846538d827afbdae5311b20fc5e5a93a3892e109bb140d"),
-    TestFile::none("pydicom/MR_small_jpeg_ls_lossless.dcm", "b2b69dd2ae854bf7dfada6745709cd5d8a4573ea12387adbbdc56e8be6056206"),
-    TestFile::none("pydicom/MR_small_padded.dcm", "b46e32d8430f1e86e7fc03b9542e06ffc40a591890a3acc644c301d6a2f0e57f"),
-    TestFile::none("pydicom/MR_truncated.dcm", "a3f26c279dd214951d32a1548362df3c93f9730135fa893a01552c0e632f587f"),
-    TestFile::none("pydicom/OBXXXX1A.dcm", "164a460bebdc15fbe391ad4bfe4c84672eb2bad57adfe7dad372fd7367b0f63e"),
-    TestFile::none("pydicom/OBXXXX1A_2frame.dcm", "6627f6e46dbf8c16292fb1eaff8807439bcd233dc68099c07f0b83c4093256b1"),
-    TestFile::none("pydicom/OBXXXX1A_expb.dcm", "668dc27f6db2c7d47d7384dbb86593cc8f681a44fca3bb93201913d5cd6463e2"),
-    TestFile::none("pydicom/OBXXXX1A_expb_2frame.dcm", "b27a5e056d005525d28fdabfebc061ef64cc3d652c835d062f4f5d31d2bf453b"),
-    TestFile::none("pydicom/OBXXXX1A_rle.dcm", "aaf57785817dbe35503c6175d677d2efa811f90e931fc5017611ba9ff4c7f92a"),
-    TestFile::none("pydicom/OBXXXX1A_rle_2frame.dcm", "65bee869c507f535edea93a446a26e941fb9cbc3819e4d73395f11eef56d4687"),
-    TestFile::none("pydicom/OT-PAL-8-face.dcm", "d5560470077f77ef6a0a52d22f9f61e803436d2b468a9550a4d12c5675ee0a97"),
-    TestFile::none("pydicom/README.txt", "b2e36c210398ac0e1b0c6b2bc22a0c5cbfd0e7631639080c1f3c720774e5b448"),
-    TestFile::none("pydicom/RG1_J2KI.dcm", "744d01372fdda4e21b507bb7f97329065de961f4f263342079b369b430064d65"),
-    TestFile::none("pydicom/RG1_J2KR.dcm", "7fbfd29360af806770102fd7c4ffcb2a133075bd00920a4bb63460d516f67ac4"),
-    TestFile::none("pydicom/RG1_UNCI.dcm", "3561020824868615a93a51078671b3ff73bb2578c966f76def99b4d982897e75"),
-    TestFile::none("pydicom/RG1_UNCR.dcm", "946f28f48b9fbf360196a9b835c8fce83b0c654bf85a5107663c8a61df02e498"),
-    TestFile::none("pydicom/RG3_J2KI.dcm", "c90c915c0c373eb6d244151f9476b05e50623c303ac20334ca9ce4aab0dddf19"),
-    TestFile::none("pydicom/RG3_J2KR.dcm", "ffde92ba154a7d5ed2ab70b7cd37892772f8bef63fb26f9080327c6a089c205b"),
-    TestFile::none("pydicom/RG3_UNCI.dcm", "9ef0260919de89774da90336ad16c03a5be899a8bb663bbaea52b6d0769bec78"),
-    TestFile::none("pydicom/RG3_UNCR.dcm", "6babfc42dd404213e1758d6dbb93648c248783cc23f593103fff4295c3374dfb"),
-    TestFile::none("pydicom/SC_rgb.dcm", "b0f868d6a689a0ff96c39b459caf1b628eacd74134114ce84549573321231138"),
-    TestFile::none("pydicom/SC_rgb_16bit.dcm", "3dc969768431d1cb2695dcd3f190588b02413798dab8420418d2fbb9cb4d4075"),
-    TestFile::none("pydicom/SC_rgb_16bit_2frame.dcm", "f251a296e1aa5dde37423a9aacba7f31b0b4869328caa6e42bf6b110f007c401"),
-    TestFile::none("pydicom/SC_rgb_2frame.dcm", "9b5c0306679675c688c2044d97878a6a14ce9976ecdf022309e5f6e9ceaffd9c"),
-    TestFile::none("pydicom/SC_rgb_32bit.dcm", "c3dac5c807ab27227c0d36b7cd34bb776103bb08d230ff74e62259eeeef0769a"),
-    TestFile::none("pydicom/SC_rgb_32bit_2frame.dcm", "33f78c27519f23e0410e9c5d24f55380a431255f00ff95e12a26fd45765a7920"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cr.dcm", "d16092b526e46328897a18cb0adc5c582bbfe953d6dcb2d12bb9270d398f6c41"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+n1.dcm", "aedfe7e31480e75bb6a64cf96b6a1048a8b24e9135954872fba4a28b60941a60"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+n2.dcm", "d5a1d71257e2763c63371ab74acaece759696850077a88bcf45e97e0a84f7065"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+np.dcm", "5467c2e97efcae6b462f59fcf1d626f4f1ac99c7ea83c2596ce6e7aaf2083ac6"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+s2.dcm", "44d80da71ae3be6048ed7333a1b6f4c1098be2310ca4981f6870f0fed2fe74ed"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+s4.dcm", "f2ed4012809e6d32bb552638221b440808b263d98f51515e6816bad7fcd9fd7b"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_ebcr_dcmd.dcm", "e183a37c833c78da6c516aed9920527d80d7f1bbaf805a92530024e1aa2e74ff"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_ebcyn1_dcmd.dcm", "a963683216b270b788682dc132a65965406a3100722c2d0c2fd2219a0ea53c66"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_ebcyn2_dcmd.dcm", "2692a16f99b879c742398f3a5b4b9508165d4fe6b056eaa85642ff6bed80ff62"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_ebcynp_dcmd.dcm", "6324aa7eb90e57299087a70ff6875b10f4d17b8e359ee2f20f1eaaf3d0876993"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_ebcys2_dcmd.dcm", "f6334492b38d4494b0e8929c4f6b34e9decba9b2dae4e01749263bf254a8c096"),
-    TestFile::none("pydicom/SC_rgb_dcmtk_ebcys4_dcmd.dcm", "9fb6b7e5dd1f1097ecb23fcd2afafeee9c5233f75680b0922b723f2f1b7b09ab"),
-    TestFile::none("pydicom/SC_rgb_expb.dcm", "e92997e0cf83407693478ca6f2ce44f42f50f73751f11c355ce555ef86dc8e84"),
-    TestFile::none("pydicom/SC_rgb_expb_16bit.dcm", "5e8e2340ba9698deba857f76e0ee007c1acb88de84841519425afe76b5b25c11"),
-    TestFile::none("pydicom/SC_rgb_expb_16bit_2frame.dcm", "fb88f409d21ca9c08672f32f756d0ba0d57de91f8240cf807971085a600e866b"),
-    TestFile::none("pydicom/SC_rgb_expb_2frame.dcm", "b8b9adb32b2c3ce33c3136620a9b00c2440e047574305e76d176e28ad374134f"),
-    TestFile::none("pydicom/SC_rgb_expb_32bit.dcm", "5153bb5df191a2b1ec40f592d433a097523a2979ee2ec22ae47ad2bf823bebd3"),
-    TestFile::none("pydicom/SC_rgb_expb_32bit_2frame.dcm", "cb4e18465d10d4c60afcf8e591b44687ffac8cfd63ab9ca3b6ad45ec25dc2175"),
-    TestFile::none("pydicom/SC_rgb_gdcm2k_uncompressed.dcm", "abf72c420b8bb97a29b93cb5d63a633271b65038d8323e28d71334bc56ef1a2b"),
-    TestFile::none("pydicom/SC_rgb_gdcm_KY.dcm", "bd0fdd97841431dd8e3a76a4842cf6d378285254a273f7884c0a7a8ddb9622d8"),
-    TestFile::none("pydicom/SC_rgb_jpeg_dcmtk.dcm", "6548a45a0800626cf70a59766146ff3b790a393ee0c9fca359f92c70f370b382"),
-    TestFile::none("pydicom/SC_rgb_jpeg_gdcm.dcm", "a492ed4a120c51a076126a6021e8cab1acb0172da3d42c62843b2a34a8ddd252"),
-    TestFile::none("pydicom/SC_rgb_jpeg_lossy_gdcm.dcm", "fb9f1a7dfbca18d3af666ef4a8d15e2d81e75745b2e118e3c590ff90de188d0b"),
-    TestFile::none("pydicom/SC_rgb_rle.dcm", "3f98ee352e75b10ccd6d279ca30b0cb1e363a0c9dde318803f0ec660111327d6"),
-    TestFile::none("pydicom/SC_rgb_rle_16bit.dcm", "86504bea4a8cea36ef4f65e687495cea800e7012546a3202a7944b4f06b376b5"),
-    TestFile::none("pydicom/SC_rgb_rle_16bit_2frame.dcm", "d71390a0ad9c66017271d4dc16c3a45fc2ad4808a5a8efdf5badf01274dcf602"),
-    TestFile::none("pydicom/SC_rgb_rle_2frame.dcm", "cc9cd098ab099b5f7a18c4599f2858d2f3f3471590ff8a14d4cf7c834692d9f0"),
-    TestFile::none("pydicom/SC_rgb_rle_32bit.dcm", "222c7060be1697a24e6659bd97ef17fe9c8596ee41bba2a89768854eec00e383"),
-    TestFile::none("pydicom/SC_rgb_rle_32bit_2frame.dcm", "5c9aa606982eadb1d8aa445fbe87318ad2fa984615e14d9c2c46bd4497cdf2c3"),
-    TestFile::none("pydicom/SC_rgb_small_odd.dcm", "4aca361ab330f57f60e6b1e3b31dcd834a512bee8a4246bbe1d151011c47e031"),
-    TestFile::none("pydicom/SC_rgb_small_odd_jpeg.dcm", "ffb5219ca45a2b492ce5e5a6fc7a5f5ad5a667716a2e5b859eba323376adf439"),
-    TestFile::none("pydicom/SC_ybr_full_422_uncompressed.dcm", "08f6f4935ae225282d8481f297d37b1cf33be8c3d99028f310a9a3f9e8aaf284"),
-    TestFile::none("pydicom/SC_ybr_full_uncompressed.dcm", "3c9f4b2b82a3f88ce5340cb07ce14782dcbb09840938e4489e8c21eac1f02dd6"),
-    TestFile::none("pydicom/US1_J2KI.dcm", "22340375674ff253196ce8a147acf0458bea3f105ff2c6af81f0eb119729605b"),
-    TestFile::none("pydicom/US1_J2KR.dcm", "2427fdc82d90cd4ce8a69b5157eecb37549902dce138ac15c6456a7eae70b83d"),
-    TestFile::none("pydicom/US1_UNCI.dcm", "64d4dcc8cf787f110296e949480b7d035b3ade806574757c931f026caa068137"),
-    TestFile::none("pydicom/US1_UNCR.dcm", "af5a66e40cd49d15dfbf7b78c850eba0662bdc7339339c3fa13f123a57e812cb"),
-    TestFile::none("pydicom/badVR.dcm", "d12583df0cf66146256238b4cada266956a317c0546c7516cb36f56982ce85b2"),
-    TestFile::none("pydicom/bad_sequence.dcm", "0677915e5c3e8c98498eb3d1b726ccf38ba0d8ada657c8ca7fe1b8b9b5890f4f"),
-    TestFile::none("pydicom/color-pl.dcm", "16bfc3134e59d789985efddfc70d924420b16e1c6d1f21c960bb4544c9e9dbf9"),
-    TestFile::none("pydicom/color-px.dcm", "bf10a89f277743ea337b7c4741efa0709a086f0161e1ff2b94cff01e428047e4"),
-    TestFile::none("pydicom/color3d_jpeg_baseline.dcm", "c8798b8abf8ae0a18e8c9952e7c7f75f3cc8465234b1b63f9e3ba3bebb9d5625"),
-    TestFile::none("pydicom/eCT_Supplemental.dcm", "0a4c3aa02d1b0b4826daa5ffe85ef13be83c1433842a9a98b901e075136dd86f"),
-    TestFile::none("pydicom/empty_charset_LEI.dcm", "7fd2082a76e9a97cb1306f1da389bafe32ec2f874262a9c6c78b7c475acffb4d"),
-    TestFile::none("pydicom/emri_small.dcm", "151233ec63f64ebb63b979df51aa827cd612a53422c073f6ef341770c7bc9a56"),
-    TestFile::none("pydicom/emri_small_RLE.dcm", "93c19bca3fb6b7202dcd067de8d16cb6b3f7c6e9a0632e474aab81175ee45266"),
-    TestFile::none("pydicom/emri_small_big_endian.dcm", "8e18ed3542bc4df70dc6acda87eab5095b19e2b4c1b7fb72ba457e7c217b1ab7"),
-    TestFile::none("pydicom/emri_small_jpeg_2k_lossless.dcm", "b2b4063359a08ed3b0afa9f4e4f72f84af79e5116515b446d9a30da9dc7f1888"),
-    TestFile::none("pydicom/emri_small_jpeg_2k_lossless_too_short.dcm", "8742a49b7d02dedb11e7926d30900a415c42efeff02a64a0aa0f0873cf6da582"),
-    TestFile::none("pydicom/emri_small_jpeg_ls_lossless.dcm", "24de03c9c0f8b5aa75d7fbcc894f94e612b66702175b4936589a0849ec9f87b4"),
-    TestFile::none("pydicom/explicit_VR-UN.dcm", "28c4a61022d7dbebec97e2f1bbdad0ed097bee2c62727c26a3f3720248c9c6e7"),
-    TestFile::none("pydicom/gdcm-US-ALOKA-16.dcm", "f1a2d5f7c4ffe87dc589b12738084099fe44a436f6980f5d7e96a026ad356d65"),
-    TestFile::none("pydicom/gdcm-US-ALOKA-16_big.dcm", "2a801cbd7bd04ed28b9c14c7a8edb04b43384e38f00574e27c0fab8f4aa62db4"),
-    TestFile::none("pydicom/image_dfl.dcm", "0029ebbba17e7c6f081408d433cd28b5d1cfee0eeb4cff509b4d972ffa9daf27"),
-    TestFile::none("pydicom/liver.dcm", "4f8fb316b6df067bdf2ef7bc2385fd571ad5be67e171aed3ed902a71293d9d5c"),
-    TestFile::none("pydicom/liver_1frame.dcm", "8ac3546185d0c18c193438b47b16c4ef323f0ebe0e8fd071ee1e6d43edef1978"),
-    TestFile::none("pydicom/liver_expb.dcm", "fe3323f3f4a2166e4c5305a2380a035a66504197f3f01a6e2b50bbd9814721d5"),
-    TestFile::none("pydicom/liver_expb_1frame.dcm", "2429258dec0f9c444b69d9d7326b442bd27c66a2ba1d6f68804005d27df6af13"),
-    TestFile::none("pydicom/meta_missing_tsyntax.dcm", "075dc51483951bb651ddafd77e803101fcee21ab656ca87d6e531eaeaeb440f2"),
-    TestFile::none("pydicom/mlut_18.dcm", "9c65b39df55dc46a4670f76e0ec1093d097206ed46c2d7e23b8051c87ef0228b"),
-    TestFile::none("pydicom/nested_priv_SQ.dcm", "5cc694964c10cb02b501f02585909a7f4ef4656c07a89e603d1833629919c5c6"),
-    TestFile::none("pydicom/no_meta.dcm", "52912b9950f457ac7618efaad0cdd91b52354e07fbc25abee895bd86beebf9bc"),
-    TestFile::none("pydicom/no_meta_group_length.dcm", "76c6af82b4246285f6e5b53f150af44b9b7e2afe7ae25e1b604c549ba3d16733"),
-    TestFile::none("pydicom/priv_SQ.dcm", "b13c0ecdbda3a23faa93ced9e833a681ec3ba06d0c2210f2a42e6d49669baf57"),
-    TestFile::none("pydicom/reportsi.dcm", "59ca5f4fbf524bd542a907f8f29028be510e9d907239dbe2f1c82ffc5088538b"),
-    TestFile::none("pydicom/reportsi_with_empty_number_tags.dcm", "fd8a8a5dc3eaa053d08974981f70ce4837f4a1676288b9f059d660f7c4701a92"),
-    TestFile::none("pydicom/rtdose.dcm", "1d6cc092146d093e086a6bcccef4ebb7d097941343f5cd3b6395d157b64e37e4"),
-    TestFile::none("pydicom/rtdose_1frame.dcm", "6685273e1661562f38dbe2b1c6284b9c950c7dbcf080a71c14305d623d0b6090"),
-    TestFile::none("pydicom/rtdose_expb.dcm", "fe40ee7ed0cd63d1e76b51b42d4e68b764bd5f8a9ad59ce9fab9487158c550b8"),
-    TestFile::none("pydicom/rtdose_expb_1frame.dcm", "a96cfd3c8d4ca70f2a8d1b85bc22b47d7bbd609d7d21ea2fe821314a0cd36746"),
-    TestFile::none("pydicom/rtdose_rle.dcm", "2f83e3a2ef0de355570c38860b233fc2fa6c37626c81ad080d8661c03a413522"),
-    TestFile::none("pydicom/rtdose_rle_1frame.dcm", "f4e7a3b7aeb386ca1a2d7460b0c49771d923f6fd8abba08c1d4c7bc5c25cfc27"),
-    TestFile::none("pydicom/rtplan.dcm", "18585dbbd6f7c5d1b7e749d6976d72251802ad89d65bccd31c03006f95aab89b"),
-    TestFile::none("pydicom/rtplan_truncated.dcm", "15009ec7713dc53b95adfd4e1a692885240ddd34a0f18f52c0327a05cacbfd53"),
-    TestFile::none("pydicom/rtstruct.dcm", "40c41bdf871fd8553396b02476a66024ed23c04927c0dc53fd10ecd3472cd0d3"),
-    TestFile::none("pydicom/test-SR.dcm", "eebf00a37e97503b5a65022f9c2f89db6e8dac4cc632682aa3456aee1b6c177e"),
-    TestFile::none("pydicom/vlut_04.dcm", "64f54c0f490ce3fa2faac0a90a7ca0166caa025f8fdcfbe181906387a7867c27"),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/CT1_J2KI", "ee5cb9fa931ac6d31a67cd8b58d05d7e6d5212f4666ba41c2b3ece9a069c2738").size(14156).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/CT2_J2KI", "5807c6f052944339d06ef387208a5512a5a69692c814b7fb33006a7ceac99071").size(6830).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/MG1_J2KI", "e7539dd3014746890e9c2341149523fbb1a8dfef4b6f8ae2927a070091464257").size(2680914).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(4664), columns: Some(3064), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/MR1_J2KI", "ee43a6b22c478a5707515119449cc2a03e43376066468cc1ff9cbc3cc215d7e4").size(9012).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/MR2_J2KI", "8319846e6ad6dc70dbbaf61748b1987a6807fd02db3da24e7989fd5a5ce19e4e").size(113550).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/MR3_J2KI", "f9c5cee35a52494d060176a1fddb287445a9822e18fb554982dd42afd3044a10").size(12430).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/MR4_J2KI", "34314e1b6c43940e7621244288b8d3287080ea5a5a0e9f84ab2eab994ce43828").size(12394).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/NM1_J2KI", "236806a555c0ccc9dc3310ff45512176e4ca4db59be44174c1b8d1fd80e2a7aa").size(3308).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/RG1_J2KI", "744d01372fdda4e21b507bb7f97329065de961f4f263342079b369b430064d65").size(676846).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/RG2_J2KI", "71ecab3798eeb3dc3a4c4c644f6d426c7f13674ec0fb700605c0b3115383fc71").size(707654).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(2140), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/RG3_J2KI", "c90c915c0c373eb6d244151f9476b05e50623c303ac20334ca9ce4aab0dddf19").size(207152).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/SC1_J2KI", "d766e76d21670a7bbbd683dc908ac41e4b2c4fc3e5d37ced88925a834cfca587").size(781702).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(2487), columns: Some(2048), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/US1_J2KI", "22340375674ff253196ce8a147acf0458bea3f105ff2c6af81f0eb119729605b").size(59140).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_ICT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/VL1_J2KI", "0affe7a7ebe8438548f5105f5a5f5776796e138e0b772817c06c1f8afb537854").size(70266).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_ICT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/VL2_J2KI", "dd57aef4882e008bfea74bfc86aed15a6a5b7313ff3cc809bf6202159ebc3a4b").size(70304).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_ICT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/VL3_J2KI", "aec99f1e212b9d1ce320a56d419bf2efdf1307f0c5d8b76fddcd5c33c6249378").size(70260).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_ICT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/VL4_J2KI", "2beab0612f82bbf690fd22bd71efc91a7f01ca2bb3a43b25fa63a2b282662148").size(781134).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1868), columns: Some(2226), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_ICT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/VL5_J2KI", "b1ce311af7dfe6194929328b6988082d67cae6fa71ef4235f2885f0eace7a6d6").size(1673714).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(3340), columns: Some(2670), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_ICT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/VL6_J2KI", "dea4fa96af7140da2766d689e1f1d159e0cdc1631564a6b49a2909959caf1c3a").size(70036).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_ICT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KI/XA1_J2KI", "24fa42e4a19ccb8f32115969fe413d5e440bcc239eb3c1da7b3b8900530890f9").size(109340).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/CT1_J2KR", "121f77705f8e26eefaacafe0d7becc8dd42c9b5553e3dcb904283d9a96c9f16a").size(180916).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1add6ede29758c6f0c68f01749ddc6c907e68a312be4eb9da8489e376e0bbd34") }).groups(&["jpeg2000", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/CT2_J2KR", "95620ff3d0e506226853507d4a44c4b7ac6dc49fd1dd13460ed97a47e184ad03").size(121356).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("ddaf7fb6a05bf7ac8b2b29e29cca3204e426179cce2888eeff3a270c1927d73d") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/MG1_J2KR", "1caf511a11d5c592785b442f0a925fdefb670b4286deb01b9ab81f97bf620a33").size(12233370).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(4664), columns: Some(3064), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("05f752514817bcdc431dc0a827a1d345d8ad6eec1a8299912e1902c47520fe63") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/MR1_J2KR", "7cc23fdb217dc81ec14aecbe6e8e0837fa2fb3710f1873417de5afe876cf4245").size(238756).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("2541a628cb676972b37008a4fe6b5cce3df9866df62a77086bdffbe422064632") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/MR2_J2KR", "707f0a1b648b79f17b61e241af31fb9edea7fe596681a4bcab6cce890300a9a5").size(589212).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("7d1a676f3c012d0ca9d4fb9069c5dcca2b0bac014173dba48f0e32b9b49198b3") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/MR3_J2KR", "d0def9effbec263a4b2d301ba907a1d62969dde6757927cc1a0c5f4906c16cfb").size(122274).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9d32a2a63e3980d08130da4606abab010d6de943e9d504deb80ccb910fe5aa45") }).groups(&["jpeg2000", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/MR4_J2KR", "d2f50d4f54df409c12dc50f15b4ea6f135c687e0457e2fd2ed6a0982e6c022e0").size(114900).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9c7574cb23eef7f99481e94764d3efe4025db704be97cc18a944c0db2dfdb3d1") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/NM1_J2KR", "24552c24921274d59b0218e86d1f6c1e6ebb2a5b7716d15229ff98edc50a4ab4").size(89958).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("a6e9d32143339d3f5748b5520aa4e6c6ffb3550b6f71fdf17bdb2ebb44bc2611") }).groups(&["jpeg2000", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/RG1_J2KR", "7fbfd29360af806770102fd7c4ffcb2a133075bd00920a4bb63460d516f67ac4").size(4285194).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("26721b2112d94887b0feae345f1b7c1c8148e1710eaf27283d8c3e650682d252") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/RG2_J2KR", "fb86f947211873ddf6caa3b46204ad64987d9a498885d19258e072c9cee32998").size(1660380).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(2140), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9ed5d9818c250bb81ff9093a6c4d5c6032df281fce82b9a288de65c74348301e") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/RG3_J2KR", "ffde92ba154a7d5ed2ab70b7cd37892772f8bef63fb26f9080327c6a089c205b").size(832104).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("85480a0287e37795bc96799747a69af475f3bf0c35203fac1010fc6e100821a7") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/SC1_J2KR", "6b58681001784c2bd46bd7442f9557e51b7fa1dc912c50622e8b7c3623638a20").size(2444182).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(2487), columns: Some(2048), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1c8e43cef2a3b25b5304c3dd1732e64c2f44d05d342387ea8e15ce01ec793c32") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/US1_J2KR", "2427fdc82d90cd4ce8a69b5157eecb37549902dce138ac15c6456a7eae70b83d").size(153760).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_RCT"), pixel_hash: Some("e16892020c73095e42ff4cf7368de5206f11012e25feaed53cc2bc614602bb9a") }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/VL1_J2KR", "eee914f936a63b9040f0955e31c284a39a97e70fa2d454a86f61e0352ede8515").size(297182).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_RCT"), pixel_hash: Some("30bf6a11b15358a6f9ee1015dbafed191ef6bf381c04fbd74c9e02082cc9eb6b") }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/VL2_J2KR", "42bd2775fcaf1401ebc71e84056b5c6a7d257e62044e3cf0344dde8ce1ada3d0").size(308850).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_RCT"), pixel_hash: Some("e18c8c149175fdd6d9a599547add7f4bdc40f6bd1672ae3ee572b4d3ecb9ff3b") }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/VL3_J2KR", "29720969dc6b5d090ae370488590db41af6d204b20a8d64cacb1194ec39a7cec").size(304298).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_RCT"), pixel_hash: Some("e4a56ff20593c58502b8a12e11d69ecbce831b67a207df7b4129600168863b94") }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/VL4_J2KR", "2febdb153413f2e635a7000fafb2724725336005e2e02b58bb7123110870bc58").size(5854298).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1868), columns: Some(2226), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_RCT"), pixel_hash: Some("49c3b7466e5b6525a74196c0e4e3dc88795dfbaeb934cdd15adeab288e64b21a") }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/VL5_J2KR", "f5b32747b47a1c2eb0cd1b554a25273aa6a0760d0937ecfb2e7ebd1721b90be5").size(15112658).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(3340), columns: Some(2670), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_RCT"), pixel_hash: Some("65a1bd2363c6a3775d333a62671a2bc2595a2a8cbb0841f2c6865fd093d319a0") }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/VL6_J2KR", "1d19d411e953b6715e3da6f5dbd173a113bf5d46f075263badb4fe1d7f3c21cc").size(612400).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_RCT"), pixel_hash: Some("2ccf3ac25d0a394af5626c2ee9c262fd563dd7d93884ba1bc15060c8bba51815") }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/J2KR/XA1_J2KR", "5f0539e8963b842915e22f819ec738547c4835acf128e55b19a3dea2ffe08d8c").size(403336).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("797b3375a2d1f94ccac04c657b5b5d90d9b4051f76508c867f2dea465d1a7f3b") }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/CT1_JLSL", "9d848ee70d48e43924eb821bb28e5bbd94035473db7aad6d6e86a37d48f98ae3").size(170882).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1add6ede29758c6f0c68f01749ddc6c907e68a312be4eb9da8489e376e0bbd34") }).groups(&["jpeg-ls", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/CT2_JLSL", "a7da533cf2f0910b5de6cdb611a1404fd07929b0c3f6aed875dbdf5d0a17e100").size(117288).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("ddaf7fb6a05bf7ac8b2b29e29cca3204e426179cce2888eeff3a270c1927d73d") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/MG1_JLSL", "0ebf776c9a3a4b1dbdcd46c5ce41a6c3ed883eab4ae0d3c966d951581b247141").size(12022866).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(4664), columns: Some(3064), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("05f752514817bcdc431dc0a827a1d345d8ad6eec1a8299912e1902c47520fe63") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/MR1_JLSL", "4a3dea4fb4eff1faf01e93caed1832d7f9f0ea7e826d2a6dd50559da3f441d6b").size(231576).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("2541a628cb676972b37008a4fe6b5cce3df9866df62a77086bdffbe422064632") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/MR2_JLSL", "abce0865143bd15936a9c4dd86c752a0ee55a54ae84a988d76641a4abf7ef129").size(599990).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("7d1a676f3c012d0ca9d4fb9069c5dcca2b0bac014173dba48f0e32b9b49198b3") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/MR3_JLSL", "481217d8ba42b8eafd7321316ffb8ca3cc60d2b226aa05e68c8169253f848570").size(127780).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9d32a2a63e3980d08130da4606abab010d6de943e9d504deb80ccb910fe5aa45") }).groups(&["jpeg-ls", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/MR4_JLSL", "4c5c63ec7b3877e2ed5227217aff133fc0bc5dc8bdfae94aedc0604255eb108b").size(118788).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9c7574cb23eef7f99481e94764d3efe4025db704be97cc18a944c0db2dfdb3d1") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/NM1_JLSL", "b38d1dd5d714f318490bee83060b58296e82099d9d338007db8b6c79af68b1a0").size(92020).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("a6e9d32143339d3f5748b5520aa4e6c6ffb3550b6f71fdf17bdb2ebb44bc2611") }).groups(&["jpeg-ls", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/RG1_JLSL", "ab2e7eb94e53f68352ed4d4569417cf07917c96ec7deb1710c381a5f607e0ce1").size(4197660).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("26721b2112d94887b0feae345f1b7c1c8148e1710eaf27283d8c3e650682d252") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/RG2_JLSL", "34e9a853fdf326d0cc89b2ae132c2b04a0384da7c37a4b3a9c98d9dcb725b009").size(1672994).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(2140), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9ed5d9818c250bb81ff9093a6c4d5c6032df281fce82b9a288de65c74348301e") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/RG3_JLSL", "4d3b746958119ab0b84f7789ea2830e141b5ba8835b3df147d4a9f19c132d476").size(849008).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("85480a0287e37795bc96799747a69af475f3bf0c35203fac1010fc6e100821a7") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/SC1_JLSL", "4b743d0b3d82a62c3950d68d4bcefa01b17e3ab06be1436aede8ef5143b9c54f").size(2154188).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(2487), columns: Some(2048), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1c8e43cef2a3b25b5304c3dd1732e64c2f44d05d342387ea8e15ce01ec793c32") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSL/XA1_JLSL", "2296005f4a4d57b757e164755f5baccf3ef6577d7fba6fdf06fcf5d193653c49").size(391932).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("797b3375a2d1f94ccac04c657b5b5d90d9b4051f76508c867f2dea465d1a7f3b") }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/CT1_JLSN", "98dee161acfc65a65987f5066cd3cbe9d7f8d182645f2417f9564c262066f144").size(91682).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/CT2_JLSN", "d9176e6d639ad835aaddd65c0d9198a296b0edf02f85b6fb62adcee9e752da66").size(51260).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/MG1_JLSN", "74d908d8bc960c7b6f2d96a54b95288c7165e00835334bcabf867dad082f672c").size(6573186).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(4664), columns: Some(3064), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/MR1_JLSN", "1c0725a47387de92b32ad498cc910bcf3307756ad618901e7a8a4cd008f48ef2").size(128998).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/MR2_JLSN", "4202ebf23ecb47cb74fac538a9365f1e511edce4f7c515e93f6dfbb4c524ea33").size(281722).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/MR3_JLSN", "2a4ac9d2dde60831991e565d83738b4a638f0bd4ccfb592ee07904acc9ab96a9").size(73956).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/MR4_JLSN", "c2b5e6dce8eb8be2b20073613bece70d39c16ca13b516d6ddd689bff40987d1c").size(47878).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/NM1_JLSN", "3c974270eca06e332277d159f4353e6b0ebbd787ffd8ee04dc4556526c90f5c9").size(32230).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/RG1_JLSN", "e04e2bdd3b433d7f76f63c60d50bb5ad9564c3cddcf2e8fcb6efb8801806af4e").size(2765596).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/RG2_JLSN", "af270ba7b1c8a221110f1eee33ef25717e763732b21621ee857fa2b8a99fd7e4").size(699292).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(2140), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/RG3_JLSN", "63f877b43c3b4d8ff557b39b39d0890e493a8b9f8d1ffae3eb2b2e5fcf5c854f").size(290040).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/SC1_JLSN", "acc5b413b9b0cd6cdb658ffbdb8fe5d074e4d0cbc7e9635637cb104727431e1a").size(1102592).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(2487), columns: Some(2048), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JLSN/XA1_JLSN", "b78c433ca4445bbd47981d110114020b99b5c041301e3348b8af4a5123ebdc3f").size(131822).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.81"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg-ls", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/CT1_JPLL", "cd3adca021b00a3556c8089d12bfaa7b2640419153c77961f4745125dd7219fc").size(210532).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1add6ede29758c6f0c68f01749ddc6c907e68a312be4eb9da8489e376e0bbd34") }).groups(&["jpeg", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/CT2_JPLL", "0db74e1c44d1f1bfd54f777a8a5a3f9ecf48b3f5811fbd904160d7df32d34bea").size(166126).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("ddaf7fb6a05bf7ac8b2b29e29cca3204e426179cce2888eeff3a270c1927d73d") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/MG1_JPLL", "74e4b966f2982194b062ad8fbf6e26ec358519df85c2e85ffcc5a7417ec340ad").size(12834242).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(4664), columns: Some(3064), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("05f752514817bcdc431dc0a827a1d345d8ad6eec1a8299912e1902c47520fe63") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/MR1_JPLL", "c45e6f2a88e8d7ee343f4d7ea1d13666cb3111da43e6b5aa013e20764d4a1e87").size(262134).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("2541a628cb676972b37008a4fe6b5cce3df9866df62a77086bdffbe422064632") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/MR2_JPLL", "43e93052b96bbfb44a7a429b189e0abee0cd146d4d362deb0c0403ed8ae5c383").size(779708).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("7d1a676f3c012d0ca9d4fb9069c5dcca2b0bac014173dba48f0e32b9b49198b3") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/MR3_JPLL", "cd00960524708cfb9517f1f4abf65e784c587aadd99ad11cfcdb34d04eb224b5").size(173104).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9d32a2a63e3980d08130da4606abab010d6de943e9d504deb80ccb910fe5aa45") }).groups(&["jpeg", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/MR4_JPLL", "a01eff905b351a408f0458932440ec9979da3277bfed94f909cf82bf2abb3051").size(155410).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9c7574cb23eef7f99481e94764d3efe4025db704be97cc18a944c0db2dfdb3d1") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/NM1_JPLL", "c9d000c75d92b143ce1c0421471a7e9a69c8996d98b2589e533e311615a10079").size(118986).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("a6e9d32143339d3f5748b5520aa4e6c6ffb3550b6f71fdf17bdb2ebb44bc2611") }).groups(&["jpeg", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/RG1_JPLL", "466a7fdf51f048102140fd36efff56db30e2099d424783d50e92615469abd85d").size(4445624).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("26721b2112d94887b0feae345f1b7c1c8148e1710eaf27283d8c3e650682d252") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/RG2_JPLL", "a147786db235221421522c9172d50b437741f0d713f2ad6252f673d453d1dbb4").size(2162496).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(2140), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9ed5d9818c250bb81ff9093a6c4d5c6032df281fce82b9a288de65c74348301e") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/RG3_JPLL", "446803e262feb66afbfe97ba60452d6726aba9b968fe5b3005f5f595cb3da762").size(1398846).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("85480a0287e37795bc96799747a69af475f3bf0c35203fac1010fc6e100821a7") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/SC1_JPLL", "949ab5b4dafd41cc3ed20aad6a1e979a516751947b879adebf1bb9e9fdb16ac8").size(3112030).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(2487), columns: Some(2048), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1c8e43cef2a3b25b5304c3dd1732e64c2f44d05d342387ea8e15ce01ec793c32") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLL/XA1_JPLL", "94f21a860a274b9df68d2cfdafe5d5a2c93a123a369f1b7c352ba16c259af129").size(495626).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("797b3375a2d1f94ccac04c657b5b5d90d9b4051f76508c867f2dea465d1a7f3b") }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/MG1_JPLY", "cfbccc760ad9ff851e87842b0f090caa38ca0ba623919d26a48462eb69654bb5").size(2823426).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(4664), columns: Some(3064), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/MR1_JPLY", "3a6d42cf5c973b0523558c0322afd135eb7d1d332a4f718bfe8549dce1399014").size(71948).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/MR2_JPLY", "1e7c43a80392f52596a32dd5dd8bb0f7717b16a7e75e96962b8a0e6cb5776530").size(120390).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/MR3_JPLY", "d259cdeeb7534325143344af1d6da7f215e14862f27aa5251f4b9acd75b8c154").size(46688).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/MR4_JPLY", "ec73206c6dc99648ea43cd8a006ae226a82e11078ef14945e666021359783ba7").size(17792).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/NM1_JPLY", "c425608e2fcda8332c75d33f890bfe3bae32700608b719046b3d9e789374c292").size(9844).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/RG2_JPLY", "65c2f33d35968b4a59e6aa11e7c4c169674b5ed626a7b79f4bf042a8007fe54d").size(211568).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(2140), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/RG3_JPLY", "f26b5ef74e8b66d5221d69a46251e387f15ff9ba8a8d9e5bd5093216843c0eb5").size(94084).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/SC1_JPLY", "a0c55225e496cbd71255e433b3f3292fe393d72664028563bfee60b99f6b803a").size(768386).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(2487), columns: Some(2048), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/JPLY/XA1_JPLY", "816cdcb324a326cbe792e5fdfbe866977bb612089c6fbb589ecc5e36344fdfd3").size(44166).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/CT1_UNC", "292d5071958c77e67044e6b8b9947481c3912597082983bf37a8fe9a52507315").size(246483).decompressed("bc92908fb936c20b29582a3db31dd0b787246d5213bb8822dc964a45d6a65d9a").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1add6ede29758c6f0c68f01749ddc6c907e68a312be4eb9da8489e376e0bbd34") }).groups(&["private-tags"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/CT2_UNC", "3f7d0a7c14230d9bc0c31b5f9b02f0cf4e0167742ded2255d18c125c589b718a").size(192583).decompressed("21419e47daa53f1b8cc12e301cf7a35f7c3284857fae287eed167a2bbba32ce2").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("ddaf7fb6a05bf7ac8b2b29e29cca3204e426179cce2888eeff3a270c1927d73d") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/MG1_UNC", "783b5456ca803f792a782dce0266e83b053b91c97f9a1c8b927466644647d842").size(16385604).decompressed("4d0a56a41b3196877f01f7706bb09dee9a3c096b780ebd80c8fefd134068bd18").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(4664), columns: Some(3064), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("05f752514817bcdc431dc0a827a1d345d8ad6eec1a8299912e1902c47520fe63") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/MR1_UNC", "32e7bc7ef7b094a212d59a8a5121d48834af7a5e631abecef4e1f445df8ec236").size(343899).decompressed("86d90934f913d06232136ad1812681fb25d0695fb99ab673187203b4d93063f2").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("2541a628cb676972b37008a4fe6b5cce3df9866df62a77086bdffbe422064632") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/MR2_UNC", "1b37e9f225adef1393c7c04ab6c44693f882b389d34f77d24a5a7418661a7021").size(828886).decompressed("c14c7f0c6e25bd4dfbb822fe264e540fc7142bf1c9d15d4c652ec8f5f97fa9e8").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("7d1a676f3c012d0ca9d4fb9069c5dcca2b0bac014173dba48f0e32b9b49198b3") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/MR3_UNC", "ac30d43bf8599b5edd2d76006c197ad6ffa63892558fd91146659dfad17f3426").size(191691).decompressed("cbf01511482dcef93d749a3a940a48956b236a0ea2a2b6422990422f7f5fae98").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9d32a2a63e3980d08130da4606abab010d6de943e9d504deb80ccb910fe5aa45") }).groups(&["private-tags"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/MR4_UNC", "9c4c699749a586d11e101f4ab53f0877c84b0ca5a2574301880fc88432a48d24").size(174128).decompressed("0b8dc433fd658aab18426e83e069c4dac25ac1e16db210e29b5afc3844310a23").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9c7574cb23eef7f99481e94764d3efe4025db704be97cc18a944c0db2dfdb3d1") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/NM1_UNC", "4457c66f44b37e8c530ced925da8fec7535b39508a284120f4627fa76c544faa").size(114950).decompressed("7eaf7a4ce14005a724a581da37572d8288d3ee8d821bc6dfe05779590a7e140a").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("a6e9d32143339d3f5748b5520aa4e6c6ffb3550b6f71fdf17bdb2ebb44bc2611") }).groups(&["private-tags"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/RG1_UNC", "a17df3ea0e4900d0d523534ac0ff69ff1c45678a57df60954fed047daf0bf3a6").size(6326310).decompressed("946f28f48b9fbf360196a9b835c8fce83b0c654bf85a5107663c8a61df02e498").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("26721b2112d94887b0feae345f1b7c1c8148e1710eaf27283d8c3e650682d252") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/RG2_UNC", "76f026b043e4089fe4ecd18ef5b8eb5f3a8e63784afd51140600c657409568ad").size(2192936).decompressed("eb3450c8f37d1b4ee38c8587b9fd17ce423bc27bd55636cf99d141097866891f").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(2140), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9ed5d9818c250bb81ff9093a6c4d5c6032df281fce82b9a288de65c74348301e") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/RG3_UNC", "7a49a81015ce78f2aedb892fd212784c76f5d03cede0d7bd6a2148e498eb9f42").size(1618923).decompressed("6babfc42dd404213e1758d6dbb93648c248783cc23f593103fff4295c3374dfb").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("85480a0287e37795bc96799747a69af475f3bf0c35203fac1010fc6e100821a7") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/SC1_UNC", "707158fc2455413a147b69117e449eb74a10171c8ae7bf8056798e9d7a2fab14").size(3096813).decompressed("0589dd81c55b632c845327860aee3884f1fffe4a24a607aeb09e5f234f3d78a6").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(2487), columns: Some(2048), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1c8e43cef2a3b25b5304c3dd1732e64c2f44d05d342387ea8e15ce01ec793c32") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/US1_UNC", "865b65bcbe70cc8f313bc3419f43d21eb6ddfa55328298a8e1ca30e08f75df16").size(130446).decompressed("af5a66e40cd49d15dfbf7b78c850eba0662bdc7339339c3fa13f123a57e812cb").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e16892020c73095e42ff4cf7368de5206f11012e25feaed53cc2bc614602bb9a") }).groups(&["color"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/VL1_UNC", "cecb9b1120a74dc4281d91215c312941b9f68e23a64410f3c28a0e6cc5cc7f5f").size(116812).decompressed("19848d1cf6ca3ae66c167929b8ddade91d576bf4d21ab686057c253815d54a19").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("30bf6a11b15358a6f9ee1015dbafed191ef6bf381c04fbd74c9e02082cc9eb6b") }).groups(&["color"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/VL2_UNC", "82a81ea23856f289dba46eb040cb023cedcab88b53b3ab6bb6a92ff5f9020323").size(135832).decompressed("71d9d5f321c67c020d90b493c3f4378ba53723f147af9f3de93bcbbc74ad7c97").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e18c8c149175fdd6d9a599547add7f4bdc40f6bd1672ae3ee572b4d3ecb9ff3b") }).groups(&["color"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/VL3_UNC", "1f66f037657216a30f533af716fbe9f106cd88ea65326532ee5dc0b664df2ce3").size(98689).decompressed("9093e6aebae451194e2bdca67b6d910fe0e1c4e5d687106e04e65a9d30983552").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e4a56ff20593c58502b8a12e11d69ecbce831b67a207df7b4129600168863b94") }).groups(&["color"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/VL4_UNC", "6e65bb5b0f80aa9ab73a3326075726f5105b2fa9e508aace6f283309601e3b30").size(5383185).decompressed("80d888624a2ac567350de1150ea238966302816886aa56b734e31707b8eb3376").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1868), columns: Some(2226), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("49c3b7466e5b6525a74196c0e4e3dc88795dfbaeb934cdd15adeab288e64b21a") }).groups(&["color"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/VL5_UNC", "911fa9dffc0b7dbfb0f090bc8340365a2e69d3826ec5dba83826b13467db6f20").size(15732203).decompressed("1a751748e5b91b31fca77484640afebcdb09583bd18c1739c28efb9f0720aa3f").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(3340), columns: Some(2670), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("65a1bd2363c6a3775d333a62671a2bc2595a2a8cbb0841f2c6865fd093d319a0") }).groups(&["color"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/VL6_UNC", "81a75f35c30c019b8d49332b597f4f203911869c493b07f7129e61409fe3c441").size(357825).decompressed("5f45e8e4ca404bd7e4f55f3b95a899cf3a14012ce5229ef6f82223940e8e695e").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("2ccf3ac25d0a394af5626c2ee9c262fd563dd7d93884ba1bc15060c8bba51815") }).groups(&["color"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::zstd("WG04/REF/XA1_UNC", "f40894fa78dde9cb47e5ab3493be1d14bd150822cfde0e3ecd0428b045504a25").size(592537).decompressed("3b3b719e241f2365e9dfb585426f0e378db47aa5e0c42476aba43144b5656fb8").provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("797b3375a2d1f94ccac04c657b5b5d90d9b4051f76508c867f2dea465d1a7f3b") }),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/CT1_RLE", "d87ebd3e2e728ff8257f083d0629134f5e1e5d155bafb6fa0ef4840af1d90f01").size(254898).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1add6ede29758c6f0c68f01749ddc6c907e68a312be4eb9da8489e376e0bbd34") }).groups(&["private-tags", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/CT2_RLE", "8df23792fec23d6d5e6fb9e2f8548ef5f8f7c10a0e4b8b63cedd72c340ffead3").size(238032).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("ddaf7fb6a05bf7ac8b2b29e29cca3204e426179cce2888eeff3a270c1927d73d") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/MG1_RLE", "7c867028dfb05b2bdfc596d0966298243a47354c72c302e227b8939670bbe2a9").size(17660198).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(4664), columns: Some(3064), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("05f752514817bcdc431dc0a827a1d345d8ad6eec1a8299912e1902c47520fe63") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/MR1_RLE", "c40c2da8d6f8d8815c0ff67bf519e71a4202d975d4d76fd3d719222010da2b57").size(342328).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("2541a628cb676972b37008a4fe6b5cce3df9866df62a77086bdffbe422064632") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/MR2_RLE", "8afb8568563895a81da870695ba2a2c54b80a1f57950c6e2507dca77891285e5").size(883366).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("7d1a676f3c012d0ca9d4fb9069c5dcca2b0bac014173dba48f0e32b9b49198b3") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/MR3_RLE", "c0fc8a81a8f0fd5b352b457302f915d5bc157cb09ac4f480811d5282b3f7b7aa").size(192692).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9d32a2a63e3980d08130da4606abab010d6de943e9d504deb80ccb910fe5aa45") }).groups(&["private-tags", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/MR4_RLE", "c50da475afc366dd2bd194d65315731cd99665bbffe37891927d3715efcb99db").size(240978).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9c7574cb23eef7f99481e94764d3efe4025db704be97cc18a944c0db2dfdb3d1") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/NM1_RLE", "550bcb521853cf0791130de4268448c3158bd786a11f3dc6dd54b66d1de2ed77").size(174842).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("a6e9d32143339d3f5748b5520aa4e6c6ffb3550b6f71fdf17bdb2ebb44bc2611") }).groups(&["private-tags", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/RG1_RLE", "4d3366fad1ef9bd9a1b44841d0148c40c4e2e45d16948e8642aae214c65d0dd9").size(6614726).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("26721b2112d94887b0feae345f1b7c1c8148e1710eaf27283d8c3e650682d252") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/RG2_RLE", "d80dab6c9d05a6925d388b07093cff75284171d0cefcbcd04201a0423051aadc").size(2803082).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(2140), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9ed5d9818c250bb81ff9093a6c4d5c6032df281fce82b9a288de65c74348301e") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/RG3_RLE", "6d63f282b70a964e8fb4aa084978a36305a892640314553547021baf991f58be").size(1930594).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("85480a0287e37795bc96799747a69af475f3bf0c35203fac1010fc6e100821a7") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/SC1_RLE", "d9ffae0840edbd57f99da12c83237a60231c6332ebe2d952b40a0c05b97f34ba").size(3496358).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(2487), columns: Some(2048), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1c8e43cef2a3b25b5304c3dd1732e64c2f44d05d342387ea8e15ce01ec793c32") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/US1_RLE", "6e44f53c164a4cfb79c1d14f3a1bd085f9ba36f5d696ce1730137f5bf7ddbbc3").size(428352).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e16892020c73095e42ff4cf7368de5206f11012e25feaed53cc2bc614602bb9a") }).groups(&["color", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/VL1_RLE", "670ded567a804996060fb3f0c4c483f4363cbbaa42b53f958914fce079c3434b").size(374100).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("30bf6a11b15358a6f9ee1015dbafed191ef6bf381c04fbd74c9e02082cc9eb6b") }).groups(&["color", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/VL2_RLE", "a36ac72ffd480a0345530bb3a7999ef6037102e96d257b60789b8a87092513fe").size(412118).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e18c8c149175fdd6d9a599547add7f4bdc40f6bd1672ae3ee572b4d3ecb9ff3b") }).groups(&["color", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/VL3_RLE", "425b1d34dea09a7f7826d7a0814b8184df646f4440d9838b2776554e42a30965").size(343724).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e4a56ff20593c58502b8a12e11d69ecbce831b67a207df7b4129600168863b94") }).groups(&["color", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/VL4_RLE", "ab690160de273c844bb11eacbd80a00e86e8ffadca65afdda654a89d20d8cd47").size(7257616).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(1868), columns: Some(2226), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("49c3b7466e5b6525a74196c0e4e3dc88795dfbaeb934cdd15adeab288e64b21a") }).groups(&["color", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/VL5_RLE", "f635d329eac85313450c48bd00cff3de77e8c83a12619321996ac311ee40f4eb").size(24543094).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(3340), columns: Some(2670), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("65a1bd2363c6a3775d333a62671a2bc2595a2a8cbb0841f2c6865fd093d319a0") }).groups(&["color", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/VL6_RLE", "5c096e6322fe97e10d08c7301c658a76f28f6184700794f10e816ebb8bb010f1").size(993098).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(486), columns: Some(756), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("2ccf3ac25d0a394af5626c2ee9c262fd563dd7d93884ba1bc15060c8bba51815") }).groups(&["color", "rle"]),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("WG04/RLE/XA1_RLE", "e9c89f2bb83c73f545d4962b2de9b0f0d01771255a9f93dccfd55746f7c4384d").size(853200).provenance(WG04).anonymization(Anonymization::VendorSample).info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("797b3375a2d1f94ccac04c657b5b5d90d9b4051f76508c867f2dea465d1a7f3b") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-invalid")]
+    TestFile::none("invalid/bad_magic_code.dcm", "2c7b7a5d9a0588c279085d795781598108695ee45c192195560f7e51da5d56b3").size(39206).provenance(INVALID).anonymization(Anonymization::Synthetic).defect(Defect::BadMagicCode).groups(&["malformed"]),
+    #[cfg(feature = "manifest-invalid")]
+    TestFile::none("invalid/element_length_overflow.dcm", "69ff8ee6216858684e37dc6df91bcd56f0f372efa900981a89927c84d41de133").size(39206).provenance(INVALID).anonymization(Anonymization::Synthetic).defect(Defect::ElementLengthOverflow).groups(&["malformed"]),
+    #[cfg(feature = "manifest-invalid")]
+    TestFile::none("invalid/invalid_vr.dcm", "c86d70b91ca85a6d17773dd77cd9f21d1cb9aafc3225dfd4643dd14303562f92").size(39206).provenance(INVALID).anonymization(Anonymization::Synthetic).defect(Defect::InvalidVr).groups(&["malformed"]),
+    #[cfg(feature = "manifest-invalid")]
+    TestFile::none("invalid/short_preamble.dcm", "e9a89635f4e491cde9fc90fa93288034bcd87437121d24d72434075844579b65").size(39142).provenance(INVALID).anonymization(Anonymization::Synthetic).defect(Defect::ShortPreamble).groups(&["malformed"]),
+    #[cfg(feature = "manifest-invalid")]
+    TestFile::none("invalid/truncated_data_set.dcm", "8657078c49b5b9107add4f5cab4d36e06bcfbeb1eb6fa7dec1ea83a516596482").size(1147).provenance(INVALID).anonymization(Anonymization::Synthetic).defect(Defect::TruncatedDataSet).groups(&["malformed"]),
+    #[cfg(feature = "manifest-invalid")]
+    TestFile::none("invalid/truncated_pixel_data.dcm", "5e80d4b005cb35fb2ed60d6c23d57486d4bfec9a1d9144ad23987d8fc8208c68").size(22684).provenance(INVALID).anonymization(Anonymization::Synthetic).defect(Defect::TruncatedPixelData).groups(&["malformed"]),
+    #[cfg(feature = "manifest-invalid")]
+    TestFile::none("invalid/wrong_group_length.dcm", "ff56567dcb67c29c1440315ede838fde4b2e9de2f4f554aeb3176486c2ecc92e").size(39206).provenance(INVALID).anonymization(Anonymization::Synthetic).defect(Defect::WrongGroupLength).groups(&["malformed"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/693_J2KI.dcm", "8d5d503fd46b9a59c628762d71d7391ea1a2a5fd8d339ac82ef9e281a15ef65f").size(3590).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/693_J2KI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/693_J2KR.dcm", "c392d8bd1f952ed2d9387d5143d34c5a29ac9d74566688169731a50ac6a82aa2").size(107060).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/693_J2KR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("6b3b6bb553a0b5692ee63737f4cb8d6bcfa960e7ae37e5d1bd9521b671b501b0") }).groups(&["jpeg2000"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/693_UNCI.dcm", "42d6c33d6666bf569a53951211be6fca2ab04956db43c3f75a9720d976ab128c").size(526324).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/693_UNCI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("f249f833d5e3cbc361b4ced94aeeb8db7fc7376087b9f395a2ccf2f6f3059268") }).groups(&["undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/693_UNCR.dcm", "cc4cdd599231922ecf63de2ddacf03d51c4588805c9154c2eef1ff49c23b32be").size(525986).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/693_UNCR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("6b3b6bb553a0b5692ee63737f4cb8d6bcfa960e7ae37e5d1bd9521b671b501b0") }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/CT_small.dcm", "3dd31e5cc835b3f2cdd46c9da1982f59251e78518fefa8163d914631c66437d6").size(39206).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/CT_small.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(128), columns: Some(128), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("7a481f6ffff833aef4d8bd54819bd8f472aaa7232090208e056c90eacf079926") }).groups(&["private-tags"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/ExplVR_BigEnd.dcm", "42eb61ea5650f1064e52d48019cd87b118e52cf4dfbc8fa57427ed2ed4c036ea").size(15412).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/ExplVR_BigEnd.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(60), columns: Some(80), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("1583c4339dd36e91dd2c30d278ef1ed95f3ea9a6de4401868d5712a76036ef2d") }).groups(&["big-endian", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/ExplVR_BigEndNoMeta.dcm", "a56be8c8c52f0d1cf55d7c2ced6abc6f22b799cbf556b84b856055a7a7565949").size(434).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/ExplVR_BigEndNoMeta.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.8"), transfer_syntax_uid: None, rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["no-preamble"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/ExplVR_LitEndNoMeta.dcm", "008e9302975d34899d89b4e3f044f8637b16acde25242eb0b36a1ffc034b9b42").size(434).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/ExplVR_LitEndNoMeta.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.8"), transfer_syntax_uid: None, rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["no-preamble"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/JPEG-LL.dcm", "c9d000c75d92b143ce1c0421471a7e9a69c8996d98b2589e533e311615a10079").size(118986).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/JPEG-LL.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/JPEG-lossy.dcm", "c425608e2fcda8332c75d33f890bfe3bae32700608b719046b3d9e789374c292").size(9844).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/JPEG-lossy.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.51"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/JPEG2000.dcm", "5be539024e6803029a7b73c0f8e72e88d032e3a0bc05922c0c047344780aa8e1").size(3308).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/JPEG2000.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/JPEG2000_UNC.dcm", "645ff302c7f7ee6c402d74c7c9e3cb5efdb861a828959cc2adc8775a8260688d").size(527370).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/JPEG2000_UNC.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1024), columns: Some(256), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("0b1224a6dcd0dcebb1ae6966270b620a8aecc3e20d7fe5b01504e574e1814ac6") }).groups(&["private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/JPGLosslessP14SV1_1s_1f_8b.dcm", "1978d4f058e52d3239fae33f261b3dc74605fdd9f89031fffd57bea6218d0dbf").size(215050).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/JPGLosslessP14SV1_1s_1f_8b.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(768), columns: Some(1024), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "private-tags"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR-SIEMENS-DICOM-WithOverlays.dcm", "094faf56c63bff84c30567e29de0c67d7c5a8ae05cf880ac12175491b6b645d2").size(510928).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR-SIEMENS-DICOM-WithOverlays.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(484), columns: Some(484), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("8c042a175e4a49cae35ae7c00cf3b57d5206c87e37b1b2894ed1cf6a03232949") }).groups(&["icon", "private-tags"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR2_J2KI.dcm", "8319846e6ad6dc70dbbaf61748b1987a6807fd02db3da24e7989fd5a5ce19e4e").size(113550).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR2_J2KI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR2_J2KR.dcm", "707f0a1b648b79f17b61e241af31fb9edea7fe596681a4bcab6cce890300a9a5").size(589212).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR2_J2KR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR2_UNCI.dcm", "7f79ac33e1ab32e1a8ca10ce62f18e5a2372e78c8a6684af17302b1a0171fc46").size(2099286).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR2_UNCI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("5964b5a1b27090d6af68adef02d7881689c13f46a43f52b588a2691004f3a278") }).groups(&["undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR2_UNCR.dcm", "c14c7f0c6e25bd4dfbb822fe264e540fc7142bf1c9d15d4c652ec8f5f97fa9e8").size(2098988).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR2_UNCR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1024), columns: Some(1024), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("7d1a676f3c012d0ca9d4fb9069c5dcca2b0bac014173dba48f0e32b9b49198b3") }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_small.dcm", "3f27d1c22f1a66e80d7bb7c911e8610fd0bb70325a76746a7adb1c0ddefcf2bb").size(9830).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_small.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("88617aaa46138fb1b6e2a951e762d962382354d69f47f8c04d4abff2f6a6a63e") }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_small_RLE.dcm", "2e5cb60878dc0acc494298ccdad28fce2cf14c51096e5d8cedab40248ea02e6c").size(7790).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_small_RLE.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("88617aaa46138fb1b6e2a951e762d962382354d69f47f8c04d4abff2f6a6a63e") }).groups(&["rle"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_small_bigendian.dcm", "3e4c8c9fe70de4f3be149bbd673fa56f211c8e8e2ff9bac63f70f9dc31b5d108").size(9708).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_small_bigendian.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("88617aaa46138fb1b6e2a951e762d962382354d69f47f8c04d4abff2f6a6a63e") }).groups(&["big-endian"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_small_expb.dcm", "8b3846771e1dbb4b36daf3eabbd331090a4735d2930174540458c51ec0808aeb").size(9846).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_small_expb.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("88617aaa46138fb1b6e2a951e762d962382354d69f47f8c04d4abff2f6a6a63e") }).groups(&["big-endian"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_small_implicit.dcm", "6077442c42a56fc7fcc7db8411a657dded9fc109e6d3275765c4de358292b299").size(9702).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_small_implicit.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("88617aaa46138fb1b6e2a951e762d962382354d69f47f8c04d4abff2f6a6a63e") }).groups(&["implicit-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_small_jp2klossless.dcm", "4c0049e0355b560c8c846538d827afbdae5311b20fc5e5a93a3892e109bb140d").size(6008).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_small_jp2klossless.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("88617aaa46138fb1b6e2a951e762d962382354d69f47f8c04d4abff2f6a6a63e") }).groups(&["jpeg2000"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_small_jpeg_ls_lossless.dcm", "b2b69dd2ae854bf7dfada6745709cd5d8a4573ea12387adbbdc56e8be6056206").size(6124).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_small_jpeg_ls_lossless.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("88617aaa46138fb1b6e2a951e762d962382354d69f47f8c04d4abff2f6a6a63e") }).groups(&["jpeg-ls"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_small_padded.dcm", "b46e32d8430f1e86e7fc03b9542e06ffc40a591890a3acc644c301d6a2f0e57f").size(9958).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_small_padded.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("88617aaa46138fb1b6e2a951e762d962382354d69f47f8c04d4abff2f6a6a63e") }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/MR_truncated.dcm", "a3f26c279dd214951d32a1548362df3c93f9730135fa893a01552c0e632f587f").size(9630).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/MR_truncated.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(64), columns: Some(64), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/OBXXXX1A.dcm", "164a460bebdc15fbe391ad4bfe4c84672eb2bad57adfe7dad372fd7367b0f63e").size(486008).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/OBXXXX1A.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(600), columns: Some(800), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("48abdc16b5064b61cf5960f7056756fc97f4547186e88b3bbcc1ebc2a66e6ca7") }).groups(&["private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/OBXXXX1A_2frame.dcm", "6627f6e46dbf8c16292fb1eaff8807439bcd233dc68099c07f0b83c4093256b1").size(966018).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/OBXXXX1A_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(600), columns: Some(800), frames: Some(2), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("a4e8cb3611e675c71a3f478b3cc231e665aaa2f55530a2b89e9e60ff42bda625") }).groups(&["multi-frame", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/OBXXXX1A_expb.dcm", "668dc27f6db2c7d47d7384dbb86593cc8f681a44fca3bb93201913d5cd6463e2").size(485930).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/OBXXXX1A_expb.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(600), columns: Some(800), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("48abdc16b5064b61cf5960f7056756fc97f4547186e88b3bbcc1ebc2a66e6ca7") }).groups(&["big-endian", "private-tags"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/OBXXXX1A_expb_2frame.dcm", "b27a5e056d005525d28fdabfebc061ef64cc3d652c835d062f4f5d31d2bf453b").size(965940).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/OBXXXX1A_expb_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(600), columns: Some(800), frames: Some(2), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("a4e8cb3611e675c71a3f478b3cc231e665aaa2f55530a2b89e9e60ff42bda625") }).groups(&["big-endian", "multi-frame", "private-tags"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/OBXXXX1A_rle.dcm", "aaf57785817dbe35503c6175d677d2efa811f90e931fc5017611ba9ff4c7f92a").size(48904).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/OBXXXX1A_rle.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(600), columns: Some(800), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("48abdc16b5064b61cf5960f7056756fc97f4547186e88b3bbcc1ebc2a66e6ca7") }).groups(&["private-tags", "rle", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/OBXXXX1A_rle_2frame.dcm", "65bee869c507f535edea93a446a26e941fb9cbc3819e4d73395f11eef56d4687").size(91754).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/OBXXXX1A_rle_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.3.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(600), columns: Some(800), frames: Some(2), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("a4e8cb3611e675c71a3f478b3cc231e665aaa2f55530a2b89e9e60ff42bda625") }).groups(&["multi-frame", "private-tags", "rle", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/OT-PAL-8-face.dcm", "d5560470077f77ef6a0a52d22f9f61e803436d2b468a9550a4d12c5675ee0a97").size(308854).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/OT-PAL-8-face.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: None, rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("b171a3008579b64288b5e1ab6d93e23cbd7fe5260223944732f36044cf4969c6") }).groups(&["no-preamble"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/README.txt", "b2e36c210398ac0e1b0c6b2bc22a0c5cbfd0e7631639080c1f3c720774e5b448").size(13862).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/README.txt"),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/RG1_J2KI.dcm", "744d01372fdda4e21b507bb7f97329065de961f4f263342079b369b430064d65").size(676846).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/RG1_J2KI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/RG1_J2KR.dcm", "7fbfd29360af806770102fd7c4ffcb2a133075bd00920a4bb63460d516f67ac4").size(4285194).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/RG1_J2KR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/RG1_UNCI.dcm", "3561020824868615a93a51078671b3ff73bb2578c966f76def99b4d982897e75").size(7200356).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/RG1_UNCI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("88aa9135b12d8582316d1db5c45b4c2c55dd1da74c3c52a80a7be49f145c7149") }).groups(&["undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/RG1_UNCR.dcm", "946f28f48b9fbf360196a9b835c8fce83b0c654bf85a5107663c8a61df02e498").size(7200056).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/RG1_UNCR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1955), columns: Some(1841), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("26721b2112d94887b0feae345f1b7c1c8148e1710eaf27283d8c3e650682d252") }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/RG3_J2KI.dcm", "c90c915c0c373eb6d244151f9476b05e50623c303ac20334ca9ce4aab0dddf19").size(207152).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/RG3_J2KI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/RG3_J2KR.dcm", "ffde92ba154a7d5ed2ab70b7cd37892772f8bef63fb26f9080327c6a089c205b").size(832104).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/RG3_J2KR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: None }).groups(&["jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/RG3_UNCI.dcm", "9ef0260919de89774da90336ad16c03a5be899a8bb663bbaea52b6d0769bec78").size(6196902).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/RG3_UNCI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("25559cb05640e9e9860e91adf4d49dd3469694d0ff56bbf76c8853c3e05f4cc5") }).groups(&["undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/RG3_UNCR.dcm", "6babfc42dd404213e1758d6dbb93648c248783cc23f593103fff4295c3374dfb").size(6196600).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/RG3_UNCR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(1760), columns: Some(1760), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME1"), pixel_hash: Some("85480a0287e37795bc96799747a69af475f3bf0c35203fac1010fc6e100821a7") }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb.dcm", "b0f868d6a689a0ff96c39b459caf1b628eacd74134114ce84549573321231138").size(31300).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("169e619557b12114a7f0be8602026e9abb3d5045804311736ec14cecb026aca9") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_16bit.dcm", "3dc969768431d1cb2695dcd3f190588b02413798dab8420418d2fbb9cb4d4075").size(61300).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_16bit.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("36de0258708d3af79cf989c0ab2cbbf861afe927799cdfd0fef36fca3b3aa058") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_16bit_2frame.dcm", "f251a296e1aa5dde37423a9aacba7f31b0b4869328caa6e42bf6b110f007c401").size(121310).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_16bit_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("d7e2338dd240b58cd8ca13452ab8f21fa3e0779575eda0677568b5ce88247271") }).groups(&["charset", "color", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_2frame.dcm", "9b5c0306679675c688c2044d97878a6a14ce9976ecdf022309e5f6e9ceaffd9c").size(61310).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("026dac3bc332e46b5ddc4cda3d990ac5a423dad4cb4134262b1a7cc1f2106c6c") }).groups(&["charset", "color", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_32bit.dcm", "c3dac5c807ab27227c0d36b7cd34bb776103bb08d230ff74e62259eeeef0769a").size(121280).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_32bit.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("1a243c9351e3a9aeadbe667627e8bae4d38950bf570c2fadab4fef93f766aafa") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_32bit_2frame.dcm", "33f78c27519f23e0410e9c5d24f55380a431255f00ff95e12a26fd45765a7920").size(241290).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_32bit_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("3caa80cc3032f7457d4509766be96484cbcdd628334b1aecad249d6a41998575") }).groups(&["charset", "color", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cr.dcm", "d16092b526e46328897a18cb0adc5c582bbfe953d6dcb2d12bb9270d398f6c41").size(3626).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_+eb+cr.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: None }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+n1.dcm", "aedfe7e31480e75bb6a64cf96b6a1048a8b24e9135954872fba4a28b60941a60").size(3136).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_+eb+cy+n1.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL"), pixel_hash: None }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+n2.dcm", "d5a1d71257e2763c63371ab74acaece759696850077a88bcf45e97e0a84f7065").size(3090).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_+eb+cy+n2.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL"), pixel_hash: None }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+np.dcm", "5467c2e97efcae6b462f59fcf1d626f4f1ac99c7ea83c2596ce6e7aaf2083ac6").size(3140).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_+eb+cy+np.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL_422"), pixel_hash: None }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+s2.dcm", "44d80da71ae3be6048ed7333a1b6f4c1098be2310ca4981f6870f0fed2fe74ed").size(3094).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_+eb+cy+s2.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL_422"), pixel_hash: None }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_+eb+cy+s4.dcm", "f2ed4012809e6d32bb552638221b440808b263d98f51515e6816bad7fcd9fd7b").size(3420).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_+eb+cy+s4.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL"), pixel_hash: None }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_ebcr_dcmd.dcm", "e183a37c833c78da6c516aed9920527d80d7f1bbaf805a92530024e1aa2e74ff").size(31662).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_ebcr_dcmd.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e414aaca686695163b4fcca90cc4b0bf6aff59d70c036a39a446ebcbb53e3360") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_ebcyn1_dcmd.dcm", "a963683216b270b788682dc132a65965406a3100722c2d0c2fd2219a0ea53c66").size(31662).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_ebcyn1_dcmd.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e0b1a561989d6f7148b4e4b0990c34751271852383a7135c8a620940f1744e06") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_ebcyn2_dcmd.dcm", "2692a16f99b879c742398f3a5b4b9508165d4fe6b056eaa85642ff6bed80ff62").size(31662).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_ebcyn2_dcmd.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("ddb100d8f45a7fbf420e8ce5d1b376a5479f068c5109daac31eb982f662d228f") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_ebcynp_dcmd.dcm", "6324aa7eb90e57299087a70ff6875b10f4d17b8e359ee2f20f1eaaf3d0876993").size(31662).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_ebcynp_dcmd.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e0b1a561989d6f7148b4e4b0990c34751271852383a7135c8a620940f1744e06") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_ebcys2_dcmd.dcm", "f6334492b38d4494b0e8929c4f6b34e9decba9b2dae4e01749263bf254a8c096").size(31662).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_ebcys2_dcmd.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("ddb100d8f45a7fbf420e8ce5d1b376a5479f068c5109daac31eb982f662d228f") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_dcmtk_ebcys4_dcmd.dcm", "9fb6b7e5dd1f1097ecb23fcd2afafeee9c5233f75680b0922b723f2f1b7b09ab").size(31662).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_dcmtk_ebcys4_dcmd.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("ddb100d8f45a7fbf420e8ce5d1b376a5479f068c5109daac31eb982f662d228f") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_expb.dcm", "e92997e0cf83407693478ca6f2ce44f42f50f73751f11c355ce555ef86dc8e84").size(31288).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_expb.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("169e619557b12114a7f0be8602026e9abb3d5045804311736ec14cecb026aca9") }).groups(&["big-endian", "charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_expb_16bit.dcm", "5e8e2340ba9698deba857f76e0ee007c1acb88de84841519425afe76b5b25c11").size(61288).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_expb_16bit.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("36de0258708d3af79cf989c0ab2cbbf861afe927799cdfd0fef36fca3b3aa058") }).groups(&["big-endian", "charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_expb_16bit_2frame.dcm", "fb88f409d21ca9c08672f32f756d0ba0d57de91f8240cf807971085a600e866b").size(121298).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_expb_16bit_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("d7e2338dd240b58cd8ca13452ab8f21fa3e0779575eda0677568b5ce88247271") }).groups(&["big-endian", "charset", "color", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_expb_2frame.dcm", "b8b9adb32b2c3ce33c3136620a9b00c2440e047574305e76d176e28ad374134f").size(61298).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_expb_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("026dac3bc332e46b5ddc4cda3d990ac5a423dad4cb4134262b1a7cc1f2106c6c") }).groups(&["big-endian", "charset", "color", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_expb_32bit.dcm", "5153bb5df191a2b1ec40f592d433a097523a2979ee2ec22ae47ad2bf823bebd3").size(121268).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_expb_32bit.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("1a243c9351e3a9aeadbe667627e8bae4d38950bf570c2fadab4fef93f766aafa") }).groups(&["big-endian", "charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_expb_32bit_2frame.dcm", "cb4e18465d10d4c60afcf8e591b44687ffac8cfd63ab9ca3b6ad45ec25dc2175").size(241278).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_expb_32bit_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("3caa80cc3032f7457d4509766be96484cbcdd628334b1aecad249d6a41998575") }).groups(&["big-endian", "charset", "color", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_gdcm2k_uncompressed.dcm", "abf72c420b8bb97a29b93cb5d63a633271b65038d8323e28d71334bc56ef1a2b").size(31722).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_gdcm2k_uncompressed.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("169e619557b12114a7f0be8602026e9abb3d5045804311736ec14cecb026aca9") }).groups(&["charset", "color", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_gdcm_KY.dcm", "bd0fdd97841431dd8e3a76a4842cf6d378285254a273f7884c0a7a8ddb9622d8").size(2998).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_gdcm_KY.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: None }).groups(&["charset", "color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_jpeg_dcmtk.dcm", "6548a45a0800626cf70a59766146ff3b790a393ee0c9fca359f92c70f370b382").size(3424).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_jpeg_dcmtk.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL"), pixel_hash: None }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_jpeg_gdcm.dcm", "a492ed4a120c51a076126a6021e8cab1acb0172da3d42c62843b2a34a8ddd252").size(5204).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_jpeg_gdcm.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("169e619557b12114a7f0be8602026e9abb3d5045804311736ec14cecb026aca9") }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_jpeg_lossy_gdcm.dcm", "fb9f1a7dfbca18d3af666ef4a8d15e2d81e75745b2e118e3c590ff90de188d0b").size(5042).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_jpeg_lossy_gdcm.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: None }).groups(&["charset", "color", "jpeg", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_rle.dcm", "3f98ee352e75b10ccd6d279ca30b0cb1e363a0c9dde318803f0ec660111327d6").size(2006).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_rle.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("169e619557b12114a7f0be8602026e9abb3d5045804311736ec14cecb026aca9") }).groups(&["charset", "color", "rle"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_rle_16bit.dcm", "86504bea4a8cea36ef4f65e687495cea800e7012546a3202a7944b4f06b376b5").size(2606).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_rle_16bit.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("36de0258708d3af79cf989c0ab2cbbf861afe927799cdfd0fef36fca3b3aa058") }).groups(&["charset", "color", "rle"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_rle_16bit_2frame.dcm", "d71390a0ad9c66017271d4dc16c3a45fc2ad4808a5a8efdf5badf01274dcf602").size(3896).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_rle_16bit_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("d7e2338dd240b58cd8ca13452ab8f21fa3e0779575eda0677568b5ce88247271") }).groups(&["charset", "color", "multi-frame", "rle"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_rle_2frame.dcm", "cc9cd098ab099b5f7a18c4599f2858d2f3f3471590ff8a14d4cf7c834692d9f0").size(2696).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_rle_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("026dac3bc332e46b5ddc4cda3d990ac5a423dad4cb4134262b1a7cc1f2106c6c") }).groups(&["charset", "color", "multi-frame", "rle"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_rle_32bit.dcm", "222c7060be1697a24e6659bd97ef17fe9c8596ee41bba2a89768854eec00e383").size(3760).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_rle_32bit.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("1a243c9351e3a9aeadbe667627e8bae4d38950bf570c2fadab4fef93f766aafa") }).groups(&["charset", "color", "rle"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_rle_32bit_2frame.dcm", "5c9aa606982eadb1d8aa445fbe87318ad2fa984615e14d9c2c46bd4497cdf2c3").size(6246).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_rle_32bit_2frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(100), columns: Some(100), frames: Some(2), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("3caa80cc3032f7457d4509766be96484cbcdd628334b1aecad249d6a41998575") }).groups(&["charset", "color", "multi-frame", "rle"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_small_odd.dcm", "4aca361ab330f57f60e6b1e3b31dcd834a512bee8a4246bbe1d151011c47e031").size(1444).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_small_odd.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(3), columns: Some(3), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("ef2df252ba3cd066405c4dd121d0efea1341083ae2f676e1f4c844b5a4838cb8") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_rgb_small_odd_jpeg.dcm", "ffb5219ca45a2b492ce5e5a6fc7a5f5ad5a667716a2e5b859eba323376adf439").size(2044).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_rgb_small_odd_jpeg.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(3), columns: Some(3), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL"), pixel_hash: None }).groups(&["charset", "color", "jpeg"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_ybr_full_422_uncompressed.dcm", "08f6f4935ae225282d8481f297d37b1cf33be8c3d99028f310a9a3f9e8aaf284").size(21686).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_ybr_full_422_uncompressed.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL_422"), pixel_hash: None }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/SC_ybr_full_uncompressed.dcm", "3c9f4b2b82a3f88ce5340cb07ce14782dcbb09840938e4489e8c21eac1f02dd6").size(31696).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/SC_ybr_full_uncompressed.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(100), columns: Some(100), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL"), pixel_hash: Some("ddddadc3c3d361b56803d6e8caa0da3f0dd3c3972aee0ece1924086f792eecc6") }).groups(&["charset", "color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/US1_J2KI.dcm", "22340375674ff253196ce8a147acf0458bea3f105ff2c6af81f0eb119729605b").size(59140).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/US1_J2KI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.91"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_ICT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/US1_J2KR.dcm", "2427fdc82d90cd4ce8a69b5157eecb37549902dce138ac15c6456a7eae70b83d").size(153760).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/US1_J2KR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_RCT"), pixel_hash: None }).groups(&["color", "jpeg2000", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/US1_UNCI.dcm", "64d4dcc8cf787f110296e949480b7d035b3ade806574757c931f026caa068137").size(923050).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/US1_UNCI.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e16892020c73095e42ff4cf7368de5206f11012e25feaed53cc2bc614602bb9a") }).groups(&["color", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/US1_UNCR.dcm", "af5a66e40cd49d15dfbf7b78c850eba0662bdc7339339c3fa13f123a57e812cb").size(922878).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/US1_UNCR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("e16892020c73095e42ff4cf7368de5206f11012e25feaed53cc2bc614602bb9a") }).groups(&["color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/badVR.dcm", "d12583df0cf66146256238b4cada266956a317c0546c7516cb36f56982ce85b2").size(7618).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/badVR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(10), columns: Some(10), frames: Some(1), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("67f96b3373d7acf18a7ea33d8c9a0e0a9d63bd62acce734b7531341bb332daec") }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/bad_sequence.dcm", "0677915e5c3e8c98498eb3d1b726ccf38ba0d8ada657c8ca7fe1b8b9b5890f4f").size(184266).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/bad_sequence.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.70"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg", "un-vr", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/color-pl.dcm", "16bfc3134e59d789985efddfc70d924420b16e1c6d1f21c960bb4544c9e9dbf9").size(93278).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/color-pl.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(120), columns: Some(256), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("4631a14e915f1a7f27d30fb4cd2c4418e592a26008b61a29221641dc6e97c8b2") }).groups(&["color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/color-px.dcm", "bf10a89f277743ea337b7c4741efa0709a086f0161e1ff2b94cff01e428047e4").size(93278).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/color-px.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(120), columns: Some(256), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("RGB"), pixel_hash: Some("4631a14e915f1a7f27d30fb4cd2c4418e592a26008b61a29221641dc6e97c8b2") }).groups(&["color"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/color3d_jpeg_baseline.dcm", "c8798b8abf8ae0a18e8c9952e7c7f75f3cc8465234b1b63f9e3ba3bebb9d5625").size(6143990).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/color3d_jpeg_baseline.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.3.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.50"), rows: Some(480), columns: Some(640), frames: Some(120), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(3), photometric_interpretation: Some("YBR_FULL_422"), pixel_hash: None }).groups(&["color", "jpeg", "multi-frame", "private-tags"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/eCT_Supplemental.dcm", "0a4c3aa02d1b0b4826daa5ffe85ef13be83c1433842a9a98b901e075136dd86f").size(1052902).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/eCT_Supplemental.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(2), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("b6b202c4af4494a26933ffa7834f9ab6b8a5b4b623f105751e84829abbcdd302") }).groups(&["multi-frame", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/empty_charset_LEI.dcm", "7fd2082a76e9a97cb1306f1da389bafe32ec2f874262a9c6c78b7c475acffb4d").size(276).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/empty_charset_LEI.dcm").info(TestFileInfo { sop_class_uid: None, transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["implicit-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/emri_small.dcm", "151233ec63f64ebb63b979df51aa827cd612a53422c073f6ef341770c7bc9a56").size(84256).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/emri_small.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(64), columns: Some(64), frames: Some(10), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9719c5d0f62ce971a1039c9cd73a6785427f4f80a1d3b6969cb9ffc425fba054") }).groups(&["multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/emri_small_RLE.dcm", "93c19bca3fb6b7202dcd067de8d16cb6b3f7c6e9a0632e474aab81175ee45266").size(49022).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/emri_small_RLE.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(64), columns: Some(64), frames: Some(10), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9719c5d0f62ce971a1039c9cd73a6785427f4f80a1d3b6969cb9ffc425fba054") }).groups(&["multi-frame", "rle"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/emri_small_big_endian.dcm", "8e18ed3542bc4df70dc6acda87eab5095b19e2b4c1b7fb72ba457e7c217b1ab7").size(84256).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/emri_small_big_endian.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(64), columns: Some(64), frames: Some(10), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9719c5d0f62ce971a1039c9cd73a6785427f4f80a1d3b6969cb9ffc425fba054") }).groups(&["big-endian", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/emri_small_jpeg_2k_lossless.dcm", "b2b4063359a08ed3b0afa9f4e4f72f84af79e5116515b446d9a30da9dc7f1888").size(40324).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/emri_small_jpeg_2k_lossless.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(64), columns: Some(64), frames: Some(10), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9719c5d0f62ce971a1039c9cd73a6785427f4f80a1d3b6969cb9ffc425fba054") }).groups(&["jpeg2000", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/emri_small_jpeg_2k_lossless_too_short.dcm", "8742a49b7d02dedb11e7926d30900a415c42efeff02a64a0aa0f0873cf6da582").size(40316).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/emri_small_jpeg_2k_lossless_too_short.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(64), columns: Some(64), frames: Some(10), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9719c5d0f62ce971a1039c9cd73a6785427f4f80a1d3b6969cb9ffc425fba054") }).groups(&["jpeg2000", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/emri_small_jpeg_ls_lossless.dcm", "24de03c9c0f8b5aa75d7fbcc894f94e612b66702175b4936589a0849ec9f87b4").size(42834).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/emri_small_jpeg_ls_lossless.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.80"), rows: Some(64), columns: Some(64), frames: Some(10), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("9719c5d0f62ce971a1039c9cd73a6785427f4f80a1d3b6969cb9ffc425fba054") }).groups(&["jpeg-ls", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/explicit_VR-UN.dcm", "28c4a61022d7dbebec97e2f1bbdad0ed097bee2c62727c26a3f3720248c9c6e7").size(186402).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/explicit_VR-UN.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.4.90"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["jpeg2000", "private-tags", "un-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/gdcm-US-ALOKA-16.dcm", "f1a2d5f7c4ffe87dc589b12738084099fe44a436f6980f5d7e96a026ad356d65").size(873140).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/gdcm-US-ALOKA-16.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("ee2a4d1309ce158764bb789a6bd40aa4cd8758dc37321c40709a282735562271") }).groups(&["implicit-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/gdcm-US-ALOKA-16_big.dcm", "2a801cbd7bd04ed28b9c14c7a8edb04b43384e38f00574e27c0fab8f4aa62db4").size(873164).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/gdcm-US-ALOKA-16_big.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.6.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(480), columns: Some(640), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("PALETTE COLOR"), pixel_hash: Some("ee2a4d1309ce158764bb789a6bd40aa4cd8758dc37321c40709a282735562271") }).groups(&["big-endian"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/image_dfl.dcm", "0029ebbba17e7c6f081408d433cd28b5d1cfee0eeb4cff509b4d972ffa9daf27").size(4637).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/image_dfl.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1.99"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("1f5f1b1c1a57606a55d7e4212ee2655c8205b45e264bd55057f7388c258deef8") }).groups(&["deflate"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/liver.dcm", "4f8fb316b6df067bdf2ef7bc2385fd571ad5be67e171aed3ed902a71293d9d5c").size(102630).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/liver.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.66.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(3), bits_allocated: Some(1), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["multi-frame", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/liver_1frame.dcm", "8ac3546185d0c18c193438b47b16c4ef323f0ebe0e8fd071ee1e6d43edef1978").size(37084).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/liver_1frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.66.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(1), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/liver_expb.dcm", "fe3323f3f4a2166e4c5305a2380a035a66504197f3f01a6e2b50bbd9814721d5").size(102078).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/liver_expb.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.66.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(512), columns: Some(512), frames: Some(3), bits_allocated: Some(1), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["big-endian", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/liver_expb_1frame.dcm", "2429258dec0f9c444b69d9d7326b442bd27c66a2ba1d6f68804005d27df6af13").size(36532).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/liver_expb_1frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.66.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(1), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: None }).groups(&["big-endian"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/meta_missing_tsyntax.dcm", "075dc51483951bb651ddafd77e803101fcee21ab656ca87d6e531eaeaeb440f2").size(317).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/meta_missing_tsyntax.dcm"),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/mlut_18.dcm", "9c65b39df55dc46a4670f76e0ec1093d097206ed46c2d7e23b8051c87ef0228b").size(533476).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/mlut_18.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(16), pixel_representation: Some(1), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("8c5e46dbbefadf90d5d4b198df81041f7803707a9ac6c8f15f30344429262626") }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/nested_priv_SQ.dcm", "5cc694964c10cb02b501f02585909a7f4ef4656c07a89e603d1833629919c5c6").size(343).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/nested_priv_SQ.dcm").info(TestFileInfo { sop_class_uid: None, transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: None, columns: None, frames: Some(1), bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["implicit-vr", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/no_meta.dcm", "52912b9950f457ac7618efaad0cdd91b52354e07fbc25abee895bd86beebf9bc").size(38871).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/no_meta.dcm").groups(&["no-preamble"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/no_meta_group_length.dcm", "76c6af82b4246285f6e5b53f150af44b9b7e2afe7ae25e1b604c549ba3d16733").size(408).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/no_meta_group_length.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.1"), transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["implicit-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/priv_SQ.dcm", "b13c0ecdbda3a23faa93ced9e833a681ec3ba06d0c2210f2a42e6d49669baf57").size(532).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/priv_SQ.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.4"), transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["implicit-vr", "private-tags", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/reportsi.dcm", "59ca5f4fbf524bd542a907f8f29028be510e9d907239dbe2f1c82ffc5088538b").size(2968).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/reportsi.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.88.11"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/reportsi_with_empty_number_tags.dcm", "fd8a8a5dc3eaa053d08974981f70ce4837f4a1676288b9f059d660f7c4701a92").size(2700).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/reportsi_with_empty_number_tags.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.88.11"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtdose.dcm", "1d6cc092146d093e086a6bcccef4ebb7d097941343f5cd3b6395d157b64e37e4").size(7568).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtdose.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: Some(10), columns: Some(10), frames: Some(15), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("e30a4288ac22902293b3b0144d9cd7866d43a96e2e5cf3ec59c6f78595c3a125") }).groups(&["implicit-vr", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtdose_1frame.dcm", "6685273e1661562f38dbe2b1c6284b9c950c7dbcf080a71c14305d623d0b6090").size(1958).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtdose_1frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: Some(10), columns: Some(10), frames: Some(1), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("67f96b3373d7acf18a7ea33d8c9a0e0a9d63bd62acce734b7531341bb332daec") }).groups(&["implicit-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtdose_expb.dcm", "fe40ee7ed0cd63d1e76b51b42d4e68b764bd5f8a9ad59ce9fab9487158c550b8").size(7618).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtdose_expb.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(10), columns: Some(10), frames: Some(15), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("e30a4288ac22902293b3b0144d9cd7866d43a96e2e5cf3ec59c6f78595c3a125") }).groups(&["big-endian", "multi-frame"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtdose_expb_1frame.dcm", "a96cfd3c8d4ca70f2a8d1b85bc22b47d7bbd609d7d21ea2fe821314a0cd36746").size(2008).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtdose_expb_1frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.2"), rows: Some(10), columns: Some(10), frames: Some(1), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("67f96b3373d7acf18a7ea33d8c9a0e0a9d63bd62acce734b7531341bb332daec") }).groups(&["big-endian"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtdose_rle.dcm", "2f83e3a2ef0de355570c38860b233fc2fa6c37626c81ad080d8661c03a413522").size(6816).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtdose_rle.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(10), columns: Some(10), frames: Some(15), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("e30a4288ac22902293b3b0144d9cd7866d43a96e2e5cf3ec59c6f78595c3a125") }).groups(&["multi-frame", "rle", "un-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtdose_rle_1frame.dcm", "f4e7a3b7aeb386ca1a2d7460b0c49771d923f6fd8abba08c1d4c7bc5c25cfc27").size(2122).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtdose_rle_1frame.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.2"), transfer_syntax_uid: Some("1.2.840.10008.1.2.5"), rows: Some(10), columns: Some(10), frames: Some(1), bits_allocated: Some(32), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("67f96b3373d7acf18a7ea33d8c9a0e0a9d63bd62acce734b7531341bb332daec") }).groups(&["rle", "un-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtplan.dcm", "18585dbbd6f7c5d1b7e749d6976d72251802ad89d65bccd31c03006f95aab89b").size(2672).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtplan.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.5"), transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["implicit-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtplan_truncated.dcm", "15009ec7713dc53b95adfd4e1a692885240ddd34a0f18f52c0327a05cacbfd53").size(2129).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtplan_truncated.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.5"), transfer_syntax_uid: Some("1.2.840.10008.1.2"), rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["implicit-vr"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/rtstruct.dcm", "40c41bdf871fd8553396b02476a66024ed23c04927c0dc53fd10ecd3472cd0d3").size(2534).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/rtstruct.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.481.3"), transfer_syntax_uid: None, rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }).groups(&["no-preamble", "undefined-length"]),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/test-SR.dcm", "eebf00a37e97503b5a65022f9c2f89db6e8dac4cc632682aa3456aee1b6c177e").size(6796).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/test-SR.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.88.33"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: None, columns: None, frames: None, bits_allocated: None, pixel_representation: None, samples_per_pixel: None, photometric_interpretation: None, pixel_hash: None }),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("pydicom/vlut_04.dcm", "64f54c0f490ce3fa2faac0a90a7ca0166caa025f8fdcfbe181906387a7867c27").size(263628).provenance(PYDICOM).anonymization(Anonymization::FullyAnonymized).upstream("https://raw.githubusercontent.com/pydicom/pydicom/main/src/pydicom/data/test_files/vlut_04.dcm").info(TestFileInfo { sop_class_uid: Some("1.2.840.10008.5.1.4.1.1.7"), transfer_syntax_uid: Some("1.2.840.10008.1.2.1"), rows: Some(512), columns: Some(512), frames: Some(1), bits_allocated: Some(8), pixel_representation: Some(0), samples_per_pixel: Some(1), photometric_interpretation: Some("MONOCHROME2"), pixel_hash: Some("74853be063ef5655c12d6c25be10f47107b8dc515978e73bff0bb35c33f01af8") }),
+];
+
+/// golden JSON dumps of the test files, see ./generate/golden.py
+pub static GOLDEN_ENTRIES: &[TestFile] = &[
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/CT1_J2KI.json", "ac43d2bf99b821853fdc47db6743bb3a2d0bc8187c6c9974391fa65b4aa7fe7d").size(22716),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/CT2_J2KI.json", "f8b0e62d001207618022d6dc0cad934827ebab2e3870468d0fe5f0d3dfac8267").size(6470),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/MG1_J2KI.json", "bc51cd0d6fb120e161767e2116ca0d30016833f7c0b61e69a40fc9e7296ea6dd").size(5286),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/MR1_J2KI.json", "ba4eb87a81366d5821232bd187f7e3ec8d395cca01b198732cefdcfdf4cd7d0b").size(6632),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/MR2_J2KI.json", "c46d644d642974f37ddb71b56c6d9d996af3a33cb3a9c64331d7534e17edadf2").size(7522),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/MR3_J2KI.json", "09f32e14b35324a56837dab541e403340ff348d86a3d08169cbe9149ead79988").size(29133),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/MR4_J2KI.json", "27270e5511622a2a078757d296ac459510c36d39b48f51b72955b8bf40b21fae").size(7559),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/NM1_J2KI.json", "72dfa1e1bd5d20a4c452c18f14430730743507d23b85e131406d4cf0599d7d9d").size(11840),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/RG1_J2KI.json", "1a42073a3de1247ce774be7d6b5fd8fa609a25afc537385ed1ead888dfd3436a").size(6990),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/RG2_J2KI.json", "6f34932e6f892f2fad24cd14a07a35d024d1e86634ab1dcbc4cac5683387c8e5").size(5172),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/RG3_J2KI.json", "1e41b043da533f7b1eb7a0728d7f01779543533cf60d7e735a2fd86133999ed5").size(5495),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/SC1_J2KI.json", "e4ff670968e50f6521834c1602706f75e4b847b5de28a2d5d4947702f936d30a").size(4440),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/US1_J2KI.json", "139890079fc3b690f48d0055e17060b5a2d993358e7236d232be774ab1379f70").size(4952),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/VL1_J2KI.json", "8cf85c54a0691bd088ebf3f36364066585030a14d975fff86e77c1a39840b5de").size(4538),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/VL2_J2KI.json", "c84ffc172b36c93ca2f92618124e42b653572cc88e449a98cfcee238e5b4eb2d").size(4536),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/VL3_J2KI.json", "f08393e1b97748b062b8b19fc27f473472f2c9683a6894b59b1e58ea907d6297").size(4547),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/VL4_J2KI.json", "7c4a846b2849c8530d846e32062b5a6152b25e280634d210f20d62c1e059b0f8").size(4532),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/VL5_J2KI.json", "7e422a4c4d767896b5f98a54544a9313936e2971fa79ed719fe121d4829677d7").size(4536),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/VL6_J2KI.json", "accf08a255dd7b41c8207abfb4defa354e51709e22358a62af8514b46925735b").size(4547),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KI/XA1_J2KI.json", "64b1a22a220b8c38e65e6afe305f85e5a3ecfdbe40d7377758db6fd99acdd86d").size(4143),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/CT1_J2KR.json", "d72f158b9637820b085e3b125426dde3d84e802f57800a8aa5505c77452febaf").size(22238),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/CT2_J2KR.json", "4fee1a34f707fd197a4609ce21590f422c95dff9ea771f5868668006bbc886dc").size(5990),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/MG1_J2KR.json", "f5193982dbabef39e0d0a22d8c74f2e4087ceb847979d22e16d7773bd185341e").size(4808),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/MR1_J2KR.json", "52bcf4625eaa268affd28ad3ddeb876e66e60bee5e976716b74cacc052be2897").size(6154),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/MR2_J2KR.json", "dbf4eb57d0927048166adf3048bf9d853aa5bdb9facfedd8a01b9998a650cad7").size(7044),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/MR3_J2KR.json", "0be91cfc5227970f13d56714b1ea250315872bb7b9984111e37779b37c67c188").size(28653),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/MR4_J2KR.json", "bde739fd9f5e2876a821c0b749c9179e80353ad76f5562e5621dfd93c6049a03").size(7081),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/NM1_J2KR.json", "710573f453d9e0c0cda24768647d939fd62e403b569b95d0a90283f2823a692c").size(11358),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/RG1_J2KR.json", "f58fcf3756a480770690f723a279d54b79c7d22cf8e050acd6012bba0094245d").size(6512),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/RG2_J2KR.json", "62d1b0eab522728a36f46730f454629b497e0286bdd34e02c24fbb5d678b669c").size(4694),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/RG3_J2KR.json", "dd5a93d11ec391b9d43091a89f7ba5ec03089b6d775a2fcd631cb5f55f768e80").size(5017),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/SC1_J2KR.json", "9b9f304f33a13dedd0be454e61cf237bf150e7d4ba7fdbabd54f5f4668105de5").size(3962),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/US1_J2KR.json", "106b12bea918a983e056ea78387e3f377c4e18ee3e1041e6c34b07d3441d33ce").size(4474),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/VL1_J2KR.json", "80d3d4d5c006be0f2e61539a901e18fe37458150f7703494999425d3881168e8").size(4060),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/VL2_J2KR.json", "18ecb05f37a5ea7d3f18611e3130e33fb644fa1d33ca8e4af605f6d235b12e22").size(4058),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/VL3_J2KR.json", "e657694c3196b84aa715911108aa7079a2d4ea3707e0800742aa8f9a6c03e78e").size(4069),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/VL4_J2KR.json", "c3671b6c0e737776379cf5584f4c26efba8c1a064ef661cef918cd35c3e37922").size(4054),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/VL5_J2KR.json", "1ead6f226e3a94886d2a910798689fb2cbd9b4a3748cfb97b84c8cf143aaa417").size(4058),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/VL6_J2KR.json", "4637dcd2b78e4dd1b430cb7be0906b1246698297316e2fce262e6320339caf92").size(4069),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/J2KR/XA1_J2KR.json", "28a854211d1eeaada1fcb10eca4850f1b2abe5cfd57fa3e269b11c27804091e3").size(3665),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/CT1_JLSL.json", "48125316443e7bcef78156c79984125c7d0c1baf5207fae66b91e60762abe62f").size(22208),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/CT2_JLSL.json", "78b0ee75424c0ef2eed5385946bedb4b81528adf33d3a49e5e5daea08679f685").size(5960),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/MG1_JLSL.json", "6ff927204a5c0c2bfa3832e869426c878da2cb64b381237a7f56d39d281a3c91").size(4778),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/MR1_JLSL.json", "57d86d3ec764aab111828cab061c9565f203046b410141e0a51517f931d86bbe").size(6124),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/MR2_JLSL.json", "39978d8b521c92a45f1b160958739f0bd912bea2279fcc85623d3c4651e88f91").size(7014),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/MR3_JLSL.json", "157e06591cdd1839c524d6c9e90537db1383562b55f65b55a3cc44c9047d3fbc").size(28623),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/MR4_JLSL.json", "c9be332dfadc23f17a64fdd2d3e6eba0d594672670699c65715b230ba22a9d31").size(7051),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/NM1_JLSL.json", "a724f15da68ad81e271c62e390f9f6ceea7c6d7d10c65f32f99d2f8a215e67cc").size(11328),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/RG1_JLSL.json", "226fb3dc1ce6903ca63cbaf885e8fb88511ff6d05f7899244fb29722e6b5a681").size(6482),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/RG2_JLSL.json", "10df4dadd118d1430be8aba0d718db29be4431fcf86800038414f68c36a63b7e").size(4664),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/RG3_JLSL.json", "d5c66d0b1a8809d24d96c4d1c96afb77fdaaf9eba885e12e2779f1833b50e5d9").size(4987),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/SC1_JLSL.json", "9946782b2eda44c0b988bbfec61329c994ce9dfb769e8775d987eabe48b20e94").size(3932),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSL/XA1_JLSL.json", "cf0fce8587521afcc015aeff4e5b685b6574de0d85abbff7da6d92d67cf7cb8a").size(3635),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/CT1_JLSN.json", "f92f4bb28e14dabf85754a1e0346a4ad64378ec634db076fee1c8dcae44870ad").size(22696),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/CT2_JLSN.json", "a0e0581ba98dec8a35f11877a100408db6c20121aa52b8555226e62e4d96312c").size(6450),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/MG1_JLSN.json", "fe6d318abddd59bf5172e8211b238359863cc95e373ada17743ba1cc5c19a964").size(5266),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/MR1_JLSN.json", "0fadc0b168adaf40537ed61a8cee7ab00632c27651353e5d6521c4ab8e296713").size(6612),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/MR2_JLSN.json", "f9f6d5e47254f2b2238789acfc23b3d3a2058aabacb7c4d886652e9930290e7c").size(7502),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/MR3_JLSN.json", "f18a96a2b17a5d1f2fd903e6a4afdbbfe2b938882099e31448b77de3c689322e").size(29111),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/MR4_JLSN.json", "0b8d63771c7388b130803b394af9e8a5606f2796b571239009e05413deee67df").size(7541),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/NM1_JLSN.json", "760dfd86d9ce77812e765f22921f7296c9f27d3468557d1c3c16d88687ff76d9").size(11818),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/RG1_JLSN.json", "767fa52cd77b1fce89214eaf522fffaae208ad5e2e4789d5892814d3e5480371").size(6970),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/RG2_JLSN.json", "81cab15ed3c1de95b10f991b5defdee69fa4a911afc9ad9487525a116a5298b0").size(5154),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/RG3_JLSN.json", "6d57a19e8c2553e922fbbbd84b8a88c1bfa782676341a2e3b1e35f258d2ebf1c").size(5477),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/SC1_JLSN.json", "18e65724ffa7a6a76c4ddf8d9c064fce3125282212920efa288cc12524c4c7b0").size(4420),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JLSN/XA1_JLSN.json", "df12ba383f910066436392b73e430bf6495d45e361b8940db6fac809543ec985").size(4125),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/CT1_JPLL.json", "31365bfc535e0468bac85531cbacd56da0d9029056845900ee8819c0777688a8").size(22210),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/CT2_JPLL.json", "460fe925811cc9ebe2cec6300af329a234e1e959b5d3f3b48e76e217c40bc72a").size(5962),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/MG1_JPLL.json", "fda45633121fdb4d992fc5396bf7806c18f1ed07d6f43719c1b0c27daab7c5c9").size(4780),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/MR1_JPLL.json", "b0b5e20f112bbdc730f62032170902dbecd9013b0d3623843e027cfac7373ca4").size(6126),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/MR2_JPLL.json", "0e0d43a04256738915d9b78b3676ce9392575e3a832aaf79b9c1a07ee2db7170").size(7016),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/MR3_JPLL.json", "3ef88e6c5680be98639c3e808a2369f48fff43c2c21eb8718c14ef06ef2984c6").size(28625),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/MR4_JPLL.json", "711a9ef22a67df131787297873a4f61fd18cfc64799e8b7bc4a96f20215958f6").size(7053),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/NM1_JPLL.json", "cb6672331df530de2a7c02bd6cdeb1810b50d65c3c43396790a025a61b640c7a").size(11330),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/RG1_JPLL.json", "256f479bbac1a252ed52f79c8df68f7fbbc81a6721968cb0b83be6df7401093d").size(6484),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/RG2_JPLL.json", "26f51055c734f27920962bff75a910d57b1f777ce7b73b430e5b280c5f4cb5f8").size(4666),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/RG3_JPLL.json", "31b52f6fb8a5481ea379501ad5369d6083f046d9292e9bd7b974865e079e398f").size(4989),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/SC1_JPLL.json", "32e2818f0a572d3af707ddd5c0c4d90b44cf2129014cfc99339c88c3f9e9e66a").size(3934),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLL/XA1_JPLL.json", "e086d6246782b314733efe4590cacdcd16bd37e44914f935cce2e81aa9a7afc8").size(3637),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/MG1_JPLY.json", "1de91a86975c6cbd606e61f76cb94ce870117db7b83d9142725bc7df3c3b0b38").size(5246),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/MR1_JPLY.json", "1bcb79b3461c79910cbb2d6db3f513eacd3100ab02836676487b9b7822ec1996").size(6590),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/MR2_JPLY.json", "af08b23912525452d8aff8207b3cf090b6fe958f96a692defbc1a7f1151805e4").size(7482),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/MR3_JPLY.json", "5a968e7c13af734a5af281ccb8f4b593025dede381f6436d6bff948fc8bd533e").size(29091),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/MR4_JPLY.json", "f7492899a7754ca4caeace4682470565a249bd8cd35ee9b2b676ba612d67abd4").size(7519),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/NM1_JPLY.json", "9e47c924ea0f6b14e94076429731a728f4d4c0d4cd25cbbb6980abacc1fd0116").size(11796),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/RG2_JPLY.json", "47aa6aa4fe98fcd68533254fb64bdccf93e1bdf87d1494377f8268cd34322ccf").size(5132),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/RG3_JPLY.json", "5f79e15ec0eea2ecba926f08db45b426360f3a13d914c0c973ee8b130e522e56").size(5455),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/SC1_JPLY.json", "fb1d05c13e773e59116ca4ef6ace133c9633f591207b8046c5d91eb11946ba53").size(4400),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/JPLY/XA1_JPLY.json", "6441bd5bbbbc41f0f64edb03018d4b9eea4d86cbc2a5aad1651c070f256e8923").size(4103),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/CT1_UNC.json", "bd1617baa377c65b55b9a5d92cdeeac47dbb254801e90fb42dda3200b97d1090").size(21364),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/CT2_UNC.json", "3f006f09145b7748f56de7e2bb976eb5d3925eb32b28d4cd5742807ec0d302d8").size(5116),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/MG1_UNC.json", "ea3d655392084635374ce2eb871262f3e8656db10cf487685aa3a65e8e540eaf").size(3842),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/MR1_UNC.json", "fed024cd760891746de69c6d79080b4219c3690e24b3a92643b6cfeaecc2e39c").size(5279),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/MR2_UNC.json", "a7974001eb350380e9dd668ed26c4b5baec8d6ea1786c005e3c0d17126ceb1f5").size(6170),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/MR3_UNC.json", "6d08d5bd8f0700ac34349211b2a4795f83cd3d9ab10036e6e5d9b92f7b2d7014").size(27779),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/MR4_UNC.json", "31365e7bc65649d859976a51bd39860e879821203a6c6447c61762a84c94e746").size(6207),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/NM1_UNC.json", "6c1a14d8f333c578b9256043709af2c905dd6958d3ba124be910bcc342e67f9d").size(10484),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/RG1_UNC.json", "080d4b93bebf4452f0b751c443bf55be929d4d27fb20026398720c564246adc3").size(5637),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/RG2_UNC.json", "0f18b1e448e386558795f7c1465a6f3aed1136fec3a9ff267a54740e9903103b").size(3819),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/RG3_UNC.json", "60b3f628c4ef868c50bb1627afbbe0022a1e0587cb8be05a507f56d8a48c9419").size(4142),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/SC1_UNC.json", "0258e24905ba418017aaea791e2346b3c93844ecdf12342d5b6454c06ed35518").size(2995),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/US1_UNC.json", "b148a52dbc04da7f0c8603700e5ccb62b907545ba5c200eee514956346c4cbec").size(3609),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/VL1_UNC.json", "4ff42d22b64c1f71aa2c0141d87f24875c0a33d65ea2ea2557dabd09a0024fdd").size(3105),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/VL2_UNC.json", "47ab3cdd626d22d19003bed03745d0fa1f3092a22cfd3b02989523b4b53be501").size(3103),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/VL3_UNC.json", "31efff517ae67d94079b3a9c3baa125da9b1627a60db6417c65286f4c6c3f851").size(3114),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/VL4_UNC.json", "d4961d038230c141e764eb11056cd58c1ad6d29369588b7d5cb19de4047c9ff5").size(3099),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/VL5_UNC.json", "5b6413e2eca4cf6c4efb374591e4ee81623667af2259b594dbce40b8d3cfcff2").size(3103),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/VL6_UNC.json", "c3b3c15b5e145a8a846413e5128b84af66d26a858137142f4e9fa4b2435b5a32").size(3114),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/REF/XA1_UNC.json", "140c7c1530b666ea9e4886c6301f7aa17853f7fedf50d5b831509b8cf816c224").size(2698),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/CT1_RLE.json", "68b42f44f5f71051a8b2e3f37cf4ff6a3f3450f54550291a4e6113679dda79f6").size(21832),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/CT2_RLE.json", "6c5fcc1a4eda12105ada33ab08b1705cca4a1af62b740d792cead24bac1b4e92").size(5582),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/MG1_RLE.json", "e140d028c384eb123239c4982fc8c7fcadc45a15bf3a0b4521576bee0ca94996").size(4403),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/MR1_RLE.json", "6577669665381bd893aff5177bf56a6e2897007ed96de0a477305c9884b8f3ca").size(5746),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/MR2_RLE.json", "fdec77ea01e69d493c07f8a8b36f4f7dca2d9efa53fd1e142f2b6bae2735402a").size(6636),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/MR3_RLE.json", "92ea60ce8b1150ca431a385a3bfba02918c4fe96fc87d82fb01769997b0b151c").size(28247),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/MR4_RLE.json", "0f8060629eba821c5bd81186c84e8d3db18a533121325a087716b17a6ca0b212").size(6673),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/NM1_RLE.json", "a9c76ad9cc00ec57b38017755ef79dc7670e3133defca224cec04f4097d62f7d").size(10953),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/RG1_RLE.json", "a75ac6c943cd1941e166f10d391daf852effdc05c2367231618e40daadeae086").size(6108),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/RG2_RLE.json", "29f0448ec3a0fd676ff5d31ce777f77066d75889cf0d02309cab17bc09322b21").size(4288),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/RG3_RLE.json", "6a04d69e14f93c5fdbe0d4c5121d735685cf1e39dbf441b78619085c4643729d").size(4606),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/SC1_RLE.json", "fe9a0eff3e9c1d0983166f492896f64eab8134dfd6e92db85b51f1e55c32bca4").size(3556),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/US1_RLE.json", "cb4063ebc32f6ad80204b13971738c8e05a1189e9084896ec15e53a4e45c1918").size(4079),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/VL1_RLE.json", "48bcf7016c424259984793f0c1ea5c18686129926670ca755923029b80ba0b76").size(3666),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/VL2_RLE.json", "5c7b3a1194cd4c0b6107685595187f496bbc49cd25091366383d47032b0dda14").size(3664),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/VL3_RLE.json", "d5364d279f37cf9bf09085441abf98133634e0f2e242820295bf638c761680d3").size(3675),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/VL4_RLE.json", "006e3f11c944297e5da8cb84ca8545e851e4ea9ad58f7a370bc77b924ec8a032").size(3660),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/VL5_RLE.json", "480cd2d8080c335340a985feb6135199eb97401f694882f0f98de25ae484428e").size(3665),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/VL6_RLE.json", "5e0406ec00a8b2ec1e194d7633774cb609bbcefa130d16756e4bb6973bf2ea0c").size(3675),
+    #[cfg(feature = "manifest-wg04")]
+    TestFile::none("golden/WG04/RLE/XA1_RLE.json", "ab2f0a96cd93ea0ae5c298e85d3804c72d5c544431f648dbf76d8259e1a1e3d1").size(3259),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/693_J2KI.dcm.json", "58d8799ae7a5d22e959e1bf8ede9c65c926bb090e684a86541d0659b75c9e856").size(6909),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/693_J2KR.dcm.json", "f669698e28b918a19c2360a4283847dff681eaaad35b88551a46a3f8fcd158a1").size(5746),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/693_UNCI.dcm.json", "b447077e876cb812a6bd922d03882399b0276b14699591e7e5e93e1ee1289328").size(6987),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/693_UNCR.dcm.json", "2cafa88ce6bee2d1c369c00b05d30bc0862aa7eb64bc93a78de5ad7ea0948a0a").size(5746),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/CT_small.dcm.json", "04489d7f6f06ec3520a1348cf09f05c78fb81d8058d6b1d1f891e0c30c7abcd9").size(21897),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/ExplVR_BigEnd.dcm.json", "3c73a216d59afa19cac3ba43e792e169cf66ee2047cdf1c516582118c79ca043").size(2847),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/ExplVR_BigEndNoMeta.dcm.json", "4f6f05146c5555ad48b49dc56b33b33ad708779f65be9009185e934cb65906c6").size(1809),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/ExplVR_LitEndNoMeta.dcm.json", "4f6f05146c5555ad48b49dc56b33b33ad708779f65be9009185e934cb65906c6").size(1809),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/JPEG-LL.dcm.json", "2137ec03d9d6aed4c9262d00d61fe6cead71b6918f90ce0f8b3b8630876b6e74").size(11331),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/JPEG-lossy.dcm.json", "0b0cd62b84dbc0391fbf0eaa9a5f4ac0d7f16b1ddacd6561dbcc8850017625f4").size(11800),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/JPEG2000.dcm.json", "4bd1706717d26eb5d10b821fe1aadf0d955c1bf5e3e63216a8804f673264c98a").size(11842),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/JPEG2000_UNC.dcm.json", "6e699d4f107ccd3753e335956abcf5fdca56e88b1b69a6d4b161cce77e426afe").size(11924),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/JPGLosslessP14SV1_1s_1f_8b.dcm.json", "944a774e2e5f736819e5037fe410f4b542898c7fe78b8a8a775fdd6c8daa4f48").size(8693),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR-SIEMENS-DICOM-WithOverlays.dcm.json", "fc19733df1d47bafa1cd7fc229fd5ba0a886df198ca7b83451817fc2bddd8780").size(58589),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR2_J2KI.dcm.json", "e5541ca50e95c401d619861b620d7433d135fd2336bfb87f9ac97856370eac7f").size(7524),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR2_J2KR.dcm.json", "80c00948d38af7d27b6ec64d49d0980287ade3b3880d2f644da1afffa54879a4").size(7046),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR2_UNCI.dcm.json", "06071aa3f7654d9f9b71e1c6d9f88fbb4d52833345721ec8fcaa02a9bc46645d").size(7602),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR2_UNCR.dcm.json", "e0cd738fae7b58b29533ff42bddfc85a8e446267e2e511c7ad08c249b2371e38").size(6174),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR_small.dcm.json", "80e86739283929462e0bbeb61a1d96fc1b917ba909ded61e722a6ddaed8572a0").size(5281),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR_small_RLE.dcm.json", "2fde4ad8c5058b09da9bf97389687b7b28690ed167c7bb57653f9fd4e4e23e07").size(5285),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR_small_bigendian.dcm.json", "3f25cb8e893a4584f3ca528de501588fbcf8ebf74708698e121ad7fd0e7a3374").size(5063),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR_small_expb.dcm.json", "d6ee433b12d22264db974e4bcd9eeef52ab531b22af74b07c5a7c4dbe23c3498").size(5286),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR_small_jp2klossless.dcm.json", "fc4f9ec07389082ddfae4313be88b6700030fd3a45b1f1e8fbe92179b4cb4bd9").size(5294),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR_small_jpeg_ls_lossless.dcm.json", "1daa5d4ebaf8d1012cf891a9545a01a24f3a526945f63f7792a9d81f7995f0b8").size(5298),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/MR_small_padded.dcm.json", "bf3a2b72331bf97adbb9c1b4031ef22229273b0c9c71c6f14a98e12589322a15").size(5288),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/OBXXXX1A.dcm.json", "27a16f9210fe1d2fdb604aa1e67994e9fba9c5ce8f6601b0a68beb594beb3193").size(20580),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/OBXXXX1A_2frame.dcm.json", "faaef82689f77a50906e30dd780cc1a549145c238d32a10233b587c338c9f8c8").size(20653),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/OBXXXX1A_expb.dcm.json", "dcbad0c564c0a9f1776eb2cfee99fb453893590a84cebe189783a3f485cf57dc").size(20585),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/OBXXXX1A_expb_2frame.dcm.json", "d42f326f671391a6f95a16d78a425112be526a2162d7513c712b535a280967ff").size(20658),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/OBXXXX1A_rle.dcm.json", "a93d083afa42b3bd587ae8def15f406c8cec7c67b56e34b4f46fccc6acd372c7").size(20584),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/OBXXXX1A_rle_2frame.dcm.json", "eeb49297462714f9c63d0019a42e209c3036551a363d5ce6a8f1e77c5146e1b3").size(20657),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/RG1_J2KI.dcm.json", "b6d077d8e76c01c048766c7239dbd39207c97f3781b0bf9af60bf6341c945c74").size(6992),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/RG1_J2KR.dcm.json", "e5f800134857d280bee236bfa426250d67cfa5369000a6ccac61ad6d3f994723").size(6514),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/RG1_UNCI.dcm.json", "aaf1d5b9fe8a9d3abb66103485b0b1e2433b5e37ace0cc72ad00f9e013781167").size(7070),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/RG1_UNCR.dcm.json", "3b77d1decaf538b268ccdff99368b213705967a0b4e9a02c678f9ab89457546d").size(5641),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/RG3_J2KI.dcm.json", "a0caf76bbcd7d0ef998b345513539e30dccc6d6d5e8359a7c0c28003e26fded5").size(5497),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/RG3_J2KR.dcm.json", "2ffc6b1277df8dd81e9c5ec5c62a0c7d1f6e1bd13cbc8f58c69b471aaf5f01bd").size(5019),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/RG3_UNCI.dcm.json", "bb4a175c4dd372091d3e9c1b0f0e9eccea8f38ee52cd31e164430a8e71cda584").size(5575),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/RG3_UNCR.dcm.json", "67606b0f6099110327d41568aee0bc41cb2129e8ba87750e69b82e46c3c30baf").size(4146),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb.dcm.json", "fa4eec57ee5c5bdb1568054584c5aa3298ead84cbcdff5c6869c106b858429e3").size(3101),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_16bit.dcm.json", "47fc6a7451e4d2d7f0548696e55d373364d010b48b97cf8b5b26916339201061").size(3112),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_16bit_2frame.dcm.json", "3e472f6986d04d58b09143ecd5b4344b406bbf34914d56ff5c8e09bf7bcd0a21").size(3185),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_2frame.dcm.json", "5429bb55e54bc95a88def14fbb1120788f4d321550ccb8d45c3578bead98b4af").size(3174),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_32bit.dcm.json", "a427323c52ddbd5ce77c0ae12f8e1ab65f0e0cca34ba1cb6b9d8c63a95638691").size(2976),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_32bit_2frame.dcm.json", "1503a835ba63bac1718154e3369504658dd56975baf09fa6f0534f75d883b137").size(3049),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_+eb+cr.dcm.json", "0fdb75f5ccea882610e7a793e7d6f2fcd04bc88a49c229777a0d842b98ed6797").size(4681),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_+eb+cy+n1.dcm.json", "2511abdfd63bc85856d2655e05975fd3ae94f7b53afe77e40830427a2c00829e").size(4688),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_+eb+cy+n2.dcm.json", "6b2b5ab48787aec42336128f18138df70b19c08700b60b6ae3bc25984839451e").size(4689),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_+eb+cy+np.dcm.json", "a5e18f5d78bfb37664c3d4d431de37f0e298e56b299dd2d5ee81af6219255e22").size(4693),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_+eb+cy+s2.dcm.json", "2a6b5300cc4fd0e82a2e2a7b0ccf1c328001068f26a298edff90fbecc2de0583").size(4693),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_+eb+cy+s4.dcm.json", "3e924d7855c1d645963695a383bcbb6f9e55c0a8106c5b4208b10789d7bb5191").size(4689),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_ebcr_dcmd.dcm.json", "d9bf87c2779938d8a9a6a61e211b97bc111d20d9c566536b91a55a3c28e72862").size(4684),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_ebcyn1_dcmd.dcm.json", "80eba4b3d9a41104dbbab62241144d6be65fba67d9dfbe2ca9e8276732577037").size(4685),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_ebcyn2_dcmd.dcm.json", "59750abf959a0857cb42b95f78f4b053ea344bc4991cac8a035c718954fd9d52").size(4686),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_ebcynp_dcmd.dcm.json", "e4a6a926072ab2402e85c76ffc343ebeeb5e9de2519fe802c34eb602027a306e").size(4686),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_ebcys2_dcmd.dcm.json", "cef460b1a2bf42d5aadfd558a5d8df1874ff7c5b25eb8f585b1d0c5fb183c182").size(4686),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_dcmtk_ebcys4_dcmd.dcm.json", "7eea708034eaa112b90db834837fb608383f094a19c5e99e7db96eb28f34571f").size(4686),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_expb.dcm.json", "81320e12ca530f69121ecb3124bb0364699d1d1364077a38f0380cf7f326fbae").size(3106),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_expb_16bit.dcm.json", "c47144fea964568ee81ec5bdde2f29524900d75f06023ac79fe72a793d11c549").size(3117),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_expb_16bit_2frame.dcm.json", "4d4a1287a45100c9bb4110728f1c6c1994d1e98590939cb573d85722275491b0").size(3190),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_expb_2frame.dcm.json", "6c343df7a64250e9cf728f1c27114ce7fedc5ce59de0dad3a1f05572fbf3bc64").size(3179),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_expb_32bit.dcm.json", "094ef5539c058c4acce5a08812b983e13674c4ae6d8707e40ebac3b019c56abc").size(2981),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_expb_32bit_2frame.dcm.json", "df4e7e82c06c30522e50b841fba38e5bc2b718f5c60f2b9f9c8967a53da4e504").size(3054),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_gdcm2k_uncompressed.dcm.json", "343ca8935455b68c3771865db9a2c8e2393546e3866fdfb26944f210a9f07450").size(4773),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_gdcm_KY.dcm.json", "5dad51deb6a46c0cf2a52df9336b33d5532689aee144739879b442b763762ffc").size(4683),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_jpeg_dcmtk.dcm.json", "573d8187edede108652d162c0ad3cbe342d3773949708cc8bd453cbdd5ce65fd").size(4685),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_jpeg_gdcm.dcm.json", "69843430e0735c008ae07ae64237d385a5a7f9e8d02198c578f6b9e5063ab717").size(3111),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_jpeg_lossy_gdcm.dcm.json", "d18af2c21fd1d01b52c782825febc8fdc35d9fa19c44325629617a15c46c505e").size(4691),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_rle.dcm.json", "be642d0a9965e265bfe34f402dae937983d5303cf3f07be266a915d1b20a1a56").size(3105),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_rle_16bit.dcm.json", "872693434feb74a6b1e73fb4ac62dc25c82074566708b52940932198f5a2d38f").size(3116),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_rle_16bit_2frame.dcm.json", "b4e101ff2a8e4020f584ea224369a06fd0421c9895b5b55024b078739305cc3a").size(3189),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_rle_2frame.dcm.json", "51eeb3206472dedc9162f9f7ff5266d7b56ee3c5136c32e1c14e9a6e9954abc6").size(3178),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_rle_32bit.dcm.json", "03dd2e28406a94c523699607df3f1c49b7c45ce900b41f99cbe635fc09d1f408").size(2980),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_rle_32bit_2frame.dcm.json", "a3cb397e67887d9346d9a35069803523bfc38edb652d2e726d46435c2bf72f8e").size(3053),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_small_odd.dcm.json", "779fac8b6db51b4e2b19f871c85d812bb39efd8a84a024ad0c1bf50405cccc30").size(3492),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_rgb_small_odd_jpeg.dcm.json", "74c21d719c611fc1c91efa3e027974e2fe1770f751cca77ceb1451ab4dd9cf71").size(4775),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_ybr_full_422_uncompressed.dcm.json", "7f51ee86ca4af825da938ecfc9a27eac7b7130ed2b85bdc8d94f7485d19223b3").size(4699),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/SC_ybr_full_uncompressed.dcm.json", "9fb3d8437a76d656a4b5a63ff73da709b6855cbf58a64f391b9c2edd7f058df7").size(4691),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/US1_J2KI.dcm.json", "8f2d57b79b4072d2f6b6c0527d4ee386326afdb3c4731002ec3b08ddf3bfd00c").size(4954),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/US1_J2KR.dcm.json", "0c98da586f7311063fdef825997446361230ab9ceb40c7943efcc3a598b9fbbc").size(4476),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/US1_UNCI.dcm.json", "cffe37fd07a488f03cda91eb7f1febbd813d7ca20e5c16acd52df65bf2b3dad9").size(4472),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/US1_UNCR.dcm.json", "e4d0becc10bffe9b37de66cfc4629ebd3801d3dc39eb02c908a8f1deab43edef").size(3613),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/bad_sequence.dcm.json", "81884a378ae65fb13e4761676d5e6fb06002fe225eed902d725c5bb0834ab344").size(6611),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/color-pl.dcm.json", "09f2eae886930190e459b17b5df1c1f47cf26ff12db7f9f044e711cbff99ede4").size(3477),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/color-px.dcm.json", "f92f020bdd13e1bccaa985f2774c8b8f73ad8f9218e8e538344e30fb7676284f").size(3477),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/color3d_jpeg_baseline.dcm.json", "77859a4aced81846a9352d5608226fa892babf5477c5affa90a21b01475beb30").size(40918),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/eCT_Supplemental.dcm.json", "6b083ecd25bdc405d3600381c8a3d30021e30941e652e43a3093651ce1e3b60b").size(18437),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/emri_small.dcm.json", "82086811d86ca1aa64b662be642ead1d135a9b55082bf64688a4abca5896f048").size(8913),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/emri_small_RLE.dcm.json", "f9c1d407bf954590aa913959d9bc2b1a57e2c2c96ec3cd8fe09c0cfa3b732dc2").size(8917),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/emri_small_big_endian.dcm.json", "6d2cdc035880a91d996a1342e2694577a04cdfb1c6bc6c6338c364b06d3b3c7c").size(8924),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/emri_small_jpeg_2k_lossless.dcm.json", "7083b9c2ab5725338f0ef069cd30af822952962adf900700b2b91372bfac5919").size(8930),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/emri_small_jpeg_ls_lossless.dcm.json", "fa0f1c932094bcfe8e662288a99bdfbbcd0c57561bba2d1138171ba538091e51").size(8930),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/explicit_VR-UN.dcm.json", "d694ab456185de4d1cdbe7f7f92db4efdc4afcdac11cadbba0dca4d026b65578").size(3337),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/gdcm-US-ALOKA-16_big.dcm.json", "79277d1251b015d2942ac59931a8b72a679fb95658e2ca60f25f374953af20df").size(350896),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/image_dfl.dcm.json", "09d11e267eab1684d59bc8b7141f5fc2f777242d7188d4eaa7dc594050b5b595").size(1960),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/liver.dcm.json", "e66d14446c5a03db6723124511602a9772dc8d8000b68d6426ec5e0bfb8a9260").size(18544),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/liver_1frame.dcm.json", "7900213febd8502c1cd311bab50676bd77d0af6aef1d427489e2599eeb3f6ce9").size(18485),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/liver_expb.dcm.json", "3513cbf84b8c646368b1a29de166192bb28623a8cc7491593fc7143df68a9a5e").size(18549),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/liver_expb_1frame.dcm.json", "d651b22e4d8a6ddfc4c3ccf5cddce7b30b7f08f4e4602cee4836c6b307046bfc").size(18490),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/mlut_18.dcm.json", "853d1aa537fbd39fde45e6a5130dca53a8b4335c15df233b4db611dc820525af").size(80072),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/reportsi.dcm.json", "77dfa04a067ae9f9cc28468430ef3387fde4f3384693dbaf0436b08bf700f89e").size(13806),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/reportsi_with_empty_number_tags.dcm.json", "c50d5cd45c46a838ce8c1bbbce22555d6a60cd100c594430e89725dfdb2e0aca").size(14058),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/rtdose_expb.dcm.json", "b74d07743812ae3fc877268951028c62e91d933d731f0ec2fc437e5e1a10c311").size(4326),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/rtdose_expb_1frame.dcm.json", "dc99f612b1c3d42f46c63eadd60d4297cfde08dc2396f92e065d984d9668ed4e").size(4266),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/rtdose_rle.dcm.json", "d06679434f7c2d8c5859004873004f476199dd0bf5b6a6c7f82a4a01f4a87dc2").size(3860),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/rtdose_rle_1frame.dcm.json", "7e0c3e887440e1d02afc2ac535024d6254ed4d4685685987c0e66b9eab60f31c").size(3800),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/test-SR.dcm.json", "39639e5c3c115a0a7178d9fceea8851634d5130dce73e2d3dad482a50830dda7").size(51156),
+    #[cfg(feature = "manifest-pydicom")]
+    TestFile::none("golden/pydicom/vlut_04.dcm.json", "6c182f202f573733fb77da82ad9cbe0310c255fc1f66120b4174093e48f5a0a7").size(7635),
+];
+
+/// bundles of test files, see ./generate/bundles.py
+#[cfg_attr(not(feature = "bundles"), allow(dead_code))]
+pub static BUNDLES: &[Bundle] = &[
 ];
@@ -0,0 +1,191 @@
+//! Selection of test file entries
+
+use crate::test_file::{Anonymization, Feature, TestFile, Tier};
+use std::fmt;
+
+/// A selection of DICOM test file entries,
+/// by name pattern or arbitrary predicate.
+///
+/// ```
+/// use dicom_test_files::Filter;
+///
+/// // all pydicom files, plus the WG04 reference images
+/// let filter = Filter::glob("pydicom/*").or(Filter::glob("WG04/REF/*"));
+/// # let _ = filter;
+/// ```
+pub struct Filter {
+    predicate: Box<dyn Fn(&TestFile) -> bool + Send + Sync>,
+}
+
+impl Filter {
+    /// Select all available entries.
+    pub fn all() -> Self {
+        Filter::predicate(|_| true)
+    }
+
+    /// Select the entries with a name matching the given glob pattern.
+    ///
+    /// `*` matches any sequence of characters within a path segment,
+    /// `**` matches any sequence of characters including `/`,
+    /// and `?` matches a single character other than `/`.
+    pub fn glob(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        Filter::predicate(move |entry| glob_match(&pattern, entry.name))
+    }
+
+    /// Select the entries encoded in the given transfer syntax,
+    /// according to their file meta group.
+    pub fn transfer_syntax(uid: impl Into<String>) -> Self {
+        let uid = uid.into();
+        Filter::predicate(move |entry| entry.info.transfer_syntax_uid == Some(uid.as_str()))
+    }
+
+    /// Select the entries of the given SOP class,
+    /// according to their file meta group.
+    pub fn sop_class(uid: impl Into<String>) -> Self {
+        let uid = uid.into();
+        Filter::predicate(move |entry| entry.info.sop_class_uid == Some(uid.as_str()))
+    }
+
+    /// Select the entries in the given group,
+    /// see [`group`](crate::group).
+    pub fn group(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Filter::predicate(move |entry| entry.groups.contains(&name.as_str()))
+    }
+
+    /// Select the entries with the given structural quirk,
+    /// see [`with_feature`](crate::with_feature).
+    pub fn feature(feature: Feature) -> Self {
+        Filter::group(feature.group())
+    }
+
+    /// Select the entries of the given size tier,
+    /// see [`tier`](crate::tier).
+    pub fn tier(tier: Tier) -> Self {
+        Filter::predicate(move |entry| entry.tier() == tier)
+    }
+
+    /// Select the entries of the given anonymization status.
+    pub fn anonymization(status: Anonymization) -> Self {
+        Filter::predicate(move |entry| entry.anonymization == Some(status))
+    }
+
+    /// Select the entries vetted to hold no patient data,
+    /// being either fully anonymized or synthetic,
+    /// see [`assert_anonymized`](crate::assert_anonymized).
+    pub fn anonymized() -> Self {
+        Filter::predicate(|entry| match entry.anonymization {
+            Some(status) => status.is_vetted(),
+            None => false,
+        })
+    }
+
+    /// Select the entries for which the given function returns `true`.
+    pub fn predicate(f: impl Fn(&TestFile) -> bool + Send + Sync + 'static) -> Self {
+        Filter {
+            predicate: Box::new(f),
+        }
+    }
+
+    /// Select the entries matched by both filters.
+    pub fn and(self, other: Filter) -> Self {
+        Filter::predicate(move |entry| self.matches(entry) && other.matches(entry))
+    }
+
+    /// Select the entries matched by either filter.
+    pub fn or(self, other: Filter) -> Self {
+        Filter::predicate(move |entry| self.matches(entry) || other.matches(entry))
+    }
+
+    /// Check whether the given entry is selected by this filter.
+    pub fn matches(&self, entry: &TestFile) -> bool {
+        (self.predicate)(entry)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::all()
+    }
+}
+
+impl From<&str> for Filter {
+    fn from(pattern: &str) -> Self {
+        Filter::glob(pattern)
+    }
+}
+
+impl From<String> for Filter {
+    fn from(pattern: String) -> Self {
+        Filter::glob(pattern)
+    }
+}
+
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Filter").finish_non_exhaustive()
+    }
+}
+
+/// Match a test file name against a glob pattern.
+pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    glob_match_chars(&pattern, &name)
+}
+
+fn glob_match_chars(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) if rest.first() == Some(&'*') => {
+            let rest = &rest[1..];
+            (0..=name.len()).any(|i| glob_match_chars(rest, &name[i..]))
+        }
+        Some(('*', rest)) => {
+            let segment_len = name.iter().take_while(|&&c| c != '/').count();
+            (0..=segment_len).any(|i| glob_match_chars(rest, &name[i..]))
+        }
+        Some(('?', rest)) => match name.split_first() {
+            Some((c, name)) if *c != '/' => glob_match_chars(rest, name),
+            _ => false,
+        },
+        Some((p, rest)) => match name.split_first() {
+            Some((c, name)) if c == p => glob_match_chars(rest, name),
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_patterns() {
+        assert!(glob_match("pydicom/CT_small.dcm", "pydicom/CT_small.dcm"));
+        assert!(!glob_match("pydicom/CT_small.dcm", "pydicom/MR_small.dcm"));
+        assert!(glob_match("pydicom/*", "pydicom/CT_small.dcm"));
+        assert!(glob_match("pydicom/*_small.dcm", "pydicom/MR_small.dcm"));
+        assert!(!glob_match("WG04/*", "WG04/JPLY/NM1_JPLY"));
+        assert!(glob_match("WG04/*/NM1_*", "WG04/JPLY/NM1_JPLY"));
+        assert!(glob_match("WG04/**", "WG04/JPLY/NM1_JPLY"));
+        assert!(glob_match("**/NM1_JPLY", "WG04/JPLY/NM1_JPLY"));
+        assert!(glob_match("WG04/???/CT1_RLE", "WG04/RLE/CT1_RLE"));
+        assert!(!glob_match("WG04/??/CT1_RLE", "WG04/RLE/CT1_RLE"));
+    }
+
+    #[test]
+    fn combine_filters() {
+        let liver = TestFile::none("pydicom/liver.dcm", "");
+        let nm = TestFile::none("WG04/JPLY/NM1_JPLY", "");
+
+        let filter = Filter::glob("pydicom/*").or(Filter::glob("WG04/**"));
+        assert!(filter.matches(&liver));
+        assert!(filter.matches(&nm));
+
+        let filter = Filter::glob("**").and(Filter::predicate(|e| e.name.ends_with(".dcm")));
+        assert!(filter.matches(&liver));
+        assert!(!filter.matches(&nm));
+    }
+}
@@ -0,0 +1,62 @@
+//! Multi-file fixtures declared by external manifests
+
+use crate::{all_entries, fetch, prefetch, suggest, Filter, Result, TestFile};
+use std::path::PathBuf;
+
+/// Names of the multi-file fixtures of the known test files,
+/// for use with [`fetch_set`].
+pub fn set_names() -> Result<Vec<&'static str>> {
+    let mut names: Vec<&'static str> = all_entries()?
+        .into_iter()
+        .filter_map(|entry| entry.set)
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    Ok(names)
+}
+
+/// The members of the given multi-file fixture,
+/// in the order of their instance numbers
+/// and those without one last, in the order of the manifest
+fn members(name: &str) -> Result<Vec<&'static TestFile>> {
+    let mut members: Vec<&'static TestFile> = all_entries()?
+        .into_iter()
+        .filter(|entry| entry.set == Some(name))
+        .collect();
+    members.sort_by_key(|entry| (entry.instance_number.is_none(), entry.instance_number));
+    Ok(members)
+}
+
+/// Fetch all members of the given multi-file fixture,
+/// such as a coherent series of instances,
+/// and return their paths in the order of their instance numbers.
+///
+/// Fixtures are declared by the files of
+/// an [external manifest](crate#external-manifests),
+/// with the `set` they are a member of and their `instance_number`.
+/// Members which are not cached yet are downloaded
+/// several at the same time, like [`prefetch`].
+/// An unknown name results in [`Error::NotFound`](crate::Error::NotFound),
+/// see [`set_names`] for the available fixtures.
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let instances = dicom_test_files::fetch_set("ct_study_1")?;
+/// # let _ = instances;
+/// # Ok(())
+/// # }
+/// ```
+pub fn fetch_set(name: &str) -> Result<Vec<PathBuf>> {
+    let members = members(name)?;
+    if members.is_empty() {
+        return Err(suggest::not_found(name, set_names()?));
+    }
+    let set = name.to_string();
+    prefetch(Filter::predicate(move |entry| {
+        entry.set == Some(set.as_str())
+    }))?;
+    members
+        .into_iter()
+        .map(|entry| fetch(entry).map(|(cached_path, _)| cached_path))
+        .collect()
+}
@@ -0,0 +1,565 @@
+//! HTTP client of the downloads
+//!
+//! Requests go through a shared ureq agent over HTTP/1.1,
+//! or with the `http2` feature,
+//! through a reqwest client which multiplexes them
+//! over a single HTTP/2 connection per data source.
+//! WebAssembly has neither,
+//! see [`bytes`](crate::bytes) for the browser.
+
+use crate::{cancel, config, object_store, progress, DownloadEvent, Error};
+use std::{
+    fmt,
+    io::{self, Read},
+    sync::{Condvar, Mutex, OnceLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The default maximum number of simultaneous downloads in the process
+#[cfg(not(feature = "http2"))]
+pub(crate) const DEFAULT_MAX_DOWNLOADS: usize = 4;
+/// The default maximum number of simultaneous downloads in the process,
+/// which are cheap streams of the same connection over HTTP/2
+#[cfg(feature = "http2")]
+pub(crate) const DEFAULT_MAX_DOWNLOADS: usize = 16;
+
+/// Number of downloads in progress in the process
+static DOWNLOADS: Mutex<usize> = Mutex::new(0);
+/// Signaled when a download is over
+static DOWNLOAD_OVER: Condvar = Condvar::new();
+
+/// A slot among the simultaneous downloads, released on drop
+struct Permit;
+
+impl Permit {
+    /// Wait until fewer downloads than the limit are in progress
+    fn acquire() -> Permit {
+        let max_downloads = config::max_downloads();
+        let mut downloads = DOWNLOADS.lock().unwrap_or_else(|e| e.into_inner());
+        while *downloads >= max_downloads {
+            downloads = DOWNLOAD_OVER
+                .wait(downloads)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        *downloads += 1;
+        Permit
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        *DOWNLOADS.lock().unwrap_or_else(|e| e.into_inner()) -= 1;
+        DOWNLOAD_OVER.notify_all();
+    }
+}
+
+/// When the bandwidth of the downloads, under a rate limit,
+/// is available to the next bytes received
+static BANDWIDTH_FREE_AT: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Wait until the given number of bytes received
+/// fit in the given number of bytes per second,
+/// shared by all downloads in the process
+fn throttle(rate: u64, bytes: usize) {
+    let wait = {
+        let mut free_at = BANDWIDTH_FREE_AT.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let start = match *free_at {
+            Some(free_at) if free_at > now => free_at,
+            _ => now,
+        };
+        let end = start + Duration::from_secs_f64(bytes as f64 / rate as f64);
+        *free_at = Some(end);
+        end - now
+    };
+    thread::sleep(wait);
+}
+
+/// The body of a response,
+/// which counts as a download in progress until dropped,
+/// and reports its progress as it is read,
+/// within the rate limit if any
+struct Body<R> {
+    body: R,
+    url: String,
+    total: Option<u64>,
+    rate: Option<u64>,
+    downloaded: u64,
+    started: bool,
+    completed: bool,
+    #[cfg(feature = "tracing")]
+    started_at: std::time::Instant,
+    _permit: Permit,
+}
+
+impl<R> Body<R> {
+    fn new(body: R, url: &str, total: Option<u64>, permit: Permit) -> Self {
+        Body {
+            body,
+            url: url.to_string(),
+            total,
+            rate: config::max_rate(),
+            downloaded: 0,
+            started: false,
+            completed: false,
+            #[cfg(feature = "tracing")]
+            started_at: std::time::Instant::now(),
+            _permit: permit,
+        }
+    }
+}
+
+impl<R> Body<R> {
+    /// The error of a body closed before its `Content-Length`
+    fn truncated(&self) -> Option<io::Error> {
+        match self.total {
+            Some(expected) if self.downloaded < expected => Some(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                Truncated {
+                    url: self.url.clone(),
+                    expected,
+                    got: self.downloaded,
+                },
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl<R: Read> Read for Body<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        cancel::check()?;
+        // bodies which are not read, such as of `304 Not Modified`,
+        // are not reported
+        if !self.started {
+            self.started = true;
+            progress::report(DownloadEvent::Started {
+                url: &self.url,
+                total: self.total,
+            });
+        }
+        // small reads keep the rate smooth
+        let buf = match self.rate {
+            Some(rate) => {
+                let len = buf.len().min((rate / 10).max(1) as usize);
+                &mut buf[..len]
+            }
+            None => buf,
+        };
+        let read = match self.body.read(buf) {
+            Ok(read) => read,
+            // the client may detect short bodies itself,
+            // which reqwest reports as errors of other kinds
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof || cfg!(feature = "http2") => {
+                return Err(self.truncated().unwrap_or(e))
+            }
+            Err(e) => return Err(e),
+        };
+        if read > 0 {
+            if let Some(rate) = self.rate {
+                throttle(rate, read);
+            }
+            self.downloaded += read as u64;
+            progress::report(DownloadEvent::Progress {
+                url: &self.url,
+                downloaded: self.downloaded,
+                total: self.total,
+            });
+        } else if !buf.is_empty() && !self.completed {
+            if let Some(e) = self.truncated() {
+                return Err(e);
+            }
+            self.completed = true;
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                url = %self.url,
+                bytes = self.downloaded,
+                duration_ms = self.started_at.elapsed().as_millis() as u64,
+                "downloaded"
+            );
+            progress::report(DownloadEvent::Completed {
+                url: &self.url,
+                downloaded: self.downloaded,
+            });
+        }
+        Ok(read)
+    }
+}
+
+/// A body which ended before its `Content-Length`,
+/// carried by I/O errors until it becomes an [`Error::Truncated`]
+#[derive(Debug)]
+pub(crate) struct Truncated {
+    /// the URL of the body
+    pub url: String,
+    /// the `Content-Length` of the body
+    pub expected: u64,
+    /// the number of bytes received
+    pub got: u64,
+}
+
+impl fmt::Display for Truncated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "body closed after {} of {} bytes",
+            self.got, self.expected
+        )
+    }
+}
+
+impl std::error::Error for Truncated {}
+
+/// A response of a data source
+pub(crate) struct Response {
+    /// the status code, which is not an error status
+    pub status: u16,
+    /// the `ETag` header
+    pub etag: Option<String>,
+    /// the `Content-Encoding` header,
+    /// if the body was not decoded by the client
+    pub content_encoding: Option<String>,
+    /// the `Content-Type` header
+    #[cfg_attr(not(feature = "dicomweb"), allow(dead_code))]
+    pub content_type: Option<String>,
+    /// the body, as received
+    pub body: Box<dyn Read + Send>,
+}
+
+/// A failed request
+pub(crate) struct Failure {
+    /// the error
+    pub error: Error,
+    /// the error status code, if the server responded
+    pub status: Option<u16>,
+}
+
+impl From<Failure> for Error {
+    fn from(failure: Failure) -> Error {
+        failure.error
+    }
+}
+
+/// Send a GET request to the given URL with the given headers,
+/// once fewer downloads than the limit are in progress.
+/// The download is over when the body of the response is dropped.
+///
+/// Fails with [`Error::Unreachable`] when the server cannot be reached,
+/// rate limits the request or fails,
+/// and with [`Error::Download`] on other error statuses.
+pub(crate) fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, Failure> {
+    request(url, headers, None)
+}
+
+/// Send a POST request of the given body to the given URL
+/// like [`get`]
+#[cfg_attr(not(feature = "lfs"), allow(dead_code))]
+pub(crate) fn post(url: &str, headers: &[(&str, &str)], body: &[u8]) -> Result<Response, Failure> {
+    request(url, headers, Some(body))
+}
+
+/// Send a request to the given URL,
+/// or to the object it names in an object store with its credentials,
+/// along with the headers of the artifact repository it belongs to, if any
+fn request(url: &str, headers: &[(&str, &str)], body: Option<&[u8]>) -> Result<Response, Failure> {
+    let artifact_headers = config::artifact_headers(url);
+    let mut headers = headers.to_vec();
+    headers.extend(
+        artifact_headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str())),
+    );
+    match object_store::resolve(url).map_err(|e| failed(e, None))? {
+        Some(object) => {
+            headers.extend(
+                object
+                    .headers
+                    .iter()
+                    .map(|(name, value)| (*name, value.as_str())),
+            );
+            send(&object.url, &headers, body)
+        }
+        None => send(url, &headers, body),
+    }
+}
+
+/// Send a request to the given URL with the given headers,
+/// a POST request of the given body if any, a GET request otherwise
+#[cfg(all(not(feature = "http2"), not(target_arch = "wasm32")))]
+fn send(url: &str, headers: &[(&str, &str)], body: Option<&[u8]>) -> Result<Response, Failure> {
+    let permit = Permit::acquire();
+    #[cfg(feature = "tracing")]
+    tracing::debug!(url, "request");
+    let method = if body.is_some() { "POST" } else { "GET" };
+    let mut request = agent().request(method, url);
+    for &(name, value) in headers {
+        request = request.set(name, value);
+    }
+    let resp = match body {
+        Some(body) => request.send_bytes(body),
+        None => request.call(),
+    };
+    let resp = resp.map_err(|e| match e {
+        ureq::Error::Transport(_) => failed(
+            Error::Unreachable(format!("Failed to download {}: {}", url, e)),
+            None,
+        ),
+        ureq::Error::Status(status, _) => {
+            status_failure(status, format!("Failed to download {}: {}", url, e))
+        }
+    })?;
+    #[cfg(feature = "tracing")]
+    tracing::debug!(url, status = resp.status(), "response");
+    let header = |name| resp.header(name).map(str::to_string);
+    // the length of a body decoded by the client is unknown
+    let total = match header("Content-Encoding") {
+        Some(encoding) if encoding.trim().eq_ignore_ascii_case("gzip") => None,
+        _ => header("Content-Length").and_then(|length| length.parse().ok()),
+    };
+    Ok(Response {
+        status: resp.status(),
+        etag: header("ETag"),
+        content_encoding: header("Content-Encoding"),
+        content_type: header("Content-Type"),
+        body: Box::new(Body::new(resp.into_reader(), url, total, permit)),
+    })
+}
+
+/// There is no HTTP client in WebAssembly,
+/// where test files are fetched by [`bytes`](crate::bytes) instead
+#[cfg(all(not(feature = "http2"), target_arch = "wasm32"))]
+fn send(url: &str, _headers: &[(&str, &str)], _body: Option<&[u8]>) -> Result<Response, Failure> {
+    Err(failed(
+        Error::Unreachable(format!(
+            "Cannot download {} in WebAssembly, use `bytes` instead",
+            url
+        )),
+        None,
+    ))
+}
+
+/// A request which failed with the given error status.
+/// Rate limiting and server errors are expected to pass,
+/// so they fail as if the server could not be reached.
+pub(crate) fn status_failure(status: u16, reason: String) -> Failure {
+    if status == 429 || status >= 500 {
+        failed(Error::Unreachable(reason), Some(status))
+    } else {
+        failed(Error::Download(reason), Some(status))
+    }
+}
+
+/// Report a failed request
+fn failed(error: Error, status: Option<u16>) -> Failure {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(error = ?error, status, "request failed");
+    Failure { error, status }
+}
+
+/// The HTTP agent shared by all downloads,
+/// so that connections to the data sources are kept alive
+/// and reused from one file to the next
+#[cfg(all(not(feature = "http2"), not(target_arch = "wasm32")))]
+fn agent() -> &'static ureq::Agent {
+    static AGENT: OnceLock<ureq::Agent> = OnceLock::new();
+    AGENT.get_or_init(|| {
+        ureq::AgentBuilder::new()
+            .max_idle_connections_per_host(DEFAULT_MAX_DOWNLOADS)
+            .build()
+    })
+}
+
+/// Send a request to the given URL with the given headers,
+/// a POST request of the given body if any, a GET request otherwise
+#[cfg(feature = "http2")]
+fn send(url: &str, headers: &[(&str, &str)], body: Option<&[u8]>) -> Result<Response, Failure> {
+    let permit = Permit::acquire();
+    #[cfg(feature = "tracing")]
+    tracing::debug!(url, "request");
+    let mut request = match body {
+        Some(body) => client().post(url).body(body.to_vec()),
+        None => client().get(url),
+    };
+    for &(name, value) in headers {
+        request = request.header(name, value);
+    }
+    let resp = request.send().map_err(|e| {
+        let error = if e.is_connect() || e.is_timeout() {
+            Error::Unreachable(format!("Failed to download {}: {}", url, e))
+        } else {
+            Error::Download(format!("Failed to download {}: {}", url, e))
+        };
+        failed(error, None)
+    })?;
+    let status = resp.status();
+    #[cfg(feature = "tracing")]
+    tracing::debug!(url, status = status.as_u16(), "response");
+    if status.is_client_error() || status.is_server_error() {
+        return Err(status_failure(
+            status.as_u16(),
+            format!("Failed to download {}: status code {}", url, status),
+        ));
+    }
+    let header = |name| {
+        resp.headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let total = resp.content_length();
+    Ok(Response {
+        status: status.as_u16(),
+        etag: header("ETag"),
+        content_encoding: header("Content-Encoding"),
+        content_type: header("Content-Type"),
+        body: Box::new(Body::new(resp, url, total, permit)),
+    })
+}
+
+/// The HTTP/2 client shared by all downloads,
+/// whose requests to the same data source share a connection
+#[cfg(feature = "http2")]
+fn client() -> &'static reqwest::blocking::Client {
+    static CLIENT: OnceLock<reqwest::blocking::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::blocking::Client::builder()
+            .http2_adaptive_window(true)
+            .build()
+            .expect("failed to initialize the HTTP client")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    #[test]
+    fn bounded_downloads() {
+        let max_downloads = config::max_downloads();
+        let in_progress = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for _ in 0..max_downloads * 3 {
+                scope.spawn(|| {
+                    let _permit = Permit::acquire();
+                    let downloads = in_progress.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(downloads, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(10));
+                    in_progress.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+        assert!(peak.load(Ordering::SeqCst) <= max_downloads);
+    }
+
+    #[test]
+    fn classify_error_statuses() {
+        let failure = status_failure(503, "Service Unavailable".to_string());
+        assert!(matches!(failure.error, Error::Unreachable(_)));
+        assert_eq!(failure.status, Some(503));
+        let failure = status_failure(429, "Too Many Requests".to_string());
+        assert!(matches!(failure.error, Error::Unreachable(_)));
+        let failure = status_failure(404, "Not Found".to_string());
+        assert!(matches!(failure.error, Error::Download(_)));
+        assert_eq!(failure.status, Some(404));
+    }
+
+    #[test]
+    fn truncated_body() {
+        let mut contents = Vec::new();
+        let mut body = Body::new(
+            &b"01234"[..],
+            "http://localhost/data/file.dcm",
+            Some(10),
+            Permit::acquire(),
+        );
+        let error = Error::from(body.read_to_end(&mut contents).unwrap_err());
+        match &error {
+            Error::Truncated {
+                name,
+                expected: 10,
+                got: 5,
+                url,
+            } => {
+                assert_eq!(url, "http://localhost/data/file.dcm");
+                assert_eq!(name, "file.dcm");
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+        assert!(error.is_transient());
+        assert_eq!(
+            error.downloading("pydicom/file.dcm").to_string(),
+            "download of pydicom/file.dcm cut short after 5 of 10 bytes \
+             from http://localhost/data/file.dcm"
+        );
+
+        // closed early according to the client
+        struct ClosedEarly;
+        impl Read for ClosedEarly {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::UnexpectedEof.into())
+            }
+        }
+        let closed = (&b"01234"[..]).chain(ClosedEarly);
+        let mut body = Body::new(closed, "http://localhost/", Some(10), Permit::acquire());
+        let error = Error::from(body.read_to_end(&mut contents).unwrap_err());
+        assert!(matches!(error, Error::Truncated { got: 5, .. }));
+    }
+
+    #[test]
+    fn rate_limit() {
+        let mut body = Body::new(
+            &[0; 1000][..],
+            "http://localhost/",
+            Some(1000),
+            Permit::acquire(),
+        );
+        body.rate = Some(2000);
+        let start = Instant::now();
+        let mut contents = Vec::new();
+        body.read_to_end(&mut contents).unwrap();
+        assert_eq!(contents.len(), 1000);
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[test]
+    fn report_progress() {
+        const URL: &str = "https://example.org/data/WG04/REF/CT1_UNC.zst";
+        static EVENTS: Mutex<Vec<(&str, u64)>> = Mutex::new(Vec::new());
+        fn handler(event: DownloadEvent) {
+            let event = match event {
+                DownloadEvent::Started { url, total } if url == URL => ("started", total.unwrap()),
+                DownloadEvent::Progress {
+                    url, downloaded, ..
+                } if url == URL => ("progress", downloaded),
+                DownloadEvent::Completed { url, downloaded } if url == URL => {
+                    ("completed", downloaded)
+                }
+                _ => return,
+            };
+            EVENTS.lock().unwrap().push(event);
+        }
+        crate::set_progress_handler(handler);
+
+        let mut body = Body::new(&b"0123456789"[..], URL, Some(10), Permit::acquire());
+        let mut buf = [0; 4];
+        while body.read(&mut buf).unwrap() > 0 {}
+        assert_eq!(body.read(&mut buf).unwrap(), 0);
+        assert_eq!(
+            *EVENTS.lock().unwrap(),
+            [
+                ("started", 10),
+                ("progress", 4),
+                ("progress", 8),
+                ("progress", 10),
+                ("completed", 10)
+            ]
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! Git LFS pointer files served in place of test files,
+//! such as by raw GitHub for files of the data repository moved to LFS
+
+#[cfg(feature = "lfs")]
+use crate::config;
+use crate::{http, Error, Result, TestFile};
+use std::io::{self, Read};
+
+/// The first line of a pointer file
+const VERSION: &str = "version https://git-lfs.github.com/spec/v1";
+/// Pointer files are smaller than this, as required by the specification
+const MAX_POINTER_SIZE: u64 = 1024;
+#[cfg(feature = "lfs")]
+const MEDIA_TYPE: &str = "application/vnd.git-lfs+json";
+#[cfg(feature = "lfs")]
+const RAW_GITHUB_URL: &str = "https://raw.githubusercontent.com/";
+
+/// An object of Git LFS, as described by its pointer file
+#[derive(Debug, PartialEq)]
+pub(crate) struct Object {
+    /// SHA-256 hash of the object
+    pub oid: String,
+    /// size of the object in bytes
+    pub size: u64,
+}
+
+/// The body of a response, told from a pointer file
+pub(crate) enum Body {
+    /// the data of the file
+    Data(Box<dyn Read + Send>),
+    /// a pointer file to the data of the file
+    Pointer(Object),
+}
+
+/// Read the start of the given body to tell whether it is a pointer file.
+///
+/// The data of a file which is not a pointer is left as it was received.
+pub(crate) fn detect(mut body: Box<dyn Read + Send>) -> io::Result<Body> {
+    let mut start = Vec::new();
+    (&mut body).take(MAX_POINTER_SIZE).read_to_end(&mut start)?;
+    if (start.len() as u64) < MAX_POINTER_SIZE {
+        if let Some(object) = std::str::from_utf8(&start).ok().and_then(parse) {
+            return Ok(Body::Pointer(object));
+        }
+    }
+    Ok(Body::Data(Box::new(io::Cursor::new(start).chain(body))))
+}
+
+/// Parse a pointer file, if it is one
+fn parse(pointer: &str) -> Option<Object> {
+    let mut lines = pointer.lines();
+    if lines.next() != Some(VERSION) {
+        return None;
+    }
+    let (mut oid, mut size) = (None, None);
+    for line in lines {
+        match line.split_once(' ')? {
+            ("oid", value) => oid = Some(value.strip_prefix("sha256:")?.to_string()),
+            ("size", value) => size = Some(value.parse().ok()?),
+            // extensions and later keys
+            _ => {}
+        }
+    }
+    Some(Object {
+        oid: oid?,
+        size: size?,
+    })
+}
+
+/// Resolve the given object of the pointer file of the given entry at the given URL
+/// through the batch API of its LFS server,
+/// and send the request of its download.
+/// Returns the URL of the object and the response.
+///
+/// Fails with [`Error::Download`] if the object is not the file of the entry
+/// or the LFS server does not provide it.
+#[cfg(feature = "lfs")]
+pub(crate) fn fetch_object(
+    file_entry: &TestFile,
+    url: &str,
+    object: &Object,
+) -> Result<(String, http::Response)> {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(url, oid = %object.oid, "Git LFS pointer");
+    let fail = |reason: String| {
+        Error::Download(format!(
+            "{} is a Git LFS pointer to {}: {}",
+            url, object.oid, reason
+        ))
+    };
+    let other_object = (file_entry.digest == crate::DigestAlgorithm::Sha256
+        && !object.oid.eq_ignore_ascii_case(file_entry.hash))
+        || (file_entry.size != 0 && object.size != file_entry.size);
+    if other_object {
+        return Err(fail(format!(
+            "not the file {} with hash {}",
+            file_entry.name, file_entry.hash
+        )));
+    }
+    let server = match config::lfs_url().or_else(|| github_lfs_url(url)) {
+        Some(server) => server,
+        None => {
+            return Err(fail(
+                "the LFS server is unknown, set it with `DICOM_TEST_FILES_LFS_URL`".to_string(),
+            ))
+        }
+    };
+
+    let batch_url = format!("{}/objects/batch", server);
+    let request = format!(
+        r#"{{"operation":"download","transfers":["basic"],"objects":[{{"oid":"{}","size":{}}}]}}"#,
+        object.oid, object.size
+    );
+    let headers = [("Accept", MEDIA_TYPE), ("Content-Type", MEDIA_TYPE)];
+    let mut batch = Vec::new();
+    http::post(&batch_url, &headers, request.as_bytes())?
+        .body
+        .read_to_end(&mut batch)?;
+    let batch: serde_json::Value = serde_json::from_slice(&batch)
+        .map_err(|e| fail(format!("invalid response from {}: {}", batch_url, e)))?;
+    let found = batch
+        .get("objects")
+        .and_then(|objects| objects.as_array())
+        .and_then(|objects| {
+            objects.iter().find(|found| {
+                found.get("oid").and_then(|oid| oid.as_str()) == Some(object.oid.as_str())
+            })
+        })
+        .ok_or_else(|| fail(format!("not listed by {}", batch_url)))?;
+    if let Some(error) = found.get("error") {
+        let message = error.get("message").and_then(|message| message.as_str());
+        return Err(fail(message.unwrap_or("not available").to_string()));
+    }
+    let download = found
+        .get("actions")
+        .and_then(|actions| actions.get("download"))
+        .ok_or_else(|| fail(format!("no download action from {}", batch_url)))?;
+    let href = download
+        .get("href")
+        .and_then(|href| href.as_str())
+        .ok_or_else(|| fail(format!("no download URL from {}", batch_url)))?;
+    let headers: Vec<(&str, &str)> = download
+        .get("header")
+        .and_then(|headers| headers.as_object())
+        .map(|headers| {
+            headers
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str(), value.as_str()?)))
+                .collect()
+        })
+        .unwrap_or_default();
+    let resp = http::get(href, &headers)?;
+    Ok((href.to_string(), resp))
+}
+
+#[cfg(not(feature = "lfs"))]
+pub(crate) fn fetch_object(
+    _file_entry: &TestFile,
+    _url: &str,
+    _object: &Object,
+) -> Result<(String, http::Response)> {
+    Err(Error::LfsRequired)
+}
+
+/// The LFS server of a GitHub repository
+/// from the raw URL of one of its files
+#[cfg(feature = "lfs")]
+fn github_lfs_url(url: &str) -> Option<String> {
+    let mut path = url.strip_prefix(RAW_GITHUB_URL)?.splitn(3, '/');
+    let (owner, repository) = (path.next()?, path.next()?);
+    Some(format!(
+        "https://github.com/{}/{}.git/info/lfs",
+        owner, repository
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        entry_path, get_data_path, path, register_registry, testing::MockServer, Registry,
+        RegistryEntry,
+    };
+    use sha2::{Digest, Sha256};
+
+    fn pointer_file(oid: &str, size: usize) -> String {
+        format!("{}\noid sha256:{}\nsize {}\n", VERSION, oid, size)
+    }
+
+    #[test]
+    fn detect_pointer_files() {
+        let read = |data: Vec<u8>| detect(Box::new(io::Cursor::new(data))).unwrap();
+        let oid = "4d7a".repeat(16);
+        match read(pointer_file(&oid, 5).into_bytes()) {
+            Body::Pointer(object) => assert_eq!(object, Object { oid, size: 5 }),
+            Body::Data(_) => panic!("pointer file not detected"),
+        }
+        // files which are not pointers are left whole
+        for data in [b"DICM".to_vec(), vec![0x28; 3000]] {
+            match read(data.clone()) {
+                Body::Data(mut body) => {
+                    let mut read = Vec::new();
+                    body.read_to_end(&mut read).unwrap();
+                    assert_eq!(read, data);
+                }
+                Body::Pointer(_) => panic!("not a pointer file"),
+            }
+        }
+        assert_eq!(parse(&format!("{}\nsize 5\n", VERSION)), None);
+        #[cfg(feature = "lfs")]
+        assert_eq!(
+            github_lfs_url(
+                "https://raw.githubusercontent.com/robyoung/dicom-test-files/master/data/WG04/REF/CT1_UNC.zst"
+            )
+            .as_deref(),
+            Some("https://github.com/robyoung/dicom-test-files.git/info/lfs")
+        );
+    }
+
+    #[test]
+    fn download_lfs_object() {
+        let contents = b"moved to LFS".to_vec();
+        let oid = format!("{:x}", Sha256::digest(&contents));
+        let server = MockServer::start().unwrap();
+        let base_url = server.url().to_string();
+        let object_path = format!("objects/{}", oid);
+        server.blob(
+            "lfs/objects/batch",
+            format!(
+                r#"{{"transfer":"basic","objects":[{{"oid":"{}","size":{},
+                    "actions":{{"download":{{"href":"{}{}","header":{{"X-Token":"t"}}}}}}}}]}}"#,
+                oid,
+                contents.len(),
+                base_url,
+                object_path
+            ),
+        );
+        server.blob(&object_path, contents.clone());
+        server.blob("lfs/moved.dcm", pointer_file(&oid, contents.len()));
+        #[cfg(feature = "lfs")]
+        crate::Config::new()
+            .lfs_url(format!("{}lfs/", base_url))
+            .apply()
+            .unwrap();
+        register_registry(Registry {
+            base_url,
+            entries: vec![RegistryEntry::new("lfs/moved.dcm", oid.clone())],
+        })
+        .unwrap();
+        // left by a run with the other features
+        let _ = std::fs::remove_file(entry_path(&get_data_path(), "lfs/moved.dcm"));
+
+        #[cfg(feature = "lfs")]
+        {
+            assert_eq!(
+                std::fs::read(path("lfs/moved.dcm").unwrap()).unwrap(),
+                b"moved to LFS"
+            );
+            let batch = server.received("lfs/objects/batch");
+            assert_eq!(batch[0].method, "POST");
+            assert!(String::from_utf8_lossy(&batch[0].body).contains(&oid));
+            assert_eq!(
+                server.received(&object_path)[0].header("x-token"),
+                Some("t")
+            );
+        }
+        #[cfg(not(feature = "lfs"))]
+        assert!(matches!(path("lfs/moved.dcm"), Err(Error::LfsRequired)));
+    }
+}
@@ -18,7 +18,107 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! To fetch many files at once,
+//! use [`prefetch`] with a [`Filter`] or a glob pattern.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), dicom_test_files::Error> {
+//! dicom_test_files::prefetch("WG04/REF/*")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`warm_in_background`] starts such a prefetch on worker threads instead,
+//! so that the downloads overlap with the tests which do not need the files.
+//!
+//! With the `http2` feature,
+//! downloads go through an HTTP/2 client instead,
+//! so that the many requests of a prefetch are multiplexed
+//! over a single connection to the data source.
+//! To show the progress of long downloads,
+//! set a handler with [`set_progress_handler`],
+//! or enable the `progress` feature
+//! for [`prefetch`] to draw progress bars on the terminal.
+//! With the `tracing` feature,
+//! fetches emit [`tracing`](https://docs.rs/tracing) spans and events
+//! with the test file names, URLs, byte counts, durations,
+//! cache hits and misses,
+//! to diagnose download issues from the logs.
+//!
+//! With the `macros` feature enabled,
+//! the [`dicom_test`] attribute fetches the files for a test
+//! and passes their paths as arguments.
+//!
+//! ```ignore
+//! use std::path::PathBuf;
+//! use dicom_test_files::dicom_test;
+//!
+//! #[dicom_test(file = "pydicom/liver.dcm")]
+//! fn open_liver(liver: PathBuf) {
+//!     let dicom_data = dicom::object::open_file(liver).unwrap();
+//! }
+//! ```
+//!
+//! Heavy files can be fetched ahead of time in a build script
+//! with [`build_support::prefetch`].
+//! Small files can also be embedded in the test binary
+//! with [`include_dicom_bytes!`],
+//! after fetching them in the build script
+//! with [`build_support::embed`].
+//!
+//! When no sample has the exact properties needed,
+//! the [`synth`] module can generate a minimal DICOM file on the fly.
+//! Whole study directories with a DICOMDIR are available through [`study`].
+//! A DICOMDIR test file is fetched along with every file it references,
+//! in the layout of the medium, by [`dicomdir`].
+//! Codec crates can test against the curated [`sets`] of each codec.
+//! Coherent series of files declared by an external manifest
+//! are fetched in instance order by [`fetch_set`].
+//!
+//! The contents of a file are returned by [`bytes`],
+//! which also runs in WebAssembly in the browser with the `wasm` feature,
+//! fetching files into memory instead of a cache directory:
+//!
+//! ```sh
+//! wasm-pack test --headless --firefox -- --no-default-features --features wasm,manifest-all
+//! ```
+//!
+//! Async test suites fetch files with [`path_async`],
+//! which does not block the executor
+//! and works the same with tokio, async-std or smol.
+//! Dropping its future, such as on a timeout, cancels the download,
+//! as does a [`CancelToken`] for blocking code.
 //! 
+//! ## Command line
+//!
+//! With the `cli` feature,
+//! the `dicom-test-files` binary fetches test files from shell scripts and Makefiles,
+//! downloading and verifying them into the cache and printing their paths,
+//! lists the available test files
+//! with their sizes and whether they are cached,
+//! verifies the cached files against their hash (`verify`),
+//! reports what takes space in the cache, by category (`stats`),
+//! removes the cache, or only its stale files (`clean --stale`),
+//! and warms the cache before a test run in CI (`prefetch`),
+//! failing if any file could not be fetched.
+//! `export-bundle` packs verified test files into one archive
+//! for [air-gapped machines](#shipping-the-cache),
+//! where `import-bundle` unpacks them into the cache.
+//! `serve` mirrors the data directory over HTTP
+//! for [`DICOM_TEST_FILES_URL`](#source-of-data).
+//! In a clone of the data repository,
+//! `add` stores a new test file under `data`,
+//! compressed if it is worth it,
+//! and lists it in the compiled entries.
+//!
+//! ```sh
+//! cargo run --features cli --bin dicom-test-files -- fetch pydicom/liver.dcm WG04/REF/CT1_UNC
+//! cargo run --features cli --bin dicom-test-files -- list --glob 'WG04/JPLL/*'
+//! cargo run --features cli --bin dicom-test-files -- prefetch --glob 'pydicom/*' --jobs 8 --max-size 50M
+//! cargo run --features cli --bin dicom-test-files -- stats --top 5
+//! ```
+//!
 //! ## Source of data
 //! 
 //! By default,
@@ -26,6 +126,22 @@
 //! the `dicom-test-files` project's [main repository][1],
 //! in the `data` folder.
 //! Inspect this folder to know what DICOM test files are available.
+//! Files in the `invalid` folder are deliberately malformed,
+//! see [`malformed`].
+//! The `golden` folder holds JSON dumps of the test files,
+//! see [`golden`].
+//!
+//! Files are downloaded as of [`DEFAULT_REF`],
+//! the `master` branch until a release of the crate
+//! is tagged along with the data it lists,
+//! from when the data used by a released version never changes.
+//! Set the environment variable `DICOM_TEST_FILES_REF`
+//! (or use [`Config::git_ref`])
+//! to download them as of another tag, branch or commit instead.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_REF=3b1f0a2 cargo test
+//! ```
 //!
 //! To override this source,
 //! you can set the environment variable `DICOM_TEST_FILES_URL`
@@ -36,26 +152,750 @@
 //! set DICOM_TEST_FILES_URL=https://raw.githubusercontent.com/Me/dicom-test-files/new/more-dicom/data
 //! cargo test
 //! ```
+//!
+//! Servers may send the files with a `gzip` content encoding,
+//! or `zstd` with the `zstd` feature,
+//! which is decoded before the file is verified and cached.
+//!
+//! On networks without internet access,
+//! `dicom-test-files serve` (with the `cli` feature)
+//! serves a clone of the data directory over HTTP,
+//! so that many machines can point `DICOM_TEST_FILES_URL` at one mirror.
+//!
+//! ```sh
+//! # on the mirror, in a clone of the data repository
+//! dicom-test-files serve --dir data --port 8080
+//! # on the runners
+//! DICOM_TEST_FILES_URL=http://mirror:8080/ cargo test
+//! ```
 //! 
+//! When the data source cannot be reached or fails to send a file,
+//! files originating from a public host, such as the pydicom test files,
+//! are downloaded from there instead,
+//! and verified against the hash of their contents.
+//!
 //! [1]: https://github.com/robyoung/dicom-test-files/tree/master/data
+//!
+//! ## Hosting backends
+//!
+//! Raw contents of the git repository can be throttled and slow
+//! for large files.
+//! Setting the environment variable `DICOM_TEST_FILES_BACKEND`
+//! to `github-releases` (or using [`Config::backend`])
+//! downloads the test files from the assets
+//! of the project's GitHub release tagged with the ref
+//! set by `DICOM_TEST_FILES_REF` (or [`DEFAULT_REF`]),
+//! with the same hash verification.
+//! As long as the default ref is a branch,
+//! the tag of a release holding the assets must be set.
+//! Each stored file is one asset,
+//! named after its path with `/` replaced by `.`
+//! (such as `WG04.REF.CT1_UNC.zst`).
+//! The default backend is `raw-git`,
+//! and `DICOM_TEST_FILES_URL` takes precedence over both.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_BACKEND=github-releases DICOM_TEST_FILES_REF=v0.4.0 cargo test
+//! ```
+//!
+//! As every test file is known by the hash of its stored data,
+//! the `content-addressed` backend downloads each of them by its hash
+//! from the gateway set by `DICOM_TEST_FILES_GATEWAY_URL`
+//! (or with [`Config::gateway_url`]),
+//! at `<gateway>/<algorithm>/<hash>`, such as `sha256/ee5cb9fa…`.
+//! This holds for all test files, including those of other sources,
+//! so any gateway holding the files is a mirror of all of them,
+//! and a file it serves either matches its hash or is rejected.
+//! See ./generate/content_addressed.py for the layout of a gateway.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_BACKEND=content-addressed \
+//! DICOM_TEST_FILES_GATEWAY_URL=https://cas.example.org/ cargo test
+//! ```
+//!
+//! ## Artifact repositories
+//!
+//! Mirrors in artifact repositories such as Artifactory or Nexus
+//! are used with the `artifact` backend,
+//! downloading the test files of this project from the URLs
+//! of the template set by `DICOM_TEST_FILES_ARTIFACT_URL`
+//! (or with [`Config::artifact_url`]),
+//! which takes the place of `DICOM_TEST_FILES_URL`.
+//! The template lays out the files as the repository holds them,
+//! replacing `{path}` with the path of a stored file
+//! (such as `WG04/REF/CT1_UNC.zst`),
+//! `{dir}` and `{file}` with its directory and file name,
+//! `{asset}` with its release asset name (such as `WG04.REF.CT1_UNC.zst`)
+//! and `{ref}` with the git ref of the data set.
+//! A template without placeholders is the base URL of the stored files.
+//!
+//! The headers set by `DICOM_TEST_FILES_ARTIFACT_HEADERS`
+//! as comma-separated `name=value` pairs
+//! (or with [`Config::artifact_header`]),
+//! such as API keys,
+//! are sent with every request to the repository,
+//! that is to the URLs starting like the template before its first placeholder.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_BACKEND=artifact \
+//! DICOM_TEST_FILES_ARTIFACT_URL='https://artifactory.example.org/artifactory/dicom/{ref}/{path}' \
+//! DICOM_TEST_FILES_ARTIFACT_HEADERS="X-JFrog-Art-Api=$ARTIFACTORY_API_KEY" cargo test
+//! ```
+//!
+//! ## Object stores
+//!
+//! A mirror of the test files in an object store
+//! is used as a data source by its URL, such as in `DICOM_TEST_FILES_URL`:
+//!
+//! - `gs://<bucket>/<prefix>/` for Google Cloud Storage,
+//!   with the `gcs` feature
+//!   and the OAuth access token set by `DICOM_TEST_FILES_GCS_TOKEN`
+//!   or [`Config::gcs_token`];
+//! - `az://<account>/<container>/<prefix>/` for Azure Blob Storage,
+//!   with the `azure` feature
+//!   and the SAS token set by `DICOM_TEST_FILES_AZURE_SAS`
+//!   or the OAuth access token set by `DICOM_TEST_FILES_AZURE_TOKEN`,
+//!   or with [`Config::azure_sas`] and [`Config::azure_token`].
+//!
+//! Without credentials, the objects are requested anonymously,
+//! as for public buckets and containers.
+//! `DICOM_TEST_FILES_GCS_ENDPOINT` and `DICOM_TEST_FILES_AZURE_ENDPOINT`
+//! (or [`Config::gcs_endpoint`] and [`Config::azure_endpoint`])
+//! point to another endpoint, such as an emulator
+//! (`http://127.0.0.1:10000/devstoreaccount1` for Azurite).
+//! Without the feature of the object store, downloads from it fail
+//! with [`Error::GcsRequired`] or [`Error::AzureRequired`].
+//!
+//! ```sh
+//! DICOM_TEST_FILES_URL=gs://ci-mirror/dicom-test-files/ \
+//! DICOM_TEST_FILES_GCS_TOKEN=$(gcloud auth print-access-token) \
+//! cargo test --features dicom-test-files/gcs
+//! ```
+//!
+//! ## Large files
+//!
+//! Files over the 100 MB limit of GitHub,
+//! such as whole slide images or videos,
+//! cannot be part of the data repository.
+//! Their entries are marked as `pointer`
+//! (in a [manifest](#external-manifests)
+//! or with [`RegistryEntry::pointer`]),
+//! and the data source holds a small pointer record in their place:
+//!
+//! ```text
+//! # dicom-test-files pointer v1
+//! url wsi/CMU-1.dcm.zst
+//! hash 3a7bd3e2360a3d29eea436fcfb7e44c735d117c42d1c1835420b6b9942dd4f1b
+//! size 1234567890
+//! ```
+//!
+//! The file is then downloaded from the URL of the record,
+//! which is either absolute or relative to the CDN
+//! set by `DICOM_TEST_FILES_CDN_URL` (or with [`Config::cdn_url`]).
+//! Without a CDN, relative URLs are release assets
+//! of the project's GitHub release, named as in the `github-releases` backend.
+//! The record must carry the hash and size of the entry,
+//! and the file is verified against its hash like any other.
+//!
+//! ## Git LFS
+//!
+//! Files of the data repository moved to [Git LFS][lfs]
+//! are served by raw GitHub as pointer files,
+//! which are detected instead of failing their hash check.
+//! With the `lfs` feature,
+//! the object of the pointer is resolved through the batch API
+//! of the LFS server of the repository
+//! and downloaded in its place,
+//! with the same hash verification.
+//! Data sources other than raw GitHub name their LFS server
+//! with `DICOM_TEST_FILES_LFS_URL` (or with [`Config::lfs_url`]).
+//! Without the feature, such files fail with [`Error::LfsRequired`].
+//!
+//! [lfs]: https://git-lfs.com
+//!
+//! ## Compiled entries
+//!
+//! The entries of the test files are compiled into the crate,
+//! grouped by the top directory of the data set
+//! behind the cargo features `manifest-pydicom`, `manifest-wg04`
+//! and `manifest-invalid` (see [`malformed`]).
+//! All of them are enabled by default through `manifest-all`.
+//! Crates using only some groups can compile fewer entries,
+//! names of the other groups are then not found:
+//!
+//! ```toml
+//! [dev-dependencies.dicom-test-files]
+//! version = "0.3"
+//! default-features = false
+//! features = ["zstd", "manifest-pydicom"]
+//! ```
+//!
+//! Tests ported from pydicom can refer to its test files
+//! by the names given to `get_testdata_file`, see [`pydicom_name`]:
+//!
+//! ```
+//! let path = dicom_test_files::path(dicom_test_files::pydicom_name("MR_small.dcm")?)?;
+//! # let _ = path;
+//! # Ok::<(), dicom_test_files::Error>(())
+//! ```
+//!
+//! ## Hosted collections
+//!
+//! Some collections of test files are hosted by their own projects
+//! and downloaded from there,
+//! pinned to the hashes of their files like the others.
+//! Their entries are compiled in behind features of their own,
+//! which are not part of `manifest-all`:
+//!
+//! - `manifest-gdcm`: the [GDCM test data][gdcm], as `gdcm/…`
+//!
+//! - `manifest-clunie`: a curated subset of the [compression test images][clunie]
+//!   of David Clunie, as `clunie/…`
+//!
+//! The base URL of a collection is overridden
+//! with the environment variable `DICOM_TEST_FILES_<NAME>_URL`,
+//! such as `DICOM_TEST_FILES_GDCM_URL` for a mirror of the GDCM test data,
+//! or with [`Config::collection_url`].
+//!
+//! ```no_run
+//! let path = dicom_test_files::path("gdcm/CT-MONO2-16-ort.dcm")?;
+//! # let _ = path;
+//! # Ok::<(), dicom_test_files::Error>(())
+//! ```
+//!
+//! [gdcm]: https://github.com/malaterre/GDCM-data
+//! [clunie]: https://www.dclunie.com/images/compressed/
+//!
+//! ## Provenance and licenses
+//!
+//! Each test file records where it comes from
+//! and under what license it may be redistributed,
+//! see [`provenance`].
+//! [`license_report`] aggregates the licenses of a selection of files,
+//! such as those used by a test suite,
+//! to answer the questions of a compliance review:
+//!
+//! ```
+//! for usage in dicom_test_files::license_report("pydicom/**")? {
+//!     println!("{}: {} files", usage.license.unwrap_or("unknown"), usage.files.len());
+//! }
+//! # Ok::<(), dicom_test_files::Error>(())
+//! ```
+//!
+//! Test files also record whether they are fully anonymized,
+//! synthetic or samples published by a vendor.
+//! Test suites bound by strict data policies
+//! can restrict themselves to vetted files with [`Filter::anonymized`],
+//! or check their selection with [`assert_anonymized`]:
+//!
+//! ```
+//! dicom_test_files::assert_anonymized("pydicom/**");
+//! ```
+//!
+//! ## External manifests
+//!
+//! Test files which are not part of this collection,
+//! such as private corpora or files for a quick local experiment,
+//! can be listed in a JSON or TOML manifest,
+//! loaded at run time without rebuilding the crate.
+//! Its path is set with the environment variable `DICOM_TEST_FILES_MANIFEST`
+//! or with [`Config::manifest`],
+//! and its format is determined by the file extension
+//! (`.json` with the `json` feature, `.toml` with the `toml` feature).
+//!
+//! ```toml
+//! # optional, the default data source otherwise
+//! base_url = "https://example.org/private-dicom/"
+//!
+//! [[files]]
+//! name = "myorg/weird.dcm"
+//! # SHA-256 hash of the file as stored
+//! hash = "0f8e3c5c0bd2b4b5fd0a1e7ab4bbd1a3c14fbc7e9f48e0937e8a537c21ef4b3a"
+//! # optional, the algorithm of the hash: "sha256", "sha512" or "blake3"
+//! # ("blake3" requires the feature of the same name)
+//! digest = "sha256"
+//! # optional, in bytes
+//! size = 5824
+//! # optional, "none", "zstd", "gzip" or "xz"
+//! # (stored with a .zst, .gz or .xz extension,
+//! # "gzip" and "xz" require the features of the same name)
+//! compression = "none"
+//! # optional, the SHA-256 hash of the file once decompressed,
+//! # checked after decompression
+//! # decompressed_hash = "…"
+//! # optional, where the file comes from and its license,
+//! # see `dicom_test_files::provenance`
+//! source = "weird files of myorg"
+//! source_url = "https://example.org/weird"
+//! license = "CC-BY-4.0"
+//! # optional, "fully-anonymized", "synthetic" or "vendor-sample",
+//! # see `dicom_test_files::assert_anonymized`
+//! anonymization = "synthetic"
+//! # optional, the URL of the file at the canonical host it originates from,
+//! # tried when the base URL fails
+//! # (the file as is, checked against `decompressed_hash` if it is compressed)
+//! upstream_url = "https://example.org/weird/weird.dcm"
+//! # optional, whether the base URL holds a pointer record of the file
+//! # instead of its data, see "Large files"
+//! pointer = false
+//! # optional, the SOP class of the file, see `dicom_test_files::by_sop_class`
+//! sop_class_uid = "1.2.840.10008.5.1.4.1.1.2"
+//! # optional, the multi-file fixture of the file,
+//! # see `dicom_test_files::fetch_set`, ordered by instance number
+//! set = "weird_series"
+//! instance_number = 1
+//!
+//! # other collections, each with their own base URL and files
+//! [sources.gdcm]
+//! base_url = "https://example.org/gdcm-mirror/"
+//!
+//! [[sources.gdcm.files]]
+//! name = "gdcm/CT-MONO2-16-ort.dcm"
+//! hash = "5ae1f3a9e6fd66bc43da5c4ba2dc2f8bd1a5e4f38e55e5c06a50de3d534c1b5f"
+//! ```
+//!
+//! Each file is downloaded from the base URL of its own source.
+//! With the `zip` feature,
+//! files can also be members of a ZIP archive shared by several entries,
+//! which is downloaded once:
+//!
+//! ```toml
+//! [[files]]
+//! name = "myorg/ct.dcm"
+//! # SHA-256 hash of the member once extracted
+//! hash = "7b0d7d6a3b1d2a4f8e3c5c0bd2b4b5fd0a1e7ab4bbd1a3c14fbc7e9f48e0937e"
+//! compression = "zip"
+//! # stored name of the archive
+//! archive = "collection.zip"
+//! # optional, the name of the file otherwise
+//! member = "images/ct.dcm"
+//! ```
+//!
+//! With the `dicomweb` feature,
+//! files can also be instances of a DICOMweb server,
+//! retrieved with WADO-RS from the base URL of their source,
+//! such as `https://pacs.example.org/dicom-web/`:
+//!
+//! ```toml
+//! [[files]]
+//! name = "hospital/ct.dcm"
+//! # SHA-256 hash of the instance as retrieved
+//! hash = "7b0d7d6a3b1d2a4f8e3c5c0bd2b4b5fd0a1e7ab4bbd1a3c14fbc7e9f48e0937e"
+//! # optional when the UIDs are given
+//! compression = "dicomweb"
+//! study_uid = "1.2.826.0.1.3680043.8.498.1"
+//! series_uid = "1.2.826.0.1.3680043.8.498.1.1"
+//! instance_uid = "1.2.826.0.1.3680043.8.498.1.1.1"
+//! ```
+//!
+//! With the `orthanc` feature,
+//! `orthanc::Orthanc` pulls sample studies from the REST API of an Orthanc server
+//! into the cache and writes the manifest of their instances,
+//! which turns an in-house demo server into a local corpus pinned by hash.
+//!
+//! The files of the manifest are fetched, verified and cached
+//! like the others, through [`path`] and [`prefetch`].
+//! Hashing dominates the time to verify large files on fast links,
+//! for which BLAKE3 is several times faster than SHA-256.
+//! They take precedence over the files compiled into the crate
+//! with the same name.
+//! Other crates can also add their own test files programmatically
+//! with [`register_registry`].
+//! Conversely, [`export_manifest`] writes all known test files
+//! to a manifest in the same format, for use by other tools.
+//!
+//! ## Files added after a release
+//!
+//! The list of test files is compiled into the crate,
+//! so files added to the data repository since
+//! are only known to newer versions of `dicom_test_files`.
+//! With the `remote-manifest` feature enabled,
+//! setting the environment variable `DICOM_TEST_FILES_REMOTE_MANIFEST` to `1`
+//! makes [`path`] look up unknown names
+//! in the manifest of the data source (`manifest.txt`).
+//! The manifest is fetched once per process
+//! and must carry a valid signature by the project's key,
+//! so a custom `DICOM_TEST_FILES_URL` cannot introduce unverified files.
+//! The project has no signing key yet,
+//! so remote manifests are refused with [`Error::Manifest`]
+//! until a release of the crate embeds one.
+//! Entries compiled into the crate always take precedence.
+//!
+//! ## Signatures
+//!
+//! The hashes of the test files protect their integrity,
+//! but only as far as the hashes themselves can be trusted.
+//! Those compiled into the crate come with it,
+//! and the remote manifest is to be signed with the project's Ed25519 key.
+//! With the `signatures` feature,
+//! an [external manifest](#external-manifests) can be signed as well,
+//! with the signature in hexadecimal in a file of the same name
+//! with a `.sig` extension
+//! (see `generate/manifest.py sign` in the repository).
+//! A manifest with a signature which is not valid fails to load.
+//! No key is trusted by default:
+//! the keys of the signers are listed in hexadecimal
+//! in `DICOM_TEST_FILES_TRUSTED_KEYS`, separated by commas,
+//! or given with [`Config::trusted_key`].
+//!
+//! Setting `DICOM_TEST_FILES_REQUIRE_SIGNATURES` to `1`
+//! (or using [`Config::require_signatures`])
+//! refuses test files from sources which are not signed,
+//! that is external manifests without a signature
+//! and [registries](register_registry),
+//! with [`Error::Manifest`].
+//!
+//! ```sh
+//! DICOM_TEST_FILES_MANIFEST=tests/private_files.toml \
+//! DICOM_TEST_FILES_TRUSTED_KEYS=03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8 \
+//! DICOM_TEST_FILES_REQUIRE_SIGNATURES=1 cargo test
+//! ```
+//!
+//! ## Bundles
+//!
+//! Whole directories of test files, such as `WG04/JPLL`,
+//! can also be published as single `.tar.zst` archives
+//! (see `generate/bundles.py` in the repository),
+//! which are then listed in the crate along with their hash.
+//! With the `bundles` feature (enabled by default),
+//! [`prefetch`] downloads the bundle of a directory
+//! when it needs more than one of its files,
+//! verifies it and unpacks these files into the cache,
+//! where [`path`] then finds them.
+//! Bundles are served by the `github-releases` backend
+//! and by custom data sources under `bundles/`;
+//! when a bundle is not available,
+//! its files are downloaded one by one.
+//!
+//! ## Shipping the cache
+//!
+//! To run tests on a machine without network access,
+//! such as the target of a cross-compiled test suite,
+//! write the files they need into a tar archive with [`bundle_cache`],
+//! then unpack it into the cache of that machine with [`unbundle_cache`].
+//! The files are verified before they are written,
+//! and checked on the way in against the manifest of hashes
+//! at the start of the archive.
+//! From the command line, `export-bundle` writes such an archive,
+//! compressed with zstd if its name ends with `.zst`,
+//! and `import-bundle` unpacks it.
+//!
+//! ```sh
+//! # on the host
+//! cargo run --features cli --bin dicom-test-files -- export-bundle --glob 'WG04/**' --out corpus.tar.zst
+//! # on the target
+//! dicom-test-files import-bundle corpus.tar.zst
+//! ```
+//!
+//! ## Lockfile
+//!
+//! To audit which test data a run used,
+//! set the environment variable `DICOM_TEST_FILES_LOCK` to `record`:
+//! each test file used is then recorded in `dicom-test-files.lock`
+//! next to the `target` directory,
+//! with its hash and the URL it is downloaded from.
+//! Commit this file and set `DICOM_TEST_FILES_LOCK` to `verify` in CI,
+//! so that test files which are not recorded,
+//! or have changed upstream,
+//! fail with [`Error::LockMismatch`].
+//! `DICOM_TEST_FILES_LOCKFILE` sets another path for the lockfile,
+//! which is required outside of a Cargo build without a `target` directory.
+//! Both are also available through [`Config`].
+//!
+//! ```sh
+//! DICOM_TEST_FILES_LOCK=record cargo test
+//! DICOM_TEST_FILES_LOCK=verify cargo test
+//! ```
+//!
+//! ## Cache location
+//!
+//! Test files are cached in `dicom_test_files`
+//! in the `target` directory containing the running binary.
+//! Binaries outside of a `target` directory,
+//! such as under Bazel, Buck or Miri, or copied to another machine,
+//! cache them in `dicom_test_files` in `CARGO_TARGET_DIR` if it is set,
+//! and otherwise in `dicom-test-files` in the cache directory of the user:
+//! `$XDG_CACHE_HOME` or `~/.cache`,
+//! `~/Library/Caches` on macOS
+//! and `%LOCALAPPDATA%` on Windows.
+//!
+//! When the cache directory cannot be written to,
+//! such as a read-only `target` directory in a Nix sandbox,
+//! test files are cached in the directory set by
+//! the environment variable `DICOM_TEST_FILES_FALLBACK_DIR`
+//! (or [`Config::fallback_dir`]) instead,
+//! and otherwise in the cache directory of the user
+//! or the temporary directory, whichever can be written to.
+//!
+//! Threads and processes sharing the cache download each file once,
+//! the others waiting for the download in progress.
+//! Files only enter the cache once complete and verified,
+//! and the download of a process which died
+//! is taken over after 20 seconds.
+//! Temporary files of downloads left over in the cache by crashed runs
+//! are removed before the first download of each process,
+//! or on demand with [`clean_temp`].
+//!
+//! ## Cache namespaces
+//!
+//! Test binaries sharing the cache see the same files,
+//! so a test which writes to or removes a cached file
+//! can break the tests of another binary.
+//! Setting the environment variable `DICOM_TEST_FILES_NAMESPACE` to `1`
+//! (or using [`Config::namespace`]) gives each test binary a view of its own,
+//! in `.views/<binary>` in the cache,
+//! where [`path`] and the other functions returning paths
+//! link to the shared cached files,
+//! so that the files are still downloaded and stored once.
+//! A file removed or replaced in a view is linked again on its next use,
+//! and the shared files are made read-only
+//! so that they cannot be written to through their links
+//! (except by root, which bypasses permissions).
+//! File systems without hard links get copies instead.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_NAMESPACE=1 cargo test --workspace
+//! ```
+//!
+//! ## Compressed cache
+//!
+//! Test files stored with compression are decompressed into the cache,
+//! which can take a lot of disk space on CI runners.
+//! Setting the environment variable `DICOM_TEST_FILES_KEEP_COMPRESSED` to `1`
+//! (or using [`Config::keep_compressed`]) keeps them compressed instead,
+//! under their stored name such as `WG04/REF/CT1_UNC.zst`.
+//! [`reader`] then decompresses them as they are read,
+//! and [`prefetch`] only downloads their compressed copy,
+//! while [`path`] still decompresses them into the cache on demand
+//! for the APIs which need a file.
+//! Whether the cache keeps them compressed or not,
+//! [`raw_path`] fetches the stored file itself.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_KEEP_COMPRESSED=1 cargo test
+//! ```
+//!
+//! ## Revalidation
+//!
+//! Cached test files are used as is by default.
+//! Set the environment variable `DICOM_TEST_FILES_REFRESH` to `1`
+//! (or use [`Config::refresh`])
+//! to confirm with the data source that each cached file is current,
+//! once per process, before using it.
+//! The ETag sent by the data source is kept along with each cached file,
+//! so that revalidating only downloads files which changed.
+//!
+//! ## Download limit
+//!
+//! At most 4 files are downloaded at the same time in the process
+//! (16 with the `http2` feature),
+//! however many tests fetch files in parallel,
+//! so as not to trip the rate limits of the data source.
+//! Set the environment variable `DICOM_TEST_FILES_MAX_DOWNLOADS`
+//! (or use [`Config::max_downloads`]) to change this limit.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_MAX_DOWNLOADS=1 cargo test
+//! ```
+//!
+//! To keep test prefetches from saturating the uplink of shared CI runners,
+//! set the environment variable `DICOM_TEST_FILES_MAX_RATE`
+//! to the maximum number of bytes per second
+//! across all downloads of the process,
+//! with a `K`, `M` or `G` suffix if needed
+//! (or use [`Config::max_rate`]).
+//!
+//! ```sh
+//! DICOM_TEST_FILES_MAX_RATE=2M cargo test
+//! ```
+//!
+//! ## Download report
+//!
+//! Every attempt to download a test file is recorded,
+//! with its URL, status code, duration, size and hash check,
+//! and is listed by [`download_report`].
+//! Set the environment variable `DICOM_TEST_FILES_REPORT` to a file path
+//! to append each attempt to that file as a line of JSON
+//! (requires the `json` feature),
+//! such as to keep it as an artifact of a failed CI job.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_REPORT=target/dicom-test-files-report.jsonl cargo test
+//! ```
+//!
+//! ## Chaos mode
+//!
+//! To check that retries and skips around this crate work
+//! before real network trouble hits CI,
+//! set the environment variable `DICOM_TEST_FILES_CHAOS`
+//! (or use [`Config::chaos`])
+//! to fail or stall a percentage of downloads on purpose.
+//! Failed downloads return [`Error::Unreachable`],
+//! and stalled ones wait for `stall-time` (5 seconds by default)
+//! before going on.
+//! Downloads are picked at random,
+//! or the same ones in each run given a `seed`.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_CHAOS=fail=20,stall=10,stall-time=2s,seed=42 cargo test
+//! ```
+//!
+//! ## Testing the download path
+//!
+//! With the `testing` feature,
+//! `testing::MockServer` serves fixture blobs from a local HTTP server
+//! with scripted failures,
+//! such as error statuses, slow responses and corrupt or truncated bodies,
+//! for deterministic tests of the download path
+//! in this crate and in crates wrapping it.
+//!
+//! ## Size limit
+//!
+//! Some test files weigh tens of megabytes.
+//! To keep them out of CI pipelines,
+//! set the environment variable `DICOM_TEST_FILES_MAX_SIZE`
+//! to the maximum size of a file to download,
+//! in bytes or with a `K`, `M` or `G` suffix
+//! (or use [`Config::max_size`]).
+//! Larger files which are not cached yet fail with [`Error::TooLarge`],
+//! are skipped by [`path_or_skip`] and [`prefetch`],
+//! and can be left out upfront by selecting a [`Tier`].
+//!
+//! ```sh
+//! DICOM_TEST_FILES_MAX_SIZE=1M cargo test
+//! ```
+//!
+//! Reading a large multi-frame file into memory
+//! can exhaust the memory of small runners as well.
+//! [`bytes`] fails with [`Error::TooLargeForMemory`]
+//! for files over 512 MiB,
+//! or over the limit set by the environment variable `DICOM_TEST_FILES_MAX_IN_MEMORY`
+//! (or [`Config::max_in_memory`]),
+//! and [`size`] tells the size of a file beforehand,
+//! so that larger files can be streamed with [`reader`] instead.
+//!
+//! ## Cache quota
+//!
+//! To keep the cache of a constrained CI container within bounds,
+//! set the environment variable `DICOM_TEST_FILES_MAX_CACHE_BYTES`
+//! to the maximum size of the cache,
+//! in bytes or with a `K`, `M` or `G` suffix
+//! (or use [`Config::max_cache_bytes`]).
+//! Downloads which would take the cache over it
+//! fail with [`Error::QuotaExceeded`],
+//! telling which files to clean or exclude,
+//! and cached files are never evicted to make room.
+//!
+//! ```sh
+//! DICOM_TEST_FILES_MAX_CACHE_BYTES=500M cargo test
+//! ```
 
 #![deny(missing_docs)]
 
 use sha2::{Digest, Sha256};
-use test_file::{TestFile, Compression};
 use std::{
     borrow::Cow,
     env::{self, VarError},
-    fs, io,
+    fmt, fs, io,
     path::{Path, PathBuf},
+    sync::Mutex,
 };
 
+mod archive;
+mod backend;
+mod bars;
+mod bench;
+mod bundle;
+mod cache;
+mod cache_bundle;
+mod cancel;
+#[cfg(any(not(target_arch = "wasm32"), feature = "wasm"))]
+mod bytes;
+mod catalog;
+mod chaos;
+mod config;
+mod corpus;
+mod corrupt;
+mod dicomdir;
+mod dicomweb;
+mod digest;
+mod download_lock;
+mod encoding;
 mod entries;
+mod filter;
+mod fixture;
+mod http;
+mod lfs;
+mod lock;
+mod manifest;
+mod namespace;
+mod object_store;
+mod pointer;
+mod prefetch;
+mod progress;
+mod provenance;
+mod registry;
+mod remote;
+mod report;
+mod revalidate;
+mod signature;
+mod space;
+mod study;
+mod suggest;
+#[cfg(not(target_arch = "wasm32"))]
+mod unblock;
+mod upstream;
 
+pub mod build_support;
+#[cfg(all(feature = "orthanc", not(target_arch = "wasm32")))]
+pub mod orthanc;
+pub mod sets;
+pub mod synth;
+#[cfg(all(any(test, feature = "testing"), not(target_arch = "wasm32")))]
+pub mod testing;
 pub(crate) mod test_file;
 
+pub use backend::Backend;
+pub use bench::bench_data;
+#[cfg(any(not(target_arch = "wasm32"), feature = "wasm"))]
+pub use bytes::bytes;
+pub use cache::{
+    cache_dir, cached_path, clean_temp, clear_cache, remove_stale_files, verify_cache,
+    verify_cache_with,
+};
+pub use cache_bundle::{bundle_cache, unbundle_cache};
+pub use cancel::CancelToken;
+pub use catalog::{
+    by_sop_class, by_transfer_syntax, coverage_by_transfer_syntax, entries, group, info,
+    malformed, pixel_hash, pydicom_name, query, tier, with_feature, Query,
+};
+pub use chaos::Chaos;
+pub use config::Config;
+pub use corpus::export_corpus;
+pub use corrupt::{corrupted_copy, CorruptionKind};
+pub use dicomdir::dicomdir;
+pub use filter::Filter;
+pub use fixture::{fetch_set, set_names};
+pub use lock::LockMode;
+pub use manifest::{export_manifest, Format};
+pub use prefetch::{
+    prefetch, warm_in_background, FetchOutcome, Prefetch, PrefetchSummary, Progress, WarmHandle,
+};
+pub use progress::{set_progress_handler, DownloadEvent};
+pub use provenance::{assert_anonymized, license_report, provenance, LicenseUsage};
+pub use registry::{register_registry, Registry, RegistryEntry};
+pub use report::{download_report, write_download_report, FetchAttempt, HashCheck};
+pub use study::{study, study_names};
+pub use test_file::{
+    Anonymization, Collection, Compression, Defect, DigestAlgorithm, Feature, Provenance,
+    TestFile, TestFileInfo, Tier,
+};
+
+#[cfg(feature = "macros")]
+pub use dicom_test_files_macros::dicom_test;
 
-use entries::FILE_ENTRIES;
+
+use entries::{FILE_ENTRIES, GOLDEN_ENTRIES};
 
 /// Error type for test_dicom_files
 #[derive(Debug)]
@@ -63,46 +903,804 @@ pub enum Error {
     /// Returned when the provided name does not exist in the hash list
     ///
     /// If you are sure it does exist you may need to update to a newer version dicom_test_files.
-    NotFound,
+    NotFound {
+        /// the requested name
+        name: String,
+        /// the closest known names, closest first
+        suggestions: Vec<String>,
+        /// the number of known names
+        available: usize,
+    },
     /// Returned when the hash of the downloaded file does not match the previously generated hash
     ///
     /// This may mean you need to update to a newer version of dicom_test_files.
-    InvalidHash,
+    InvalidHash {
+        /// the name of the file
+        name: String,
+        /// the expected SHA-256 hash
+        expected: String,
+        /// the SHA-256 hash of the file
+        actual: String,
+        /// the size of the file in bytes
+        bytes: u64,
+        /// the URL the file was downloaded from,
+        /// or `None` for a cached copy
+        url: Option<String>,
+    },
     /// Returned when the file cannot be downloaded. Contains the generated URL.
     Download(String),
+    /// Returned when the data source could not be reached,
+    /// such as when the network is unavailable,
+    /// or responded with a server error or rate limiting status.
+    /// Contains the generated URL.
+    Unreachable(String),
+    /// Returned when the data source closed the connection
+    /// before sending the whole file
+    Truncated {
+        /// the name of the file
+        name: String,
+        /// the size announced by the data source, in bytes
+        expected: u64,
+        /// the number of bytes received
+        got: u64,
+        /// the URL the file was downloaded from
+        url: String,
+    },
+    /// Returned when the download was cancelled,
+    /// see [`CancelToken`]
+    Cancelled,
+    /// Returned before downloading a file
+    /// when the file system of the cache does not have room for it
+    InsufficientSpace {
+        /// the number of bytes needed for the file
+        needed: u64,
+        /// the number of bytes available
+        available: u64,
+    },
+    /// Returned before downloading a file
+    /// when the cache would then hold more than its quota,
+    /// set by `DICOM_TEST_FILES_MAX_CACHE_BYTES`
+    QuotaExceeded {
+        /// the name of the file
+        name: String,
+        /// the number of bytes needed for the file
+        needed: u64,
+        /// the number of bytes in the cache
+        used: u64,
+        /// the quota of the cache, in bytes
+        quota: u64,
+    },
+    /// Returned by [`bytes`] for a file larger than
+    /// the limit of the files read into memory,
+    /// set by `DICOM_TEST_FILES_MAX_IN_MEMORY`
+    TooLargeForMemory {
+        /// the name of the file
+        name: String,
+        /// the size of the file in bytes
+        size: u64,
+        /// the limit of the files read into memory, in bytes
+        max: u64,
+    },
     /// Wrapped errors from std::io
     Io(io::Error),
     /// Failed to resolve data source URL
     ResolveUrl(VarError),
     /// Feature "zstd" is required for this file 
     ZstdRequired,
+    /// Feature "zip" is required for this file
+    ZipRequired,
+    /// Feature "dicomweb" is required for this file
+    DicomWebRequired,
+    /// Feature "gzip" is required for this file
+    GzipRequired,
+    /// Feature "xz" is required for this file
+    XzRequired,
+    /// Feature "tar" is required for this operation
+    TarRequired,
+    /// Feature "blake3" is required for this file
+    Blake3Required,
+    /// Feature "lfs" is required for this file,
+    /// which the data source serves as a Git LFS pointer file
+    LfsRequired,
+    /// Feature "gcs" is required for `gs://` data sources
+    GcsRequired,
+    /// Feature "azure" is required for `az://` data sources
+    AzureRequired,
+    /// Returned when the file is larger than the size limit
+    /// set by `DICOM_TEST_FILES_MAX_SIZE`.
+    /// Contains the name of the file and the limit.
+    TooLarge(String),
+    /// Returned when a manifest or registry is malformed,
+    /// or when the signature of the remote manifest is not valid.
+    /// Contains the reason.
+    Manifest(String),
+    /// Returned in the `verify` lock mode
+    /// when a test file is not in the lockfile or differs from it,
+    /// or when the lockfile is malformed.
+    /// Contains the reason.
+    LockMismatch(String),
+}
+
+impl Error {
+    /// Whether the error may go away by trying again later,
+    /// such as network failures, timeouts, server errors and rate limiting,
+    /// as opposed to permanent errors
+    /// such as unknown files or hash mismatches.
+    ///
+    /// ```no_run
+    /// # fn main() -> Result<(), dicom_test_files::Error> {
+    /// let path = match dicom_test_files::path("pydicom/liver.dcm") {
+    ///     Err(e) if e.is_transient() => dicom_test_files::path("pydicom/liver.dcm")?,
+    ///     result => result?,
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Error::Unreachable(_) | Error::Truncated { .. } => true,
+            Error::Io(e) => matches!(
+                e.kind(),
+                io::ErrorKind::TimedOut
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::Interrupted
+                    | io::ErrorKind::UnexpectedEof
+            ),
+            _ => false,
+        }
+    }
+}
+
+impl Error {
+    /// Attach the URL of a downloaded file to its hash mismatch
+    pub(crate) fn downloaded_from(self, source: &str) -> Error {
+        match self {
+            Error::InvalidHash {
+                name,
+                expected,
+                actual,
+                bytes,
+                url: None,
+            } => Error::InvalidHash {
+                name,
+                expected,
+                actual,
+                bytes,
+                url: Some(source.to_string()),
+            },
+            e => e,
+        }
+    }
+
+    /// Attach the name of the test file being downloaded
+    /// to a download cut short
+    pub(crate) fn downloading(self, name: &str) -> Error {
+        match self {
+            Error::Truncated {
+                expected, got, url, ..
+            } => Error::Truncated {
+                name: name.to_string(),
+                expected,
+                got,
+                url,
+            },
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound {
+                name,
+                suggestions,
+                available,
+            } => {
+                write!(f, "unknown DICOM test file {:?}", name)?;
+                if !suggestions.is_empty() {
+                    let suggestions: Vec<_> = suggestions
+                        .iter()
+                        .map(|suggestion| format!("{:?}", suggestion))
+                        .collect();
+                    write!(f, ", did you mean {}?", suggestions.join(" or "))?;
+                } else {
+                    f.write_str(",")?;
+                }
+                write!(
+                    f,
+                    " {} test files are available, \
+                     listed by `dicom_test_files::export_manifest`",
+                    available
+                )
+            }
+            Error::InvalidHash {
+                name,
+                expected,
+                actual,
+                bytes,
+                url,
+            } => {
+                write!(
+                    f,
+                    "{} does not match its hash, expected {} but got {} for {} bytes",
+                    name, expected, actual, bytes
+                )?;
+                match url {
+                    Some(url) => write!(f, " downloaded from {}", url),
+                    None => f.write_str(" in the cache"),
+                }
+            }
+            Error::Download(reason)
+            | Error::Unreachable(reason)
+            | Error::TooLarge(reason)
+            | Error::Manifest(reason)
+            | Error::LockMismatch(reason) => f.write_str(reason),
+            Error::Truncated {
+                name,
+                expected,
+                got,
+                url,
+            } => write!(
+                f,
+                "download of {} cut short after {} of {} bytes from {}",
+                name, got, expected, url
+            ),
+            Error::Cancelled => f.write_str("download cancelled"),
+            Error::InsufficientSpace { needed, available } => write!(
+                f,
+                "not enough free space in the cache, {} bytes are needed but {} are available",
+                needed, available
+            ),
+            Error::QuotaExceeded {
+                name,
+                needed,
+                used,
+                quota,
+            } => write!(
+                f,
+                "downloading {} ({} bytes) would exceed the cache quota of {} bytes, \
+                 with {} bytes cached already; \
+                 remove unused files with `dicom_test_files::remove_stale_files` \
+                 or `clear_cache`, or leave large files out \
+                 with `DICOM_TEST_FILES_MAX_SIZE` or a `Tier`",
+                name, needed, quota, used
+            ),
+            Error::TooLargeForMemory { name, size, max } => write!(
+                f,
+                "{} has {} bytes, over the limit of {} bytes of the files read into memory; \
+                 stream it with `dicom_test_files::reader` \
+                 or map the file at `dicom_test_files::path` into memory instead, \
+                 or raise the limit with `DICOM_TEST_FILES_MAX_IN_MEMORY`",
+                name, size, max
+            ),
+            Error::Io(_) => f.write_str("I/O error"),
+            Error::ResolveUrl(_) => f.write_str("failed to resolve the data source URL"),
+            Error::ZstdRequired => f.write_str("feature \"zstd\" is required for this file"),
+            Error::ZipRequired => f.write_str("feature \"zip\" is required for this file"),
+            Error::DicomWebRequired => {
+                f.write_str("feature \"dicomweb\" is required for this file")
+            }
+            Error::GzipRequired => f.write_str("feature \"gzip\" is required for this file"),
+            Error::XzRequired => f.write_str("feature \"xz\" is required for this file"),
+            Error::TarRequired => f.write_str("feature \"tar\" is required for this operation"),
+            Error::Blake3Required => f.write_str("feature \"blake3\" is required for this file"),
+            Error::LfsRequired => f.write_str(
+                "the data source serves this file as a Git LFS pointer, \
+                 feature \"lfs\" is required to download it",
+            ),
+            Error::GcsRequired => {
+                f.write_str("feature \"gcs\" is required for gs:// data sources")
+            }
+            Error::AzureRequired => {
+                f.write_str("feature \"azure\" is required for az:// data sources")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::ResolveUrl(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        if let Some(truncated) = err.get_ref().and_then(|e| e.downcast_ref::<http::Truncated>()) {
+            // named after the URL until the name of the test file is attached
+            let name = truncated.url.rsplit('/').next().unwrap_or_default();
+            return Error::Truncated {
+                name: name.to_string(),
+                expected: truncated.expected,
+                got: truncated.got,
+                url: truncated.url.clone(),
+            };
+        }
+        match err.get_ref().and_then(|e| e.downcast_ref::<cancel::Cancelled>()) {
+            Some(_) => Error::Cancelled,
+            None => Error::Io(err),
+        }
+    }
+}
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+pub(crate) fn lookup(name: &str) -> Option<&'static TestFile> {
+    FILE_ENTRIES.iter().find(|entry| entry.name == name)
+}
+
+/// Look up a compiled entry by name,
+/// failing with the closest known names
+pub(crate) fn find_entry(name: &str) -> Result<&'static TestFile> {
+    lookup(name)
+        .ok_or_else(|| suggest::not_found(name, FILE_ENTRIES.iter().map(|entry| entry.name)))
+}
+
+/// Look up a test file by name,
+/// in the external manifest, the registered registries
+/// and then in the compiled entries,
+/// using the remote manifest for names unknown to this version
+/// if it is enabled.
+pub(crate) fn resolve(name: &str) -> Result<&'static TestFile> {
+    let external = config::manifest_entries()?;
+    if let Some(entry) = external.iter().find(|entry| entry.name == name) {
+        return Ok(entry);
+    }
+    if let Some(entry) = registry::entries()?.into_iter().find(|entry| entry.name == name) {
+        return Ok(entry);
+    }
+    if let Some(entry) = lookup(name) {
+        return Ok(entry);
+    }
+    if remote::enabled() {
+        if let Some(entry) = remote::lookup(name)? {
+            return Ok(entry);
+        }
+    }
+    Err(suggest::not_found(
+        name,
+        all_entries()?.into_iter().map(|entry| entry.name),
+    ))
+}
+
+/// All known test file entries,
+/// those of the external manifest first,
+/// then those of the registered registries.
+pub(crate) fn all_entries() -> Result<Vec<&'static TestFile>> {
+    let external = config::manifest_entries()?;
+    let others = registry::entries()?
+        .into_iter()
+        .chain(FILE_ENTRIES)
+        .filter(|entry| !external.iter().any(|e| e.name == entry.name));
+    Ok(external.iter().chain(others).collect())
+}
+
+/// Fetch a DICOM file by its relative path (`name`)
+/// if it has not been downloaded yet,
+/// and return its path in the local file system.
+///
+/// This function will download and cache the file locally in
+/// `target/dicom_test_files`,
+/// or elsewhere outside of a Cargo build, see the [cache location](crate#cache-location).
+pub fn path(name: &str) -> Result<PathBuf, Error> {
+    let entry = resolve(name)?;
+    let (cached_path, _) = fetch(entry)?;
+    namespace::view(entry.name, cached_path)
+}
+
+/// Fetch a DICOM file by its relative path (`name`) like [`path`]
+/// from async code, and return its path in the local file system.
+///
+/// The file is fetched on a thread of its own,
+/// without blocking the executor of the awaiting task.
+/// Dropping the future before it completes,
+/// such as on a timeout, cancels the download,
+/// see [`CancelToken`].
+/// The future only relies on the standard library,
+/// so that it can be awaited from tokio, async-std, smol
+/// or any other executor.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), dicom_test_files::Error> {
+/// let path = dicom_test_files::path_async("pydicom/liver.dcm").await?;
+/// # let _ = path;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn path_async(name: &str) -> Result<PathBuf> {
+    let name = name.to_string();
+    unblock::unblock(move || path(&name)).await
+}
+
+/// Return the size in bytes of the contents of a DICOM file
+/// by its relative path (`name`),
+/// such as to choose between [`bytes`] and [`reader`].
+///
+/// The size of a file stored without compression is known upfront,
+/// as is the size of any file already in the cache.
+/// Files stored with compression are fetched like [`path`] to measure them,
+/// or decompressed without being written
+/// when the cache [keeps them compressed](crate#compressed-cache).
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// if dicom_test_files::size("WG04/REF/CT1_UNC")? > 100_000_000 {
+///     let reader = dicom_test_files::reader("WG04/REF/CT1_UNC")?;
+///     # let _ = reader;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn size(name: &str) -> Result<u64> {
+    let entry = resolve(name)?;
+    if let Ok(metadata) = fs::metadata(entry_path(&get_data_path(), entry.name)) {
+        return Ok(metadata.len());
+    }
+    match entry.compression {
+        Compression::None | Compression::DicomWeb { .. } => Ok(entry.size),
+        compression if compression.is_stream() && config::keep_compressed() => {
+            Ok(io::copy(&mut reader(name)?, &mut io::sink())?)
+        }
+        _ => Ok(fs::metadata(path(name)?)?.len()),
+    }
+}
+
+/// Fetch the stored file of a DICOM file by its relative path (`name`)
+/// exactly as the data source serves it,
+/// such as `WG04/REF/CT1_UNC.zst`,
+/// and return its path in the local file system.
+///
+/// The stored file is verified against the hash of the manifest once downloaded,
+/// and cached under its stored name without being decompressed,
+/// as in a [compressed cache](crate#compressed-cache).
+/// This gives access to the blob itself,
+/// such as to test a zstd decoder or to host it elsewhere.
+/// Test files stored without compression are the same as with [`path`].
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let stored = dicom_test_files::raw_path("WG04/REF/CT1_UNC")?;
+/// assert!(stored.ends_with("CT1_UNC.zst"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn raw_path(name: &str) -> Result<PathBuf> {
+    let entry = resolve(name)?;
+    if !entry.compression.is_stream() {
+        return path(name);
+    }
+    lock::check(entry)?;
+    let (stored_path, _) = fetch_stored(entry)?;
+    namespace::view(&entry.real_file_name(), stored_path)
+}
+
+/// Fetch a DICOM file by its relative path (`name`) like [`path`],
+/// and open it for reading.
+///
+/// When the cache [keeps files compressed](crate#compressed-cache),
+/// test files stored with compression are only fetched in their compressed form,
+/// and decompressed as they are read,
+/// unless [`path`] already had them decompressed into the cache.
+/// The compressed copy is verified against the hash of the stored file
+/// once downloaded.
+///
+/// ```no_run
+/// use std::io::Read;
+///
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let mut contents = Vec::new();
+/// dicom_test_files::reader("WG04/REF/CT1_UNC")?.read_to_end(&mut contents)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn reader(name: &str) -> Result<Box<dyn io::Read + Send>> {
+    let entry = resolve(name)?;
+    if config::keep_compressed() && entry.compression.is_stream() {
+        let cached_path = entry_path(&get_data_path(), entry.name);
+        if !cached_path.exists() {
+            lock::check(entry)?;
+            let (stored_path, _) = fetch_stored(entry)?;
+            return decoder(entry.compression, fs::File::open(stored_path)?);
+        }
+    }
+    let (cached_path, _) = fetch(entry)?;
+    Ok(Box::new(fs::File::open(cached_path)?))
+}
+
+/// Fetch the golden JSON dump of the given test file,
+/// returning its local path.
+///
+/// The dump holds the main data set of the test file
+/// in the DICOM JSON model (PS3.18 Annex F),
+/// so that parser implementations can be checked against it.
+/// Pixel data is not included,
+/// but replaced with a `BulkDataURI` holding the test file name.
+///
+/// Golden dumps are only available for test files
+/// with explicit value representations,
+/// [`Error::NotFound`] is returned for the others.
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let expected = std::fs::read_to_string(dicom_test_files::golden("pydicom/CT_small.dcm")?)?;
+/// # let _ = expected;
+/// # Ok(())
+/// # }
+/// ```
+pub fn golden(name: &str) -> Result<PathBuf> {
+    let golden_name = format!("golden/{}.json", name);
+    let entry = GOLDEN_ENTRIES
+        .iter()
+        .find(|entry| entry.name == golden_name)
+        .ok_or_else(|| {
+            let known = GOLDEN_ENTRIES.iter().map(|entry| {
+                let name = entry.name.strip_prefix("golden/").unwrap_or(entry.name);
+                name.strip_suffix(".json").unwrap_or(name)
+            });
+            suggest::not_found(name, known)
+        })?;
+    let (cached_path, _) = fetch(entry)?;
+    namespace::view(entry.name, cached_path)
+}
+
+/// Fetch the given entry if it is not cached yet,
+/// returning its local path
+/// and whether it had to be downloaded.
+pub(crate) fn fetch(entry: &TestFile) -> Result<(PathBuf, bool)> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("fetch", name = entry.name).entered();
+    lock::check(entry)?;
+    let cached_path = entry_path(&get_data_path(), entry.name);
+    if cached_path.exists() {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(path = %cached_path.display(), "cache hit");
+        let downloaded = config::refresh() && revalidate::revalidate(entry, &cached_path)?;
+        return Ok((cached_path, downloaded));
+    }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(path = %cached_path.display(), "cache miss");
+    if config::keep_compressed() && entry.compression.is_stream() {
+        // decompressed on demand from the compressed copy
+        let (stored_path, downloaded) = fetch_stored(entry)?;
+        let _lock = download_lock::acquire(&cached_path)?;
+        if !cached_path.exists() {
+            decompress_stored(entry, &stored_path, &cached_path)?;
+        }
+        return Ok((cached_path, downloaded));
+    }
+    check_size(entry)?;
+    let _lock = download_lock::acquire(&cached_path)?;
+    // downloaded by another thread or process in the meantime
+    if cached_path.exists() {
+        return Ok((cached_path, false));
+    }
+    download(entry, &cached_path, None)?;
+    Ok((cached_path, true))
+}
+
+/// Fetch the given entry in the form it is kept in the cache,
+/// compressed if [`Config::keep_compressed`] applies to it,
+/// returning whether it had to be downloaded
+pub(crate) fn fetch_kept(entry: &TestFile) -> Result<bool> {
+    if config::keep_compressed() && entry.compression.is_stream() {
+        if entry_path(&get_data_path(), entry.name).exists() {
+            return Ok(false);
+        }
+        lock::check(entry)?;
+        return fetch_stored(entry).map(|(_, downloaded)| downloaded);
+    }
+    fetch(entry).map(|(_, downloaded)| downloaded)
+}
+
+/// Fetch the compressed copy of the given entry stored with compression,
+/// unless it is already decompressed in the cache,
+/// for the caller to decompress it with [`decompress_fetched`].
+/// Returns its local path and whether it had to be downloaded.
+pub(crate) fn fetch_deferred(entry: &TestFile) -> Result<Option<(PathBuf, bool)>> {
+    if entry_path(&get_data_path(), entry.name).exists() {
+        return Ok(None);
+    }
+    lock::check(entry)?;
+    fetch_stored(entry).map(Some)
+}
+
+/// Decompress the compressed copy of the given entry
+/// fetched with [`fetch_deferred`] into its cached path,
+/// removing the copy if it was downloaded for that
+pub(crate) fn decompress_fetched(
+    entry: &TestFile,
+    stored_path: &Path,
+    downloaded: bool,
+) -> Result<()> {
+    let cached_path = entry_path(&get_data_path(), entry.name);
+    let decompressed = {
+        let _lock = download_lock::acquire(&cached_path)?;
+        if cached_path.exists() {
+            Ok(())
+        } else {
+            decompress_stored(entry, stored_path, &cached_path)
+        }
+    };
+    if downloaded {
+        fs::remove_file(stored_path).unwrap_or_else(|e| {
+            eprintln!("[dicom-test-files] Failed to remove temporary file: {}", e);
+        });
+    }
+    decompressed
+}
+
+/// Fetch the compressed copy of the given entry stored with compression
+/// if it is not cached yet,
+/// returning its local path
+/// and whether it had to be downloaded.
+///
+/// The copy is cached under its stored name, next to the decompressed file.
+/// Such copies are not revalidated.
+fn fetch_stored(entry: &TestFile) -> Result<(PathBuf, bool)> {
+    let stored_path = entry_path(&get_data_path(), &entry.real_file_name());
+    if stored_path.exists() {
+        return Ok((stored_path, false));
+    }
+    check_size(entry)?;
+    let _lock = download_lock::acquire(&stored_path)?;
+    if stored_path.exists() {
+        return Ok((stored_path, false));
+    }
+    download_as(entry, &stored_path, None, Form::Stored)?;
+    Ok((stored_path, true))
+}
+
+/// Fail with [`Error::TooLarge`] if the given entry is over the size limit
+fn check_size(entry: &TestFile) -> Result<()> {
+    if let Some(max_size) = max_size() {
+        if entry.size > max_size {
+            #[cfg(feature = "tracing")]
+            tracing::info!(size = entry.size, max_size, "over the size limit");
+            return Err(Error::TooLarge(format!(
+                "{} has {} bytes, over the size limit of {} bytes",
+                entry.name, entry.size, max_size
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Decompress the compressed copy of the given entry at the stored path
+/// into its cached path,
+/// checking it against the hash of its contents, if known
+fn decompress_stored(entry: &TestFile, stored_path: &Path, cached_path: &Path) -> Result<()> {
+    let tempdir = tempfile::tempdir_in(cached_path.parent().unwrap())?;
+    let decompressed_path = tempdir.path().join("decompressed");
+    let mut decoder = decoder(entry.compression, fs::File::open(stored_path)?)?;
+    io::copy(&mut decoder, &mut fs::File::create(&decompressed_path)?)?;
+    check_decompressed(&decompressed_path, entry)?;
+    fs::rename(&decompressed_path, cached_path)?;
+    Ok(())
 }
 
-impl From<io::Error> for Error {
-    fn from(err: io::Error) -> Error {
-        Error::Io(err)
+/// Fetch the given entry if it is not cached yet,
+/// verifying the cached copy against its hash
+/// when it is stored without compression,
+/// or against the hash of its decompressed contents if known.
+/// A corrupted copy is fetched again.
+pub(crate) fn fetch_verified(entry: &TestFile) -> Result<PathBuf> {
+    let (path, downloaded) = fetch(entry)?;
+    if downloaded {
+        return Ok(path);
+    }
+    let checked = match entry.compression {
+        Compression::None => check_hash(&path, entry),
+        _ => check_decompressed(&path, entry),
+    };
+    match checked {
+        Ok(()) => Ok(path),
+        // the corrupted file was removed, fetch it again
+        Err(Error::InvalidHash { .. }) => {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(name = entry.name, "corrupted cached copy, fetching it again");
+            fetch(entry).map(|(path, _)| path)
+        }
+        Err(e) => Err(e),
     }
 }
 
-type Result<T, E = Error> = std::result::Result<T, E>;
+/// Fetch a DICOM file like [`path`],
+/// but return `None` instead of failing
+/// when the file is not cached
+/// and the data source cannot be reached.
+///
+/// This allows tests to skip themselves
+/// when working without a network connection.
+/// Setting the environment variable `DICOM_TEST_FILES_OFFLINE` to `1`
+/// skips all files which are not cached yet
+/// without attempting to download them.
+/// Files over the [size limit](crate#size-limit) are skipped as well.
+///
+/// ```no_run
+/// # fn main() {
+/// let liver = match dicom_test_files::path_or_skip("pydicom/liver.dcm") {
+///     Some(path) => path,
+///     None => return,
+/// };
+/// # let _ = liver;
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Any other error,
+/// such as an unknown file name or a hash mismatch,
+/// results in a panic,
+/// as it would not go away by retrying online.
+pub fn path_or_skip(name: &str) -> Option<PathBuf> {
+    let entry = match resolve(name) {
+        Ok(entry) => entry,
+        Err(e @ Error::NotFound { .. }) => panic!("{}", e),
+        Err(Error::Unreachable(reason)) => {
+            eprintln!("[dicom-test-files] Skipping {}: {}", name, reason);
+            return None;
+        }
+        Err(e) => panic!("failed to resolve DICOM test file {}: {:?}", name, e),
+    };
+    let view = |cached_path| {
+        namespace::view(entry.name, cached_path)
+            .unwrap_or_else(|e| panic!("failed to link DICOM test file {}: {:?}", name, e))
+    };
+    let cached_path = entry_path(&get_data_path(), entry.name);
+    if cached_path.exists() {
+        return Some(view(cached_path));
+    }
+    if env::var("DICOM_TEST_FILES_OFFLINE").as_deref() == Ok("1") {
+        eprintln!("[dicom-test-files] Offline, skipping {}", name);
+        return None;
+    }
 
-fn lookup(name: &str) -> Option<&'static TestFile> {
-    FILE_ENTRIES.iter().find(|entry| entry.name == name)
+    match fetch(entry) {
+        Ok((cached_path, _)) => Some(view(cached_path)),
+        Err(Error::Unreachable(reason)) | Err(Error::TooLarge(reason)) => {
+            eprintln!("[dicom-test-files] Skipping {}: {}", name, reason);
+            None
+        }
+        Err(e) => panic!("failed to fetch DICOM test file {}: {:?}", name, e),
+    }
 }
 
-/// Fetch a DICOM file by its relative path (`name`)
-/// if it has not been downloaded yet,
-/// and return its path in the local file system.
+/// Iterate over the local paths of the test files
+/// selected by the given filter or glob pattern,
+/// fetching each file only when the iterator reaches it.
 ///
-/// This function will download and cache the file locally in
-/// `target/dicom_test_files`.
-pub fn path(name: &str) -> Result<PathBuf, Error> {
-    let entry = lookup(name).ok_or(Error::NotFound)?;
-    let cached_path = get_data_path().join(entry.name);
-    if !cached_path.exists() {
-        download(name, &cached_path)?;
-    }
-    Ok(cached_path)
+/// A test which stops early does not download the files it did not reach,
+/// unlike [`prefetch`], which fetches them all upfront.
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// for path in dicom_test_files::iter_paths("WG04/REF/*").take(2) {
+///     let path = path?;
+///     # let _ = path;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn iter_paths(filter: impl Into<Filter>) -> impl Iterator<Item = Result<PathBuf>> {
+    let (selected, failed) = match entries(filter) {
+        Ok(selected) => (selected, None),
+        Err(e) => (Vec::new(), Some(Err(e))),
+    };
+    failed.into_iter().chain(
+        selected
+            .into_iter()
+            .map(|entry| {
+                let (cached_path, _) = fetch(entry)?;
+                namespace::view(entry.name, cached_path)
+            }),
+    )
 }
 
 /// Return a vector of local paths to all DICOM test files available.
@@ -111,8 +1709,10 @@ pub fn path(name: &str) -> Result<PathBuf, Error> {
 /// and cache the files locally to `target/dicom_test_files`.
 ///
 /// Note that this operation may be unnecessarily expensive.
-/// Retrieving only the files that you need via [`path`] is preferred.
-#[deprecated(note = "Too expensive. Use `path` for the files that you need.")]
+/// Retrieving only the files that you need via [`path`] is preferred,
+/// or use [`prefetch`] to fetch a selection of files concurrently,
+/// or [`iter_paths`] to fetch them as they are needed.
+#[deprecated(note = "Too expensive. Use `path` for the files that you need, or `prefetch`.")]
 pub fn all() -> Result<Vec<PathBuf>, Error> {
     FILE_ENTRIES
         .iter()
@@ -120,41 +1720,248 @@ pub fn all() -> Result<Vec<PathBuf>, Error> {
         .collect::<Result<Vec<PathBuf>, Error>>()
 }
 
-/// Determine the target data path
+/// Embed the contents of a DICOM test file as a `&'static [u8; N]`.
+///
+/// The file must have been fetched during the build
+/// by calling [`build_support::embed`] in the build script
+/// of the crate using this macro.
+/// No network access is needed at run time.
+///
+/// ```ignore
+/// // build.rs
+/// fn main() {
+///     dicom_test_files::build_support::embed(&["pydicom/CT_small.dcm"]).unwrap();
+/// }
+///
+/// // tests
+/// static CT_SMALL: &[u8] = dicom_test_files::include_dicom_bytes!("pydicom/CT_small.dcm");
+/// ```
+#[macro_export]
+macro_rules! include_dicom_bytes {
+    ($name:literal) => {
+        include_bytes!(concat!(env!("OUT_DIR"), "/dicom_test_files/", $name))
+    };
+}
+
+/// Determine the target data path,
+/// see the [cache location](crate#cache-location)
 pub(crate) fn get_data_path() -> PathBuf {
-    let mut target_dir = PathBuf::from(
-        env::current_exe()
-            .expect("exe path")
-            .parent()
-            .expect("exe parent"),
-    );
-    while target_dir.file_name() != Some(std::ffi::OsStr::new("target")) {
-        if !target_dir.pop() {
-            panic!("Cannot find target directory");
+    writable_data_path(default_data_path(), config::fallback_dir())
+}
+
+/// The data path when it can be written to
+fn default_data_path() -> PathBuf {
+    match target_dir() {
+        Some(target_dir) => target_dir.join("dicom_test_files"),
+        None => platform_cache_dir().join("dicom-test-files"),
+    }
+}
+
+/// The given data path if it can be written to,
+/// or else the first writable one of the given fallback directory,
+/// the cache directory of the user and the temporary directory.
+/// Directories are only checked once per process.
+fn writable_data_path(data_path: PathBuf, fallback_dir: Option<PathBuf>) -> PathBuf {
+    type Checked = (PathBuf, Option<PathBuf>, PathBuf);
+    static CHECKED: Mutex<Option<Checked>> = Mutex::new(None);
+
+    let mut checked = CHECKED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((checked_path, checked_fallback, writable)) = &*checked {
+        if *checked_path == data_path && *checked_fallback == fallback_dir {
+            return writable.clone();
+        }
+    }
+    let writable = if is_writable(&data_path) {
+        data_path.clone()
+    } else {
+        let fallbacks = fallback_dir.clone().into_iter().chain([
+            platform_cache_dir().join("dicom-test-files"),
+            env::temp_dir().join("dicom-test-files"),
+        ]);
+        match fallbacks.filter(|dir| *dir != data_path).find(|dir| is_writable(dir)) {
+            Some(dir) => {
+                eprintln!(
+                    "[dicom-test-files] Cannot write to {}, caching test files in {}",
+                    data_path.display(),
+                    dir.display()
+                );
+                dir
+            }
+            // let the downloads fail
+            None => data_path.clone(),
+        }
+    };
+    *checked = Some((data_path, fallback_dir, writable.clone()));
+    writable
+}
+
+/// Whether files can be created in the given directory
+fn is_writable(dir: &Path) -> bool {
+    fs::create_dir_all(dir).is_ok() && tempfile::NamedTempFile::new_in(dir).is_ok()
+}
+
+/// The Cargo target directory of the running binary, if known:
+/// the `target` directory among its ancestors,
+/// or else the one set by `CARGO_TARGET_DIR`
+pub(crate) fn target_dir() -> Option<PathBuf> {
+    exe_target_dir().or_else(|| {
+        env::var_os("CARGO_TARGET_DIR")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    })
+}
+
+/// The `target` directory among the ancestors of the running binary
+fn exe_target_dir() -> Option<PathBuf> {
+    let exe = env::current_exe().ok()?;
+    exe.ancestors()
+        .skip(1)
+        .find(|dir| dir.file_name() == Some(std::ffi::OsStr::new("target")))
+        .map(Path::to_path_buf)
+}
+
+/// The cache directory of the user on this platform,
+/// or else the temporary directory
+fn platform_cache_dir() -> PathBuf {
+    let var = |name| {
+        env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    #[cfg(windows)]
+    let dir = var("LOCALAPPDATA");
+    #[cfg(target_os = "macos")]
+    let dir = var("HOME").map(|home| home.join("Library").join("Caches"));
+    #[cfg(not(any(windows, target_os = "macos")))]
+    let dir = var("XDG_CACHE_HOME").or_else(|| var("HOME").map(|home| home.join(".cache")));
+    dir.unwrap_or_else(env::temp_dir)
+}
+
+/// The path of the test file of the given name in the given directory.
+///
+/// Names are split on `/` into the components of the path,
+/// and on Windows, the path is extended-length
+/// so that deeply nested files are not cut off by `MAX_PATH`.
+pub(crate) fn entry_path(dir: &Path, name: &str) -> PathBuf {
+    let path = name
+        .split('/')
+        .fold(dir.to_path_buf(), |path, component| path.join(component));
+    extended_length(path)
+}
+
+#[cfg(windows)]
+fn extended_length(path: PathBuf) -> PathBuf {
+    use std::path::Component;
+
+    // verbatim paths are not normalized, so only plain absolute paths qualify
+    let plain = path.is_absolute()
+        && !path
+            .components()
+            .any(|component| matches!(component, Component::ParentDir | Component::CurDir));
+    match path.to_str() {
+        Some(p) if plain && !p.starts_with(r"\\") => {
+            PathBuf::from(format!(r"\\?\{}", p.replace('/', r"\")))
         }
+        _ => path,
+    }
+}
+
+#[cfg(not(windows))]
+fn extended_length(path: PathBuf) -> PathBuf {
+    path
+}
+
+/// Determine the maximum size of a file to download,
+/// from [`Config::max_size`] or `DICOM_TEST_FILES_MAX_SIZE`
+pub(crate) fn max_size() -> Option<u64> {
+    if let Some(max_size) = config::max_size() {
+        return Some(max_size);
     }
-    target_dir.join("dicom_test_files")
+    let value = env::var("DICOM_TEST_FILES_MAX_SIZE").ok()?;
+    if value.is_empty() {
+        return None;
+    }
+    let max_size = parse_size(&value);
+    if max_size.is_none() {
+        eprintln!(
+            "[dicom-test-files] Ignoring invalid DICOM_TEST_FILES_MAX_SIZE {:?}",
+            value
+        );
+    }
+    max_size
+}
+
+/// Parse a number of bytes with an optional binary unit suffix
+fn parse_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (digits, unit) = match value.char_indices().last()? {
+        (i, 'K') | (i, 'k') => (&value[..i], 1 << 10),
+        (i, 'M') | (i, 'm') => (&value[..i], 1 << 20),
+        (i, 'G') | (i, 'g') => (&value[..i], 1 << 30),
+        _ => (value, 1),
+    };
+    digits.trim_end().parse::<u64>().ok()?.checked_mul(unit)
 }
 
-const DEFAULT_GITHUB_BASE_URL: &str =
-    "https://raw.githubusercontent.com/robyoung/dicom-test-files/master/data/";
+/// The git ref of the data set used by default.
+///
+/// This is the `master` branch,
+/// as no release tag holds the data sets listed by this version yet,
+/// such as `data/golden` and `data/invalid`.
+/// It becomes the tag of the release of the crate
+/// once one is cut along with the data,
+/// so that test files cannot change under a released version.
+/// Use `DICOM_TEST_FILES_REF` or [`Config::git_ref`] to pick another one.
+pub const DEFAULT_REF: &str = "master";
+
+/// The branch with the latest data set
+pub(crate) const LATEST_REF: &str = "master";
+
+const GITHUB_REPOSITORY: &str = "robyoung/dicom-test-files";
 
 const RAW_GITHUBUSERCONTENT_URL: &str = "https://raw.githubusercontent.com";
 
+/// The URL of the raw contents of the data set
+/// in the given GitHub repository at the given git ref
+fn github_data_url(repository: &str, git_ref: &str) -> String {
+    format!(
+        "{}/{}/{}/data/",
+        RAW_GITHUBUSERCONTENT_URL, repository, git_ref
+    )
+}
+
+/// The base URL set by `DICOM_TEST_FILES_URL`, with a trailing slash
+fn custom_url() -> Option<String> {
+    let url = std::env::var("DICOM_TEST_FILES_URL").ok()?;
+    if url.is_empty() {
+        return None;
+    }
+    let url = if !url.ends_with("/") {
+        format!("{url}/")
+    } else {
+        url
+    };
+    Some(url)
+}
+
 /// Determine the base URL in this environment.
+fn base_url() -> Result<Cow<'static, str>, VarError> {
+    base_url_at(DEFAULT_REF)
+}
+
+/// Determine the base URL in this environment,
+/// using the given git ref if no other source is configured.
 ///
 /// When this is part of a pull request to the project,
-/// use the contents provided through the pull request's head branch.
-fn base_url() -> Result<Cow<'static, str>, VarError> {
-    if let Ok(url) = std::env::var("DICOM_TEST_FILES_URL") {
-        if url != "" {
-            let url = if !url.ends_with("/") {
-                format!("{url}/")
-            } else {
-                url
-            };
-            return Ok(url.into());
-        }
+/// use the contents provided through the pull request's head branch,
+/// or the commit being tested on other events.
+pub(crate) fn base_url_at(default_ref: &str) -> Result<Cow<'static, str>, VarError> {
+    if let Some(url) = custom_url() {
+        return Ok(url.into());
+    }
+
+    if let Some(git_ref) = config::git_ref() {
+        return Ok(github_data_url(GITHUB_REPOSITORY, &git_ref).into());
     }
 
     // CI: always true on GitHub Actions
@@ -170,87 +1977,528 @@ fn base_url() -> Result<Cow<'static, str>, VarError> {
             if github_event_name == "pull_request" {
                 // GITHUB_HEAD_REF: name of the branch when it's a pull request
                 let github_head_ref = std::env::var("GITHUB_HEAD_REF")?;
-                let url = format!(
-                    "{}/{}/{}/data/",
-                    RAW_GITHUBUSERCONTENT_URL, github_repository, github_head_ref
-                );
-
-                return Ok(url.into());
+                return Ok(github_data_url(&github_repository, &github_head_ref).into());
             }
+
+            // GITHUB_SHA: the commit being tested,
+            // as the release tag may not exist yet
+            let github_sha = std::env::var("GITHUB_SHA")?;
+            return Ok(github_data_url(&github_repository, &github_sha).into());
         }
     }
 
-    Ok(DEFAULT_GITHUB_BASE_URL.into())
+    Ok(github_data_url(GITHUB_REPOSITORY, default_ref).into())
 }
 
-fn download(name: &str, cached_path: &PathBuf) -> Result<(), Error> {
-    let file_entry = lookup(name).ok_or(Error::NotFound)?;
+/// The URL which the given entry is downloaded from
+pub(crate) fn source_url(file_entry: &TestFile) -> Result<String> {
+    if config::backend() == Backend::ContentAddressed {
+        return gateway_url(file_entry.digest, file_entry.hash);
+    }
+    if let Some(collection) = file_entry.collection {
+        let stored_name = file_entry.real_file_name();
+        let path = stored_name
+            .strip_prefix(collection.name)
+            .and_then(|path| path.strip_prefix('/'))
+            .unwrap_or(&stored_name);
+        return Ok(config::collection_url(collection) + path);
+    }
+    stored_url(file_entry.base_url, &file_entry.real_file_name())
+}
 
-    let target_parent_dir = cached_path.as_path().parent().unwrap();
-    fs::create_dir_all(target_parent_dir)?;
+/// The URL of a stored file of the given source,
+/// or of this project if none
+pub(crate) fn stored_url(source_url: Option<&str>, stored_name: &str) -> Result<String> {
+    let base_url = match source_url {
+        Some(base_url) => Cow::Borrowed(base_url),
+        None if config::backend() == Backend::Artifact => {
+            let template = config::artifact_url().ok_or(Error::ResolveUrl(VarError::NotPresent))?;
+            let git_ref = config::git_ref();
+            return Ok(backend::artifact_url(
+                &template,
+                git_ref.as_deref().unwrap_or(DEFAULT_REF),
+                stored_name,
+            ));
+        }
+        None if config::backend() == Backend::GithubReleases && custom_url().is_none() => {
+            let git_ref = config::git_ref();
+            return Ok(backend::release_asset_url(
+                GITHUB_REPOSITORY,
+                git_ref.as_deref().unwrap_or(DEFAULT_REF),
+                stored_name,
+            ));
+        }
+        None => base_url().map_err(Error::ResolveUrl)?,
+    };
+    Ok(base_url.into_owned() + stored_name)
+}
+
+/// The URL of a stored file with the given hash
+/// at the content-addressed gateway.
+///
+/// Fails with [`Error::ResolveUrl`] if no gateway is configured.
+pub(crate) fn gateway_url(digest: DigestAlgorithm, hash: &str) -> Result<String> {
+    let gateway_url = config::gateway_url().ok_or(Error::ResolveUrl(VarError::NotPresent))?;
+    Ok(backend::content_url(&gateway_url, digest, hash))
+}
+
+/// Download the given entry into its cached path,
+/// unless the data source confirms with `304 Not Modified`
+/// that the cached copy with the given ETag is current.
+/// Returns whether the file was downloaded.
+pub(crate) fn download(
+    file_entry: &TestFile,
+    cached_path: &Path,
+    etag: Option<&str>,
+) -> Result<bool, Error> {
+    download_as(file_entry, cached_path, etag, Form::Decompressed)
+}
 
-    let url = base_url().map_err(Error::ResolveUrl)?.to_owned() + file_entry.real_file_name();
-    let resp = ureq::get(&url)
-        .call()
-        .map_err(|e| Error::Download(format!("Failed to download {}: {}", url, e)))?;
+/// The form in which a download of a file stored with compression
+/// enters the cache
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Form {
+    /// decompressed into the path of the test file
+    Decompressed,
+    /// kept compressed under its stored name
+    Stored,
+}
 
-    // write into temporary file first
-    let tempdir = tempfile::tempdir_in(target_parent_dir)?;
-    let mut tempfile_path = tempdir.into_path();
-    tempfile_path.push("tmpfile");
+/// Download the given entry into the given path like [`download`],
+/// in the given form
+fn download_as(
+    file_entry: &TestFile,
+    cached_path: &Path,
+    etag: Option<&str>,
+    form: Form,
+) -> Result<bool, Error> {
+    bench::warn_if_measuring(file_entry.name);
+    cancel::check()?;
+    cache::clean_temp_once();
+    space::check(file_entry, cached_path)?;
+    cache::check_quota(file_entry)?;
+    let mut attempt = report::Attempt::start(file_entry.name);
+    let downloaded = try_download(file_entry, cached_path, etag, form, &mut attempt)
+        .map_err(|e| e.downloading(file_entry.name));
+    attempt.finish(&downloaded);
+    match downloaded {
+        // the canonical host of the file may still be up,
+        // serving it without compression
+        Err(e @ Error::Unreachable(_)) | Err(e @ Error::Download(_))
+            if form == Form::Decompressed && upstream::available(file_entry) =>
+        {
+            upstream::download(file_entry, cached_path).map_err(|_| e)
+        }
+        downloaded => downloaded,
+    }
+}
 
+/// Download the test file of the given entry,
+/// recording the request and response in the given attempt
+fn try_download(
+    file_entry: &TestFile,
+    cached_path: &Path,
+    etag: Option<&str>,
+    form: Form,
+    attempt: &mut report::Attempt,
+) -> Result<bool, Error> {
+    if let Compression::ZipMember { archive, member } = file_entry.compression {
+        archive::fetch_member(file_entry, archive, member, cached_path)?;
+        return Ok(true);
+    }
+    if let Compression::DicomWeb {
+        study,
+        series,
+        instance,
+    } = file_entry.compression
     {
-        let mut target = fs::File::create(&tempfile_path)?;
-        std::io::copy(&mut resp.into_reader(), &mut target)?;
+        dicomweb::fetch_instance(file_entry, study, series, instance, cached_path, attempt)?;
+        return Ok(true);
+    }
+
+    let target_parent_dir = cached_path.parent().unwrap();
+    fs::create_dir_all(target_parent_dir)?;
+
+    let mut url = source_url(file_entry)?;
+    // a gateway serves the file itself by its hash
+    if file_entry.pointer && config::backend() != Backend::ContentAddressed {
+        url = pointer::resolve(file_entry, &url)?;
+    }
+    attempt.url = Some(url.clone());
+    chaos::inject(&url)?;
+    let mut headers = vec![("Accept-Encoding", encoding::ACCEPT_ENCODING)];
+    if let Some(etag) = etag {
+        headers.push(("If-None-Match", etag));
+    }
+    let resp = http::get(&url, &headers).map_err(|failure| {
+        attempt.status = failure.status;
+        failure.error
+    })?;
+    attempt.status = Some(resp.status);
+    if etag.is_some() && resp.status == 304 {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(url = %url, "not modified");
+        return Ok(false);
+    }
+    let new_etag = resp.etag.clone();
+
+    let mut body = match lfs::detect(encoding::decode(resp, &url)?)? {
+        lfs::Body::Data(body) => body,
+        lfs::Body::Pointer(object) => {
+            let (object_url, resp) = lfs::fetch_object(file_entry, &url, &object)?;
+            attempt.status = Some(resp.status);
+            url = object_url;
+            attempt.url = Some(url.clone());
+            encoding::decode(resp, &url)?
+        }
+    };
+    attempt.bytes = if file_entry.compression.is_stream() && form == Form::Decompressed {
+        install_streamed(file_entry, &mut body, target_parent_dir, cached_path)
+            .map_err(|e| e.downloaded_from(&url))?
+    } else {
+        // write into temporary file first, hashing it on the way
+        let (tempfile_path, digest) =
+            write_temp_hashed(&mut body, target_parent_dir, file_entry.digest)?;
+        let bytes = digest.1;
+        let installed = match form {
+            Form::Decompressed => install_hashed(file_entry, tempfile_path, digest, cached_path),
+            // kept as it is stored
+            Form::Stored => {
+                let (actual, _) = digest;
+                let (name, hash) = (file_entry.name, file_entry.hash);
+                check_digest(&tempfile_path, name, hash, actual, bytes)
+                    .and_then(|()| Ok(fs::rename(&tempfile_path, cached_path)?))
+            }
+        };
+        installed.map_err(|e| e.downloaded_from(&url))?;
+        bytes
+    };
+    revalidate::store_etag(file_entry.name, new_etag.as_deref());
+    Ok(true)
+}
+
+/// Write the contents of a reader into a new temporary file
+/// in the given directory, returning its path.
+/// The temporary file is removed if the reader fails,
+/// such as when the download is cancelled.
+pub(crate) fn write_temp(reader: &mut impl io::Read, dir: &Path) -> Result<PathBuf> {
+    let tempdir = tempfile::tempdir_in(dir)?;
+    let tempfile_path = tempdir.path().join("tmpfile");
+
+    let mut target = fs::File::create(&tempfile_path)?;
+    std::io::copy(reader, &mut target)?;
+    // kept until installed
+    let _ = tempdir.into_path();
+    Ok(tempfile_path)
+}
+
+/// Write the contents of a reader into a new temporary file
+/// in the given directory like [`write_temp`],
+/// hashing them with the given algorithm as they are written,
+/// returning its path along with its hash and size in bytes
+pub(crate) fn write_temp_hashed(
+    reader: &mut impl io::Read,
+    dir: &Path,
+    algorithm: DigestAlgorithm,
+) -> Result<(PathBuf, (String, u64))> {
+    let mut hasher = digest::Hasher::new(algorithm)?;
+    let mut tee = Tee::new(reader, Some(&mut hasher));
+    let tempfile_path = write_temp(&mut tee, dir)?;
+    let bytes = tee.bytes;
+    Ok((tempfile_path, (hasher.finalize(), bytes)))
+}
+
+/// A reader which passes the data it reads to a hasher, if any,
+/// so that a download is hashed in the same pass as it is written
+struct Tee<'a, R> {
+    reader: R,
+    hasher: Option<&'a mut digest::Hasher>,
+    /// The number of bytes read so far
+    bytes: u64,
+}
+
+impl<'a, R> Tee<'a, R> {
+    fn new(reader: R, hasher: Option<&'a mut digest::Hasher>) -> Self {
+        Tee {
+            reader,
+            hasher,
+            bytes: 0,
+        }
+    }
+}
+
+impl<R: io::Read> io::Read for Tee<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        if let Some(hasher) = &mut self.hasher {
+            io::Write::write_all(hasher, &buf[..read])?;
+        }
+        self.bytes += read as u64;
+        Ok(read)
+    }
+}
+
+/// A reader which keeps the first error of the stream it reads,
+/// which a decoder reading it may not pass on as it is
+struct Received<R> {
+    reader: R,
+    error: Option<io::Error>,
+}
+
+impl<R: io::Read> io::Read for Received<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf).map_err(|e| {
+            let copy = io::Error::new(e.kind(), e.to_string());
+            self.error.get_or_insert(e);
+            copy
+        })
+    }
+}
+
+/// Decompress the download of the given entry stored with compression
+/// into its cached path as it is received,
+/// returning the number of bytes received.
+///
+/// The stored stream is hashed on its way into the decoder,
+/// and the decompressed contents on their way out of it,
+/// so that the data is only gone through once
+/// and the stored file is never written.
+/// The decompressed contents are written next to the cached path first,
+/// so that an interrupted run never leaves a partial file in the cache.
+pub(crate) fn install_streamed(
+    file_entry: &TestFile,
+    stored: &mut (impl io::Read + Send),
+    dir: &Path,
+    cached_path: &Path,
+) -> Result<u64> {
+    let tempdir = tempfile::tempdir_in(dir)?;
+    let decompressed_path = tempdir.path().join("decompressed");
+    let mut target = fs::File::create(&decompressed_path)?;
+
+    let mut hasher = digest::Hasher::new(file_entry.digest)?;
+    let mut decompressed_hasher = match file_entry.decompressed_hash {
+        Some(_) => Some(digest::Hasher::new(DigestAlgorithm::Sha256)?),
+        None => None,
+    };
+    let mut stored = Tee::new(
+        Received {
+            reader: stored,
+            error: None,
+        },
+        Some(&mut hasher),
+    );
+    let decompressed = decoder(file_entry.compression, &mut stored).and_then(|decoder| {
+        let mut decoder = Tee::new(decoder, decompressed_hasher.as_mut());
+        io::copy(&mut decoder, &mut target)?;
+        Ok(decoder.bytes)
+    });
+    // the decoder may stop short of the end of the stream,
+    // which is part of the stored file all the same
+    let drained = io::copy(&mut stored, &mut io::sink());
+    // such as a truncated or cancelled download
+    if let Some(error) = stored.reader.error.take() {
+        return Err(error.into());
     }
+    drained?;
+    let bytes = stored.bytes;
+    drop(target);
 
-    check_hash(&tempfile_path, file_entry)?;
+    // a corrupt download is reported as such rather than as a decoding error
+    check_digest(
+        &decompressed_path,
+        file_entry.name,
+        file_entry.hash,
+        hasher.finalize(),
+        bytes,
+    )?;
+    let decompressed_bytes = decompressed?;
+    if let (Some(expected), Some(hasher)) = (file_entry.decompressed_hash, decompressed_hasher) {
+        check_digest(
+            &decompressed_path,
+            file_entry.name,
+            expected,
+            hasher.finalize(),
+            decompressed_bytes,
+        )?;
+    }
+    fs::rename(&decompressed_path, cached_path)?;
+    Ok(bytes)
+}
+
+/// Verify the stored file of the given entry at the temporary path
+/// and move it to its cached path,
+/// decompressing it if needed.
+///
+/// Files are decompressed next to the temporary file first,
+/// so that an interrupted run never leaves a partial file in the cache.
+// downloads are hashed as they are written, unlike members of archives
+#[cfg_attr(not(any(feature = "zip", feature = "bundles")), allow(dead_code))]
+pub(crate) fn install(
+    file_entry: &TestFile,
+    tempfile_path: PathBuf,
+    cached_path: &Path,
+) -> Result<()> {
+    let digest = digest::file_digest(&tempfile_path, file_entry.digest)?;
+    install_hashed(file_entry, tempfile_path, digest, cached_path)
+}
+
+/// Verify the stored file of the given entry at the temporary path
+/// against its hash and size, computed as it was written,
+/// and move it to its cached path like [`install`]
+pub(crate) fn install_hashed(
+    file_entry: &TestFile,
+    tempfile_path: PathBuf,
+    (actual, bytes): (String, u64),
+    cached_path: &Path,
+) -> Result<()> {
+    check_digest(
+        &tempfile_path,
+        file_entry.name,
+        file_entry.hash,
+        actual,
+        bytes,
+    )?;
+    // decode and write to target destination
+    let decompressed_path = tempfile_path.with_file_name("decompressed");
     match file_entry.compression {
-        Compression::None => {
+        Compression::None | Compression::ZipMember { .. } | Compression::DicomWeb { .. } => {
             // move to target destination
-            fs::rename(tempfile_path, cached_path.as_path())?;
+            fs::rename(tempfile_path, cached_path)?;
+            return Ok(());
         },
-        Compression::Zstd => {
-            // decode and write to target destination
-            write_zstd(tempfile_path.as_path(), cached_path.as_path())?;
-
-            // remove temporary file
-            fs::remove_file(tempfile_path).unwrap_or_else(|e| {
-                eprintln!("[dicom-test-files] Failed to remove temporary file: {}", e);
-            });
+        Compression::Zstd | Compression::Gzip | Compression::Xz => {
+            let mut decoder = decoder(file_entry.compression, fs::File::open(&tempfile_path)?)?;
+            io::copy(&mut decoder, &mut fs::File::create(&decompressed_path)?)?;
         }
     }
 
+    // remove temporary file
+    fs::remove_file(tempfile_path).unwrap_or_else(|e| {
+        eprintln!("[dicom-test-files] Failed to remove temporary file: {}", e);
+    });
+    check_decompressed(&decompressed_path, file_entry)?;
+    fs::rename(decompressed_path, cached_path)?;
     Ok(())
 }
 
+/// A reader of the decompressed contents
+/// of a stream stored with the given compression
+fn decoder<'a>(
+    compression: Compression,
+    stored: impl io::Read + Send + 'a,
+) -> Result<Box<dyn io::Read + Send + 'a>> {
+    match compression {
+        Compression::Zstd => zstd_decoder(stored),
+        Compression::Gzip => gzip_decoder(stored),
+        Compression::Xz => xz_decoder(stored),
+        Compression::None | Compression::ZipMember { .. } | Compression::DicomWeb { .. } => {
+            Ok(Box::new(stored))
+        }
+    }
+}
+
 #[cfg(feature = "zstd")]
-fn write_zstd(source_path: impl AsRef<Path>, cached_path: impl AsRef<Path>) -> Result<()> {
-    let mut decoder = zstd::Decoder::new(fs::File::open(source_path)?)?;
-    let mut target = fs::File::create(cached_path)?;
-    std::io::copy(&mut decoder, &mut target)?;
-    Ok(())
+fn zstd_decoder<'a>(stored: impl io::Read + Send + 'a) -> Result<Box<dyn io::Read + Send + 'a>> {
+    Ok(Box::new(zstd::Decoder::new(stored)?))
 }
 
 #[cfg(not(feature = "zstd"))]
-fn write_zstd(_source_path: impl AsRef<Path>, _cached_path: impl AsRef<Path>) -> Result<()> {
+fn zstd_decoder<'a>(_stored: impl io::Read + Send + 'a) -> Result<Box<dyn io::Read + Send + 'a>> {
     Err(Error::ZstdRequired)
 }
 
-fn check_hash(path: impl AsRef<Path>, file_entry: &TestFile) -> Result<()> {
-    let mut file = fs::File::open(path.as_ref())?;
-    let mut hasher = Sha256::new();
-    io::copy(&mut file, &mut hasher)?;
-    let hash = hasher.finalize();
+#[cfg(feature = "gzip")]
+fn gzip_decoder<'a>(stored: impl io::Read + Send + 'a) -> Result<Box<dyn io::Read + Send + 'a>> {
+    Ok(Box::new(flate2::read::GzDecoder::new(stored)))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gzip_decoder<'a>(_stored: impl io::Read + Send + 'a) -> Result<Box<dyn io::Read + Send + 'a>> {
+    Err(Error::GzipRequired)
+}
+
+#[cfg(feature = "xz")]
+fn xz_decoder<'a>(stored: impl io::Read + Send + 'a) -> Result<Box<dyn io::Read + Send + 'a>> {
+    Ok(Box::new(xz2::read::XzDecoder::new(stored)))
+}
+
+#[cfg(not(feature = "xz"))]
+fn xz_decoder<'a>(_stored: impl io::Read + Send + 'a) -> Result<Box<dyn io::Read + Send + 'a>> {
+    Err(Error::XzRequired)
+}
+
+pub(crate) fn check_hash(path: impl AsRef<Path>, file_entry: &TestFile) -> Result<()> {
+    verify_digest(path, file_entry.name, file_entry.hash, file_entry.digest)
+}
+
+/// Check the decompressed copy of a test file stored with compression
+/// against the hash of its contents, if known,
+/// removing it if it does not match
+fn check_decompressed(path: impl AsRef<Path>, file_entry: &TestFile) -> Result<()> {
+    match file_entry.decompressed_hash {
+        Some(hash) => verify_hash(path, file_entry.name, hash),
+        None => Ok(()),
+    }
+}
+
+/// Check the SHA-256 hash of a file,
+/// removing it if it does not match
+pub(crate) fn verify_hash(path: impl AsRef<Path>, name: &str, expected: &str) -> Result<()> {
+    verify_digest(path, name, expected, DigestAlgorithm::Sha256)
+}
 
-    if format!("{:x}", hash) != file_entry.hash {
+/// Check the hash of a file with the given algorithm,
+/// removing it if it does not match
+pub(crate) fn verify_digest(
+    path: impl AsRef<Path>,
+    name: &str,
+    expected: &str,
+    algorithm: DigestAlgorithm,
+) -> Result<()> {
+    let (actual, bytes) = digest::file_digest(path.as_ref(), algorithm)?;
+    check_digest(path, name, expected, actual, bytes)
+}
+
+/// Check the given hash of a file,
+/// computed with the algorithm of the expected one,
+/// removing the file if it does not match
+pub(crate) fn check_digest(
+    path: impl AsRef<Path>,
+    name: &str,
+    expected: &str,
+    actual: String,
+    bytes: u64,
+) -> Result<()> {
+    if actual != expected {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            path = %path.as_ref().display(),
+            expected,
+            actual = %actual,
+            bytes,
+            "hash mismatch"
+        );
         fs::remove_file(path)?;
-        return Err(Error::InvalidHash);
+        return Err(Error::InvalidHash {
+            name: name.to_string(),
+            expected: expected.to_string(),
+            actual,
+            bytes,
+            url: None,
+        });
     }
 
     Ok(())
 }
 
+/// The SHA-256 hash of a file and its size in bytes
+pub(crate) fn file_hash(path: &Path) -> io::Result<(String, u64)> {
+    let mut file = fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let bytes = io::copy(&mut file, &mut hasher)?;
+    Ok((format!("{:x}", hasher.finalize()), bytes))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,7 +2506,7 @@ mod tests {
     #[test]
     fn load_a_single_path_1() {
         // ensure it does not exist
-        let cached_path = get_data_path().join("pydicom/liver.dcm");
+        let cached_path = entry_path(&get_data_path(), "pydicom/liver.dcm");
         let _ = fs::remove_file(cached_path);
 
         let path = path("pydicom/liver.dcm").unwrap();
@@ -272,7 +2520,7 @@ mod tests {
     fn load_a_single_path_wg04_1() {
         const FILE: &str = "WG04/JPLY/NM1_JPLY";
         // ensure it does not exist
-        let cached_path = get_data_path().join(FILE);
+        let cached_path = entry_path(&get_data_path(), FILE);
         let _ = fs::remove_file(cached_path);
 
         let path = path(FILE).unwrap();
@@ -291,7 +2539,7 @@ mod tests {
     fn load_path_wg04_unc_1() {
         const FILE: &str = "WG04/REF/NM1_UNC";
         // ensure it does not exist beforehand
-        let cached_path = get_data_path().join(FILE);
+        let cached_path = entry_path(&get_data_path(), FILE);
         let _ = fs::remove_file(cached_path);
 
         let path = path(FILE).unwrap();
@@ -307,7 +2555,7 @@ mod tests {
 
     fn load_a_single_path_2() {
         // ensure it does not exist
-        let cached_path = get_data_path().join("pydicom/CT_small.dcm");
+        let cached_path = entry_path(&get_data_path(), "pydicom/CT_small.dcm");
         let _ = fs::remove_file(cached_path);
 
         let path = path("pydicom/CT_small.dcm").unwrap();
@@ -317,6 +2565,372 @@ mod tests {
         assert!(path.exists());
     }
 
+    #[test]
+    fn path_or_skip_online() {
+        let path = path_or_skip("pydicom/MR_small.dcm").unwrap();
+        assert_eq!(path.file_name().unwrap(), "MR_small.dcm");
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn golden_dump() {
+        let golden_path = golden("pydicom/CT_small.dcm").unwrap();
+        let dump = fs::read_to_string(golden_path).unwrap();
+        assert!(dump.starts_with('{'));
+        assert!(dump.contains("\"00100010\""));
+        assert!(dump.contains("\"BulkDataURI\": \"pydicom/CT_small.dcm\""));
+
+        // implicit VR
+        assert!(matches!(
+            golden("pydicom/rtplan.dcm"),
+            Err(Error::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn pinned_data_url() {
+        assert_eq!(
+            github_data_url(GITHUB_REPOSITORY, "v1.2.3"),
+            "https://raw.githubusercontent.com/robyoung/dicom-test-files/v1.2.3/data/"
+        );
+    }
+
+    #[test]
+    fn hosted_collection() {
+        static MIRROR: Collection = Collection {
+            name: "mirror",
+            base_url: "https://example.org/mirror/",
+        };
+        let hash = lookup("pydicom/CT_small.dcm").unwrap().hash;
+        let entry = TestFile::none("mirror/CT_small.dcm", hash).collection(&MIRROR);
+        assert_eq!(
+            source_url(&entry).unwrap(),
+            "https://example.org/mirror/CT_small.dcm"
+        );
+
+        // from a mirror of its own
+        Config::new()
+            .collection_url("mirror", format!("{}pydicom", base_url().unwrap()))
+            .apply()
+            .unwrap();
+        let entry: &'static TestFile = Box::leak(Box::new(entry));
+        let (path, _) = fetch(entry).unwrap();
+        assert!(path.ends_with("mirror/CT_small.dcm"));
+        fs::remove_file(path).unwrap();
+
+        Config::new()
+            .collection_url("mirror", "https://example.org/other-mirror")
+            .apply()
+            .unwrap();
+        assert_eq!(
+            source_url(entry).unwrap(),
+            "https://example.org/other-mirror/CT_small.dcm"
+        );
+        Config::new()
+            .collection_url("mirror", MIRROR.base_url)
+            .apply()
+            .unwrap();
+    }
+
+    #[test]
+    fn size_limits() {
+        assert_eq!(parse_size("1024"), Some(1024));
+        assert_eq!(parse_size("64K"), Some(64 * 1024));
+        assert_eq!(parse_size("1M"), Some(1024 * 1024));
+        assert_eq!(parse_size(" 2 g"), Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("M"), None);
+        assert_eq!(parse_size("1.5M"), None);
+        assert_eq!(parse_size("-1"), None);
+    }
+
+    #[cfg(any(feature = "gzip", feature = "xz"))]
+    #[test]
+    fn install_compressed() {
+        let contents = fs::read(path("pydicom/MR_small.dcm").unwrap()).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let mut stored = Vec::new();
+        #[cfg(feature = "gzip")]
+        {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            io::Write::write_all(&mut encoder, &contents).unwrap();
+            stored.push((Compression::Gzip, encoder.finish().unwrap()));
+        }
+        #[cfg(feature = "xz")]
+        {
+            let mut encoder = xz2::write::XzEncoder::new(Vec::new(), 6);
+            io::Write::write_all(&mut encoder, &contents).unwrap();
+            stored.push((Compression::Xz, encoder.finish().unwrap()));
+        }
+
+        for (compression, data) in stored {
+            let hash = format!("{:x}", Sha256::digest(&data));
+            let entry = TestFile::new("myorg/mr.dcm", compression, Box::leak(hash.into()));
+            let tempfile_path = dir.path().join("stored");
+            fs::write(&tempfile_path, &data).unwrap();
+            let cached_path = dir.path().join(format!("{:?}.dcm", compression));
+            install(&entry, tempfile_path.clone(), &cached_path).unwrap();
+            assert_eq!(fs::read(&cached_path).unwrap(), contents);
+            assert!(!tempfile_path.exists());
+        }
+    }
+
+    #[test]
+    fn hash_while_writing() {
+        let dir = tempfile::tempdir().unwrap();
+        let contents = b"contents of the downloaded file".repeat(1000);
+        let (tempfile_path, digest) =
+            write_temp_hashed(&mut &contents[..], dir.path(), DigestAlgorithm::Sha256).unwrap();
+        assert_eq!(fs::read(&tempfile_path).unwrap(), contents);
+        assert_eq!(digest, file_hash(&tempfile_path).unwrap());
+
+        let entry = TestFile::none("myorg/file.dcm", Box::leak(digest.0.clone().into()));
+        let cached_path = dir.path().join("file.dcm");
+        install_hashed(&entry, tempfile_path, digest, &cached_path).unwrap();
+        assert_eq!(fs::read(&cached_path).unwrap(), contents);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn install_checks_decompressed_contents() {
+        let contents = fs::read(path("pydicom/MR_small.dcm").unwrap()).unwrap();
+        let stored = zstd::bulk::compress(&contents, 3).unwrap();
+        let hash: &str = Box::leak(format!("{:x}", Sha256::digest(&stored)).into());
+        let decompressed: &str = Box::leak(format!("{:x}", Sha256::digest(&contents)).into());
+        let dir = tempfile::tempdir().unwrap();
+        let tempfile_path = dir.path().join("stored");
+        let cached_path = dir.path().join("mr.dcm");
+
+        let entry = TestFile::zstd("myorg/mr.dcm", hash).decompressed(decompressed);
+        fs::write(&tempfile_path, &stored).unwrap();
+        install(&entry, tempfile_path.clone(), &cached_path).unwrap();
+        assert_eq!(fs::read(&cached_path).unwrap(), contents);
+
+        // as if the decoder had gone wrong
+        fs::remove_file(&cached_path).unwrap();
+        let entry = entry.decompressed(hash);
+        fs::write(&tempfile_path, &stored).unwrap();
+        assert!(matches!(
+            install(&entry, tempfile_path, &cached_path),
+            Err(Error::InvalidHash { .. })
+        ));
+        assert!(!cached_path.exists());
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn install_streamed_in_one_pass() {
+        let contents = fs::read(path("pydicom/MR_small.dcm").unwrap()).unwrap();
+        let stored = zstd::bulk::compress(&contents, 3).unwrap();
+        let hash: &str = Box::leak(format!("{:x}", Sha256::digest(&stored)).into());
+        let decompressed: &str = Box::leak(format!("{:x}", Sha256::digest(&contents)).into());
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("mr.dcm");
+
+        let entry = TestFile::zstd("myorg/mr.dcm", hash).decompressed(decompressed);
+        let bytes = install_streamed(&entry, &mut &stored[..], dir.path(), &cached_path).unwrap();
+        assert_eq!(bytes, stored.len() as u64);
+        assert_eq!(fs::read(&cached_path).unwrap(), contents);
+        fs::remove_file(&cached_path).unwrap();
+
+        // a corrupt download, which does not even decode
+        let mut corrupt = stored.clone();
+        corrupt[..4].copy_from_slice(b"nope");
+        assert!(matches!(
+            install_streamed(&entry, &mut &corrupt[..], dir.path(), &cached_path),
+            Err(Error::InvalidHash { expected, .. }) if expected == hash
+        ));
+        // as if the decoder had gone wrong
+        let entry = entry.decompressed(hash);
+        assert!(matches!(
+            install_streamed(&entry, &mut &stored[..], dir.path(), &cached_path),
+            Err(Error::InvalidHash { bytes, .. }) if bytes == contents.len() as u64
+        ));
+        // nothing is left behind
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn keep_compressed_copy() {
+        use std::io::Read;
+
+        let entry = find_entry("WG04/REF/CT2_UNC").unwrap();
+        let (stored_path, _) = fetch_stored(entry).unwrap();
+        assert_eq!(
+            stored_path,
+            entry_path(&get_data_path(), "WG04/REF/CT2_UNC.zst")
+        );
+        let (hash, _) = digest::file_digest(&stored_path, entry.digest).unwrap();
+        assert_eq!(hash, entry.hash);
+
+        // decompressed as it is read
+        let mut contents = Vec::new();
+        decoder(entry.compression, fs::File::open(&stored_path).unwrap())
+            .unwrap()
+            .read_to_end(&mut contents)
+            .unwrap();
+        if let Some(hash) = entry.decompressed_hash {
+            assert_eq!(format!("{:x}", Sha256::digest(&contents)), hash);
+        }
+        // or into a file on demand
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("CT2_UNC");
+        decompress_stored(entry, &stored_path, &cached_path).unwrap();
+        assert_eq!(fs::read(&cached_path).unwrap(), contents);
+
+        // without keeping files compressed, the cached file is read
+        let mut read = Vec::new();
+        reader("pydicom/CT_small.dcm")
+            .unwrap()
+            .read_to_end(&mut read)
+            .unwrap();
+        assert_eq!(
+            read,
+            fs::read(path("pydicom/CT_small.dcm").unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn size_of_contents() {
+        let liver = path("pydicom/liver.dcm").unwrap();
+        let size_of = |path| fs::metadata(path).unwrap().len();
+        assert_eq!(size("pydicom/liver.dcm").unwrap(), size_of(liver));
+        // measured once decompressed
+        let size_ct = size("WG04/REF/CT1_UNC").unwrap();
+        assert_eq!(size_ct, size_of(path("WG04/REF/CT1_UNC").unwrap()));
+        assert!(size_ct > find_entry("WG04/REF/CT1_UNC").unwrap().size);
+        assert!(matches!(
+            size("pydicom/livr.dcm"),
+            Err(Error::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn raw_stored_file() {
+        let stored_path = raw_path("WG04/REF/CT2_UNC").unwrap();
+        assert_eq!(
+            stored_path,
+            entry_path(&get_data_path(), "WG04/REF/CT2_UNC.zst")
+        );
+        let entry = find_entry("WG04/REF/CT2_UNC").unwrap();
+        let (hash, _) = digest::file_digest(&stored_path, entry.digest).unwrap();
+        assert_eq!(hash, entry.hash);
+
+        let raw = raw_path("pydicom/CT_small.dcm").unwrap();
+        assert_eq!(raw, path("pydicom/CT_small.dcm").unwrap());
+        assert!(matches!(
+            raw_path("WG04/REF/CT2"),
+            Err(Error::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown DICOM test file")]
+    fn path_or_skip_unknown_file() {
+        path_or_skip("pydicom/no_such_file.dcm");
+    }
+
+    #[test]
+    fn error_display_and_source() {
+        use std::error::Error as _;
+
+        let error = Error::TooLarge("WG04/REF/CT1_UNC has 3 bytes".to_string());
+        assert_eq!(error.to_string(), "WG04/REF/CT1_UNC has 3 bytes");
+        assert!(error.source().is_none());
+
+        let error = Error::from(io::Error::new(io::ErrorKind::NotFound, "no such file"));
+        assert_eq!(error.to_string(), "I/O error");
+        assert_eq!(error.source().unwrap().to_string(), "no such file");
+    }
+
+    #[test]
+    fn data_path_in_target() {
+        // test binaries are built into the target directory,
+        // unless `CARGO_TARGET_DIR` gave it another name
+        if let Some(target_dir) = target_dir() {
+            assert_eq!(default_data_path(), target_dir.join("dicom_test_files"));
+        }
+        assert!(platform_cache_dir().is_absolute());
+    }
+
+    #[test]
+    fn read_only_data_path() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(is_writable(&dir.path().join("cache")));
+
+        // nothing can be created under a file
+        let file = dir.path().join("file");
+        fs::write(&file, b"").unwrap();
+        let read_only = file.join("dicom_test_files");
+        assert!(!is_writable(&read_only));
+        let fallback = dir.path().join("fallback");
+        assert_eq!(
+            writable_data_path(read_only, Some(fallback.clone())),
+            fallback
+        );
+    }
+
+    #[test]
+    fn entry_paths() {
+        let dir = env::current_dir().unwrap();
+        let path = entry_path(&dir, "WG04/JPLY/NM1_JPLY");
+        assert!(path.ends_with(Path::new("WG04").join("JPLY").join("NM1_JPLY")));
+        #[cfg(not(windows))]
+        assert_eq!(path, dir.join("WG04/JPLY/NM1_JPLY"));
+        #[cfg(windows)]
+        {
+            let path = entry_path(Path::new(r"C:\target\dicom_test_files"), "WG04/JPLY/NM1_JPLY");
+            assert_eq!(
+                path,
+                Path::new(r"\\?\C:\target\dicom_test_files\WG04\JPLY\NM1_JPLY")
+            );
+            // relative paths cannot be extended
+            let path = entry_path(Path::new(r"..\cache"), "pydicom/liver.dcm");
+            assert_eq!(path, Path::new(r"..\cache\pydicom\liver.dcm"));
+        }
+    }
+
+    #[test]
+    fn entry_names_are_portable() {
+        let mut seen = std::collections::HashSet::new();
+        for entry in FILE_ENTRIES.iter().chain(GOLDEN_ENTRIES) {
+            // case-insensitive file systems would conflate them
+            assert!(
+                seen.insert(entry.name.to_lowercase()),
+                "{} differs from another name only by case",
+                entry.name
+            );
+            for component in entry.name.split('/') {
+                assert!(
+                    !component.is_empty()
+                        && !component.ends_with('.')
+                        && !component.ends_with(' ')
+                        && !component.contains(|c| "<>:\"\\|?*".contains(c)),
+                    "{} is not a valid path on Windows",
+                    entry.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn transient_errors() {
+        assert!(Error::Unreachable("offline".to_string()).is_transient());
+        assert!(Error::from(io::Error::from(io::ErrorKind::TimedOut)).is_transient());
+        assert!(!Error::from(io::Error::from(io::ErrorKind::NotFound)).is_transient());
+        assert!(!Error::Download("status code 404".to_string()).is_transient());
+        assert!(!suggest::not_found("nope.dcm", []).is_transient());
+        assert!(!Error::InvalidHash {
+            name: "pydicom/liver.dcm".to_string(),
+            expected: "0".repeat(64),
+            actual: "1".repeat(64),
+            bytes: 0,
+            url: None,
+        }
+        .is_transient());
+    }
+
     #[test]
     fn load_a_single_path_concurrent() {
         let handles: Vec<_> = (0..4)
@@ -0,0 +1,235 @@
+//! Lockfile of the test files used by a workspace
+
+use crate::{config, source_url, target_dir, Error, Result, TestFile};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// Name of the lockfile in the workspace
+pub(crate) const LOCKFILE_NAME: &str = "dicom-test-files.lock";
+
+const HEADER: &str = "# dicom-test-files lock v1";
+
+/// What to do with the [lockfile](crate#lockfile)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+    /// Record each test file used into the lockfile
+    Record,
+    /// Fail with [`Error::LockMismatch`] on test files
+    /// which are not in the lockfile as they are now
+    Verify,
+}
+
+impl LockMode {
+    /// Parse the value of `DICOM_TEST_FILES_LOCK`
+    pub(crate) fn parse(value: &str) -> Option<LockMode> {
+        match value {
+            "record" => Some(LockMode::Record),
+            "verify" => Some(LockMode::Verify),
+            _ => None,
+        }
+    }
+}
+
+/// A test file as recorded in the lockfile
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Locked {
+    hash: String,
+    url: String,
+}
+
+/// The entries of the lockfile, once read
+static LOCKED: Mutex<Option<(PathBuf, BTreeMap<String, Locked>)>> = Mutex::new(None);
+
+/// The default path of the lockfile:
+/// in the workspace holding the target directory
+pub(crate) fn default_path() -> Result<PathBuf> {
+    lockfile_next_to(target_dir())
+}
+
+/// The path of the lockfile next to the given target directory,
+/// which has to be known
+fn lockfile_next_to(target_dir: Option<PathBuf>) -> Result<PathBuf> {
+    let target_dir = target_dir.ok_or_else(|| {
+        Error::LockMismatch(
+            "no target directory to keep the lockfile next to, \
+             set DICOM_TEST_FILES_LOCKFILE"
+                .to_string(),
+        )
+    })?;
+    Ok(match target_dir.parent() {
+        Some(workspace) => workspace.join(LOCKFILE_NAME),
+        None => PathBuf::from(LOCKFILE_NAME),
+    })
+}
+
+/// Record or verify the given entry according to the lock mode
+pub(crate) fn check(entry: &TestFile) -> Result<()> {
+    let (mode, path) = match config::lock() {
+        Some((mode, Some(path))) => (mode, path),
+        Some((mode, None)) => (mode, default_path()?),
+        None => return Ok(()),
+    };
+    let current = Locked {
+        hash: entry.hash.to_string(),
+        url: source_url(entry)?,
+    };
+
+    let mut locked = LOCKED.lock().unwrap_or_else(|e| e.into_inner());
+    if locked.as_ref().map(|(locked_path, _)| locked_path) != Some(&path) {
+        *locked = Some((path.clone(), read(&path)?));
+    }
+    let (_, entries) = locked.as_mut().expect("lockfile entries");
+
+    match mode {
+        LockMode::Verify => verify(entries, entry.name, &current),
+        LockMode::Record => {
+            if entries.get(entry.name) != Some(&current) {
+                entries.insert(entry.name.to_string(), current);
+                write(&path, entries)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Check a test file against the entries of the lockfile
+fn verify(entries: &BTreeMap<String, Locked>, name: &str, current: &Locked) -> Result<()> {
+    let mismatch = |reason: String| Err(Error::LockMismatch(format!("{} {}", name, reason)));
+    match entries.get(name) {
+        None => mismatch("is not in the lockfile".to_string()),
+        Some(locked) if locked.hash != current.hash => mismatch(format!(
+            "has changed: hash {} was locked, now {}",
+            locked.hash, current.hash
+        )),
+        Some(locked) if locked.url != current.url => mismatch(format!(
+            "has changed: source {} was locked, now {}",
+            locked.url, current.url
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Read the entries of the lockfile, if it exists
+fn read(path: &Path) -> Result<BTreeMap<String, Locked>> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text).map_err(|reason| {
+            Error::LockMismatch(format!("invalid lockfile {}: {}", path.display(), reason))
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Write the entries to the lockfile, replacing it as a whole
+fn write(path: &Path, entries: &BTreeMap<String, Locked>) -> Result<()> {
+    let temp_path = path.with_extension(format!("lock.{}", std::process::id()));
+    fs::write(&temp_path, render(entries))?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+/// Parse the lines of a lockfile:
+/// the hash of the stored file, its source URL and its name,
+/// separated by single spaces
+fn parse(text: &str) -> Result<BTreeMap<String, Locked>, String> {
+    let mut lines = text.lines();
+    if lines.next() != Some(HEADER) {
+        return Err("unsupported version".to_string());
+    }
+    let mut entries = BTreeMap::new();
+    for line in lines.filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let mut fields = line.splitn(3, ' ');
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(hash), Some(url), Some(name)) => {
+                let locked = Locked {
+                    hash: hash.to_string(),
+                    url: url.to_string(),
+                };
+                entries.insert(name.to_string(), locked);
+            }
+            _ => return Err(format!("invalid line {:?}", line)),
+        }
+    }
+    Ok(entries)
+}
+
+fn render(entries: &BTreeMap<String, Locked>) -> String {
+    let mut text = format!("{}\n", HEADER);
+    for (name, locked) in entries {
+        text += &format!("{} {} {}\n", locked.hash, locked.url, name);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lockfile_entries() {
+        let locked = |hash: &str, url: &str| Locked {
+            hash: hash.repeat(64),
+            url: url.to_string(),
+        };
+        let mut entries = BTreeMap::new();
+        entries.insert(
+            "pydicom/CT_small.dcm".to_string(),
+            locked("a", "https://example.org/data/pydicom/CT_small.dcm"),
+        );
+        entries.insert(
+            "WG04/REF/NM1 UNC".to_string(),
+            locked("b", "https://example.org/data/WG04/REF/NM1%20UNC.zst"),
+        );
+        let text = render(&entries);
+        assert!(text.starts_with(HEADER));
+        assert_eq!(parse(&text).unwrap(), entries);
+        assert!(parse("not a lockfile").is_err());
+
+        let current = locked("a", "https://example.org/data/pydicom/CT_small.dcm");
+        assert!(verify(&entries, "pydicom/CT_small.dcm", &current).is_ok());
+        for (name, current) in [
+            ("pydicom/MR_small.dcm", current.clone()),
+            ("pydicom/CT_small.dcm", locked("c", &current.url)),
+            (
+                "pydicom/CT_small.dcm",
+                locked("a", "https://example.org/other"),
+            ),
+        ] {
+            assert!(matches!(
+                verify(&entries, name, &current),
+                Err(Error::LockMismatch(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn lockfile_next_to_target() {
+        // wherever the cache is, such as in the cache directory of the user
+        let workspace = Path::new("/home/user/project");
+        assert_eq!(
+            lockfile_next_to(Some(workspace.join("target"))).unwrap(),
+            workspace.join(LOCKFILE_NAME)
+        );
+        if let Some(target_dir) = target_dir() {
+            assert_eq!(
+                default_path().unwrap(),
+                target_dir.parent().unwrap().join(LOCKFILE_NAME)
+            );
+        }
+        assert!(matches!(
+            lockfile_next_to(None),
+            Err(Error::LockMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn lock_mode() {
+        assert_eq!(LockMode::parse("record"), Some(LockMode::Record));
+        assert_eq!(LockMode::parse("verify"), Some(LockMode::Verify));
+        assert_eq!(LockMode::parse("yes"), None);
+    }
+}
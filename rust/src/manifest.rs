@@ -0,0 +1,886 @@
+//! External manifest documents of test files
+
+use crate::{
+    all_entries, base_url, Anonymization, Compression, DigestAlgorithm, Error, Provenance, Result,
+    TestFile,
+};
+use std::{collections::HashSet, convert::TryFrom, fs, io::Write, path::Path};
+
+/// Format of a manifest document, see [`export_manifest`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// JSON, requires the `json` feature
+    Json,
+    /// TOML, requires the `toml` feature
+    Toml,
+}
+
+impl Format {
+    /// Determine the format of a document from its file extension
+    pub(crate) fn of_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Format::Json),
+            "toml" => Some(Format::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// Write the entries of all known test files to a manifest document.
+///
+/// The document lists the name, hash, size and compression of each file,
+/// so that other tools can mirror or audit the test files,
+/// in the format of [external manifests](crate#external-manifests).
+/// Files downloaded from the default data source are at the root,
+/// with its current `base_url`,
+/// the others are grouped by base URL
+/// into sources named `source-1`, `source-2`, and so on.
+///
+/// ```no_run
+/// use dicom_test_files::{export_manifest, Format};
+///
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let file = std::fs::File::create("dicom-test-files.json")?;
+/// export_manifest(Format::Json, file)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Fails with [`Error::Manifest`]
+/// if the feature of the format is not enabled.
+pub fn export_manifest(format: Format, writer: impl Write) -> Result<()> {
+    let default_url = base_url().map_err(Error::ResolveUrl)?;
+    let mut sources: Vec<(&str, Vec<&TestFile>)> = vec![(&default_url, Vec::new())];
+    for entry in all_entries()? {
+        let url = entry.base_url.unwrap_or(&default_url);
+        match sources
+            .iter_mut()
+            .find(|(source_url, _)| *source_url == url)
+        {
+            Some((_, entries)) => entries.push(entry),
+            None => sources.push((url, vec![entry])),
+        }
+    }
+    write_sources(format, &sources, writer)
+}
+
+/// Write a manifest document of the given entries grouped by base URL,
+/// those of the first source at the root
+pub(crate) fn write_sources(
+    format: Format,
+    sources: &[(&str, Vec<&TestFile>)],
+    writer: impl Write,
+) -> Result<()> {
+    match format {
+        #[cfg(feature = "json")]
+        Format::Json => {
+            use serde_json::{Map, Value};
+
+            let mut writer = writer;
+
+            let source = |url: &str, entries: &[&TestFile]| {
+                let files = entries
+                    .iter()
+                    .map(|entry| {
+                        let mut file = Map::new();
+                        file.insert("name".to_string(), Value::from(entry.name));
+                        file.insert("hash".to_string(), Value::from(entry.hash));
+                        if entry.digest != DigestAlgorithm::Sha256 {
+                            file.insert("digest".to_string(), Value::from(entry.digest.name()));
+                        }
+                        file.insert("size".to_string(), Value::from(entry.size));
+                        file.insert(
+                            "compression".to_string(),
+                            Value::from(compression_name(entry.compression)),
+                        );
+                        if let Some(hash) = entry.decompressed_hash {
+                            file.insert("decompressed_hash".to_string(), Value::from(hash));
+                        }
+                        if let Some(provenance) = entry.provenance {
+                            file.insert("source".to_string(), Value::from(provenance.source));
+                            if let Some(url) = provenance.url {
+                                file.insert("source_url".to_string(), Value::from(url));
+                            }
+                            file.insert("license".to_string(), Value::from(provenance.license));
+                        }
+                        if let Some(status) = entry.anonymization {
+                            file.insert("anonymization".to_string(), Value::from(status.name()));
+                        }
+                        if let Some(url) = entry.upstream_url {
+                            file.insert("upstream_url".to_string(), Value::from(url));
+                        }
+                        if entry.pointer {
+                            file.insert("pointer".to_string(), Value::from(true));
+                        }
+                        if let Some(uid) = entry.info.sop_class_uid {
+                            file.insert("sop_class_uid".to_string(), Value::from(uid));
+                        }
+                        if let Some(set) = entry.set {
+                            file.insert("set".to_string(), Value::from(set));
+                        }
+                        if let Some(number) = entry.instance_number {
+                            file.insert("instance_number".to_string(), Value::from(number));
+                        }
+                        if let Compression::ZipMember { archive, member } = entry.compression {
+                            file.insert("archive".to_string(), Value::from(archive));
+                            file.insert("member".to_string(), Value::from(member));
+                        }
+                        if let Compression::DicomWeb {
+                            study,
+                            series,
+                            instance,
+                        } = entry.compression
+                        {
+                            file.insert("study_uid".to_string(), Value::from(study));
+                            file.insert("series_uid".to_string(), Value::from(series));
+                            file.insert("instance_uid".to_string(), Value::from(instance));
+                        }
+                        Value::Object(file)
+                    })
+                    .collect();
+                let mut source = Map::new();
+                source.insert("base_url".to_string(), Value::from(url));
+                source.insert("files".to_string(), Value::Array(files));
+                source
+            };
+            let (url, entries) = &sources[0];
+            let mut document = source(url, entries);
+            if sources.len() > 1 {
+                let others = sources[1..]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (url, entries))| {
+                        (
+                            format!("source-{}", i + 1),
+                            Value::Object(source(url, entries)),
+                        )
+                    })
+                    .collect();
+                document.insert("sources".to_string(), Value::Object(others));
+            }
+            serde_json::to_writer_pretty(&mut writer, &Value::Object(document))
+                .map_err(std::io::Error::from)?;
+            writer.write_all(b"\n")?;
+            Ok(())
+        }
+        #[cfg(feature = "toml")]
+        Format::Toml => {
+            use toml::{Table, Value};
+
+            let mut writer = writer;
+
+            let source = |url: &str, entries: &[&TestFile]| {
+                let files = entries
+                    .iter()
+                    .map(|entry| {
+                        let mut file = Table::new();
+                        file.insert("name".to_string(), Value::from(entry.name));
+                        file.insert("hash".to_string(), Value::from(entry.hash));
+                        if entry.digest != DigestAlgorithm::Sha256 {
+                            file.insert("digest".to_string(), Value::from(entry.digest.name()));
+                        }
+                        // sizes of test files are far below i64::MAX
+                        file.insert("size".to_string(), Value::Integer(entry.size as i64));
+                        file.insert(
+                            "compression".to_string(),
+                            Value::from(compression_name(entry.compression)),
+                        );
+                        if let Some(hash) = entry.decompressed_hash {
+                            file.insert("decompressed_hash".to_string(), Value::from(hash));
+                        }
+                        if let Some(provenance) = entry.provenance {
+                            file.insert("source".to_string(), Value::from(provenance.source));
+                            if let Some(url) = provenance.url {
+                                file.insert("source_url".to_string(), Value::from(url));
+                            }
+                            file.insert("license".to_string(), Value::from(provenance.license));
+                        }
+                        if let Some(status) = entry.anonymization {
+                            file.insert("anonymization".to_string(), Value::from(status.name()));
+                        }
+                        if let Some(url) = entry.upstream_url {
+                            file.insert("upstream_url".to_string(), Value::from(url));
+                        }
+                        if entry.pointer {
+                            file.insert("pointer".to_string(), Value::from(true));
+                        }
+                        if let Some(uid) = entry.info.sop_class_uid {
+                            file.insert("sop_class_uid".to_string(), Value::from(uid));
+                        }
+                        if let Some(set) = entry.set {
+                            file.insert("set".to_string(), Value::from(set));
+                        }
+                        if let Some(number) = entry.instance_number {
+                            file.insert(
+                                "instance_number".to_string(),
+                                Value::Integer(i64::from(number)),
+                            );
+                        }
+                        if let Compression::ZipMember { archive, member } = entry.compression {
+                            file.insert("archive".to_string(), Value::from(archive));
+                            file.insert("member".to_string(), Value::from(member));
+                        }
+                        if let Compression::DicomWeb {
+                            study,
+                            series,
+                            instance,
+                        } = entry.compression
+                        {
+                            file.insert("study_uid".to_string(), Value::from(study));
+                            file.insert("series_uid".to_string(), Value::from(series));
+                            file.insert("instance_uid".to_string(), Value::from(instance));
+                        }
+                        Value::Table(file)
+                    })
+                    .collect();
+                let mut source = Table::new();
+                source.insert("base_url".to_string(), Value::from(url));
+                source.insert("files".to_string(), Value::Array(files));
+                source
+            };
+            let (url, entries) = &sources[0];
+            let mut document = source(url, entries);
+            if sources.len() > 1 {
+                let others = sources[1..]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (url, entries))| {
+                        (
+                            format!("source-{}", i + 1),
+                            Value::Table(source(url, entries)),
+                        )
+                    })
+                    .collect();
+                document.insert("sources".to_string(), Value::Table(others));
+            }
+            let document =
+                toml::to_string(&document).map_err(|e| Error::Manifest(e.to_string()))?;
+            writer.write_all(document.as_bytes())?;
+            Ok(())
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = (sources, writer);
+            Err(Error::Manifest(feature_required(format)))
+        }
+    }
+}
+
+#[cfg_attr(not(any(feature = "json", feature = "toml")), allow(dead_code))]
+fn compression_name(compression: Compression) -> &'static str {
+    match compression {
+        Compression::None => "none",
+        Compression::Zstd => "zstd",
+        Compression::Gzip => "gzip",
+        Compression::Xz => "xz",
+        Compression::ZipMember { .. } => "zip",
+        Compression::DicomWeb { .. } => "dicomweb",
+    }
+}
+
+fn feature_required(format: Format) -> String {
+    format!(
+        "feature \"{}\" is required",
+        if format == Format::Json {
+            "json"
+        } else {
+            "toml"
+        }
+    )
+}
+
+/// Load the test file entries of the manifest document at the given path
+pub(crate) fn load(path: &Path) -> Result<Vec<TestFile>> {
+    let invalid = |reason: String| {
+        Error::Manifest(format!("invalid manifest {}: {}", path.display(), reason))
+    };
+    let format = Format::of_path(path)
+        .ok_or_else(|| invalid("unknown format, expected a .json or .toml file".to_string()))?;
+    let document = fs::read(path)?;
+    parse(format, &document).map_err(invalid)
+}
+
+/// Parse the test file entries of a manifest document
+fn parse(format: Format, document: &[u8]) -> Result<Vec<TestFile>, String> {
+    match format {
+        #[cfg(feature = "json")]
+        Format::Json => {
+            let document: serde_json::Value =
+                serde_json::from_slice(document).map_err(|e| e.to_string())?;
+            entries(&document)
+        }
+        #[cfg(feature = "toml")]
+        Format::Toml => {
+            let document = std::str::from_utf8(document).map_err(|e| e.to_string())?;
+            let document: toml::Table = toml::from_str(document).map_err(|e| e.to_string())?;
+            entries(&toml::Value::Table(document))
+        }
+        #[allow(unreachable_patterns)]
+        _ => {
+            let _ = document;
+            Err(feature_required(format))
+        }
+    }
+}
+
+/// Access to the values of a parsed manifest document
+trait Node: Sized {
+    fn field(&self, key: &str) -> Option<&Self>;
+    fn as_text(&self) -> Option<&str>;
+    fn as_size(&self) -> Option<u64>;
+    fn as_flag(&self) -> Option<bool>;
+    fn as_list(&self) -> Option<&[Self]>;
+    fn as_map(&self) -> Option<Vec<(&str, &Self)>>;
+}
+
+#[cfg(feature = "json")]
+impl Node for serde_json::Value {
+    fn field(&self, key: &str) -> Option<&Self> {
+        self.get(key)
+    }
+
+    fn as_text(&self) -> Option<&str> {
+        self.as_str()
+    }
+
+    fn as_size(&self) -> Option<u64> {
+        self.as_u64()
+    }
+
+    fn as_flag(&self) -> Option<bool> {
+        self.as_bool()
+    }
+
+    fn as_list(&self) -> Option<&[Self]> {
+        self.as_array().map(Vec::as_slice)
+    }
+
+    fn as_map(&self) -> Option<Vec<(&str, &Self)>> {
+        let map = self.as_object()?;
+        Some(
+            map.iter()
+                .map(|(key, value)| (key.as_str(), value))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "toml")]
+impl Node for toml::Value {
+    fn field(&self, key: &str) -> Option<&Self> {
+        self.get(key)
+    }
+
+    fn as_text(&self) -> Option<&str> {
+        self.as_str()
+    }
+
+    fn as_size(&self) -> Option<u64> {
+        self.as_integer()
+            .filter(|size| *size >= 0)
+            .map(|size| size as u64)
+    }
+
+    fn as_flag(&self) -> Option<bool> {
+        self.as_bool()
+    }
+
+    fn as_list(&self) -> Option<&[Self]> {
+        self.as_array().map(Vec::as_slice)
+    }
+
+    fn as_map(&self) -> Option<Vec<(&str, &Self)>> {
+        let table = self.as_table()?;
+        Some(
+            table
+                .iter()
+                .map(|(key, value)| (key.as_str(), value))
+                .collect(),
+        )
+    }
+}
+
+/// Collect the entries of a manifest document:
+///
+/// - `base_url` (optional): where the files are downloaded from,
+///   instead of the default data source
+/// - `files`: the list of test files, each with
+///   - `name`: the path identifier of the file, without the extension
+///     of its compression
+///   - `hash`: SHA-256 hash of the file as stored
+///   - `size` (optional): size of the file as stored
+///   - `compression` (optional): `"none"` (the default), `"zstd"`,
+///     `"gzip"`, `"xz"` or `"zip"` for a member of a ZIP archive, with
+///     - `archive`: the stored name of the archive
+///     - `member` (optional): the path of the member, the name otherwise
+/// - `sources` (optional): other sources by name,
+///   each with its own `base_url` and `files`
+#[cfg_attr(not(any(feature = "json", feature = "toml")), allow(dead_code))]
+fn entries<N: Node>(document: &N) -> Result<Vec<TestFile>, String> {
+    let mut entries = Vec::new();
+    let sources = document.field("sources");
+    if document.field("files").is_some() || sources.is_none() {
+        source_entries(document, None, &mut entries)?;
+    }
+    if let Some(sources) = sources {
+        let sources = sources
+            .as_map()
+            .ok_or("`sources` must be a table of named sources")?;
+        for (name, source) in sources {
+            source_entries(source, Some(name), &mut entries)?;
+        }
+    }
+
+    let mut names = HashSet::new();
+    if let Some(entry) = entries.iter().find(|entry| !names.insert(entry.name)) {
+        return Err(format!("{} is listed more than once", entry.name));
+    }
+    Ok(entries)
+}
+
+/// Collect the entries of one source of a manifest document
+#[cfg_attr(not(any(feature = "json", feature = "toml")), allow(dead_code))]
+fn source_entries<N: Node>(
+    source: &N,
+    source_name: Option<&str>,
+    entries: &mut Vec<TestFile>,
+) -> Result<(), String> {
+    let of_source = match source_name {
+        Some(name) => format!(" of source `{}`", name),
+        None => String::new(),
+    };
+    let base_url = match source.field("base_url") {
+        Some(url) => {
+            let url = url
+                .as_text()
+                .ok_or_else(|| format!("`base_url`{} must be a string", of_source))?;
+            let url = if url.ends_with('/') {
+                url.to_string()
+            } else {
+                format!("{}/", url)
+            };
+            Some(leak(url))
+        }
+        None => None,
+    };
+
+    let files = source
+        .field("files")
+        .and_then(N::as_list)
+        .ok_or_else(|| format!("`files`{} must be a list of test files", of_source))?;
+    for (i, file) in files.iter().enumerate() {
+        let entry = entry(file, base_url).map_err(|key| {
+            format!(
+                "missing or invalid `{}` of file #{}{}",
+                key,
+                i + 1,
+                of_source
+            )
+        })?;
+        entries.push(entry);
+    }
+    Ok(())
+}
+
+/// Collect a test file entry,
+/// failing with the name of the first invalid field
+#[cfg_attr(not(any(feature = "json", feature = "toml")), allow(dead_code))]
+fn entry<N: Node>(file: &N, base_url: Option<&'static str>) -> Result<TestFile, &'static str> {
+    let text = |key: &'static str| match file.field(key) {
+        Some(value) => value.as_text().map(Some).ok_or(key),
+        None => Ok(None),
+    };
+    let name = text("name")?.ok_or("name")?;
+    let digest = match text("digest")? {
+        None => DigestAlgorithm::Sha256,
+        Some(name) => DigestAlgorithm::from_name(name).ok_or("digest")?,
+    };
+    let hash = text("hash")?
+        .filter(|hash| digest.is_hash(hash))
+        .ok_or("hash")?;
+    let dicomweb = || {
+        let uid = |key| Ok::<_, &str>(leak(text(key)?.ok_or(key)?.to_string()));
+        Ok::<_, &str>(Compression::DicomWeb {
+            study: uid("study_uid")?,
+            series: uid("series_uid")?,
+            instance: uid("instance_uid")?,
+        })
+    };
+    let compression = match text("compression")? {
+        // instances of a DICOMweb server are known by their UIDs
+        None if file.field("instance_uid").is_some() => dicomweb()?,
+        None | Some("none") => Compression::None,
+        Some("zstd") => Compression::Zstd,
+        Some("gzip") => Compression::Gzip,
+        Some("xz") => Compression::Xz,
+        Some("zip") => Compression::ZipMember {
+            archive: leak(text("archive")?.ok_or("archive")?.to_string()),
+            member: leak(text("member")?.unwrap_or(name).to_string()),
+        },
+        Some("dicomweb") => dicomweb()?,
+        Some(_) => return Err("compression"),
+    };
+    let size = match file.field("size") {
+        Some(size) => size.as_size().ok_or("size")?,
+        None => 0,
+    };
+    let decompressed_hash = match text("decompressed_hash")? {
+        Some(hash) if !is_sha256(hash) => return Err("decompressed_hash"),
+        hash => hash.map(|hash| leak(hash.to_string())),
+    };
+    let provenance = match (text("source")?, text("license")?) {
+        (Some(source), Some(license)) => Some(Provenance {
+            source: leak(source.to_string()),
+            url: text("source_url")?.map(|url| leak(url.to_string())),
+            license: leak(license.to_string()),
+        }),
+        (None, None) => None,
+        (Some(_), None) => return Err("license"),
+        (None, Some(_)) => return Err("source"),
+    };
+    let anonymization = match text("anonymization")? {
+        Some(name) => Some(Anonymization::from_name(name).ok_or("anonymization")?),
+        None => None,
+    };
+    let pointer = match file.field("pointer") {
+        Some(pointer) => pointer.as_flag().ok_or("pointer")?,
+        None => false,
+    };
+    let instance_number = match file.field("instance_number") {
+        Some(number) => Some(
+            number
+                .as_size()
+                .and_then(|number| u32::try_from(number).ok())
+                .ok_or("instance_number")?,
+        ),
+        None => None,
+    };
+
+    let mut entry = TestFile::new(leak(name.to_string()), compression, leak(hash.to_string()))
+        .size(size)
+        .digest(digest);
+    entry.decompressed_hash = decompressed_hash;
+    entry.provenance = provenance;
+    entry.anonymization = anonymization;
+    entry.upstream_url = text("upstream_url")?.map(|url| leak(url.to_string()));
+    entry.info.sop_class_uid = text("sop_class_uid")?.map(|uid| leak(uid.to_string()));
+    entry.set = text("set")?.map(|set| leak(set.to_string()));
+    entry.instance_number = instance_number;
+    entry.pointer = pointer;
+    entry.base_url = base_url;
+    Ok(entry)
+}
+
+/// Whether the given text is a SHA-256 hash in lowercase hexadecimal
+pub(crate) fn is_sha256(hash: &str) -> bool {
+    DigestAlgorithm::Sha256.is_hash(hash)
+}
+
+/// Keep a string for the rest of the process, as entries are `'static`
+#[cfg_attr(not(any(feature = "json", feature = "toml")), allow(dead_code))]
+fn leak(text: String) -> &'static str {
+    Box::leak(text.into_boxed_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_manifest() {
+        let document = format!(
+            r#"{{
+                "base_url": "https://example.org/private",
+                "files": [
+                    {{ "name": "myorg/weird.dcm", "hash": "{}", "size": 1234 }},
+                    {{ "name": "myorg/big", "hash": "{}", "compression": "zstd",
+                       "decompressed_hash": "{}" }}
+                ]
+            }}"#,
+            "a".repeat(64),
+            "b".repeat(64),
+            "e".repeat(64)
+        );
+        let entries = parse(Format::Json, document.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "myorg/weird.dcm");
+        assert_eq!(entries[0].size, 1234);
+        assert_eq!(entries[0].compression, Compression::None);
+        assert_eq!(entries[0].base_url, Some("https://example.org/private/"));
+        assert_eq!(entries[1].compression, Compression::Zstd);
+        assert_eq!(entries[1].size, 0);
+        assert_eq!(entries[0].decompressed_hash, None);
+        assert_eq!(entries[1].decompressed_hash, Some(&*"e".repeat(64)));
+        assert_eq!(entries[1].provenance, None);
+
+        let document = r#"{ "files": [{ "name": "myorg/weird.dcm", "hash": "nope" }] }"#;
+        assert_eq!(
+            parse(Format::Json, document.as_bytes()).unwrap_err(),
+            "missing or invalid `hash` of file #1"
+        );
+
+        let document = format!(
+            r#"{{ "files": [{{ "name": "myorg/fast.dcm", "hash": "{}", "digest": "sha512" }}] }}"#,
+            "d".repeat(128)
+        );
+        let entries = parse(Format::Json, document.as_bytes()).unwrap();
+        assert_eq!(entries[0].digest, DigestAlgorithm::Sha512);
+        assert_eq!(entries[0].hash.len(), 128);
+        let document = document.replace("sha512", "md5");
+        assert_eq!(
+            parse(Format::Json, document.as_bytes()).unwrap_err(),
+            "missing or invalid `digest` of file #1"
+        );
+        let document = format!(
+            r#"{{ "files": [{{ "name": "myorg/slide.dcm", "hash": "{}", "pointer": true }}] }}"#,
+            "f".repeat(64)
+        );
+        let entries = parse(Format::Json, document.as_bytes()).unwrap();
+        assert!(entries[0].pointer);
+        assert_eq!(
+            parse(Format::Json, document.replace("true", "1").as_bytes()).unwrap_err(),
+            "missing or invalid `pointer` of file #1"
+        );
+        assert!(parse(Format::Json, b"{ \"files\": 3 }").is_err());
+        assert!(parse(Format::Json, b"not json").is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_manifest() {
+        let document = format!(
+            "[[files]]\nname = \"myorg/weird.dcm\"\nhash = \"{}\"\nsize = 99\n",
+            "c".repeat(64)
+        );
+        let entries = parse(Format::Toml, document.as_bytes()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "myorg/weird.dcm");
+        assert_eq!(entries[0].size, 99);
+        assert_eq!(entries[0].base_url, None);
+
+        let document = format!(
+            "[[files]]\nname = \"myorg/weird.dcm\"\nhash = \"{}\"\n\
+             source = \"myorg\"\nlicense = \"CC0-1.0\"\n",
+            "c".repeat(64)
+        );
+        let entries = parse(Format::Toml, document.as_bytes()).unwrap();
+        assert_eq!(
+            entries[0].provenance,
+            Some(Provenance {
+                source: "myorg",
+                url: None,
+                license: "CC0-1.0"
+            })
+        );
+        assert_eq!(
+            parse(
+                Format::Toml,
+                document.replace("license", "licence").as_bytes()
+            )
+            .unwrap_err(),
+            "missing or invalid `license` of file #1"
+        );
+        let document = document.replace("license", "anonymization = \"synthetic\"\nlicense");
+        let entries = parse(Format::Toml, document.as_bytes()).unwrap();
+        assert_eq!(entries[0].anonymization, Some(Anonymization::Synthetic));
+        assert_eq!(entries[0].upstream_url, None);
+        let document = format!(
+            "{}upstream_url = \"https://example.org/weird.dcm\"\n",
+            document
+        );
+        let entries = parse(Format::Toml, document.as_bytes()).unwrap();
+        assert_eq!(
+            entries[0].upstream_url,
+            Some("https://example.org/weird.dcm")
+        );
+        assert_eq!(entries[0].info.sop_class_uid, None);
+        let document = format!(
+            "{}set = \"weird\"\ninstance_number = 3\nsop_class_uid = \"1.2.840.10008.5.1.4.1.1.2\"\n",
+            document
+        );
+        let entries = parse(Format::Toml, document.as_bytes()).unwrap();
+        assert_eq!(entries[0].set, Some("weird"));
+        assert_eq!(
+            entries[0].info.sop_class_uid,
+            Some("1.2.840.10008.5.1.4.1.1.2")
+        );
+        assert_eq!(entries[0].instance_number, Some(3));
+        assert_eq!(
+            parse(Format::Toml, document.replace("= 3", "= -3").as_bytes()).unwrap_err(),
+            "missing or invalid `instance_number` of file #1"
+        );
+        assert_eq!(
+            parse(
+                Format::Toml,
+                document.replace("synthetic", "public").as_bytes()
+            )
+            .unwrap_err(),
+            "missing or invalid `anonymization` of file #1"
+        );
+
+        let document = format!(
+            "[[files]]\nname = \"myorg/a.dcm\"\nhash = \"{h}\"\ncompression = \"zip\"\n\
+             archive = \"all.zip\"\n\
+             [[files]]\nname = \"myorg/b.dcm\"\nhash = \"{h}\"\ncompression = \"zip\"\n\
+             archive = \"all.zip\"\nmember = \"images/b.dcm\"\n",
+            h = "c".repeat(64)
+        );
+        let entries = parse(Format::Toml, document.as_bytes()).unwrap();
+        let members: Vec<_> = entries.iter().map(|entry| entry.compression).collect();
+        assert_eq!(
+            members,
+            vec![
+                Compression::ZipMember {
+                    archive: "all.zip",
+                    member: "myorg/a.dcm"
+                },
+                Compression::ZipMember {
+                    archive: "all.zip",
+                    member: "images/b.dcm"
+                },
+            ]
+        );
+        let document = format!(
+            "[[files]]\nname = \"myorg/a.dcm\"\nhash = \"{}\"\ncompression = \"zip\"\n",
+            "c".repeat(64)
+        );
+        assert_eq!(
+            parse(Format::Toml, document.as_bytes()).unwrap_err(),
+            "missing or invalid `archive` of file #1"
+        );
+
+        let document = format!(
+            "[[files]]\nname = \"hospital/ct.dcm\"\nhash = \"{}\"\n\
+             study_uid = \"1.2\"\nseries_uid = \"1.2.3\"\ninstance_uid = \"1.2.3.4\"\n",
+            "c".repeat(64)
+        );
+        let entries = parse(Format::Toml, document.as_bytes()).unwrap();
+        assert_eq!(
+            entries[0].compression,
+            Compression::DicomWeb {
+                study: "1.2",
+                series: "1.2.3",
+                instance: "1.2.3.4"
+            }
+        );
+        assert_eq!(
+            parse(
+                Format::Toml,
+                document.replace("series_uid", "series").as_bytes()
+            )
+            .unwrap_err(),
+            "missing or invalid `series_uid` of file #1"
+        );
+
+        let document = format!("[[files]]\nhash = \"{}\"\n", "c".repeat(64));
+        assert_eq!(
+            parse(Format::Toml, document.as_bytes()).unwrap_err(),
+            "missing or invalid `name` of file #1"
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn multiple_sources() {
+        let document = format!(
+            r#"
+[[files]]
+name = "myorg/weird.dcm"
+hash = "{a}"
+
+[sources.gdcm]
+base_url = "https://example.org/gdcm-data"
+
+[[sources.gdcm.files]]
+name = "gdcm/CT-MONO2-16-ort.dcm"
+hash = "{b}"
+
+[sources.pydicom]
+base_url = "https://example.org/pydicom-data/"
+files = [{{ name = "pydicom-mirror/CT_small.dcm", hash = "{c}", size = 39206 }}]
+"#,
+            a = "a".repeat(64),
+            b = "b".repeat(64),
+            c = "c".repeat(64)
+        );
+        let entries = parse(Format::Toml, document.as_bytes()).unwrap();
+        let base_urls: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.name, entry.base_url))
+            .collect();
+        assert_eq!(
+            base_urls,
+            vec![
+                ("myorg/weird.dcm", None),
+                (
+                    "gdcm/CT-MONO2-16-ort.dcm",
+                    Some("https://example.org/gdcm-data/")
+                ),
+                (
+                    "pydicom-mirror/CT_small.dcm",
+                    Some("https://example.org/pydicom-data/")
+                ),
+            ]
+        );
+
+        let document = format!(
+            "[sources.a]\nfiles = [{{ name = \"x.dcm\", hash = \"{h}\" }}]\n\
+             [sources.b]\nfiles = [{{ name = \"x.dcm\", hash = \"{h}\" }}]\n",
+            h = "d".repeat(64)
+        );
+        assert_eq!(
+            parse(Format::Toml, document.as_bytes()).unwrap_err(),
+            "x.dcm is listed more than once"
+        );
+        let document = "[sources.a]\nfiles = [{ name = \"x.dcm\" }]\n";
+        assert_eq!(
+            parse(Format::Toml, document.as_bytes()).unwrap_err(),
+            "missing or invalid `hash` of file #1 of source `a`"
+        );
+    }
+
+    #[cfg(all(feature = "json", feature = "toml"))]
+    #[test]
+    fn export_and_parse_manifest() {
+        for format in [Format::Json, Format::Toml] {
+            let mut document = Vec::new();
+            export_manifest(format, &mut document).unwrap();
+            let entries = parse(format, &document).unwrap();
+            // other tests may add entries meanwhile
+            assert!(entries.len() >= crate::entries::FILE_ENTRIES.len());
+            let ct = entries
+                .iter()
+                .find(|entry| entry.name == "pydicom/CT_small.dcm")
+                .unwrap();
+            let expected = crate::lookup("pydicom/CT_small.dcm").unwrap();
+            assert_eq!(ct.hash, expected.hash);
+            assert_eq!(ct.size, expected.size);
+            assert_eq!(ct.compression, expected.compression);
+            assert_eq!(ct.provenance, expected.provenance);
+            assert_eq!(ct.anonymization, expected.anonymization);
+            assert_eq!(ct.upstream_url, expected.upstream_url);
+            let nm = entries
+                .iter()
+                .find(|entry| entry.name == "WG04/REF/NM1_UNC")
+                .unwrap();
+            let expected = crate::lookup("WG04/REF/NM1_UNC").unwrap();
+            assert_eq!(nm.compression, Compression::Zstd);
+            assert_eq!(nm.decompressed_hash, expected.decompressed_hash);
+            assert!(nm.decompressed_hash.is_some());
+        }
+    }
+
+    #[test]
+    fn manifest_format() {
+        assert_eq!(Format::of_path(Path::new("files.json")), Some(Format::Json));
+        assert_eq!(
+            Format::of_path(Path::new("a/files.toml")),
+            Some(Format::Toml)
+        );
+        assert!(matches!(
+            load(Path::new("files.yaml")),
+            Err(Error::Manifest(_))
+        ));
+    }
+}
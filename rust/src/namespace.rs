@@ -0,0 +1,166 @@
+//! Views of the cache of each test binary,
+//! linking to the files of the shared cache
+
+use crate::{config, entry_path, get_data_path, Result};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Directory of the cache holding the views, by binary
+pub(crate) const VIEWS_DIR: &str = ".views";
+
+/// The path of the given cached file of the test file of the given name
+/// as seen by the running binary:
+/// the link of its [namespace](crate#cache-namespaces) if enabled,
+/// the cached file itself otherwise
+pub(crate) fn view(name: &str, cached_path: PathBuf) -> Result<PathBuf> {
+    if !config::namespace() {
+        return Ok(cached_path);
+    }
+    let view_dir = get_data_path().join(VIEWS_DIR).join(binary_name());
+    Ok(view_in(&view_dir, name, &cached_path)?)
+}
+
+/// The name of the namespace of the running binary,
+/// unique to each test binary of a build, such as `integration-3f2a9c1d0e5b7a64`
+fn binary_name() -> String {
+    env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.file_name()?.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| format!("pid-{}", std::process::id()))
+}
+
+/// Link the given cached file into the given view under the given name,
+/// unless the view already links to it.
+///
+/// A view which was removed or replaced is linked again,
+/// and the cached file is made read-only
+/// so that it cannot be written to through any of its links.
+/// File systems without hard links get a copy instead.
+fn view_in(view_dir: &Path, name: &str, cached_path: &Path) -> io::Result<PathBuf> {
+    static LINKS: AtomicUsize = AtomicUsize::new(0);
+
+    let view_path = entry_path(view_dir, name);
+    if is_link_of(&view_path, cached_path) {
+        return Ok(view_path);
+    }
+    make_read_only(cached_path)?;
+    let dir = view_path.parent().unwrap();
+    fs::create_dir_all(dir)?;
+    // left over by a crashed run, see `cache::clean_temp`
+    let temp_path = dir.join(format!(
+        ".tmp-view-{}-{}",
+        std::process::id(),
+        LINKS.fetch_add(1, Ordering::Relaxed)
+    ));
+    if fs::hard_link(cached_path, &temp_path).is_err() {
+        fs::copy(cached_path, &temp_path)?;
+    }
+    // replaces the view atomically, also when other threads link it
+    if let Err(e) = fs::rename(&temp_path, &view_path) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e);
+    }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(view = %view_path.display(), "linked into the cache namespace");
+    Ok(view_path)
+}
+
+/// Remove the links to the cached file of the given name
+/// from the views of all binaries
+pub(crate) fn remove_views(data_path: &Path, name: &str) {
+    let views = match fs::read_dir(data_path.join(VIEWS_DIR)) {
+        Ok(views) => views,
+        Err(_) => return,
+    };
+    for view in views.flatten() {
+        let _ = fs::remove_file(entry_path(&view.path(), name));
+    }
+}
+
+/// Whether the given view is a link to the given cached file
+#[cfg(unix)]
+fn is_link_of(view_path: &Path, cached_path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match (fs::metadata(view_path), fs::metadata(cached_path)) {
+        (Ok(view), Ok(cached)) => view.dev() == cached.dev() && view.ino() == cached.ino(),
+        _ => false,
+    }
+}
+
+/// Whether the given view is a link to the given cached file,
+/// or a copy of it, by their size and modification time
+#[cfg(not(unix))]
+fn is_link_of(view_path: &Path, cached_path: &Path) -> bool {
+    match (fs::metadata(view_path), fs::metadata(cached_path)) {
+        (Ok(view), Ok(cached)) => {
+            view.len() == cached.len() && view.modified().ok() == cached.modified().ok()
+        }
+        _ => false,
+    }
+}
+
+#[cfg(unix)]
+fn make_read_only(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(path)?.permissions();
+    if permissions.mode() & 0o222 != 0 {
+        permissions.set_mode(permissions.mode() & !0o222);
+        fs::set_permissions(path, permissions)?;
+    }
+    Ok(())
+}
+
+/// Read-only files cannot be replaced or removed on Windows,
+/// which the cache does when files are revalidated or pruned
+#[cfg(not(unix))]
+fn make_read_only(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views_of_binaries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = entry_path(dir.path(), "pydicom/CT_small.dcm");
+        fs::create_dir_all(cached_path.parent().unwrap()).unwrap();
+        fs::write(&cached_path, b"DICM shared").unwrap();
+        let views = dir.path().join(VIEWS_DIR);
+
+        let first = view_in(&views.join("unit-1"), "pydicom/CT_small.dcm", &cached_path).unwrap();
+        let second = view_in(&views.join("it-2"), "pydicom/CT_small.dcm", &cached_path).unwrap();
+        assert_eq!(
+            first,
+            entry_path(&views.join("unit-1"), "pydicom/CT_small.dcm")
+        );
+        assert_ne!(first, second);
+        assert_eq!(fs::read(&second).unwrap(), b"DICM shared");
+        #[cfg(unix)]
+        assert!(is_link_of(&first, &cached_path) && is_link_of(&second, &cached_path));
+        #[cfg(unix)]
+        assert!(fs::metadata(&cached_path).unwrap().permissions().readonly());
+
+        // a binary replacing or removing its file leaves the others alone
+        fs::remove_file(&first).unwrap();
+        fs::write(&first, b"mutated").unwrap();
+        assert_eq!(fs::read(&cached_path).unwrap(), b"DICM shared");
+        assert_eq!(fs::read(&second).unwrap(), b"DICM shared");
+        fs::remove_file(&second).unwrap();
+        assert!(cached_path.exists());
+
+        // and gets the shared file back on its next use
+        let first = view_in(&views.join("unit-1"), "pydicom/CT_small.dcm", &cached_path).unwrap();
+        assert_eq!(fs::read(&first).unwrap(), b"DICM shared");
+
+        remove_views(dir.path(), "pydicom/CT_small.dcm");
+        assert!(!first.exists());
+        assert!(cached_path.exists());
+    }
+}
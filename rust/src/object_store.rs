@@ -0,0 +1,262 @@
+//! Object stores as data sources, selected by the scheme of their URL:
+//! `gs://<bucket>/…` for Google Cloud Storage
+//! and `az://<account>/<container>/…` for Azure Blob Storage
+
+#[cfg(any(feature = "gcs", feature = "azure"))]
+use crate::config;
+use crate::{Error, Result};
+
+#[cfg(feature = "gcs")]
+const GCS_ENDPOINT: &str = "https://storage.googleapis.com";
+/// Version of the Blob service REST API, required with OAuth tokens
+#[cfg(feature = "azure")]
+const AZURE_VERSION: &str = "2021-08-06";
+
+/// The HTTP request of an object of an object store
+#[derive(Debug, PartialEq)]
+pub(crate) struct ObjectRequest {
+    /// the URL of the object over HTTP
+    pub url: String,
+    /// the headers authorizing the request
+    pub headers: Vec<(&'static str, String)>,
+}
+
+/// The HTTP request of the object at the given URL,
+/// with the credentials of the environment,
+/// or `None` if the URL is not the one of an object store.
+///
+/// Fails with [`Error::GcsRequired`] or [`Error::AzureRequired`]
+/// without the feature of the object store,
+/// and with [`Error::Download`] if the URL does not name an object.
+pub(crate) fn resolve(url: &str) -> Result<Option<ObjectRequest>> {
+    if let Some(path) = url.strip_prefix("gs://") {
+        return gcs_request(url, path).map(Some);
+    }
+    if let Some(path) = url.strip_prefix("az://") {
+        return azure_request(url, path).map(Some);
+    }
+    Ok(None)
+}
+
+/// The request of `gs://<bucket>/<object>`
+/// to the endpoint set by [`Config::gcs_endpoint`](crate::Config::gcs_endpoint)
+/// or `DICOM_TEST_FILES_GCS_ENDPOINT`, such as an emulator,
+/// or the one of Google Cloud Storage,
+/// with the OAuth access token set by [`Config::gcs_token`](crate::Config::gcs_token)
+/// or `DICOM_TEST_FILES_GCS_TOKEN`, if any
+#[cfg(feature = "gcs")]
+fn gcs_request(url: &str, path: &str) -> Result<ObjectRequest> {
+    let (endpoint, token) = config::gcs();
+    let endpoint = endpoint.unwrap_or_else(|| GCS_ENDPOINT.to_string());
+    gcs(&endpoint, path, token.as_deref()).ok_or_else(|| not_an_object(url))
+}
+
+#[cfg(not(feature = "gcs"))]
+fn gcs_request(_url: &str, _path: &str) -> Result<ObjectRequest> {
+    Err(Error::GcsRequired)
+}
+
+/// The request of the given path of a bucket at the given endpoint
+#[cfg(feature = "gcs")]
+fn gcs(endpoint: &str, path: &str, token: Option<&str>) -> Option<ObjectRequest> {
+    let (bucket, object) = path.split_once('/')?;
+    if bucket.is_empty() || object.is_empty() {
+        return None;
+    }
+    Some(ObjectRequest {
+        url: format!(
+            "{}/{}/{}",
+            endpoint.trim_end_matches('/'),
+            bucket,
+            encode_path(object)
+        ),
+        headers: token
+            .map(|token| ("Authorization", format!("Bearer {}", token)))
+            .into_iter()
+            .collect(),
+    })
+}
+
+/// The request of `az://<account>/<container>/<blob>`
+/// to the endpoint of the account set by [`Config::azure_endpoint`](crate::Config::azure_endpoint)
+/// or `DICOM_TEST_FILES_AZURE_ENDPOINT`,
+/// such as Azurite, or the one of Azure Blob Storage,
+/// with the SAS token set by [`Config::azure_sas`](crate::Config::azure_sas)
+/// or `DICOM_TEST_FILES_AZURE_SAS`
+/// or else the OAuth access token set by [`Config::azure_token`](crate::Config::azure_token)
+/// or `DICOM_TEST_FILES_AZURE_TOKEN`, if any
+#[cfg(feature = "azure")]
+fn azure_request(url: &str, path: &str) -> Result<ObjectRequest> {
+    let (endpoint, sas, token) = config::azure();
+    let credential = match (sas, token) {
+        (Some(sas), _) => Some(AzureCredential::Sas(sas)),
+        (None, Some(token)) => Some(AzureCredential::Token(token)),
+        (None, None) => None,
+    };
+    azure(endpoint.as_deref(), path, credential.as_ref()).ok_or_else(|| not_an_object(url))
+}
+
+#[cfg(not(feature = "azure"))]
+fn azure_request(_url: &str, _path: &str) -> Result<ObjectRequest> {
+    Err(Error::AzureRequired)
+}
+
+/// A credential of Azure Blob Storage
+#[cfg(feature = "azure")]
+enum AzureCredential {
+    /// a shared access signature, as a query string
+    Sas(String),
+    /// an OAuth access token of Microsoft Entra ID
+    Token(String),
+}
+
+/// The request of the given path of an account
+/// at the given endpoint, or the default one
+#[cfg(feature = "azure")]
+fn azure(
+    endpoint: Option<&str>,
+    path: &str,
+    credential: Option<&AzureCredential>,
+) -> Option<ObjectRequest> {
+    let mut path = path.splitn(3, '/');
+    let (account, container, blob) = (path.next()?, path.next()?, path.next()?);
+    if account.is_empty() || container.is_empty() || blob.is_empty() {
+        return None;
+    }
+    let endpoint = match endpoint {
+        Some(endpoint) => endpoint.trim_end_matches('/').to_string(),
+        None => format!("https://{}.blob.core.windows.net", account),
+    };
+    let mut url = format!("{}/{}/{}", endpoint, container, encode_path(blob));
+    let mut headers = vec![("x-ms-version", AZURE_VERSION.to_string())];
+    match credential {
+        Some(AzureCredential::Sas(sas)) => {
+            url.push('?');
+            url.push_str(sas.trim_start_matches('?'));
+        }
+        Some(AzureCredential::Token(token)) => {
+            headers.push(("Authorization", format!("Bearer {}", token)));
+        }
+        None => {}
+    }
+    Some(ObjectRequest { url, headers })
+}
+
+/// Percent-encode the path of an object, keeping its `/`
+#[cfg(any(feature = "gcs", feature = "azure"))]
+fn encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(any(feature = "gcs", feature = "azure"))]
+fn not_an_object(url: &str) -> Error {
+    Error::Download(format!("{} does not name an object", url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        entry_path, get_data_path, path, register_registry, testing::MockServer, Registry,
+        RegistryEntry,
+    };
+    use sha2::{Digest, Sha256};
+
+    #[cfg(feature = "gcs")]
+    #[test]
+    fn gcs_requests() {
+        assert_eq!(
+            gcs(GCS_ENDPOINT, "corpus/WG04/REF/CT1_UNC.zst", Some("ya29.t")),
+            Some(ObjectRequest {
+                url: "https://storage.googleapis.com/corpus/WG04/REF/CT1_UNC.zst".to_string(),
+                headers: vec![("Authorization", "Bearer ya29.t".to_string())],
+            })
+        );
+        let request = gcs("http://127.0.0.1:4443/", "corpus/odd name+.dcm", None).unwrap();
+        assert_eq!(
+            request.url,
+            "http://127.0.0.1:4443/corpus/odd%20name%2B.dcm"
+        );
+        assert!(request.headers.is_empty());
+        assert_eq!(gcs(GCS_ENDPOINT, "corpus", None), None);
+    }
+
+    #[cfg(feature = "azure")]
+    #[test]
+    fn azure_requests() {
+        let sas = AzureCredential::Sas("?sv=2022-11-02&sig=abc".to_string());
+        let request = azure(None, "myorg/corpus/pydicom/CT_small.dcm", Some(&sas)).unwrap();
+        assert_eq!(
+            request.url,
+            "https://myorg.blob.core.windows.net/corpus/pydicom/CT_small.dcm?sv=2022-11-02&sig=abc"
+        );
+        let token = AzureCredential::Token("eyJ0".to_string());
+        let request = azure(
+            Some("http://127.0.0.1:10000/devstoreaccount1/"),
+            "devstoreaccount1/corpus/CT_small.dcm",
+            Some(&token),
+        )
+        .unwrap();
+        assert_eq!(
+            request.url,
+            "http://127.0.0.1:10000/devstoreaccount1/corpus/CT_small.dcm"
+        );
+        assert!(request
+            .headers
+            .contains(&("Authorization", "Bearer eyJ0".to_string())));
+        assert_eq!(azure(None, "myorg/corpus", None), None);
+    }
+
+    #[test]
+    fn download_from_object_store() {
+        assert_eq!(resolve("https://example.org/data/x.dcm").unwrap(), None);
+
+        let contents = b"mirrored next to the CI".to_vec();
+        let hash = format!("{:x}", Sha256::digest(&contents));
+        let server = MockServer::start().unwrap();
+        let object = "corpus/mirror/object-store/CT_small.dcm";
+        server.blob(object, contents);
+        // only used for the URLs of this test
+        crate::Config::new()
+            .gcs_endpoint(server.url())
+            .gcs_token("ya29.test")
+            .apply()
+            .unwrap();
+        register_registry(Registry {
+            base_url: "gs://corpus/mirror".to_string(),
+            entries: vec![RegistryEntry::new("object-store/CT_small.dcm", hash)],
+        })
+        .unwrap();
+        // left by a run with the other features
+        let _ = std::fs::remove_file(entry_path(&get_data_path(), "object-store/CT_small.dcm"));
+
+        #[cfg(feature = "gcs")]
+        {
+            let cached = path("object-store/CT_small.dcm").unwrap();
+            assert_eq!(std::fs::read(cached).unwrap(), b"mirrored next to the CI");
+            let received = server.received(object);
+            assert_eq!(received.len(), 1);
+            assert_eq!(
+                received[0].header("authorization"),
+                Some("Bearer ya29.test")
+            );
+        }
+        #[cfg(not(feature = "gcs"))]
+        {
+            assert!(matches!(
+                path("object-store/CT_small.dcm"),
+                Err(Error::GcsRequired)
+            ));
+            assert_eq!(server.requests(object), 0);
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! Sample studies pulled from an Orthanc server.
+//!
+//! [`Orthanc`] downloads the instances of studies
+//! through the REST API of an [Orthanc](https://www.orthanc-server.com/) server,
+//! such as an in-house demo server, into the cache,
+//! and writes a JSON or TOML [external manifest](crate#external-manifests)
+//! which pins the hash and size of each of them.
+//! Each instance is named after its UIDs,
+//! `<prefix>/<study UID>/<series UID>/<instance UID>.dcm`,
+//! and listed as an instance of the DICOMweb plugin of the server,
+//! where it is retrieved from with WADO-RS when it is not cached,
+//! see the `dicomweb` feature.
+//!
+//! ```no_run
+//! use dicom_test_files::orthanc::Orthanc;
+//!
+//! # fn main() -> Result<(), dicom_test_files::Error> {
+//! let files = Orthanc::new("http://orthanc.example.org:8042/")
+//!     .credentials("demo", "demo")
+//!     .prefix("demo")
+//!     .import("tests/demo_files.toml")?;
+//! println!("{} instances imported", files.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The test files are then those of the manifest:
+//!
+//! ```sh
+//! DICOM_TEST_FILES_MANIFEST=tests/demo_files.toml cargo test
+//! ```
+
+use crate::{
+    digest, entry_path, get_data_path, http, manifest, write_temp, Compression, DigestAlgorithm,
+    Error, Format, Result, TestFile,
+};
+use serde_json::Value;
+use std::{fs, io::Read, path::Path};
+
+/// An Orthanc server to import sample studies from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Orthanc {
+    url: String,
+    authorization: Option<String>,
+    dicomweb_root: String,
+    prefix: String,
+    studies: Vec<String>,
+}
+
+impl Orthanc {
+    /// Import from the Orthanc server with the given URL,
+    /// such as `http://localhost:8042/`
+    pub fn new(url: impl Into<String>) -> Self {
+        let mut url = url.into();
+        if !url.ends_with('/') {
+            url.push('/');
+        }
+        Orthanc {
+            url,
+            authorization: None,
+            dicomweb_root: "dicom-web/".to_string(),
+            prefix: "orthanc".to_string(),
+            studies: Vec::new(),
+        }
+    }
+
+    /// Authenticate to the REST API with HTTP basic authentication.
+    ///
+    /// The credentials are not written to the manifest,
+    /// fetching the files which are not cached
+    /// requires anonymous access to the DICOMweb plugin.
+    pub fn credentials(mut self, user: &str, password: &str) -> Self {
+        self.authorization = Some(basic_authorization(user, password));
+        self
+    }
+
+    /// Set the path of the DICOMweb plugin relative to the URL of the server,
+    /// `dicom-web/` by default
+    pub fn dicomweb_root(mut self, root: impl Into<String>) -> Self {
+        let mut root = root.into();
+        if !root.ends_with('/') {
+            root.push('/');
+        }
+        self.dicomweb_root = root;
+        self
+    }
+
+    /// Set the first component of the names of the imported files,
+    /// `orthanc` by default
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Import the study with the given Orthanc identifier,
+    /// rather than all studies of the server
+    pub fn study(mut self, id: impl Into<String>) -> Self {
+        self.studies.push(id.into());
+        self
+    }
+
+    /// Download the instances of the studies into the cache
+    /// and write their manifest to the given path,
+    /// in the format of its file extension.
+    /// Returns the entries of the manifest.
+    ///
+    /// Fails with [`Error::Download`] if the server does not respond
+    /// as the REST API of Orthanc,
+    /// and with [`Error::Manifest`] if the format is unknown
+    /// or its feature is not enabled.
+    pub fn import(&self, manifest_path: impl AsRef<Path>) -> Result<Vec<TestFile>> {
+        let manifest_path = manifest_path.as_ref();
+        let format = Format::of_path(manifest_path).ok_or_else(|| {
+            Error::Manifest(format!(
+                "unknown format of {}, expected a .json or .toml file",
+                manifest_path.display()
+            ))
+        })?;
+
+        let studies = if self.studies.is_empty() {
+            array(self.get("studies")?)
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        } else {
+            self.studies.clone()
+        };
+        let mut entries = Vec::new();
+        for study in &studies {
+            self.import_study(study, &mut entries)?;
+        }
+
+        let base_url = format!("{}{}", self.url, self.dicomweb_root);
+        let listed = entries.iter().collect();
+        let mut document = Vec::new();
+        manifest::write_sources(format, &[(base_url.as_str(), listed)], &mut document)?;
+        if let Some(parent) = manifest_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(manifest_path, document)?;
+        Ok(entries)
+    }
+
+    /// Download the instances of the study with the given Orthanc identifier
+    fn import_study(&self, study: &str, entries: &mut Vec<TestFile>) -> Result<()> {
+        let path = format!("studies/{}", study);
+        let study_uid = main_tag(&self.get(&path)?, "StudyInstanceUID", &path)?;
+        for series in array(self.get(&format!("studies/{}/series", study))?) {
+            let path = format!("series/{}", text(&series, "ID", "series")?);
+            let series_uid = main_tag(&series, "SeriesInstanceUID", &path)?;
+            for instance in array(self.get(&format!("{}/instances", path))?) {
+                let id = text(&instance, "ID", "instance")?;
+                let instance_uid = main_tag(&instance, "SOPInstanceUID", id)?;
+                let name = format!(
+                    "{}/{}/{}/{}.dcm",
+                    self.prefix, study_uid, series_uid, instance_uid
+                );
+                let (hash, size) = self.download(&format!("instances/{}/file", id), &name)?;
+                let mut entry = TestFile::new(
+                    leak(name),
+                    Compression::DicomWeb {
+                        study: leak(study_uid.clone()),
+                        series: leak(series_uid.clone()),
+                        instance: leak(instance_uid),
+                    },
+                    leak(hash),
+                )
+                .size(size);
+                entry.base_url = Some(leak(format!("{}{}", self.url, self.dicomweb_root)));
+                entries.push(entry);
+            }
+        }
+        Ok(())
+    }
+
+    /// Send a request to the REST API
+    fn request(&self, path: &str) -> Result<http::Response> {
+        let url = format!("{}{}", self.url, path);
+        let headers: Vec<_> = self
+            .authorization
+            .iter()
+            .map(|authorization| ("Authorization", authorization.as_str()))
+            .collect();
+        Ok(http::get(&url, &headers)?)
+    }
+
+    /// The JSON value of a resource of the REST API
+    fn get(&self, path: &str) -> Result<Value> {
+        let mut body = Vec::new();
+        self.request(path)?.body.read_to_end(&mut body)?;
+        serde_json::from_slice(&body).map_err(|e| {
+            Error::Download(format!("Invalid response from {}{}: {}", self.url, path, e))
+        })
+    }
+
+    /// Download an instance into the cached path of the given name,
+    /// returning its SHA-256 hash and size
+    fn download(&self, path: &str, name: &str) -> Result<(String, u64)> {
+        let mut resp = self.request(path)?;
+        let cached_path = entry_path(&get_data_path(), name);
+        let parent_dir = cached_path.parent().unwrap();
+        fs::create_dir_all(parent_dir)?;
+        let tempfile_path = write_temp(&mut resp.body, parent_dir)?;
+        let hashed = digest::file_digest(&tempfile_path, DigestAlgorithm::Sha256)?;
+        fs::rename(tempfile_path, cached_path)?;
+        Ok(hashed)
+    }
+}
+
+/// The elements of a JSON array, none if it is not one
+fn array(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(values) => values,
+        _ => Vec::new(),
+    }
+}
+
+/// A string field of a resource of the REST API
+fn text<'a>(resource: &'a Value, key: &str, of: &str) -> Result<&'a str> {
+    resource.get(key).and_then(Value::as_str).ok_or_else(|| {
+        Error::Download(format!(
+            "Invalid response of Orthanc: missing `{}` of {}",
+            key, of
+        ))
+    })
+}
+
+/// A main DICOM tag of a resource of the REST API
+fn main_tag(resource: &Value, tag: &str, of: &str) -> Result<String> {
+    let tags = resource.get("MainDicomTags").unwrap_or(&Value::Null);
+    Ok(text(tags, tag, of)?.trim().to_string())
+}
+
+fn leak(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+/// The `Authorization` header of HTTP basic authentication
+fn basic_authorization(user: &str, password: &str) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let credentials = format!("{}:{}", user, password);
+    let mut header = "Basic ".to_string();
+    for chunk in credentials.as_bytes().chunks(3) {
+        let byte = |i: usize| u32::from(chunk.get(i).copied().unwrap_or(0));
+        let bits = byte(0) << 16 | byte(1) << 8 | byte(2);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                header.push(char::from(ALPHABET[(bits >> (18 - 6 * i) & 63) as usize]));
+            } else {
+                header.push('=');
+            }
+        }
+    }
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        lookup, path,
+        testing::{Fault, MockServer, Request},
+    };
+
+    #[test]
+    fn basic_authorizations() {
+        assert_eq!(
+            basic_authorization("orthanc", "orthanc"),
+            "Basic b3J0aGFuYzpvcnRoYW5j"
+        );
+        assert_eq!(basic_authorization("a", "b"), "Basic YTpi");
+        assert_eq!(basic_authorization("ab", "c"), "Basic YWI6Yw==");
+    }
+
+    #[test]
+    fn import_studies() {
+        let contents = fs::read(path("pydicom/CT_small.dcm").unwrap()).unwrap();
+        let hash = lookup("pydicom/CT_small.dcm").unwrap().hash;
+
+        // an Orthanc server with a single instance, behind authentication
+        let server = MockServer::start().unwrap();
+        let server_url = format!("{}orthanc", server.url());
+        server.blob("orthanc/studies", &b"[\"s1\"]"[..]);
+        server.blob(
+            "orthanc/studies/s1",
+            &br#"{"MainDicomTags": {"StudyInstanceUID": "1.2"}}"#[..],
+        );
+        server.blob(
+            "orthanc/studies/s1/series",
+            &br#"[{"ID": "se1", "MainDicomTags": {"SeriesInstanceUID": "1.2.3"}}]"#[..],
+        );
+        server.blob(
+            "orthanc/series/se1/instances",
+            &br#"[{"ID": "i1", "MainDicomTags": {"SOPInstanceUID": "1.2.3.4"}}]"#[..],
+        );
+        server.blob("orthanc/instances/i1/file", contents.clone());
+        server.fault("orthanc/studies", Fault::Status(401));
+
+        let dir = tempfile::tempdir().unwrap();
+        let manifest_path = dir.path().join("orthanc.json");
+        let orthanc = Orthanc::new(server_url.as_str()).prefix("orthanc-test");
+        assert!(matches!(
+            orthanc.import(&manifest_path),
+            Err(Error::Download(_))
+        ));
+        let entries = orthanc
+            .credentials("orthanc", "orthanc")
+            .import(&manifest_path)
+            .unwrap();
+        assert_eq!(entries.len(), 1);
+        let authorized = |request: &Request| {
+            request.header("authorization") == Some("Basic b3J0aGFuYzpvcnRoYW5j")
+        };
+        let studies = server.received("orthanc/studies");
+        assert!(!authorized(&studies[0]) && authorized(&studies[1]));
+        let files = server.received("orthanc/instances/i1/file");
+        assert!(files.len() == 1 && authorized(&files[0]));
+        let entry = &entries[0];
+        assert_eq!(entry.name, "orthanc-test/1.2/1.2.3/1.2.3.4.dcm");
+        assert_eq!(entry.hash, hash);
+        assert_eq!(entry.size, contents.len() as u64);
+        assert_eq!(
+            entry.compression,
+            Compression::DicomWeb {
+                study: "1.2",
+                series: "1.2.3",
+                instance: "1.2.3.4",
+            }
+        );
+        let cached_path = entry_path(&get_data_path(), entry.name);
+        assert_eq!(fs::read(cached_path).unwrap(), contents);
+
+        // the manifest pins the imported instances
+        let listed = manifest::load(&manifest_path).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].name, entry.name);
+        assert_eq!(listed[0].hash, hash);
+        assert_eq!(listed[0].compression, entry.compression);
+        assert_eq!(
+            listed[0].base_url,
+            Some(format!("{}/dicom-web/", server_url).as_str())
+        );
+
+        assert!(matches!(
+            Orthanc::new(server_url.as_str()).import(dir.path().join("orthanc.yaml")),
+            Err(Error::Manifest(_))
+        ));
+    }
+}
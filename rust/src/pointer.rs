@@ -0,0 +1,198 @@
+//! Pointer records of test files too large for the data repository,
+//! which are hosted on a CDN or as release assets instead
+
+use crate::{backend, config, http, Error, Result, TestFile, DEFAULT_REF, GITHUB_REPOSITORY};
+use std::io::Read;
+
+const HEADER: &str = "# dicom-test-files pointer v1";
+/// Pointer records are a few lines, anything larger is not one
+const MAX_RECORD_SIZE: u64 = 4096;
+
+/// Where the contents of a test file are hosted,
+/// as held by the data source in place of the file
+#[derive(Debug, PartialEq)]
+struct Pointer {
+    /// URL of the contents of the file,
+    /// absolute or relative to the CDN
+    url: String,
+    /// hash of the file as stored
+    hash: String,
+    /// size of the file as stored, in bytes
+    size: u64,
+}
+
+/// Fetch the pointer record of the given entry from the given URL
+/// and resolve the URL of the file it points to.
+///
+/// Fails with [`Error::Download`] if the record is malformed
+/// or points to another file than the entry.
+pub(crate) fn resolve(file_entry: &TestFile, record_url: &str) -> Result<String> {
+    let invalid = |reason: String| {
+        Error::Download(format!(
+            "invalid pointer record of {} at {}: {}",
+            file_entry.name, record_url, reason
+        ))
+    };
+    let mut record = String::new();
+    http::get(record_url, &[])?
+        .body
+        .take(MAX_RECORD_SIZE)
+        .read_to_string(&mut record)
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::InvalidData => invalid("not UTF-8".to_string()),
+            _ => e.into(),
+        })?;
+    let pointer = parse(&record).map_err(invalid)?;
+    if !pointer.hash.eq_ignore_ascii_case(file_entry.hash) {
+        return Err(invalid(format!(
+            "points to a file with hash {}",
+            pointer.hash
+        )));
+    }
+    if file_entry.size != 0 && pointer.size != file_entry.size {
+        return Err(invalid(format!(
+            "points to a file of {} bytes instead of {}",
+            pointer.size, file_entry.size
+        )));
+    }
+    Ok(content_url(&pointer.url))
+}
+
+/// Parse the lines of a pointer record:
+///
+/// ```text
+/// # dicom-test-files pointer v1
+/// url wsi/CMU-1.dcm.zst
+/// hash 3a7b…
+/// size 1234567890
+/// ```
+///
+/// Other keys are left for later versions of the format.
+fn parse(record: &str) -> Result<Pointer, String> {
+    let mut lines = record.lines();
+    if lines.next() != Some(HEADER) {
+        return Err("unsupported version".to_string());
+    }
+    let (mut url, mut hash, mut size) = (None, None, None);
+    for line in lines.filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        match line.split_once(' ') {
+            Some(("url", value)) => url = Some(value.trim()),
+            Some(("hash", value)) => hash = Some(value.trim()),
+            Some(("size", value)) => {
+                size = Some(
+                    value
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("invalid size {:?}", value))?,
+                )
+            }
+            Some(_) => {}
+            None => return Err(format!("invalid line {:?}", line)),
+        }
+    }
+    match (url.filter(|url| !url.is_empty()), hash, size) {
+        (Some(url), Some(hash), Some(size)) => Ok(Pointer {
+            url: url.to_string(),
+            hash: hash.to_string(),
+            size,
+        }),
+        (None, ..) => Err("missing `url`".to_string()),
+        (_, None, _) => Err("missing `hash`".to_string()),
+        (.., None) => Err("missing `size`".to_string()),
+    }
+}
+
+/// The URL of the contents of a pointer record,
+/// resolving a relative one against the CDN
+/// or the release assets of the project
+fn content_url(url: &str) -> String {
+    if url.contains("://") {
+        return url.to_string();
+    }
+    match config::cdn_url() {
+        Some(cdn_url) => cdn_url + url,
+        None => {
+            let git_ref = config::git_ref();
+            backend::release_asset_url(
+                GITHUB_REPOSITORY,
+                git_ref.as_deref().unwrap_or(DEFAULT_REF),
+                url,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        entry_path, get_data_path, path, register_registry, testing::MockServer, Registry,
+        RegistryEntry,
+    };
+    use sha2::{Digest, Sha256};
+    use std::fs;
+
+    #[test]
+    fn pointer_records() {
+        let hash = "a".repeat(64);
+        let record = format!("{}\nurl wsi/huge.dcm.zst\nhash {}\nsize 42\n", HEADER, hash);
+        assert_eq!(
+            parse(&record),
+            Ok(Pointer {
+                url: "wsi/huge.dcm.zst".to_string(),
+                hash: hash.clone(),
+                size: 42,
+            })
+        );
+        // later keys are ignored
+        let later = format!("{}oid sha256:{}\n", record, hash);
+        assert!(parse(&later).is_ok());
+        assert_eq!(
+            parse(&record.replace("size 42\n", "")).unwrap_err(),
+            "missing `size`"
+        );
+        assert!(parse(&record.replace("42", "many")).is_err());
+        assert!(parse(&record.replace("v1", "v2")).is_err());
+        assert!(parse("DICM").is_err());
+
+        assert_eq!(
+            content_url("https://cdn.example.org/huge.dcm"),
+            "https://cdn.example.org/huge.dcm"
+        );
+        assert!(content_url("wsi/huge.dcm.zst").ends_with("/wsi.huge.dcm.zst"));
+    }
+
+    #[test]
+    fn download_through_pointer() {
+        let contents = b"a whole slide image, trust me".to_vec();
+        let hash = format!("{:x}", Sha256::digest(&contents));
+        let server = MockServer::start().unwrap();
+        let record = format!(
+            "{}\nurl {}cdn/huge.dcm\nhash {}\nsize {}\n",
+            HEADER,
+            server.url(),
+            hash,
+            contents.len()
+        );
+        server.blob("cdn/huge.dcm", contents);
+        server.blob("pointer/huge.dcm", record.clone());
+        server.blob("pointer/other.dcm", record);
+        register_registry(Registry {
+            base_url: server.url().to_string(),
+            entries: vec![
+                RegistryEntry::new("pointer/huge.dcm", hash.clone()).pointer(),
+                RegistryEntry::new("pointer/other.dcm", "b".repeat(64)).pointer(),
+            ],
+        })
+        .unwrap();
+
+        let cached = path("pointer/huge.dcm").unwrap();
+        assert_eq!(cached, entry_path(&get_data_path(), "pointer/huge.dcm"));
+        assert_eq!(fs::read(&cached).unwrap(), b"a whole slide image, trust me");
+        // the record is for another file
+        assert!(matches!(
+            path("pointer/other.dcm"),
+            Err(Error::Download(reason)) if reason.contains("points to a file with hash")
+        ));
+    }
+}
@@ -0,0 +1,506 @@
+//! Bulk fetching of test files
+
+use crate::{
+    all_entries, bars::PrefetchBar, bundle, cancel, config, decompress_fetched, fetch_deferred,
+    fetch_kept, http, Error, Filter, Result, TestFile,
+};
+use std::{
+    fmt,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc, Condvar, Mutex, OnceLock,
+    },
+    thread,
+};
+
+/// The default number of simultaneous downloads
+const DEFAULT_JOBS: usize = http::DEFAULT_MAX_DOWNLOADS;
+
+/// How a test file was obtained during a prefetch
+#[derive(Debug)]
+pub enum FetchOutcome<'a> {
+    /// The file was already in the local cache
+    Cached,
+    /// The file was downloaded
+    Fetched,
+    /// The file was not downloaded,
+    /// as it is larger than `DICOM_TEST_FILES_MAX_SIZE`
+    Skipped,
+    /// The file could not be fetched
+    Failed(&'a Error),
+}
+
+/// Progress of a prefetch,
+/// reported once for each selected test file
+#[derive(Debug)]
+pub struct Progress<'a> {
+    /// the test file name
+    pub name: &'static str,
+    /// how the test file was obtained
+    pub outcome: FetchOutcome<'a>,
+    /// number of test files processed so far, including this one
+    pub completed: usize,
+    /// total number of test files selected
+    pub total: usize,
+}
+
+/// Summary of a successful prefetch
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PrefetchSummary {
+    /// names of the test files which were downloaded
+    pub fetched: Vec<&'static str>,
+    /// names of the test files which were already cached
+    pub cached: Vec<&'static str>,
+    /// names of the test files which were too large to download,
+    /// see the [size limit](crate#size-limit)
+    pub skipped: Vec<&'static str>,
+}
+
+type ProgressHandler = Box<dyn Fn(&Progress) + Send + Sync>;
+
+/// A configurable prefetch of multiple test files.
+///
+/// See [`prefetch`] for a version with default options.
+///
+/// ```no_run
+/// use dicom_test_files::Prefetch;
+///
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let summary = Prefetch::new("WG04/**")
+///     .jobs(8)
+///     .on_progress(|p| eprintln!("[{}/{}] {}", p.completed, p.total, p.name))
+///     .run()?;
+/// println!("{} files downloaded", summary.fetched.len());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Prefetch {
+    filter: Filter,
+    jobs: usize,
+    decompress_jobs: Option<usize>,
+    on_progress: Option<ProgressHandler>,
+}
+
+impl Prefetch {
+    /// Prepare a prefetch of the test files selected by the given filter.
+    pub fn new(filter: impl Into<Filter>) -> Self {
+        Prefetch {
+            filter: filter.into(),
+            jobs: DEFAULT_JOBS,
+            decompress_jobs: None,
+            on_progress: None,
+        }
+    }
+
+    /// Set the maximum number of files to download at the same time.
+    ///
+    /// Downloads are also bounded by the
+    /// [download limit](crate#download-limit) of the process.
+    pub fn jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs.max(1);
+        self
+    }
+
+    /// Decompress the files stored with compression
+    /// on a pool of the given number of workers of its own,
+    /// instead of as they are downloaded.
+    ///
+    /// Downloads are then only bound by the network,
+    /// while decompression takes as many cores as given,
+    /// which suits prefetches of hundreds of compressed files
+    /// with few simultaneous downloads.
+    /// Each file is still decoded on a single thread,
+    /// as zstd has no multi-threaded decoder.
+    /// The compressed copies are removed once decompressed,
+    /// unless the cache [keeps them](crate#compressed-cache),
+    /// in which case there is nothing to decompress.
+    ///
+    /// ```no_run
+    /// use dicom_test_files::Prefetch;
+    /// use std::thread;
+    ///
+    /// # fn main() -> Result<(), dicom_test_files::Error> {
+    /// let cores = thread::available_parallelism().map_or(1, |n| n.get());
+    /// Prefetch::new("WG04/**").jobs(4).decompress_jobs(cores).run()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decompress_jobs(mut self, jobs: usize) -> Self {
+        self.decompress_jobs = Some(jobs.max(1));
+        self
+    }
+
+    /// Set a function to be called each time a test file is processed.
+    pub fn on_progress(mut self, f: impl Fn(&Progress) + Send + Sync + 'static) -> Self {
+        self.on_progress = Some(Box::new(f));
+        self
+    }
+
+    /// Fetch all selected test files which are not cached yet.
+    ///
+    /// When several of them are in the same [bundle](crate#bundles),
+    /// the bundle is downloaded and unpacked at once.
+    /// All selected files are attempted,
+    /// even if some of them fail.
+    /// If any of them failed,
+    /// the error of the first failing file is returned.
+    ///
+    /// With the `progress` feature,
+    /// progress bars of the prefetch and of each download
+    /// are drawn on standard error when it is a terminal.
+    pub fn run(self) -> Result<PrefetchSummary> {
+        let selected: Vec<&'static TestFile> = all_entries()?
+            .into_iter()
+            .filter(|entry| self.filter.matches(entry))
+            .collect();
+        let total = selected.len();
+        let bar = PrefetchBar::start(total);
+        // files available in bundles are downloaded at once first
+        let unpacked = bundle::fetch_bundles(&selected);
+
+        struct State {
+            completed: usize,
+            results: Vec<Option<Result<FetchOutcome<'static>>>>,
+        }
+
+        // the workers are cancelled along with the caller
+        let token = cancel::current();
+        let next = AtomicUsize::new(0);
+        let state = Mutex::new(State {
+            completed: 0,
+            results: (0..total).map(|_| None).collect(),
+        });
+        let outcome = |entry: &TestFile, fetched: Result<bool>| match fetched {
+            Ok(true) => Ok(FetchOutcome::Fetched),
+            Ok(false) if unpacked.contains(&entry.name) => Ok(FetchOutcome::Fetched),
+            Ok(false) => Ok(FetchOutcome::Cached),
+            Err(Error::TooLarge(_)) => Ok(FetchOutcome::Skipped),
+            Err(e) => Err(e),
+        };
+        let record = |i: usize, entry: &'static TestFile, result: Result<FetchOutcome<'static>>| {
+            let mut state = state.lock().unwrap();
+            state.completed += 1;
+            bar.processed(entry.name);
+            if let Some(on_progress) = &self.on_progress {
+                let outcome = match &result {
+                    Ok(FetchOutcome::Fetched) => FetchOutcome::Fetched,
+                    Ok(FetchOutcome::Skipped) => FetchOutcome::Skipped,
+                    Ok(_) => FetchOutcome::Cached,
+                    Err(e) => FetchOutcome::Failed(e),
+                };
+                on_progress(&Progress {
+                    name: entry.name,
+                    outcome,
+                    completed: state.completed,
+                    total,
+                });
+            }
+            state.results[i] = Some(result);
+        };
+        // compressed copies handed over to the decompression workers, if any
+        let deferred = self.decompress_jobs.is_some() && !config::keep_compressed();
+        let (sender, receiver) = mpsc::channel::<(usize, &'static TestFile, PathBuf, bool)>();
+        let receiver = Mutex::new(receiver);
+
+        thread::scope(|scope| {
+            for _ in 0..self.jobs.min(total) {
+                let sender = sender.clone();
+                let (token, next, selected) = (&token, &next, &selected);
+                let (outcome, record) = (&outcome, &record);
+                scope.spawn(move || {
+                    cancel::within(token.clone(), || loop {
+                        let i = next.fetch_add(1, Ordering::SeqCst);
+                        let entry = match selected.get(i) {
+                            Some(entry) => *entry,
+                            None => break,
+                        };
+                        let compressed = if deferred && entry.compression.is_stream() {
+                            fetch_deferred(entry)
+                        } else {
+                            Ok(None)
+                        };
+                        let fetched = match compressed {
+                            Ok(Some((stored_path, downloaded))) => {
+                                let _ = sender.send((i, entry, stored_path, downloaded));
+                                continue;
+                            }
+                            Ok(None) => fetch_kept(entry),
+                            Err(e) => Err(e),
+                        };
+                        record(i, entry, outcome(entry, fetched));
+                    })
+                });
+            }
+            // the decompression workers stop once the download workers are done
+            drop(sender);
+            for _ in 0..self.decompress_jobs.unwrap_or(0).min(total) {
+                scope.spawn(|| {
+                    cancel::within(token.clone(), || loop {
+                        let job = receiver.lock().unwrap().recv();
+                        let (i, entry, stored_path, downloaded) = match job {
+                            Ok(job) => job,
+                            Err(_) => break,
+                        };
+                        let decompressed = decompress_fetched(entry, &stored_path, downloaded);
+                        record(i, entry, outcome(entry, decompressed.map(|()| downloaded)));
+                    })
+                });
+            }
+        });
+
+        let mut summary = PrefetchSummary::default();
+        let results = state.into_inner().unwrap().results;
+        for (entry, result) in selected.into_iter().zip(results) {
+            match result.expect("all selected entries are processed")? {
+                FetchOutcome::Fetched => summary.fetched.push(entry.name),
+                FetchOutcome::Skipped => summary.skipped.push(entry.name),
+                _ => summary.cached.push(entry.name),
+            }
+        }
+        Ok(summary)
+    }
+
+    /// Start fetching the selected test files on worker threads
+    /// and return at once,
+    /// so that the downloads overlap with the tests which do not need them.
+    ///
+    /// See [`warm_in_background`].
+    pub fn in_background(self) -> WarmHandle {
+        let warming = Arc::new(Warming {
+            result: OnceLock::new(),
+            lock: Mutex::new(()),
+            finished: Condvar::new(),
+        });
+        let token = cancel::current();
+        let background = Arc::clone(&warming);
+        thread::spawn(move || {
+            let result = cancel::within(token, || self.run());
+            let _lock = background.lock.lock().unwrap_or_else(|e| e.into_inner());
+            let _ = background.result.set(result);
+            background.finished.notify_all();
+        });
+        WarmHandle { warming }
+    }
+}
+
+/// The state of a prefetch in the background
+struct Warming {
+    result: OnceLock<Result<PrefetchSummary>>,
+    lock: Mutex<()>,
+    finished: Condvar,
+}
+
+/// A prefetch running in the background,
+/// see [`warm_in_background`].
+///
+/// The prefetch goes on when the handle is dropped.
+#[derive(Clone)]
+pub struct WarmHandle {
+    warming: Arc<Warming>,
+}
+
+impl WarmHandle {
+    /// Wait until all selected test files were processed,
+    /// returning the result of the prefetch, as of [`Prefetch::run`].
+    ///
+    /// The handle can be waited on any number of times,
+    /// from any number of threads.
+    pub fn wait(&self) -> Result<&PrefetchSummary, &Error> {
+        let mut lock = self.warming.lock.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            if let Some(result) = self.warming.result.get() {
+                return result.as_ref();
+            }
+            lock = self
+                .warming
+                .finished
+                .wait(lock)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    /// Whether all selected test files were processed.
+    pub fn is_finished(&self) -> bool {
+        self.warming.result.get().is_some()
+    }
+}
+
+impl fmt::Debug for WarmHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WarmHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+impl fmt::Debug for Prefetch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Prefetch")
+            .field("filter", &self.filter)
+            .field("jobs", &self.jobs)
+            .field("decompress_jobs", &self.decompress_jobs)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Fetch all test files selected by the given filter
+/// which are not cached yet,
+/// downloading several files at the same time.
+///
+/// The filter can also be given as a glob pattern.
+/// Use [`Prefetch`] to configure the number of simultaneous downloads
+/// or to observe the progress.
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let summary = dicom_test_files::prefetch("pydicom/*")?;
+/// # let _ = summary;
+/// # Ok(())
+/// # }
+/// ```
+pub fn prefetch(filter: impl Into<Filter>) -> Result<PrefetchSummary> {
+    Prefetch::new(filter).run()
+}
+
+/// Start fetching the test files selected by the given filter
+/// on worker threads, like [`prefetch`], and return at once.
+///
+/// Early tests run while the files are downloaded,
+/// and later tests which need the heavy files
+/// wait for them with [`WarmHandle::wait`].
+/// Use [`Prefetch::in_background`] to configure the prefetch.
+///
+/// ```no_run
+/// use dicom_test_files::WarmHandle;
+/// use std::sync::OnceLock;
+///
+/// fn warm() -> &'static WarmHandle {
+///     static WARM: OnceLock<WarmHandle> = OnceLock::new();
+///     WARM.get_or_init(|| dicom_test_files::warm_in_background("WG04/**"))
+/// }
+///
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// // at the start of the early tests
+/// warm();
+/// // in a later test
+/// warm().wait().expect("failed to warm the cache");
+/// let path = dicom_test_files::path("WG04/J2KR/MR1_J2KR")?;
+/// # let _ = path;
+/// # Ok(())
+/// # }
+/// ```
+pub fn warm_in_background(filter: impl Into<Filter>) -> WarmHandle {
+    Prefetch::new(filter).in_background()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn prefetch_with_progress() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let reported_ = Arc::clone(&reported);
+        let summary = Prefetch::new("WG04/JPLY/NM1_*")
+            .jobs(2)
+            .on_progress(move |p| {
+                assert!(!matches!(p.outcome, FetchOutcome::Failed(_)));
+                reported_
+                    .lock()
+                    .unwrap()
+                    .push((p.name, p.completed, p.total));
+            })
+            .run()
+            .unwrap();
+
+        assert_eq!(summary.fetched.len() + summary.cached.len(), 1);
+        assert_eq!(
+            *reported.lock().unwrap(),
+            vec![("WG04/JPLY/NM1_JPLY", 1, 1)]
+        );
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn decompress_on_worker_pool() {
+        use crate::{
+            entry_path, get_data_path, register_registry, testing::MockServer, Compression,
+            Registry, RegistryEntry,
+        };
+        use sha2::{Digest, Sha256};
+
+        let contents: Vec<Vec<u8>> = (0..4)
+            .map(|i| format!("contents of file {}", i).repeat(1000).into_bytes())
+            .collect();
+        let stored: Vec<Vec<u8>> = contents
+            .iter()
+            .map(|contents| zstd::bulk::compress(contents, 3).unwrap())
+            .collect();
+        let server = MockServer::start().unwrap();
+        register_registry(Registry {
+            base_url: server.url().to_string(),
+            entries: stored
+                .iter()
+                .enumerate()
+                .map(|(i, stored)| {
+                    server.blob(&format!("prefetch-pool/{}.dcm.zst", i), &stored[..]);
+                    RegistryEntry::new(
+                        format!("prefetch-pool/{}.dcm", i),
+                        format!("{:x}", Sha256::digest(stored)),
+                    )
+                    .size(stored.len() as u64)
+                    .compression(Compression::Zstd)
+                })
+                .collect(),
+        })
+        .unwrap();
+
+        let summary = Prefetch::new("prefetch-pool/*")
+            .jobs(1)
+            .decompress_jobs(2)
+            .run()
+            .unwrap();
+        assert_eq!(summary.fetched.len() + summary.cached.len(), 4);
+        for (i, contents) in contents.iter().enumerate() {
+            let name = format!("prefetch-pool/{}.dcm", i);
+            let cached_path = entry_path(&get_data_path(), &name);
+            assert_eq!(&std::fs::read(cached_path).unwrap(), contents);
+            // the compressed copies are not kept
+            assert!(!entry_path(&get_data_path(), &format!("{}.zst", name)).exists());
+        }
+    }
+
+    #[test]
+    fn warm_cache_in_background() {
+        let handle = warm_in_background("pydicom/MR_small_*.dcm");
+        let waiting = handle.clone();
+        let waited = thread::spawn(move || waiting.wait().unwrap().clone());
+        let summary = handle.wait().unwrap().clone();
+        assert!(handle.is_finished());
+        assert_eq!(waited.join().unwrap(), summary);
+        assert!(summary.cached.len() + summary.fetched.len() > 1);
+        assert!(summary
+            .fetched
+            .iter()
+            .chain(&summary.cached)
+            .all(|name| crate::cached_path(name).unwrap().is_some()));
+
+        let handle = warm_in_background("pydicom/nope.dcm");
+        assert_eq!(handle.wait().unwrap(), &PrefetchSummary::default());
+    }
+
+    #[test]
+    fn prefetch_all_cached() {
+        let filter = Filter::glob("pydicom/*").and(Filter::predicate(|e| {
+            e.name == "pydicom/MR_small.dcm" || e.name == "pydicom/MR_small_RLE.dcm"
+        }));
+        let summary = prefetch(filter).unwrap();
+        assert_eq!(summary.fetched.len() + summary.cached.len(), 2);
+
+        let summary = prefetch("pydicom/MR_small.dcm").unwrap();
+        assert_eq!(summary.fetched, Vec::<&str>::new());
+        assert_eq!(summary.cached, vec!["pydicom/MR_small.dcm"]);
+    }
+}
@@ -0,0 +1,69 @@
+//! Progress of the downloads
+
+use crate::bars;
+use std::sync::RwLock;
+
+/// The handler set by [`set_progress_handler`]
+static HANDLER: RwLock<Option<fn(DownloadEvent)>> = RwLock::new(None);
+
+/// An event in the download of a file,
+/// reported to the handler set by [`set_progress_handler`].
+///
+/// Byte counts are of the file as received,
+/// before it is decompressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadEvent<'a> {
+    /// The data source started sending the file
+    Started {
+        /// URL of the file
+        url: &'a str,
+        /// size of the file in bytes, if the data source sent it
+        total: Option<u64>,
+    },
+    /// Part of the file was received
+    Progress {
+        /// URL of the file
+        url: &'a str,
+        /// number of bytes received so far
+        downloaded: u64,
+        /// size of the file in bytes, if the data source sent it
+        total: Option<u64>,
+    },
+    /// The whole file was received
+    Completed {
+        /// URL of the file
+        url: &'a str,
+        /// number of bytes received
+        downloaded: u64,
+    },
+}
+
+/// Set a function to be called with the progress of all downloads
+/// in the process,
+/// such as to tell a long download from a hung test.
+///
+/// The handler is called from the threads downloading the files,
+/// possibly several at the same time,
+/// and replaces any previous handler.
+///
+/// ```no_run
+/// use dicom_test_files::DownloadEvent;
+///
+/// dicom_test_files::set_progress_handler(|event| match event {
+///     DownloadEvent::Started { url, total } => eprintln!("downloading {} ({:?} bytes)", url, total),
+///     DownloadEvent::Completed { url, downloaded } => eprintln!("downloaded {} ({} bytes)", url, downloaded),
+///     DownloadEvent::Progress { .. } => {}
+/// });
+/// ```
+pub fn set_progress_handler(handler: fn(DownloadEvent)) {
+    *HANDLER.write().unwrap_or_else(|e| e.into_inner()) = Some(handler);
+}
+
+/// Report a download event to the progress bars and the handler, if any
+pub(crate) fn report(event: DownloadEvent) {
+    bars::on_download(event);
+    let handler = *HANDLER.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(handler) = handler {
+        handler(event);
+    }
+}
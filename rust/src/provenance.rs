@@ -0,0 +1,161 @@
+//! Provenance, licenses and anonymization of the test files, for compliance reviews
+
+use crate::{all_entries, resolve, Filter, Provenance, Result};
+use std::collections::BTreeMap;
+
+/// Return where the given test file comes from
+/// and under what terms it may be redistributed,
+/// or `None` if it is not known,
+/// such as for files of an external manifest without provenance.
+///
+/// ```
+/// let provenance = dicom_test_files::provenance("pydicom/CT_small.dcm")?.unwrap();
+/// assert_eq!(provenance.license, "MIT");
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn provenance(name: &str) -> Result<Option<&'static Provenance>> {
+    resolve(name).map(|entry| entry.provenance.as_ref())
+}
+
+/// The test files under one license, see [`license_report`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseUsage {
+    /// the license, or `None` for the files of unknown provenance
+    pub license: Option<&'static str>,
+    /// the sources of the files, in alphabetical order
+    pub sources: Vec<&'static str>,
+    /// the names of the files, in alphabetical order
+    pub files: Vec<&'static str>,
+}
+
+/// Aggregate the licenses of the known test files selected by the given filter
+/// or glob pattern, such as the files used by a test suite,
+/// with the sources and names of the files under each license.
+///
+/// Licenses are in alphabetical order,
+/// after the files of unknown provenance if there are any.
+///
+/// ```
+/// for usage in dicom_test_files::license_report("WG04/**")? {
+///     println!("{:?}: {} files from {:?}", usage.license, usage.files.len(), usage.sources);
+/// }
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+pub fn license_report(filter: impl Into<Filter>) -> Result<Vec<LicenseUsage>> {
+    let filter = filter.into();
+    let mut licenses: BTreeMap<Option<&'static str>, LicenseUsage> = BTreeMap::new();
+    for entry in all_entries()?
+        .into_iter()
+        .filter(|entry| filter.matches(entry))
+    {
+        let license = entry.provenance.map(|provenance| provenance.license);
+        let usage = licenses.entry(license).or_insert_with(|| LicenseUsage {
+            license,
+            sources: Vec::new(),
+            files: Vec::new(),
+        });
+        if let Some(provenance) = entry.provenance {
+            if !usage.sources.contains(&provenance.source) {
+                usage.sources.push(provenance.source);
+            }
+        }
+        usage.files.push(entry.name);
+    }
+    Ok(licenses
+        .into_values()
+        .map(|mut usage| {
+            usage.sources.sort_unstable();
+            usage.files.sort_unstable();
+            usage
+        })
+        .collect())
+}
+
+/// Check that all the known test files selected by the given filter
+/// or glob pattern are vetted to hold no patient data,
+/// being either fully anonymized or synthetic,
+/// for test suites of institutions with strict data policies.
+///
+/// ```
+/// dicom_test_files::assert_anonymized("pydicom/*");
+/// let path = dicom_test_files::path("pydicom/CT_small.dcm")?;
+/// # let _ = path;
+/// # Ok::<(), dicom_test_files::Error>(())
+/// ```
+///
+/// To restrict a selection to vetted files instead,
+/// see [`Filter::anonymized`].
+///
+/// # Panics
+///
+/// If any of the selected files is a vendor sample
+/// or of unknown anonymization status,
+/// listing all of them,
+/// or if the entries cannot be collected.
+pub fn assert_anonymized(filter: impl Into<Filter>) {
+    let filter = filter.into();
+    let entries = match all_entries() {
+        Ok(entries) => entries,
+        Err(e) => panic!("failed to collect DICOM test files: {:?}", e),
+    };
+    let unvetted: Vec<String> = entries
+        .into_iter()
+        .filter(|entry| filter.matches(entry))
+        .filter_map(|entry| match entry.anonymization {
+            Some(status) if status.is_vetted() => None,
+            Some(status) => Some(format!("{} ({})", entry.name, status)),
+            None => Some(format!("{} (unknown)", entry.name)),
+        })
+        .collect();
+    if !unvetted.is_empty() {
+        panic!(
+            "{} DICOM test files are not known to be anonymized: {}",
+            unvetted.len(),
+            unvetted.join(", ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn licenses_of_test_files() {
+        let wg04 = provenance("WG04/REF/CT1_UNC").unwrap().unwrap();
+        assert!(wg04.source.contains("WG-04"));
+        assert!(provenance("pydicom/livr.dcm").is_err());
+
+        let report = license_report("pydicom/CT_small.dcm").unwrap();
+        assert_eq!(
+            report,
+            [LicenseUsage {
+                license: Some("MIT"),
+                sources: vec!["pydicom test files"],
+                files: vec!["pydicom/CT_small.dcm"],
+            }]
+        );
+        let report = license_report(Filter::all()).unwrap();
+        let files: usize = report.iter().map(|usage| usage.files.len()).sum();
+        assert!(files >= crate::entries::FILE_ENTRIES.len());
+        let mit = report
+            .iter()
+            .find(|usage| usage.license == Some("MIT"))
+            .unwrap();
+        assert!(mit.files.contains(&"invalid/bad_magic_code.dcm"));
+        assert!(mit.sources.len() >= 2);
+    }
+
+    #[test]
+    fn anonymized_test_files() {
+        assert_anonymized("pydicom/CT_small.dcm");
+        assert_anonymized(Filter::anonymized());
+        let unvetted = std::panic::catch_unwind(|| assert_anonymized("WG04/REF/CT1_UNC"));
+        let message = *unvetted.unwrap_err().downcast::<String>().unwrap();
+        assert_eq!(
+            message,
+            "1 DICOM test files are not known to be anonymized: WG04/REF/CT1_UNC (vendor sample)"
+        );
+        assert!(!Filter::anonymized().matches(&crate::TestFile::none("myorg/weird.dcm", "")));
+    }
+}
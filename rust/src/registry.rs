@@ -0,0 +1,240 @@
+//! Registration of additional test files from other crates
+
+use crate::{
+    config, lookup, Anonymization, Compression, DigestAlgorithm, Error, Provenance, Result,
+    TestFile,
+};
+use std::sync::RwLock;
+
+/// Entries of all registered registries, in registration order
+static REGISTERED: RwLock<Vec<&'static TestFile>> = RwLock::new(Vec::new());
+
+/// A collection of test files hosted outside of this project,
+/// see [`register_registry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Registry {
+    /// base URL of the files,
+    /// to which the name of each file is appended
+    pub base_url: String,
+    /// the test files of the registry
+    pub entries: Vec<RegistryEntry>,
+}
+
+/// A test file of a [`Registry`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryEntry {
+    name: String,
+    hash: String,
+    size: u64,
+    compression: Compression,
+    digest: DigestAlgorithm,
+    provenance: Option<Provenance>,
+    anonymization: Option<Anonymization>,
+    pointer: bool,
+}
+
+impl RegistryEntry {
+    /// Describe a test file by its path identifier
+    /// and the SHA-256 hash of its contents in lowercase hexadecimal,
+    /// or the hash of another algorithm set with [`digest`](RegistryEntry::digest).
+    pub fn new(name: impl Into<String>, hash: impl Into<String>) -> Self {
+        RegistryEntry {
+            name: name.into(),
+            hash: hash.into(),
+            size: 0,
+            compression: Compression::None,
+            digest: DigestAlgorithm::Sha256,
+            provenance: None,
+            anonymization: None,
+            pointer: false,
+        }
+    }
+
+    /// Set the size of the file in bytes as stored,
+    /// which is otherwise unknown.
+    pub fn size(mut self, size: u64) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the compression of the file as stored.
+    ///
+    /// Files compressed with Zstandard are stored with a `.zst` extension,
+    /// which is not part of the name,
+    /// and the hash is the one of the compressed file.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the algorithm of the hash, SHA-256 by default.
+    ///
+    /// Files hashed with BLAKE3 are verified faster,
+    /// but require the `blake3` feature.
+    pub fn digest(mut self, digest: DigestAlgorithm) -> Self {
+        self.digest = digest;
+        self
+    }
+
+    /// Set where the file comes from and its license,
+    /// reported by [`provenance`](crate::provenance).
+    pub fn provenance(mut self, provenance: Provenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// Set how the file is known to be free of patient data,
+    /// checked by [`assert_anonymized`](crate::assert_anonymized).
+    pub fn anonymization(mut self, anonymization: Anonymization) -> Self {
+        self.anonymization = Some(anonymization);
+        self
+    }
+
+    /// Mark the file as stored as a pointer record
+    /// to its data hosted on a CDN,
+    /// see the [crate documentation](crate#large-files).
+    ///
+    /// The hash and size are still the ones of the file.
+    pub fn pointer(mut self) -> Self {
+        self.pointer = true;
+        self
+    }
+}
+
+/// Merge the test files of another registry
+/// into the ones available to [`path`](crate::path) and [`prefetch`](crate::prefetch).
+///
+/// The files are downloaded from the registry's base URL,
+/// verified against their hash and cached like the others.
+/// Their names must not clash with files already known,
+/// except for files of an [external manifest](crate#external-manifests),
+/// which take precedence.
+///
+/// ```no_run
+/// use dicom_test_files::{register_registry, Registry, RegistryEntry};
+///
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// register_registry(Registry {
+///     base_url: "https://example.org/dicom/".to_string(),
+///     entries: vec![RegistryEntry::new(
+///         "myorg/weird.dcm",
+///         "0f8e3c5c0bd2b4b5fd0a1e7ab4bbd1a3c14fbc7e9f48e0937e8a537c21ef4b3a",
+///     )],
+/// })?;
+/// let weird = dicom_test_files::path("myorg/weird.dcm")?;
+/// # let _ = weird;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Fails with [`Error::Manifest`] if an entry is invalid or already known,
+/// in which case none of the entries are registered,
+/// or if [signatures are required](crate#signatures),
+/// as registries are not signed.
+pub fn register_registry(registry: Registry) -> Result<()> {
+    let invalid = |reason: String| Error::Manifest(format!("invalid registry: {}", reason));
+    if config::require_signatures() {
+        return Err(unsigned());
+    }
+    let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+
+    for (i, entry) in registry.entries.iter().enumerate() {
+        if entry.name.is_empty() || !entry.digest.is_hash(&entry.hash) {
+            return Err(invalid(format!("invalid entry {:?}", entry.name)));
+        }
+        let duplicate = registry.entries[..i].iter().any(|e| e.name == entry.name)
+            || lookup(&entry.name).is_some()
+            || registered.iter().any(|e| e.name == entry.name);
+        if duplicate {
+            return Err(invalid(format!("{} is already known", entry.name)));
+        }
+    }
+
+    // entries are kept for the rest of the process
+    let base_url = if registry.base_url.ends_with('/') {
+        registry.base_url
+    } else {
+        format!("{}/", registry.base_url)
+    };
+    let base_url: &'static str = Box::leak(base_url.into_boxed_str());
+    for entry in registry.entries {
+        let name = Box::leak(entry.name.into_boxed_str());
+        let hash = Box::leak(entry.hash.into_boxed_str());
+        let mut test_file = TestFile::new(name, entry.compression, hash)
+            .size(entry.size)
+            .digest(entry.digest);
+        test_file.base_url = Some(base_url);
+        test_file.provenance = entry.provenance;
+        test_file.anonymization = entry.anonymization;
+        test_file.pointer = entry.pointer;
+        registered.push(Box::leak(Box::new(test_file)));
+    }
+    Ok(())
+}
+
+/// The entries of all registered registries.
+///
+/// Fails if signatures are required and registries were registered.
+pub(crate) fn entries() -> Result<Vec<&'static TestFile>> {
+    let entries = REGISTERED.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if !entries.is_empty() && config::require_signatures() {
+        return Err(unsigned());
+    }
+    Ok(entries)
+}
+
+fn unsigned() -> Error {
+    Error::Manifest("registries are not signed, as signatures are required".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{base_url, path, prefetch};
+
+    #[test]
+    fn register_and_fetch() {
+        let jpll = lookup("WG04/JPLL/NM1_JPLL").unwrap();
+        let jlsl = lookup("WG04/JLSL/NM1_JLSL").unwrap();
+        let registry = |entries| Registry {
+            base_url: format!("{}WG04", base_url().unwrap()),
+            entries,
+        };
+
+        register_registry(registry(vec![
+            RegistryEntry::new("JPLL/NM1_JPLL", jpll.hash).size(jpll.size),
+            // a wrong hash
+            RegistryEntry::new("JLSL/NM1_JLSL", jpll.hash),
+        ]))
+        .unwrap();
+
+        let registered = path("JPLL/NM1_JPLL").unwrap();
+        assert!(registered.ends_with("JPLL/NM1_JPLL"));
+        assert!(matches!(
+            path("JLSL/NM1_JLSL"),
+            Err(Error::InvalidHash { .. })
+        ));
+        let summary = prefetch("JPLL/*").unwrap();
+        assert_eq!(summary.fetched.len() + summary.cached.len(), 1);
+
+        // clashing or invalid entries
+        for entries in [
+            vec![RegistryEntry::new("JPLL/NM1_JPLL", jpll.hash)],
+            vec![RegistryEntry::new("pydicom/liver.dcm", jpll.hash)],
+            vec![RegistryEntry::new("JLSL/NM1_JLSL2", "abc")],
+            vec![
+                RegistryEntry::new("JLSL/NM1_JLSL3", jlsl.hash),
+                RegistryEntry::new("JLSL/NM1_JLSL3", jlsl.hash),
+            ],
+        ] {
+            assert!(matches!(
+                register_registry(registry(entries)),
+                Err(Error::Manifest(_))
+            ));
+        }
+        assert!(matches!(
+            path("JLSL/NM1_JLSL3"),
+            Err(Error::NotFound { .. })
+        ));
+    }
+}
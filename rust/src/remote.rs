@@ -0,0 +1,199 @@
+//! Remote manifest of the test files,
+//! to resolve files added to the data repository after this crate version
+
+use crate::{
+    base_url_at, get_data_path, http, manifest::is_sha256, signature, Compression, Error, Result,
+    TestFile, LATEST_REF,
+};
+use std::{env, fs, io::Read, sync::Mutex};
+
+/// Environment variable enabling the remote manifest when set to `1`
+const ENV_VAR: &str = "DICOM_TEST_FILES_REMOTE_MANIFEST";
+
+const MANIFEST_NAME: &str = "manifest.txt";
+const SIGNATURE_NAME: &str = "manifest.txt.sig";
+const HEADER: &str = "# dicom-test-files manifest v1";
+/// Extensions of the stored names of compressed files
+const COMPRESSED_EXTENSIONS: &[(&str, Compression)] = &[
+    (".zst", Compression::Zstd),
+    (".gz", Compression::Gzip),
+    (".xz", Compression::Xz),
+];
+
+/// The remote entries, once fetched and verified
+static ENTRIES: Mutex<Option<&'static [TestFile]>> = Mutex::new(None);
+
+/// Whether the remote manifest mode is enabled
+pub(crate) fn enabled() -> bool {
+    env::var(ENV_VAR).as_deref() == Ok("1")
+}
+
+/// Look up a test file in the remote manifest,
+/// fetching it on first use.
+pub(crate) fn lookup(name: &str) -> Result<Option<&'static TestFile>> {
+    let mut entries = ENTRIES.lock().unwrap_or_else(|e| e.into_inner());
+    let entries = match *entries {
+        Some(entries) => entries,
+        None => {
+            // loaded once for the whole process
+            let loaded: &'static [TestFile] = Vec::leak(load()?);
+            *entries = Some(loaded);
+            loaded
+        }
+    };
+    Ok(entries.iter().find(|entry| entry.name == name))
+}
+
+/// Fetch, verify and parse the remote manifest.
+///
+/// Unless another source is configured,
+/// the manifest is the one of the latest data set,
+/// and its files are downloaded from there too.
+/// The last verified copy is kept in the cache,
+/// and used when the data source cannot be reached.
+fn load() -> Result<Vec<TestFile>> {
+    let cache_dir = get_data_path();
+    let base_url = base_url_at(LATEST_REF).map_err(Error::ResolveUrl)?;
+    let (manifest, signature) = match download(&base_url) {
+        Err(Error::Unreachable(reason)) => {
+            match (
+                fs::read(cache_dir.join(MANIFEST_NAME)),
+                fs::read_to_string(cache_dir.join(SIGNATURE_NAME)),
+            ) {
+                (Ok(manifest), Ok(signature)) => (manifest, signature),
+                _ => return Err(Error::Unreachable(reason)),
+            }
+        }
+        result => result?,
+    };
+
+    signature::verify_project(&manifest, &signature)?;
+    let base_url: &'static str = Box::leak(base_url.into_owned().into_boxed_str());
+    let entries = parse(&manifest, Some(base_url))?;
+
+    fs::create_dir_all(&cache_dir)?;
+    fs::write(cache_dir.join(MANIFEST_NAME), &manifest)?;
+    fs::write(cache_dir.join(SIGNATURE_NAME), &signature)?;
+    Ok(entries)
+}
+
+/// Download the manifest and its signature
+fn download(base_url: &str) -> Result<(Vec<u8>, String)> {
+    if env::var("DICOM_TEST_FILES_OFFLINE").as_deref() == Ok("1") {
+        return Err(Error::Unreachable(format!(
+            "Not downloading {}{} in offline mode",
+            base_url, MANIFEST_NAME
+        )));
+    }
+    let get = |name: &str| http::get(&format!("{}{}", base_url, name), &[]);
+
+    let mut manifest = Vec::new();
+    get(MANIFEST_NAME)?.body.read_to_end(&mut manifest)?;
+    let mut signature = String::new();
+    get(SIGNATURE_NAME)?.body.read_to_string(&mut signature)?;
+    Ok((manifest, signature))
+}
+
+/// Parse the lines of the manifest into test file entries
+/// downloaded from the given base URL
+fn parse(manifest: &[u8], base_url: Option<&'static str>) -> Result<Vec<TestFile>> {
+    let invalid = |reason: &str| Error::Manifest(format!("invalid manifest: {}", reason));
+    let manifest = std::str::from_utf8(manifest).map_err(|_| invalid("not UTF-8"))?;
+    let mut lines = manifest.lines();
+    if lines.next() != Some(HEADER) {
+        return Err(invalid("unsupported version"));
+    }
+
+    let mut entries = Vec::new();
+    for line in lines.filter(|line| !line.is_empty() && !line.starts_with('#')) {
+        let mut fields = line.splitn(3, ' ');
+        let (hash, size, stored_name) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(hash), Some(size), Some(stored_name)) => (hash, size, stored_name),
+            _ => return Err(invalid(line)),
+        };
+        if !is_sha256(hash) {
+            return Err(invalid(line));
+        }
+        let size: u64 = size.parse().map_err(|_| invalid(line))?;
+        let (name, compression) = COMPRESSED_EXTENSIONS
+            .iter()
+            .find_map(|(extension, compression)| {
+                stored_name
+                    .strip_suffix(extension)
+                    .map(|name| (name, *compression))
+            })
+            .unwrap_or((stored_name, Compression::None));
+        let name: &'static str = Box::leak(name.to_string().into_boxed_str());
+        let hash: &'static str = Box::leak(hash.to_string().into_boxed_str());
+        let mut entry = TestFile::new(name, compression, hash).size(size);
+        entry.base_url = base_url;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = format!(
+            "{}\n# comment\n{} 1234 pydicom/new.dcm\n{} 99 WG04/REF/NEW_UNC.zst\n{} 42 gdcm/NEW.dcm.gz\n",
+            HEADER,
+            "a".repeat(64),
+            "b".repeat(64),
+            "c".repeat(64)
+        );
+        let entries = parse(manifest.as_bytes(), None).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].name, "pydicom/new.dcm");
+        assert_eq!(entries[0].size, 1234);
+        assert_eq!(entries[0].compression, Compression::None);
+        assert_eq!(entries[1].name, "WG04/REF/NEW_UNC");
+        assert_eq!(entries[1].compression, Compression::Zstd);
+        assert_eq!(entries[1].real_file_name(), "WG04/REF/NEW_UNC.zst");
+        assert_eq!(entries[2].name, "gdcm/NEW.dcm");
+        assert_eq!(entries[2].compression, Compression::Gzip);
+
+        assert!(matches!(parse(b"# other\n", None), Err(Error::Manifest(_))));
+        let manifest = format!("{}\nnot a valid line\n", HEADER);
+        assert!(matches!(
+            parse(manifest.as_bytes(), None),
+            Err(Error::Manifest(_))
+        ));
+    }
+
+    #[cfg(feature = "remote-manifest")]
+    #[test]
+    fn remote_manifest() {
+        use crate::entries::FILE_ENTRIES;
+
+        let base_url = base_url_at(LATEST_REF).unwrap();
+        let mut manifest = Vec::new();
+        http::get(&format!("{}{}", base_url, MANIFEST_NAME), &[])
+            .map_err(Error::from)
+            .unwrap()
+            .body
+            .read_to_end(&mut manifest)
+            .unwrap();
+
+        // the manifest lists the compiled entries
+        let entries = parse(&manifest, None).unwrap();
+        for compiled in FILE_ENTRIES {
+            let entry = entries
+                .iter()
+                .find(|entry| entry.name == compiled.name)
+                .unwrap();
+            assert_eq!(entry.hash, compiled.hash);
+            assert_eq!(entry.size, compiled.size);
+            assert_eq!(entry.compression, compiled.compression);
+        }
+
+        // until the project has a signing key
+        assert!(matches!(
+            signature::verify_project(&manifest, &"0".repeat(128)),
+            Err(Error::Manifest(_))
+        ));
+    }
+}
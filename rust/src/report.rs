@@ -0,0 +1,238 @@
+//! Report of the downloads of the process
+
+use crate::{Error, Result};
+#[cfg(not(feature = "json"))]
+use std::io;
+use std::{
+    env, fs,
+    io::Write,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// All download attempts of the process so far
+static ATTEMPTS: Mutex<Vec<FetchAttempt>> = Mutex::new(Vec::new());
+
+/// The hash check of a downloaded test file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashCheck {
+    /// The file matched its hash
+    Valid,
+    /// The file did not match its hash, and was discarded
+    Mismatch,
+    /// The file was not checked,
+    /// as it was not modified or could not be received
+    Unchecked,
+}
+
+impl HashCheck {
+    #[cfg_attr(not(feature = "json"), allow(dead_code))]
+    fn name(self) -> &'static str {
+        match self {
+            HashCheck::Valid => "valid",
+            HashCheck::Mismatch => "mismatch",
+            HashCheck::Unchecked => "unchecked",
+        }
+    }
+}
+
+/// An attempt to download a test file, see [`download_report`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchAttempt {
+    /// the test file name
+    pub name: &'static str,
+    /// the URL the file was downloaded from, if it could be resolved
+    pub url: Option<String>,
+    /// the HTTP status code of the response, if the server responded
+    pub status: Option<u16>,
+    /// the number of previous attempts for the same file in the process
+    pub retries: usize,
+    /// how long the attempt took
+    pub duration: Duration,
+    /// the number of bytes received
+    pub bytes: u64,
+    /// the hash check of the received file
+    pub hash: HashCheck,
+    /// the error of a failed attempt
+    pub error: Option<String>,
+}
+
+/// All attempts to download test files in this process so far,
+/// in the order they ended.
+///
+/// Files which were already cached are not downloaded,
+/// so they are not listed.
+/// Set the environment variable `DICOM_TEST_FILES_REPORT`
+/// to a file path to also append each attempt to that file
+/// as they end, as a line of JSON (requires the `json` feature),
+/// so that the report covers all the test binaries of a run:
+///
+/// ```sh
+/// DICOM_TEST_FILES_REPORT=target/dicom-test-files-report.jsonl cargo test
+/// ```
+pub fn download_report() -> Vec<FetchAttempt> {
+    ATTEMPTS.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Write the [download report](download_report) of this process,
+/// with one JSON object per line and attempt:
+///
+/// ```json
+/// {"name":"WG04/REF/CT1_UNC","url":"https://…/data/WG04/REF/CT1_UNC.zst","status":200,"retries":0,"duration_ms":812,"bytes":4213561,"hash":"valid","error":null}
+/// ```
+///
+/// The `hash` is one of `"valid"`, `"mismatch"` or `"unchecked"`.
+///
+/// Fails with an [`io::ErrorKind::Unsupported`] error
+/// if the `json` feature is not enabled.
+pub fn write_download_report(mut writer: impl Write) -> Result<()> {
+    for attempt in download_report() {
+        writeln!(writer, "{}", json_line(&attempt)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+fn json_line(attempt: &FetchAttempt) -> Result<String> {
+    let duration_ms = attempt.duration.as_millis() as u64;
+    let line = serde_json::json!({
+        "name": attempt.name,
+        "url": attempt.url.as_deref(),
+        "status": attempt.status,
+        "retries": attempt.retries,
+        "duration_ms": duration_ms,
+        "bytes": attempt.bytes,
+        "hash": attempt.hash.name(),
+        "error": attempt.error.as_deref(),
+    });
+    Ok(line.to_string())
+}
+
+#[cfg(not(feature = "json"))]
+fn json_line(_attempt: &FetchAttempt) -> Result<String> {
+    Err(Error::Io(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "feature \"json\" is required for the download report",
+    )))
+}
+
+/// A download attempt in progress
+pub(crate) struct Attempt {
+    name: &'static str,
+    started: Instant,
+    /// the URL of the file, once resolved
+    pub url: Option<String>,
+    /// the status code of the response, once received
+    pub status: Option<u16>,
+    /// the number of bytes received
+    pub bytes: u64,
+}
+
+impl Attempt {
+    /// Start an attempt to download the given test file
+    pub(crate) fn start(name: &'static str) -> Self {
+        Attempt {
+            name,
+            started: Instant::now(),
+            url: None,
+            status: None,
+            bytes: 0,
+        }
+    }
+
+    /// Add the attempt to the report,
+    /// with the result of the download
+    pub(crate) fn finish(self, result: &Result<bool>) {
+        let hash = match result {
+            Ok(true) => HashCheck::Valid,
+            Err(Error::InvalidHash { .. }) => HashCheck::Mismatch,
+            _ => HashCheck::Unchecked,
+        };
+        let mut attempts = ATTEMPTS.lock().unwrap_or_else(|e| e.into_inner());
+        let retries = attempts.iter().filter(|a| a.name == self.name).count();
+        let attempt = FetchAttempt {
+            name: self.name,
+            url: self.url,
+            status: self.status,
+            retries,
+            duration: self.started.elapsed(),
+            bytes: self.bytes,
+            hash,
+            error: result.as_ref().err().map(Error::to_string),
+        };
+        append_to_report_file(&attempt);
+        attempts.push(attempt);
+    }
+}
+
+/// Append an attempt to the file set by `DICOM_TEST_FILES_REPORT`, if any
+fn append_to_report_file(attempt: &FetchAttempt) {
+    let path = match env::var_os("DICOM_TEST_FILES_REPORT") {
+        Some(path) if !path.is_empty() => path,
+        _ => return,
+    };
+    let appended = json_line(attempt).and_then(|line| {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        // a single write, as other test binaries may append at the same time
+        file.write_all(format!("{}\n", line).as_bytes())?;
+        Ok(())
+    });
+    if let Err(e) = appended {
+        eprintln!(
+            "[dicom-test-files] Failed to append to DICOM_TEST_FILES_REPORT {:?}: {:?}",
+            path, e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_attempts() {
+        const NAME: &str = "report/attempts.dcm";
+        let mut attempt = Attempt::start(NAME);
+        attempt.url = Some("https://example.org/data/report/attempts.dcm".to_string());
+        attempt.status = Some(200);
+        attempt.bytes = 42;
+        attempt.finish(&Err(Error::InvalidHash {
+            name: NAME.to_string(),
+            expected: "0".repeat(64),
+            actual: "1".repeat(64),
+            bytes: 42,
+            url: None,
+        }
+        .downloaded_from("https://example.org/data/report/attempts.dcm")));
+        Attempt::start(NAME).finish(&Err(Error::Unreachable("offline".to_string())));
+
+        let attempts: Vec<_> = download_report()
+            .into_iter()
+            .filter(|attempt| attempt.name == NAME)
+            .collect();
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(attempts[0].status, Some(200));
+        assert_eq!(attempts[0].bytes, 42);
+        assert_eq!(attempts[0].hash, HashCheck::Mismatch);
+        assert_eq!(attempts[0].retries, 0);
+        assert_eq!(attempts[1].url, None);
+        assert_eq!(attempts[1].hash, HashCheck::Unchecked);
+        assert_eq!(attempts[1].retries, 1);
+        assert!(attempts[1].error.as_deref().unwrap().contains("offline"));
+
+        #[cfg(feature = "json")]
+        {
+            let line: serde_json::Value =
+                serde_json::from_str(&json_line(&attempts[0]).unwrap()).unwrap();
+            assert_eq!(line["name"], NAME);
+            assert_eq!(line["status"], 200);
+            assert_eq!(line["hash"], "mismatch");
+            assert!(line["error"].as_str().unwrap().ends_with(
+                "for 42 bytes downloaded from https://example.org/data/report/attempts.dcm"
+            ));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Revalidation of cached test files with the ETags of the data source
+
+use crate::{entry_path, get_data_path, Compression, Error, Result, TestFile};
+use std::{collections::HashSet, env, fs, path::Path, path::PathBuf, sync::Mutex};
+
+/// Names of the entries revalidated so far in this process
+static REVALIDATED: Mutex<Option<HashSet<&'static str>>> = Mutex::new(None);
+
+/// The path of the stored ETag of a cached test file,
+/// in a tree mirroring the cache
+fn etag_path(data_path: &Path, name: &str) -> PathBuf {
+    entry_path(&data_path.join(".etags"), name)
+}
+
+/// The ETag the data source sent with the cached copy of a test file
+pub(crate) fn stored_etag(name: &str) -> Option<String> {
+    fs::read_to_string(etag_path(&get_data_path(), name))
+        .ok()
+        .filter(|etag| !etag.is_empty())
+}
+
+/// Store the ETag of a test file which was just downloaded,
+/// or forget the previous one if the data source sent none.
+///
+/// ETags only save downloads later on,
+/// so failing to store them is not an error.
+pub(crate) fn store_etag(name: &str, etag: Option<&str>) {
+    let path = etag_path(&get_data_path(), name);
+    match etag {
+        Some(etag) => {
+            let _ = fs::create_dir_all(path.parent().unwrap());
+            if let Err(e) = fs::write(&path, etag) {
+                eprintln!(
+                    "[dicom-test-files] Failed to store the ETag of {}: {}",
+                    name, e
+                );
+            }
+        }
+        None => {
+            let _ = fs::remove_file(&path);
+        }
+    }
+}
+
+/// Confirm with the data source that the cached copy of the given entry
+/// is current, once per process,
+/// downloading the file again if it changed.
+/// Returns whether the file was downloaded.
+///
+/// With a stored ETag, the data source only sends the file
+/// if it no longer matches;
+/// without one, the file is downloaded again.
+/// Files from ZIP archives are not revalidated,
+/// and neither are files when the data source cannot be reached.
+pub(crate) fn revalidate(entry: &TestFile, cached_path: &Path) -> Result<bool> {
+    if let Compression::ZipMember { .. } | Compression::DicomWeb { .. } = entry.compression {
+        return Ok(false);
+    }
+    if env::var("DICOM_TEST_FILES_OFFLINE").as_deref() == Ok("1") {
+        return Ok(false);
+    }
+    {
+        let mut revalidated = REVALIDATED.lock().unwrap_or_else(|e| e.into_inner());
+        if !revalidated
+            .get_or_insert_with(HashSet::new)
+            .insert(entry.name)
+        {
+            return Ok(false);
+        }
+    }
+    match crate::download(entry, cached_path, stored_etag(entry.name).as_deref()) {
+        Err(Error::Unreachable(_)) => {
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                name = entry.name,
+                "data source unreachable, keeping the cached copy"
+            );
+            Ok(false)
+        }
+        downloaded => downloaded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    #[test]
+    fn revalidate_with_etag() {
+        const BODY: &[u8] = b"contents of the test file";
+        static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+        // a data source with ETags
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                REQUESTS.fetch_add(1, Ordering::SeqCst);
+                let mut current = false;
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    current |= line.eq_ignore_ascii_case("if-none-match: \"v1\"");
+                }
+                let head = if current {
+                    "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\n\r\n".to_string()
+                } else {
+                    format!(
+                        "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: {}\r\n\r\n",
+                        BODY.len()
+                    )
+                };
+                stream.write_all(head.as_bytes()).unwrap();
+                if !current {
+                    stream.write_all(BODY).unwrap();
+                }
+            }
+        });
+
+        let hash = format!("{:x}", Sha256::digest(BODY));
+        let mut entry = TestFile::new(
+            "revalidate/etag.dcm",
+            Compression::None,
+            Box::leak(hash.into()),
+        );
+        entry.base_url = Some(Box::leak(base_url.into()));
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("etag.dcm");
+
+        assert!(crate::download(&entry, &cached_path, None).unwrap());
+        assert_eq!(fs::read(&cached_path).unwrap(), BODY);
+        assert_eq!(stored_etag(entry.name).as_deref(), Some("\"v1\""));
+
+        // not modified, then only revalidated once
+        assert!(!revalidate(&entry, &cached_path).unwrap());
+        assert!(!revalidate(&entry, &cached_path).unwrap());
+        assert_eq!(REQUESTS.load(Ordering::SeqCst), 2);
+        assert_eq!(fs::read(&cached_path).unwrap(), BODY);
+
+        assert_eq!(
+            etag_path(Path::new("/cache"), "WG04/REF/CT1_UNC"),
+            Path::new("/cache/.etags/WG04/REF/CT1_UNC")
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Curated sets of test files for the codecs of encapsulated pixel data.
+//!
+//! Each set holds every test file encoded in the transfer syntaxes
+//! of one codec, according to their file meta group,
+//! so that it grows along with the collection
+//! instead of being listed by hand in each codec crate.
+//!
+//! ```
+//! use dicom_test_files::sets;
+//!
+//! for entry in sets::jpeg_baseline()? {
+//!     println!("{}", entry.name);
+//! }
+//! assert!(!sets::rle()?.is_empty());
+//! # Ok::<(), dicom_test_files::Error>(())
+//! ```
+
+use crate::{by_transfer_syntax, group, Result, TestFile};
+
+/// JPEG Baseline (Process 1)
+const JPEG_BASELINE: &str = "1.2.840.10008.1.2.4.50";
+/// JPEG Extended (Process 2 & 4)
+const JPEG_EXTENDED: &str = "1.2.840.10008.1.2.4.51";
+/// JPEG Lossless, Non-Hierarchical (Process 14)
+const JPEG_LOSSLESS: &str = "1.2.840.10008.1.2.4.57";
+/// JPEG Lossless, Non-Hierarchical, First-Order Prediction (Process 14, Selection Value 1)
+const JPEG_LOSSLESS_SV1: &str = "1.2.840.10008.1.2.4.70";
+
+/// The test files in JPEG Baseline (Process 1),
+/// 8-bit lossy JPEG
+pub fn jpeg_baseline() -> Result<Vec<&'static TestFile>> {
+    by_transfer_syntax(JPEG_BASELINE)
+}
+
+/// The test files in JPEG Extended (Process 2 & 4),
+/// 12-bit lossy JPEG
+pub fn jpeg_extended() -> Result<Vec<&'static TestFile>> {
+    by_transfer_syntax(JPEG_EXTENDED)
+}
+
+/// The test files in JPEG Lossless, Non-Hierarchical (Process 14),
+/// with any predictor or with the first-order one
+pub fn jpeg_lossless() -> Result<Vec<&'static TestFile>> {
+    let mut entries = by_transfer_syntax(JPEG_LOSSLESS)?;
+    entries.extend(by_transfer_syntax(JPEG_LOSSLESS_SV1)?);
+    Ok(entries)
+}
+
+/// The test files in JPEG-LS, lossless or near-lossless
+pub fn jpeg_ls() -> Result<Vec<&'static TestFile>> {
+    group("jpeg-ls")
+}
+
+/// The test files in JPEG 2000, lossless or lossy,
+/// and in High-Throughput JPEG 2000
+pub fn jpeg2000() -> Result<Vec<&'static TestFile>> {
+    group("jpeg2000")
+}
+
+/// The test files in RLE Lossless
+pub fn rle() -> Result<Vec<&'static TestFile>> {
+    group("rle")
+}
+
+/// The test files in Deflated Explicit VR Little Endian
+pub fn deflate() -> Result<Vec<&'static TestFile>> {
+    group("deflate")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codec_sets() {
+        let transfer_syntaxes = |entries: Vec<&TestFile>| -> Vec<&str> {
+            let mut uids: Vec<&str> = entries
+                .into_iter()
+                .filter_map(|entry| entry.info.transfer_syntax_uid)
+                .collect();
+            uids.sort_unstable();
+            uids.dedup();
+            uids
+        };
+        assert_eq!(transfer_syntaxes(jpeg_baseline().unwrap()), [JPEG_BASELINE]);
+        assert_eq!(transfer_syntaxes(jpeg_extended().unwrap()), [JPEG_EXTENDED]);
+        assert_eq!(
+            transfer_syntaxes(jpeg_lossless().unwrap()),
+            [JPEG_LOSSLESS_SV1]
+        );
+        assert_eq!(
+            transfer_syntaxes(jpeg_ls().unwrap()),
+            ["1.2.840.10008.1.2.4.80", "1.2.840.10008.1.2.4.81"]
+        );
+        assert_eq!(
+            transfer_syntaxes(jpeg2000().unwrap()),
+            ["1.2.840.10008.1.2.4.90", "1.2.840.10008.1.2.4.91"]
+        );
+        assert_eq!(transfer_syntaxes(rle().unwrap()), ["1.2.840.10008.1.2.5"]);
+        assert_eq!(
+            transfer_syntaxes(deflate().unwrap()),
+            ["1.2.840.10008.1.2.1.99"]
+        );
+        assert!(rle()
+            .unwrap()
+            .iter()
+            .any(|entry| entry.name == "WG04/RLE/CT1_RLE"));
+    }
+}
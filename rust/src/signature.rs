@@ -0,0 +1,130 @@
+//! Ed25519 signatures of manifests,
+//! by keys trusted in the configuration
+
+use crate::{Error, Result};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The path of the signature of a manifest, next to it
+pub(crate) fn signature_path(path: &Path) -> PathBuf {
+    let mut signature_path = path.as_os_str().to_owned();
+    signature_path.push(".sig");
+    PathBuf::from(signature_path)
+}
+
+/// Check the signature of a manifest file, if it has one,
+/// returning whether it is signed.
+///
+/// Fails with [`Error::Manifest`] if the signature is not valid.
+pub(crate) fn check_file(path: &Path, trusted_keys: &[String]) -> Result<bool> {
+    let signature = match fs::read_to_string(signature_path(path)) {
+        Ok(signature) => signature,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+    verify(&fs::read(path)?, &signature, trusted_keys).map_err(|e| match e {
+        Error::Manifest(reason) => Error::Manifest(format!("{}: {}", path.display(), reason)),
+        e => e,
+    })?;
+    Ok(true)
+}
+
+/// Check the signature of the project's remote manifest.
+///
+/// The project has no signing key yet,
+/// so remote manifests are refused with [`Error::Manifest`]
+/// rather than trusted, see ./generate/manifest.py
+pub(crate) fn verify_project(_data: &[u8], _signature: &str) -> Result<()> {
+    Err(Error::Manifest(
+        "the project has no signing key yet, remote manifests cannot be verified".to_string(),
+    ))
+}
+
+/// Check a signature in hexadecimal
+/// against the given trusted keys
+#[cfg(feature = "signatures")]
+pub(crate) fn verify(data: &[u8], signature: &str, trusted_keys: &[String]) -> Result<()> {
+    use ed25519_dalek::{Signature, VerifyingKey};
+
+    if trusted_keys.is_empty() {
+        return Err(Error::Manifest(
+            "signed manifest, but no key is trusted, see `DICOM_TEST_FILES_TRUSTED_KEYS`"
+                .to_string(),
+        ));
+    }
+    let invalid = || Error::Manifest("invalid manifest signature".to_string());
+    let mut signature_bytes = [0; 64];
+    decode_hex(signature.trim(), &mut signature_bytes).ok_or_else(invalid)?;
+    let signature = Signature::from_bytes(&signature_bytes);
+
+    for key in trusted_keys {
+        let mut key_bytes = [0; 32];
+        let key = decode_hex(key, &mut key_bytes)
+            .and_then(|()| VerifyingKey::from_bytes(&key_bytes).ok());
+        match key {
+            Some(key) if key.verify_strict(data, &signature).is_ok() => return Ok(()),
+            _ => {}
+        }
+    }
+    Err(invalid())
+}
+
+#[cfg(not(feature = "signatures"))]
+pub(crate) fn verify(_data: &[u8], _signature: &str, _trusted_keys: &[String]) -> Result<()> {
+    Err(Error::Manifest(
+        "feature \"signatures\" is required to verify the manifest signature".to_string(),
+    ))
+}
+
+/// Whether the given text is an Ed25519 public key in hexadecimal
+pub(crate) fn is_public_key(key: &str) -> bool {
+    decode_hex(key, &mut [0; 32]).is_some()
+}
+
+fn decode_hex(hex: &str, out: &mut [u8]) -> Option<()> {
+    if hex.len() != out.len() * 2 || !hex.is_ascii() {
+        return None;
+    }
+    for (byte, digits) in out.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()?;
+    }
+    Some(())
+}
+
+#[cfg(all(test, feature = "signatures"))]
+mod tests {
+    use super::*;
+
+    /// The public key of a test key pair, whose private key is
+    /// the bytes 0 to 31
+    const TEST_KEY: &str = "03a107bff3ce10be1d70dd18e74bc09967e4d6309ba50d5f1ddc8664125531b8";
+    const MANIFEST: &[u8] = b"{\"files\": []}\n";
+    /// The signature of `MANIFEST` by the test key
+    const SIGNATURE: &str = "24a5980c04a6acc52654f89a87bb88321c4226144cbe49255fbdbfb9973b75bdfa7b48d05d280b2a48d1c5db5ceb9b65decb024fa90295d1ff8d566e05a71108";
+
+    #[test]
+    fn signed_manifest_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("files.json");
+        fs::write(&path, MANIFEST).unwrap();
+        let trusted = [TEST_KEY.to_string()];
+        assert!(!check_file(&path, &trusted).unwrap());
+
+        fs::write(signature_path(&path), format!("{}\n", SIGNATURE)).unwrap();
+        assert_eq!(signature_path(&path), dir.path().join("files.json.sig"));
+        assert!(check_file(&path, &trusted).unwrap());
+        // no key is trusted by default
+        assert!(matches!(check_file(&path, &[]), Err(Error::Manifest(_))));
+
+        fs::write(&path, b"{\"files\": [1]}\n").unwrap();
+        assert!(matches!(
+            check_file(&path, &trusted),
+            Err(Error::Manifest(_))
+        ));
+
+        assert!(is_public_key(TEST_KEY));
+        assert!(!is_public_key("03a107"));
+    }
+}
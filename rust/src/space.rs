@@ -0,0 +1,123 @@
+//! Free space of the cache
+
+use crate::{Compression, Error, Result, TestFile};
+use std::path::Path;
+
+/// The number of bytes needed in the cache to download the given entry.
+///
+/// A file stored with compression needs room for its stored copy
+/// and at least as much again for its decompressed contents.
+pub(crate) fn needed(entry: &TestFile) -> u64 {
+    match entry.compression {
+        Compression::None => entry.size,
+        _ => entry.size.saturating_mul(2),
+    }
+}
+
+/// Fail with [`Error::InsufficientSpace`]
+/// if the file system of the given cached path
+/// does not have room for the download of the given entry,
+/// see [`needed`].
+/// Nothing is checked where the free space cannot be determined.
+pub(crate) fn check(entry: &TestFile, cached_path: &Path) -> Result<()> {
+    let needed = needed(entry);
+    // the directories of the file may not be created yet
+    let available = match cached_path.ancestors().skip(1).find(|dir| dir.is_dir()) {
+        Some(dir) => available(dir),
+        None => None,
+    };
+    match available {
+        Some(available) if available < needed => {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(needed, available, "insufficient space");
+            Err(Error::InsufficientSpace { needed, available })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The number of bytes available to this process
+/// in the file system of the given directory
+#[cfg(unix)]
+fn available(dir: &Path) -> Option<u64> {
+    use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+    let dir = CString::new(dir.as_os_str().as_bytes()).ok()?;
+    let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+    // SAFETY: the path is a valid C string
+    // and the structure is only read once filled in
+    let stat = unsafe {
+        if libc::statvfs(dir.as_ptr(), stat.as_mut_ptr()) != 0 {
+            return None;
+        }
+        stat.assume_init()
+    };
+    #[allow(clippy::unnecessary_cast)]
+    (stat.f_bavail as u64).checked_mul(stat.f_frsize as u64)
+}
+
+/// The number of bytes available to this process
+/// in the file system of the given directory
+#[cfg(windows)]
+fn available(dir: &Path) -> Option<u64> {
+    use std::os::windows::ffi::OsStrExt;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetDiskFreeSpaceExW(
+            directory_name: *const u16,
+            free_bytes_available_to_caller: *mut u64,
+            total_number_of_bytes: *mut u64,
+            total_number_of_free_bytes: *mut u64,
+        ) -> i32;
+    }
+
+    let dir: Vec<u16> = dir.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut available = 0;
+    // SAFETY: the path is a valid wide string
+    // and the sizes which are not wanted may be null
+    let succeeded = unsafe {
+        GetDiskFreeSpaceExW(
+            dir.as_ptr(),
+            &mut available,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    if succeeded == 0 {
+        return None;
+    }
+    Some(available)
+}
+
+/// The free space cannot be determined on other platforms
+#[cfg(not(any(unix, windows)))]
+fn available(_dir: &Path) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(unix, windows))]
+    #[test]
+    fn insufficient_space() {
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("not/created/yet.dcm");
+        assert!(available(dir.path()).unwrap() > 0);
+
+        let entry = TestFile::none("space/small.dcm", "").size(1);
+        check(&entry, &cached_path).unwrap();
+        let entry = TestFile::none("space/huge.dcm", "").size(u64::MAX / 4);
+        assert!(matches!(
+            check(&entry, &cached_path),
+            Err(Error::InsufficientSpace { needed, .. }) if needed == u64::MAX / 4
+        ));
+        let entry = TestFile::zstd("space/huge.dcm", "").size(u64::MAX / 4);
+        assert!(matches!(
+            check(&entry, &cached_path),
+            Err(Error::InsufficientSpace { needed, .. }) if needed == u64::MAX / 4 * 2
+        ));
+    }
+}
@@ -0,0 +1,351 @@
+//! Whole study directories with a DICOMDIR
+
+use crate::{
+    get_data_path, suggest,
+    synth::{
+        derived_uid, write_file_meta, ElementWriter, Position, SynthFile, TransferSyntax,
+        FORMAT_VERSION, PATIENT_ID, PATIENT_NAME, STUDY_DATE, STUDY_ID, STUDY_TIME,
+    },
+    Result,
+};
+use std::{
+    fs,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+/// Media Storage Directory Storage
+const DICOMDIR_SOP_CLASS_UID: &str = "1.2.840.10008.1.3.10";
+
+const FILE_SET_ID: &str = "SYNTH";
+
+struct SeriesDef {
+    modality: &'static str,
+    instances: u32,
+    file: fn() -> SynthFile,
+}
+
+struct StudyDef {
+    name: &'static str,
+    series: &'static [SeriesDef],
+}
+
+const STUDIES: &[StudyDef] = &[
+    StudyDef {
+        name: "synth/ct_mr",
+        series: &[
+            SeriesDef {
+                modality: "CT",
+                instances: 4,
+                file: || SynthFile::new().rows(32).columns(32),
+            },
+            SeriesDef {
+                modality: "MR",
+                instances: 3,
+                file: || {
+                    SynthFile::new()
+                        .rows(24)
+                        .columns(24)
+                        .bits_allocated(8)
+                        .transfer_syntax(TransferSyntax::ImplicitVrLittleEndian)
+                },
+            },
+        ],
+    },
+    StudyDef {
+        name: "synth/multi_frame",
+        series: &[
+            SeriesDef {
+                modality: "US",
+                instances: 1,
+                file: || {
+                    SynthFile::new()
+                        .rows(32)
+                        .columns(32)
+                        .bits_allocated(8)
+                        .samples_per_pixel(3)
+                        .frames(6)
+                },
+            },
+            SeriesDef {
+                modality: "XA",
+                instances: 2,
+                file: || SynthFile::new().rows(32).columns(32).frames(10),
+            },
+        ],
+    },
+];
+
+/// Names of the available study fixtures, for use with [`study`].
+pub fn study_names() -> impl Iterator<Item = &'static str> {
+    STUDIES.iter().map(|def| def.name)
+}
+
+/// Prepare a whole study directory and return its root.
+///
+/// The root contains a `DICOMDIR` file
+/// and the instances of every series of the study,
+/// referenced by the DICOMDIR as `DICOM/STxxxxxx/SExxxxxx/IMxxxxxx`.
+/// All instances share the same patient and study,
+/// with consistent study and series UIDs.
+///
+/// Studies are synthesized locally (see [`synth`](crate::synth))
+/// and cached next to the downloaded test files.
+/// See [`study_names`] for the available studies,
+/// an unknown name results in [`Error::NotFound`](crate::Error::NotFound).
+///
+/// ```no_run
+/// # fn main() -> Result<(), dicom_test_files::Error> {
+/// let root = dicom_test_files::study("synth/ct_mr")?;
+/// let dicomdir = root.join("DICOMDIR");
+/// # let _ = dicomdir;
+/// # Ok(())
+/// # }
+/// ```
+pub fn study(name: &str) -> Result<PathBuf> {
+    let def = STUDIES
+        .iter()
+        .find(|def| def.name == name)
+        .ok_or_else(|| suggest::not_found(name, study_names()))?;
+
+    let target = get_data_path()
+        .join("studies")
+        .join(format!("v{}", FORMAT_VERSION))
+        .join(name);
+    if target.join("DICOMDIR").exists() {
+        return Ok(target);
+    }
+    let target_parent = target.parent().unwrap();
+    fs::create_dir_all(target_parent)?;
+
+    let staging = tempfile::tempdir_in(target_parent)?;
+    write_study(def, staging.path())?;
+    if let Err(e) = fs::rename(staging.path(), &target) {
+        // the study may have been written concurrently
+        if !target.join("DICOMDIR").exists() {
+            return Err(e.into());
+        }
+    }
+    Ok(target)
+}
+
+/// A file of the study with its file ID components
+struct Instance {
+    file_id: [String; 4],
+    file: SynthFile,
+}
+
+fn write_study(def: &StudyDef, root: &Path) -> Result<()> {
+    let mut series = Vec::new();
+    for (i, series_def) in def.series.iter().enumerate() {
+        let series_number = i as u32 + 1;
+        let instances: Vec<Instance> = (1..=series_def.instances)
+            .map(|instance_number| Instance {
+                file_id: [
+                    "DICOM".to_string(),
+                    "ST000001".to_string(),
+                    format!("SE{:06}", series_number),
+                    format!("IM{:06}", instance_number),
+                ],
+                file: (series_def.file)().at(Position {
+                    study: def.name,
+                    modality: series_def.modality,
+                    series_number,
+                    instance_number,
+                }),
+            })
+            .collect();
+        series.push((series_def, series_number, instances));
+    }
+
+    for instance in series.iter().flat_map(|(_, _, instances)| instances) {
+        instance.file.validate()?;
+        let path = instance
+            .file_id
+            .iter()
+            .fold(root.to_path_buf(), |path, component| path.join(component));
+        fs::create_dir_all(path.parent().unwrap())?;
+        let mut writer = BufWriter::new(fs::File::create(path)?);
+        instance.file.write_to(&mut writer)?;
+        writer.flush()?;
+    }
+
+    // directory records, depth first
+    let first = &series[0].2[0].file;
+    let mut records = vec![
+        Record::new("PATIENT", |w| {
+            w.string(0x0010, 0x0010, b"PN", PATIENT_NAME)?;
+            w.string(0x0010, 0x0020, b"LO", PATIENT_ID)
+        })?,
+        Record::new("STUDY", |w| {
+            w.string(0x0008, 0x0020, b"DA", STUDY_DATE)?;
+            w.string(0x0008, 0x0030, b"TM", STUDY_TIME)?;
+            w.string(0x0008, 0x0050, b"SH", "")?;
+            w.string(0x0008, 0x1030, b"LO", def.name)?;
+            w.string(0x0020, 0x000D, b"UI", &first.uid("study"))?;
+            w.string(0x0020, 0x0010, b"SH", STUDY_ID)
+        })?,
+    ];
+    records[0].lower = Some(1);
+    records[1].lower = Some(2);
+    let mut previous_series: Option<usize> = None;
+    for (series_def, series_number, instances) in &series {
+        let series_record = records.len();
+        if let Some(previous) = previous_series {
+            records[previous].next = Some(series_record);
+        }
+        previous_series = Some(series_record);
+        records.push(Record::new("SERIES", |w| {
+            w.string(0x0008, 0x0060, b"CS", series_def.modality)?;
+            w.string(0x0020, 0x000E, b"UI", &instances[0].file.uid("series"))?;
+            w.string(0x0020, 0x0011, b"IS", &series_number.to_string())
+        })?);
+        records[series_record].lower = Some(series_record + 1);
+
+        for (i, instance) in instances.iter().enumerate() {
+            let file = &instance.file;
+            let mut record = Record::new("IMAGE", |w| {
+                w.string(0x0004, 0x1500, b"CS", &instance.file_id.join("\\"))?;
+                w.string(0x0004, 0x1510, b"UI", file.sop_class_uid())?;
+                w.string(0x0004, 0x1511, b"UI", &file.uid("instance"))?;
+                w.string(0x0004, 0x1512, b"UI", file.transfer_syntax_uid())?;
+                w.string(0x0020, 0x0013, b"IS", &(i + 1).to_string())
+            })?;
+            if i + 1 < instances.len() {
+                record.next = Some(records.len() + 1);
+            }
+            records.push(record);
+        }
+    }
+
+    let mut writer = BufWriter::new(fs::File::create(root.join("DICOMDIR"))?);
+    writer.write_all(&dicomdir(def.name, &records)?)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// A directory record, with references to other records by index
+struct Record {
+    kind: &'static str,
+    keys: Vec<u8>,
+    next: Option<usize>,
+    lower: Option<usize>,
+}
+
+impl Record {
+    fn new(
+        kind: &'static str,
+        keys: impl FnOnce(&mut ElementWriter<Vec<u8>>) -> io::Result<()>,
+    ) -> io::Result<Self> {
+        let mut writer = ElementWriter::new(Vec::new(), TransferSyntax::ExplicitVrLittleEndian);
+        keys(&mut writer)?;
+        Ok(Record {
+            kind,
+            keys: writer.into_inner(),
+            next: None,
+            lower: None,
+        })
+    }
+
+    /// Encode the record as a sequence item,
+    /// with the given offsets of the next and lower level records
+    fn encode(&self, next: u32, lower: u32) -> io::Result<Vec<u8>> {
+        let mut content = ElementWriter::new(Vec::new(), TransferSyntax::ExplicitVrLittleEndian);
+        content.u32(0x0004, 0x1400, b"UL", next)?;
+        content.u16(0x0004, 0x1410, b"US", 0xFFFF)?;
+        content.u32(0x0004, 0x1420, b"UL", lower)?;
+        content.string(0x0004, 0x1430, b"CS", self.kind)?;
+        let mut content = content.into_inner();
+        content.extend_from_slice(&self.keys);
+
+        let mut item = ElementWriter::new(Vec::new(), TransferSyntax::ExplicitVrLittleEndian);
+        item.item_header(0xE000, content.len() as u32)?;
+        let mut item = item.into_inner();
+        item.extend_from_slice(&content);
+        Ok(item)
+    }
+}
+
+/// Encode a DICOMDIR with the given directory records,
+/// the first of which is the only root record
+fn dicomdir(name: &str, records: &[Record]) -> io::Result<Vec<u8>> {
+    let header = |first_record: u32, sequence_len: u32| -> io::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        write_file_meta(
+            &mut out,
+            DICOMDIR_SOP_CLASS_UID,
+            &derived_uid(name, "dicomdir"),
+            TransferSyntax::ExplicitVrLittleEndian,
+        )?;
+        let mut ds = ElementWriter::new(out, TransferSyntax::ExplicitVrLittleEndian);
+        ds.string(0x0004, 0x1130, b"CS", FILE_SET_ID)?;
+        ds.u32(0x0004, 0x1200, b"UL", first_record)?;
+        ds.u32(0x0004, 0x1202, b"UL", first_record)?;
+        ds.u16(0x0004, 0x1212, b"US", 0)?;
+        ds.header(0x0004, 0x1220, b"SQ", sequence_len)?;
+        Ok(ds.into_inner())
+    };
+
+    // offsets have a fixed size, so the layout can be computed up front
+    let start = header(0, 0)?.len() as u32;
+    let mut offsets = Vec::with_capacity(records.len());
+    let mut offset = start;
+    for record in records {
+        offsets.push(offset);
+        offset += record.encode(0, 0)?.len() as u32;
+    }
+    let offset_of = |index: Option<usize>| index.map_or(0, |i| offsets[i]);
+
+    let mut out = header(start, offset - start)?;
+    for record in records {
+        out.extend(record.encode(offset_of(record.next), offset_of(record.lower))?);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use std::convert::TryInto;
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn study_with_dicomdir() {
+        let root = study("synth/ct_mr").unwrap();
+        for series in 1..=2 {
+            let series_dir = root.join(format!("DICOM/ST000001/SE{:06}", series));
+            assert_eq!(
+                fs::read_dir(series_dir).unwrap().count(),
+                [4, 3][series - 1]
+            );
+        }
+
+        let dicomdir = fs::read(root.join("DICOMDIR")).unwrap();
+        assert_eq!(&dicomdir[128..132], b"DICM");
+        let file_id = b"DICOM\\ST000001\\SE000002\\IM000003";
+        assert!(dicomdir.windows(file_id.len()).any(|w| w == file_id));
+
+        // the root record offset points at the patient record item
+        let tag = [0x04, 0x00, 0x00, 0x12, b'U', b'L', 4, 0];
+        let pos = dicomdir.windows(8).position(|w| w == tag).unwrap();
+        let root_record = u32_at(&dicomdir, pos + 8) as usize;
+        assert!(dicomdir[root_record..].starts_with(&[0xFE, 0xFF, 0x00, 0xE0]));
+        let patient = b"PATIENT ";
+        assert!(dicomdir[root_record..root_record + 64]
+            .windows(patient.len())
+            .any(|w| w == patient));
+
+        // cached on the second call
+        assert_eq!(study("synth/ct_mr").unwrap(), root);
+    }
+
+    #[test]
+    fn unknown_study() {
+        assert!(study_names().any(|name| name == "synth/multi_frame"));
+        assert!(matches!(study("synth/nope"), Err(Error::NotFound { .. })));
+    }
+}
@@ -0,0 +1,102 @@
+//! Suggestions of known names for unknown test file names
+
+use crate::Error;
+
+/// Maximum number of names suggested
+const MAX_SUGGESTIONS: usize = 3;
+
+/// The [`Error::NotFound`] of the given unknown name,
+/// suggesting the closest of the given known names
+pub(crate) fn not_found<'a>(name: &str, known: impl IntoIterator<Item = &'a str>) -> Error {
+    let wanted = name.to_lowercase();
+    // close enough for a typo, or a missing directory
+    let max_distance = (wanted.chars().count() / 3).max(2);
+    let mut available = 0;
+    let mut candidates: Vec<(usize, &str)> = Vec::new();
+    for known in known {
+        available += 1;
+        let candidate = known.to_lowercase();
+        let distance = if candidate.ends_with(&wanted) {
+            0
+        } else {
+            distance(&wanted, &candidate)
+        };
+        if distance <= max_distance {
+            candidates.push((distance, known));
+        }
+    }
+    candidates.sort();
+    Error::NotFound {
+        name: name.to_string(),
+        suggestions: candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, known)| known.to_string())
+            .collect(),
+        available,
+    }
+}
+
+/// The Levenshtein distance between two strings
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggest_close_names() {
+        let known = [
+            "pydicom/liver.dcm",
+            "pydicom/CT_small.dcm",
+            "pydicom/MR_small.dcm",
+            "WG04/REF/CT1_UNC",
+        ];
+        let error = not_found("pydicom/livr.dcm", known);
+        match &error {
+            Error::NotFound {
+                name,
+                suggestions,
+                available,
+            } => {
+                assert_eq!(name, "pydicom/livr.dcm");
+                assert_eq!(suggestions, &["pydicom/liver.dcm"]);
+                assert_eq!(*available, 4);
+            }
+            _ => panic!("unexpected error {:?}", error),
+        }
+        assert_eq!(
+            error.to_string(),
+            "unknown DICOM test file \"pydicom/livr.dcm\", did you mean \"pydicom/liver.dcm\"? \
+             4 test files are available, listed by `dicom_test_files::export_manifest`"
+        );
+
+        // missing directory, then case
+        let suggestions = |name| match not_found(name, known) {
+            Error::NotFound { suggestions, .. } => suggestions,
+            _ => unreachable!(),
+        };
+        assert_eq!(suggestions("CT_small.dcm"), ["pydicom/CT_small.dcm"]);
+        assert_eq!(
+            suggestions("pydicom/ct_small.dcm"),
+            ["pydicom/CT_small.dcm", "pydicom/MR_small.dcm"]
+        );
+        assert!(suggestions("nothing/like/it").is_empty());
+
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "abc"), 3);
+    }
+}
@@ -0,0 +1,787 @@
+//! Synthetic DICOM files with exact properties.
+//!
+//! When no real sample has the properties needed by a test,
+//! [`SynthFile`] can write a minimal, valid DICOM Part 10 file
+//! with the requested image dimensions, bit depth, number of frames,
+//! transfer syntax and number of additional elements.
+//! Generated files are cached next to the downloaded test files,
+//! and the same parameters always produce the same bytes.
+//!
+//! Pixel data is written one row at a time,
+//! so very large multi-frame objects for memory usage and streaming tests
+//! can be generated without holding them in memory.
+//! Objects with more than 4 GiB of pixel data
+//! need [`TransferSyntax::EncapsulatedUncompressed`],
+//! which stores each frame in its own fragment.
+//!
+//! ```no_run
+//! use dicom_test_files::synth::{SynthFile, TransferSyntax};
+//!
+//! # fn main() -> Result<(), dicom_test_files::Error> {
+//! let path = SynthFile::new()
+//!     .rows(512)
+//!     .columns(256)
+//!     .bits_allocated(8)
+//!     .transfer_syntax(TransferSyntax::ExplicitVrBigEndian)
+//!     .path()?;
+//! # let _ = path;
+//!
+//! // 5000 frames of 1024x1024 16-bit samples, about 10 GB
+//! let large = SynthFile::new()
+//!     .rows(1024)
+//!     .columns(1024)
+//!     .frames(5000)
+//!     .transfer_syntax(TransferSyntax::EncapsulatedUncompressed)
+//!     .path()?;
+//! # let _ = large;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{get_data_path, Error, Result};
+use sha2::{Digest, Sha256};
+use std::{
+    fmt, fs,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+};
+
+/// Version of the generated contents,
+/// to be increased whenever the same parameters would produce different bytes
+pub(crate) const FORMAT_VERSION: u32 = 2;
+
+/// Secondary Capture Image Storage
+const SOP_CLASS_UID: &str = "1.2.840.10008.5.1.4.1.1.7";
+/// Multi-frame Grayscale Byte Secondary Capture Image Storage
+const MULTI_FRAME_BYTE_SOP_CLASS_UID: &str = "1.2.840.10008.5.1.4.1.1.7.2";
+/// Multi-frame Grayscale Word Secondary Capture Image Storage
+const MULTI_FRAME_WORD_SOP_CLASS_UID: &str = "1.2.840.10008.5.1.4.1.1.7.3";
+/// Multi-frame True Color Secondary Capture Image Storage
+const MULTI_FRAME_TRUE_COLOR_SOP_CLASS_UID: &str = "1.2.840.10008.5.1.4.1.1.7.4";
+
+/// Number of extra elements in each private block
+const EXTRA_PER_GROUP: u32 = 0xF0;
+/// First and last private groups holding extra elements,
+/// the odd groups up to the pixel data
+const EXTRA_GROUPS: (u16, u16) = (0x0009, 0x7FDF);
+/// Maximum number of extra elements
+const MAX_EXTRA_ELEMENTS: u32 =
+    ((EXTRA_GROUPS.1 - EXTRA_GROUPS.0) as u32 / 2 + 1) * EXTRA_PER_GROUP;
+
+/// The largest value length which can be recorded
+const MAX_VALUE_LEN: u64 = 0xFFFF_FFFE;
+/// The value length of encapsulated pixel data
+const UNDEFINED_LEN: u32 = 0xFFFF_FFFF;
+
+/// UID root of the generated identifiers (UUID-derived, see PS3.5 B.2)
+const UID_ROOT: &str = "2.25.";
+
+pub(crate) const PATIENT_NAME: &str = "Synthetic^Test";
+pub(crate) const PATIENT_ID: &str = "SYNTH";
+pub(crate) const STUDY_DATE: &str = "20000101";
+pub(crate) const STUDY_TIME: &str = "120000";
+pub(crate) const STUDY_ID: &str = "1";
+
+const IMPLEMENTATION_CLASS_UID: &str = "2.25.119520252589436716613673058062278293262";
+const IMPLEMENTATION_VERSION_NAME: &str = "DICOMTESTFILES";
+
+/// Transfer syntaxes supported by the synthetic file generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransferSyntax {
+    /// Implicit VR Little Endian (`1.2.840.10008.1.2`)
+    ImplicitVrLittleEndian,
+    /// Explicit VR Little Endian (`1.2.840.10008.1.2.1`)
+    ExplicitVrLittleEndian,
+    /// Explicit VR Big Endian (`1.2.840.10008.1.2.2`, retired)
+    ExplicitVrBigEndian,
+    /// Encapsulated Uncompressed Explicit VR Little Endian
+    /// (`1.2.840.10008.1.2.1.98`),
+    /// with one fragment per frame
+    EncapsulatedUncompressed,
+}
+
+impl TransferSyntax {
+    /// The transfer syntax UID
+    pub fn uid(self) -> &'static str {
+        match self {
+            TransferSyntax::ImplicitVrLittleEndian => "1.2.840.10008.1.2",
+            TransferSyntax::ExplicitVrLittleEndian => "1.2.840.10008.1.2.1",
+            TransferSyntax::ExplicitVrBigEndian => "1.2.840.10008.1.2.2",
+            TransferSyntax::EncapsulatedUncompressed => "1.2.840.10008.1.2.1.98",
+        }
+    }
+
+    fn explicit_vr(self) -> bool {
+        self != TransferSyntax::ImplicitVrLittleEndian
+    }
+
+    fn big_endian(self) -> bool {
+        self == TransferSyntax::ExplicitVrBigEndian
+    }
+
+    fn encapsulated(self) -> bool {
+        self == TransferSyntax::EncapsulatedUncompressed
+    }
+
+    fn short_name(self) -> &'static str {
+        match self {
+            TransferSyntax::ImplicitVrLittleEndian => "ivrle",
+            TransferSyntax::ExplicitVrLittleEndian => "evrle",
+            TransferSyntax::ExplicitVrBigEndian => "evrbe",
+            TransferSyntax::EncapsulatedUncompressed => "encap",
+        }
+    }
+}
+
+/// Parameters of a synthetic DICOM file.
+///
+/// All parameters have defaults,
+/// yielding a single frame 64x64 monochrome 16-bit image
+/// in Explicit VR Little Endian.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SynthFile {
+    rows: u16,
+    columns: u16,
+    bits_allocated: u16,
+    samples_per_pixel: u16,
+    frames: u32,
+    transfer_syntax: TransferSyntax,
+    extra_elements: u32,
+    position: Option<Position>,
+}
+
+/// Where a synthetic file belongs in a synthetic study
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Position {
+    pub(crate) study: &'static str,
+    pub(crate) modality: &'static str,
+    pub(crate) series_number: u32,
+    pub(crate) instance_number: u32,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Position {
+            study: "",
+            modality: "OT",
+            series_number: 1,
+            instance_number: 1,
+        }
+    }
+}
+
+impl Default for SynthFile {
+    fn default() -> Self {
+        SynthFile {
+            rows: 64,
+            columns: 64,
+            bits_allocated: 16,
+            samples_per_pixel: 1,
+            frames: 1,
+            transfer_syntax: TransferSyntax::ExplicitVrLittleEndian,
+            extra_elements: 0,
+            position: None,
+        }
+    }
+}
+
+impl SynthFile {
+    /// Start from the default parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of rows of the image.
+    pub fn rows(mut self, rows: u16) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Set the number of columns of the image.
+    pub fn columns(mut self, columns: u16) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the number of bits allocated per sample: 8, 16 or 32.
+    pub fn bits_allocated(mut self, bits_allocated: u16) -> Self {
+        self.bits_allocated = bits_allocated;
+        self
+    }
+
+    /// Set the number of samples per pixel:
+    /// 1 for monochrome, 3 for RGB.
+    pub fn samples_per_pixel(mut self, samples_per_pixel: u16) -> Self {
+        self.samples_per_pixel = samples_per_pixel;
+        self
+    }
+
+    /// Set the number of frames.
+    ///
+    /// Files with more than one frame use
+    /// one of the multi-frame secondary capture storage classes
+    /// when the bit depth allows it.
+    pub fn frames(mut self, frames: u32) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Set the transfer syntax of the main data set.
+    pub fn transfer_syntax(mut self, transfer_syntax: TransferSyntax) -> Self {
+        self.transfer_syntax = transfer_syntax;
+        self
+    }
+
+    /// Set the number of additional private elements in the data set,
+    /// on top of the ones required for a valid image,
+    /// at most 240 in each odd group up to the pixel data.
+    pub fn extra_elements(mut self, extra_elements: u32) -> Self {
+        self.extra_elements = extra_elements;
+        self
+    }
+
+    /// A name which uniquely identifies this set of parameters,
+    /// used as the file name in the cache.
+    pub fn key(&self) -> String {
+        format!(
+            "{}_{}x{}x{}_{}bit_{}spp_{}extra",
+            self.transfer_syntax.short_name(),
+            self.rows,
+            self.columns,
+            self.frames,
+            self.bits_allocated,
+            self.samples_per_pixel,
+            self.extra_elements,
+        )
+    }
+
+    /// Write the file to the local cache if it is not there yet,
+    /// and return its path.
+    pub fn path(&self) -> Result<PathBuf> {
+        self.validate()?;
+        let target = get_data_path()
+            .join("synth")
+            .join(format!("v{}", FORMAT_VERSION))
+            .join(format!("{}.dcm", self.key()));
+        if target.exists() {
+            return Ok(target);
+        }
+        let target_dir = target.parent().unwrap();
+        fs::create_dir_all(target_dir)?;
+
+        let mut file = tempfile::NamedTempFile::new_in(target_dir)?;
+        {
+            let mut writer = BufWriter::new(file.as_file_mut());
+            self.write_to(&mut writer)?;
+            writer.flush()?;
+        }
+        file.persist(&target).map_err(io::Error::from)?;
+        Ok(target)
+    }
+
+    /// Produce the contents of the file in memory.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.validate()?;
+        let mut out = Vec::new();
+        self.write_to(&mut out)?;
+        Ok(out)
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        let problem = if ![8, 16, 32].contains(&self.bits_allocated) {
+            "bits allocated must be 8, 16 or 32"
+        } else if ![1, 3].contains(&self.samples_per_pixel) {
+            "samples per pixel must be 1 or 3"
+        } else if self.rows == 0 || self.columns == 0 || self.frames == 0 {
+            "image must not be empty"
+        } else if self.extra_elements > MAX_EXTRA_ELEMENTS {
+            "too many extra elements"
+        } else if self.frame_len() > MAX_VALUE_LEN {
+            "frame too large"
+        } else if !self.transfer_syntax.encapsulated() && self.pixel_data_len() > MAX_VALUE_LEN {
+            "pixel data too large for a native transfer syntax, \
+             use TransferSyntax::EncapsulatedUncompressed"
+        } else {
+            return Ok(());
+        };
+        Err(Error::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            problem,
+        )))
+    }
+
+    /// The length of a single frame in bytes, without padding
+    fn frame_len(&self) -> u64 {
+        u64::from(self.rows)
+            * u64::from(self.columns)
+            * u64::from(self.samples_per_pixel)
+            * u64::from(self.bits_allocated / 8)
+    }
+
+    /// The length of native pixel data, padded to an even length
+    fn pixel_data_len(&self) -> u64 {
+        let len = self.frame_len() * u64::from(self.frames);
+        len + len % 2
+    }
+
+    pub(crate) fn sop_class_uid(&self) -> &'static str {
+        match (self.frames, self.samples_per_pixel, self.bits_allocated) {
+            (1, _, _) => SOP_CLASS_UID,
+            (_, 1, 8) => MULTI_FRAME_BYTE_SOP_CLASS_UID,
+            (_, 1, 16) => MULTI_FRAME_WORD_SOP_CLASS_UID,
+            (_, 3, 8) => MULTI_FRAME_TRUE_COLOR_SOP_CLASS_UID,
+            _ => SOP_CLASS_UID,
+        }
+    }
+
+    /// Study, series or instance UID of the file.
+    ///
+    /// Files placed in a study share the study and series UIDs,
+    /// otherwise all UIDs are derived from the parameters.
+    pub(crate) fn uid(&self, kind: &str) -> String {
+        let seed = match (&self.position, kind) {
+            (None, _) => self.key(),
+            (Some(p), "study") => p.study.to_string(),
+            (Some(p), "series") => format!("{}/{}", p.study, p.series_number),
+            (Some(p), _) => format!("{}/{}/{}", p.study, p.series_number, p.instance_number),
+        };
+        derived_uid(&seed, kind)
+    }
+
+    pub(crate) fn transfer_syntax_uid(&self) -> &'static str {
+        self.transfer_syntax.uid()
+    }
+
+    /// Place the file in a synthetic study.
+    pub(crate) fn at(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub(crate) fn write_to(&self, mut out: impl Write) -> io::Result<()> {
+        let sop_class_uid = self.sop_class_uid();
+        let instance_uid = self.uid("instance");
+        write_file_meta(&mut out, sop_class_uid, &instance_uid, self.transfer_syntax)?;
+
+        let position = self.position.unwrap_or_default();
+        let ts = self.transfer_syntax;
+        let mut ds = ElementWriter::new(&mut out, ts);
+        ds.string(0x0008, 0x0016, b"UI", sop_class_uid)?;
+        ds.string(0x0008, 0x0018, b"UI", &instance_uid)?;
+        ds.string(0x0008, 0x0020, b"DA", STUDY_DATE)?;
+        ds.string(0x0008, 0x0030, b"TM", STUDY_TIME)?;
+        ds.string(0x0008, 0x0060, b"CS", position.modality)?;
+        ds.string(0x0008, 0x0064, b"CS", "SYN")?;
+        // in ascending order of tags along with the standard elements
+        let mut extra = ExtraElements::new(self.extra_elements);
+        extra.write_before(&mut ds, 0x0010)?;
+        ds.string(0x0010, 0x0010, b"PN", PATIENT_NAME)?;
+        ds.string(0x0010, 0x0020, b"LO", PATIENT_ID)?;
+        extra.write_before(&mut ds, 0x0020)?;
+        ds.string(0x0020, 0x000D, b"UI", &self.uid("study"))?;
+        ds.string(0x0020, 0x000E, b"UI", &self.uid("series"))?;
+        ds.string(0x0020, 0x0010, b"SH", STUDY_ID)?;
+        ds.string(0x0020, 0x0011, b"IS", &position.series_number.to_string())?;
+        ds.string(0x0020, 0x0013, b"IS", &position.instance_number.to_string())?;
+        extra.write_before(&mut ds, 0x0028)?;
+        ds.u16(0x0028, 0x0002, b"US", self.samples_per_pixel)?;
+        let photometric = if self.samples_per_pixel == 3 {
+            "RGB"
+        } else {
+            "MONOCHROME2"
+        };
+        ds.string(0x0028, 0x0004, b"CS", photometric)?;
+        if self.samples_per_pixel > 1 {
+            ds.u16(0x0028, 0x0006, b"US", 0)?;
+        }
+        if self.frames > 1 {
+            ds.string(0x0028, 0x0008, b"IS", &self.frames.to_string())?;
+        }
+        ds.u16(0x0028, 0x0010, b"US", self.rows)?;
+        ds.u16(0x0028, 0x0011, b"US", self.columns)?;
+        ds.u16(0x0028, 0x0100, b"US", self.bits_allocated)?;
+        ds.u16(0x0028, 0x0101, b"US", self.bits_allocated)?;
+        ds.u16(0x0028, 0x0102, b"US", self.bits_allocated - 1)?;
+        ds.u16(0x0028, 0x0103, b"US", 0)?;
+        extra.write_before(&mut ds, 0x7FE0)?;
+
+        let vr = if self.bits_allocated == 8 {
+            b"OB"
+        } else {
+            b"OW"
+        };
+        if !ts.encapsulated() {
+            ds.header(0x7FE0, 0x0010, vr, self.pixel_data_len() as u32)?;
+            for frame in 0..self.frames {
+                self.write_frame(frame, ds.writer())?;
+            }
+            if self.pixel_data_len() > self.frame_len() * u64::from(self.frames) {
+                ds.writer().write_all(&[0])?;
+            }
+            return Ok(());
+        }
+
+        ds.header(0x7FE0, 0x0010, b"OB", UNDEFINED_LEN)?;
+        // empty basic offset table
+        ds.item_header(0xE000, 0)?;
+        let padding = self.frame_len() % 2;
+        for frame in 0..self.frames {
+            ds.item_header(0xE000, (self.frame_len() + padding) as u32)?;
+            self.write_frame(frame, ds.writer())?;
+            if padding == 1 {
+                ds.writer().write_all(&[0])?;
+            }
+        }
+        ds.item_header(0xE0DD, 0)
+    }
+
+    /// Write a deterministic pattern for the given frame, one row at a time
+    fn write_frame(&self, frame: u32, mut out: impl Write) -> io::Result<()> {
+        let bytes_per_sample = usize::from(self.bits_allocated / 8);
+        let big_endian = self.transfer_syntax.big_endian();
+        let mut row = Vec::with_capacity(
+            usize::from(self.columns) * usize::from(self.samples_per_pixel) * bytes_per_sample,
+        );
+        for r in 0..u32::from(self.rows) {
+            row.clear();
+            for c in 0..u32::from(self.columns) {
+                for s in 0..u32::from(self.samples_per_pixel) {
+                    let value = r.wrapping_mul(3)
+                        ^ c.wrapping_mul(5)
+                        ^ s.wrapping_mul(0x55)
+                        ^ frame.wrapping_mul(7);
+                    match (bytes_per_sample, big_endian) {
+                        (1, _) => row.push(value as u8),
+                        (2, false) => row.extend_from_slice(&(value as u16).to_le_bytes()),
+                        (2, true) => row.extend_from_slice(&(value as u16).to_be_bytes()),
+                        (_, false) => row.extend_from_slice(&value.to_le_bytes()),
+                        (_, true) => row.extend_from_slice(&value.to_be_bytes()),
+                    }
+                }
+            }
+            out.write_all(&row)?;
+        }
+        Ok(())
+    }
+}
+
+/// Derive a UID from the given seed and kind of identifier
+pub(crate) fn derived_uid(seed: &str, kind: &str) -> String {
+    let hash = Sha256::new()
+        .chain_update(seed)
+        .chain_update(kind)
+        .finalize();
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hash[..16]);
+    format!("{}{}", UID_ROOT, u128::from_be_bytes(bytes))
+}
+
+/// Write the preamble, magic code and file meta group
+/// for the given SOP instance
+pub(crate) fn write_file_meta(
+    mut out: impl Write,
+    sop_class_uid: &str,
+    instance_uid: &str,
+    transfer_syntax: TransferSyntax,
+) -> io::Result<()> {
+    // the file meta group is always in explicit VR little endian
+    let mut meta = ElementWriter::new(Vec::new(), TransferSyntax::ExplicitVrLittleEndian);
+    meta.bytes(0x0002, 0x0001, b"OB", &[0, 1])?;
+    meta.string(0x0002, 0x0002, b"UI", sop_class_uid)?;
+    meta.string(0x0002, 0x0003, b"UI", instance_uid)?;
+    meta.string(0x0002, 0x0010, b"UI", transfer_syntax.uid())?;
+    meta.string(0x0002, 0x0012, b"UI", IMPLEMENTATION_CLASS_UID)?;
+    meta.string(0x0002, 0x0013, b"SH", IMPLEMENTATION_VERSION_NAME)?;
+    let meta = meta.into_inner();
+
+    out.write_all(&[0; 128])?;
+    out.write_all(b"DICM")?;
+    let mut group_length = ElementWriter::new(&mut out, TransferSyntax::ExplicitVrLittleEndian);
+    group_length.u32(0x0002, 0x0000, b"UL", meta.len() as u32)?;
+    out.write_all(&meta)
+}
+
+impl fmt::Display for SynthFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.key())
+    }
+}
+
+/// The extra elements left to write:
+/// private elements in odd groups from 0009,
+/// up to 240 elements per private block
+struct ExtraElements {
+    remaining: u32,
+    group: u16,
+}
+
+impl ExtraElements {
+    fn new(count: u32) -> Self {
+        ExtraElements {
+            remaining: count,
+            group: EXTRA_GROUPS.0,
+        }
+    }
+
+    /// Write the private groups which come before the given group
+    fn write_before<W: Write>(&mut self, ds: &mut ElementWriter<W>, group: u16) -> io::Result<()> {
+        while self.remaining > 0 && self.group < group {
+            let count = self.remaining.min(EXTRA_PER_GROUP);
+            ds.string(self.group, 0x0010, b"LO", "DICOM-TEST-FILES SYNTH")?;
+            for i in 0..count {
+                let element = 0x1010 + i as u16;
+                ds.string(self.group, element, b"LO", &format!("value {}", i))?;
+            }
+            self.remaining -= count;
+            self.group += 2;
+        }
+        Ok(())
+    }
+}
+
+/// Writes data elements in the given transfer syntax
+pub(crate) struct ElementWriter<W> {
+    out: W,
+    ts: TransferSyntax,
+}
+
+impl<W: Write> ElementWriter<W> {
+    pub(crate) fn new(out: W, ts: TransferSyntax) -> Self {
+        ElementWriter { out, ts }
+    }
+
+    pub(crate) fn into_inner(self) -> W {
+        self.out
+    }
+
+    pub(crate) fn writer(&mut self) -> &mut W {
+        &mut self.out
+    }
+
+    pub(crate) fn header(
+        &mut self,
+        group: u16,
+        element: u16,
+        vr: &[u8; 2],
+        len: u32,
+    ) -> io::Result<()> {
+        let big_endian = self.ts.big_endian();
+        let u16_bytes = |v: u16| {
+            if big_endian {
+                v.to_be_bytes()
+            } else {
+                v.to_le_bytes()
+            }
+        };
+        let u32_bytes = |v: u32| {
+            if big_endian {
+                v.to_be_bytes()
+            } else {
+                v.to_le_bytes()
+            }
+        };
+
+        self.out.write_all(&u16_bytes(group))?;
+        self.out.write_all(&u16_bytes(element))?;
+        if !self.ts.explicit_vr() {
+            return self.out.write_all(&u32_bytes(len));
+        }
+        self.out.write_all(vr)?;
+        match vr {
+            b"OB" | b"OW" | b"SQ" | b"UN" | b"UT" => {
+                self.out.write_all(&[0, 0])?;
+                self.out.write_all(&u32_bytes(len))
+            }
+            _ => self.out.write_all(&u16_bytes(len as u16)),
+        }
+    }
+
+    /// Item or sequence delimiter header,
+    /// which are always little endian in encapsulated pixel data
+    pub(crate) fn item_header(&mut self, element: u16, len: u32) -> io::Result<()> {
+        self.out.write_all(&0xFFFE_u16.to_le_bytes())?;
+        self.out.write_all(&element.to_le_bytes())?;
+        self.out.write_all(&len.to_le_bytes())
+    }
+
+    pub(crate) fn bytes(
+        &mut self,
+        group: u16,
+        element: u16,
+        vr: &[u8; 2],
+        value: &[u8],
+    ) -> io::Result<()> {
+        self.header(group, element, vr, value.len() as u32)?;
+        self.out.write_all(value)
+    }
+
+    pub(crate) fn string(
+        &mut self,
+        group: u16,
+        element: u16,
+        vr: &[u8; 2],
+        value: &str,
+    ) -> io::Result<()> {
+        let mut value = value.as_bytes().to_vec();
+        if value.len() % 2 == 1 {
+            value.push(if vr == b"UI" { 0 } else { b' ' });
+        }
+        self.bytes(group, element, vr, &value)
+    }
+
+    pub(crate) fn u16(
+        &mut self,
+        group: u16,
+        element: u16,
+        vr: &[u8; 2],
+        value: u16,
+    ) -> io::Result<()> {
+        let value = if self.ts.big_endian() {
+            value.to_be_bytes()
+        } else {
+            value.to_le_bytes()
+        };
+        self.bytes(group, element, vr, &value)
+    }
+
+    pub(crate) fn u32(
+        &mut self,
+        group: u16,
+        element: u16,
+        vr: &[u8; 2],
+        value: u32,
+    ) -> io::Result<()> {
+        let value = if self.ts.big_endian() {
+            value.to_be_bytes()
+        } else {
+            value.to_le_bytes()
+        };
+        self.bytes(group, element, vr, &value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn synth_file_is_deterministic() {
+        let synth = SynthFile::new().rows(3).columns(5).bits_allocated(8);
+        let bytes = synth.to_bytes().unwrap();
+        assert_eq!(bytes, synth.to_bytes().unwrap());
+        assert_eq!(&bytes[128..132], b"DICM");
+        // odd pixel data is padded to an even length
+        assert_eq!(bytes.len() % 2, 0);
+
+        let path = synth.path().unwrap();
+        assert!(path.ends_with("synth/v2/evrle_3x5x1_8bit_1spp_0extra.dcm"));
+        assert_eq!(fs::read(path).unwrap(), bytes);
+    }
+
+    #[test]
+    fn synth_file_parameters() {
+        let small = SynthFile::new().rows(16).columns(16).to_bytes().unwrap();
+        let rgb = SynthFile::new()
+            .rows(16)
+            .columns(16)
+            .samples_per_pixel(3)
+            .to_bytes()
+            .unwrap();
+        assert!(rgb.len() > small.len() + 2 * 16 * 16 * 2);
+
+        let with_extra = SynthFile::new()
+            .rows(16)
+            .columns(16)
+            .extra_elements(300)
+            .to_bytes()
+            .unwrap();
+        assert!(with_extra.len() > small.len() + 300 * 8);
+
+        for ts in [
+            TransferSyntax::ImplicitVrLittleEndian,
+            TransferSyntax::ExplicitVrBigEndian,
+        ] {
+            let bytes = SynthFile::new().transfer_syntax(ts).to_bytes().unwrap();
+            let uid = ts.uid().as_bytes();
+            assert!(bytes.windows(uid.len()).any(|w| w == uid));
+        }
+
+        assert!(SynthFile::new().bits_allocated(12).to_bytes().is_err());
+    }
+
+    #[test]
+    fn synth_extra_elements_in_tag_order() {
+        // past the private groups before the patient module
+        let bytes = SynthFile::new()
+            .rows(2)
+            .columns(2)
+            .extra_elements(1000)
+            .to_bytes()
+            .unwrap();
+        let mut tags = Vec::new();
+        let mut pos = 132;
+        while pos < bytes.len() {
+            let u16_at = |at: usize| u16::from_le_bytes([bytes[at], bytes[at + 1]]);
+            let tag = (u16_at(pos), u16_at(pos + 2));
+            if tag == (0x7FE0, 0x0010) {
+                break;
+            }
+            let (header_len, len) = match &bytes[pos + 4..pos + 6] {
+                b"OB" | b"OW" | b"SQ" | b"UN" | b"UT" => (
+                    12,
+                    usize::from(u16_at(pos + 8)) | usize::from(u16_at(pos + 10)) << 16,
+                ),
+                _ => (8, usize::from(u16_at(pos + 6))),
+            };
+            tags.push(tag);
+            pos += header_len + len;
+        }
+        assert!(tags.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(tags.contains(&(0x0011, 0x1010)));
+        assert!(tags.contains(&(0x0010, 0x0010)));
+
+        assert!(SynthFile::new()
+            .extra_elements(MAX_EXTRA_ELEMENTS)
+            .validate()
+            .is_ok());
+        assert!(SynthFile::new()
+            .extra_elements(MAX_EXTRA_ELEMENTS + 1)
+            .to_bytes()
+            .is_err());
+    }
+
+    #[test]
+    fn synth_multi_frame() {
+        let single = SynthFile::new().rows(8).columns(8).to_bytes().unwrap();
+        let multi = SynthFile::new()
+            .rows(8)
+            .columns(8)
+            .frames(10)
+            .to_bytes()
+            .unwrap();
+        assert!(multi.len() >= single.len() + 9 * 8 * 8 * 2);
+        let uid = MULTI_FRAME_WORD_SOP_CLASS_UID.as_bytes();
+        assert!(multi.windows(uid.len()).any(|w| w == uid));
+
+        let encapsulated = SynthFile::new()
+            .rows(3)
+            .columns(3)
+            .bits_allocated(8)
+            .frames(4)
+            .transfer_syntax(TransferSyntax::EncapsulatedUncompressed)
+            .to_bytes()
+            .unwrap();
+        // each odd-length frame is padded within its own fragment
+        let item = [0xFE, 0xFF, 0x00, 0xE0, 10, 0, 0, 0];
+        assert_eq!(encapsulated.windows(8).filter(|w| *w == item).count(), 4);
+        assert!(encapsulated.ends_with(&[0xFE, 0xFF, 0xDD, 0xE0, 0, 0, 0, 0]));
+
+        // over 4 GiB of pixel data
+        let large = SynthFile::new().rows(1024).columns(1024).frames(3000);
+        assert!(large.to_bytes().is_err());
+        assert!(large
+            .transfer_syntax(TransferSyntax::EncapsulatedUncompressed)
+            .validate()
+            .is_ok());
+    }
+}
@@ -1,13 +1,333 @@
 //! Test file base data structures
 
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt};
 
-#[derive(Debug)]
+/// Compression applied to a test file in the data repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Compression {
     /// no compression
     None,
     /// Zstandard compression
     Zstd,
+    /// gzip compression, stored with a `.gz` extension.
+    /// Requires the `gzip` feature.
+    Gzip,
+    /// xz compression, stored with a `.xz` extension.
+    /// Requires the `xz` feature.
+    Xz,
+    /// member of a ZIP archive, which may hold several test files.
+    ///
+    /// The archive is downloaded once and kept in the cache,
+    /// and the hash is the one of the member once extracted.
+    /// Requires the `zip` feature.
+    ZipMember {
+        /// stored name of the archive
+        archive: &'static str,
+        /// path of the member in the archive
+        member: &'static str,
+    },
+    /// instance retrieved with WADO-RS
+    /// from the DICOMweb server at the base URL of its source.
+    ///
+    /// The hash is the one of the instance as retrieved.
+    /// Requires the `dicomweb` feature.
+    DicomWeb {
+        /// Study Instance UID
+        study: &'static str,
+        /// Series Instance UID
+        series: &'static str,
+        /// SOP Instance UID
+        instance: &'static str,
+    },
+}
+
+impl Compression {
+    /// Whether the stored file is a compressed stream of the test file,
+    /// decompressed into the cache unless it is
+    /// [kept compressed](crate::Config::keep_compressed)
+    pub(crate) fn is_stream(self) -> bool {
+        matches!(
+            self,
+            Compression::Zstd | Compression::Gzip | Compression::Xz
+        )
+    }
+}
+
+/// Algorithm of the hash of a test file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DigestAlgorithm {
+    /// SHA-256, the algorithm of the test files of this project
+    Sha256,
+    /// SHA-512
+    Sha512,
+    /// BLAKE3, several times faster to verify than SHA-256.
+    /// Requires the `blake3` feature.
+    Blake3,
+}
+
+impl DigestAlgorithm {
+    /// The name of the algorithm in manifests,
+    /// such as `sha256`
+    pub fn name(self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha256 => "sha256",
+            DigestAlgorithm::Sha512 => "sha512",
+            DigestAlgorithm::Blake3 => "blake3",
+        }
+    }
+
+    /// The algorithm with the given name in manifests
+    pub(crate) fn from_name(name: &str) -> Option<DigestAlgorithm> {
+        match name {
+            "sha256" => Some(DigestAlgorithm::Sha256),
+            "sha512" => Some(DigestAlgorithm::Sha512),
+            "blake3" => Some(DigestAlgorithm::Blake3),
+            _ => None,
+        }
+    }
+
+    /// Whether the given text is a hash of this algorithm
+    /// in lowercase hexadecimal
+    pub(crate) fn is_hash(self, hash: &str) -> bool {
+        let len = match self {
+            DigestAlgorithm::Sha256 | DigestAlgorithm::Blake3 => 64,
+            DigestAlgorithm::Sha512 => 128,
+        };
+        hash.len() == len && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+    }
+}
+
+impl fmt::Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// What is wrong with a deliberately malformed test file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Defect {
+    /// The preamble before the `DICM` magic code is shorter than 128 bytes
+    ShortPreamble,
+    /// The magic code after the preamble is not `DICM`
+    BadMagicCode,
+    /// The file meta group length does not match the actual group length
+    WrongGroupLength,
+    /// An element declares a value length beyond the end of the file
+    ElementLengthOverflow,
+    /// An element has a value representation which does not exist
+    InvalidVr,
+    /// The file ends in the middle of an element of the main data set
+    TruncatedDataSet,
+    /// The file ends in the middle of the pixel data
+    TruncatedPixelData,
+}
+
+impl Defect {
+    /// A short human readable description of the defect
+    pub fn description(self) -> &'static str {
+        match self {
+            Defect::ShortPreamble => "preamble shorter than 128 bytes",
+            Defect::BadMagicCode => "magic code is not DICM",
+            Defect::WrongGroupLength => "file meta group length does not match the group",
+            Defect::ElementLengthOverflow => "element length goes past the end of the file",
+            Defect::InvalidVr => "element with an invalid value representation",
+            Defect::TruncatedDataSet => "data set truncated in the middle of an element",
+            Defect::TruncatedPixelData => "pixel data truncated",
+        }
+    }
+}
+
+impl fmt::Display for Defect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// How a test file is known to be free of protected health information,
+/// see [`assert_anonymized`](crate::assert_anonymized)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anonymization {
+    /// Acquired from a patient, with all identifying information removed
+    FullyAnonymized,
+    /// Generated or derived from other test files, never from a patient
+    Synthetic,
+    /// Published by a vendor as a sample of its modalities,
+    /// without a vetted anonymization
+    VendorSample,
+}
+
+impl Anonymization {
+    /// The name of the status in manifests,
+    /// such as `fully-anonymized`
+    pub fn name(self) -> &'static str {
+        match self {
+            Anonymization::FullyAnonymized => "fully-anonymized",
+            Anonymization::Synthetic => "synthetic",
+            Anonymization::VendorSample => "vendor-sample",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Anonymization> {
+        match name {
+            "fully-anonymized" => Some(Anonymization::FullyAnonymized),
+            "synthetic" => Some(Anonymization::Synthetic),
+            "vendor-sample" => Some(Anonymization::VendorSample),
+            _ => None,
+        }
+    }
+
+    /// Whether files of this status are vetted to hold no patient data,
+    /// which vendor samples are not
+    pub fn is_vetted(self) -> bool {
+        match self {
+            Anonymization::FullyAnonymized | Anonymization::Synthetic => true,
+            Anonymization::VendorSample => false,
+        }
+    }
+}
+
+impl fmt::Display for Anonymization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Anonymization::FullyAnonymized => "fully anonymized",
+            Anonymization::Synthetic => "synthetic",
+            Anonymization::VendorSample => "vendor sample",
+        })
+    }
+}
+
+/// A structural quirk of valid test files,
+/// for robustness tests of parsers,
+/// see [`with_feature`](crate::with_feature).
+///
+/// Quirks are detected in the main data set of each file,
+/// up to its pixel data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// No preamble and file meta group, only the data set
+    NoPreamble,
+    /// Private elements, with their private creators
+    PrivateTags,
+    /// Sequences with an undefined length
+    UndefinedLengthSequences,
+    /// Elements with the UN (unknown) value representation
+    UnknownVr,
+    /// Values of an odd length, which writers should pad
+    OddLengthValues,
+    /// An Icon Image Sequence
+    IconImage,
+}
+
+impl Feature {
+    /// The group of the files with this feature,
+    /// see [`group`](crate::group)
+    pub fn group(self) -> &'static str {
+        match self {
+            Feature::NoPreamble => "no-preamble",
+            Feature::PrivateTags => "private-tags",
+            Feature::UndefinedLengthSequences => "undefined-length",
+            Feature::UnknownVr => "un-vr",
+            Feature::OddLengthValues => "odd-length",
+            Feature::IconImage => "icon",
+        }
+    }
+}
+
+/// A size class of test files, to leave out the heavy ones.
+///
+/// Tiers are ordered from the smallest to the largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tier {
+    /// Files of less than 1 MiB, suitable for every CI run
+    Small,
+    /// Files from 1 MiB up to 10 MiB
+    Medium,
+    /// Files of 10 MiB or more
+    Large,
+}
+
+impl Tier {
+    /// The tier of a file with the given size in bytes
+    pub fn of_size(size: u64) -> Tier {
+        const MIB: u64 = 1024 * 1024;
+        match size {
+            s if s < MIB => Tier::Small,
+            s if s < 10 * MIB => Tier::Medium,
+            _ => Tier::Large,
+        }
+    }
+}
+
+/// Expected values of a test file, read from its DICOM header.
+///
+/// Attributes which are absent from the file
+/// (or files which are not DICOM at all)
+/// are `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TestFileInfo {
+    /// SOP Class UID
+    pub sop_class_uid: Option<&'static str>,
+    /// Transfer Syntax UID, from the file meta group
+    pub transfer_syntax_uid: Option<&'static str>,
+    /// Rows
+    pub rows: Option<u16>,
+    /// Columns
+    pub columns: Option<u16>,
+    /// Number of Frames, or 1 for single frame images
+    pub frames: Option<u32>,
+    /// Bits Allocated
+    pub bits_allocated: Option<u16>,
+    /// Pixel Representation, 1 for signed samples and 0 for unsigned ones
+    pub pixel_representation: Option<u16>,
+    /// Samples per Pixel
+    pub samples_per_pixel: Option<u16>,
+    /// Photometric Interpretation, such as `MONOCHROME2` or `RGB`
+    pub photometric_interpretation: Option<&'static str>,
+    /// SHA-256 hash of the decoded pixel data,
+    /// see [`pixel_hash`](crate::pixel_hash)
+    pub pixel_hash: Option<&'static str>,
+}
+
+impl TestFileInfo {
+    const EMPTY: TestFileInfo = TestFileInfo {
+        sop_class_uid: None,
+        transfer_syntax_uid: None,
+        rows: None,
+        columns: None,
+        frames: None,
+        bits_allocated: None,
+        pixel_representation: None,
+        samples_per_pixel: None,
+        photometric_interpretation: None,
+        pixel_hash: None,
+    };
+}
+
+/// Where a test file comes from
+/// and under what terms it may be redistributed,
+/// see [`provenance`](crate::provenance)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Provenance {
+    /// the collection or project the file comes from
+    pub source: &'static str,
+    /// URL of the source, if known
+    pub url: Option<&'static str>,
+    /// license of the file, as an SPDX license expression where one applies,
+    /// or a description of the terms of redistribution
+    pub license: &'static str,
+}
+
+/// A collection of test files hosted outside of this project,
+/// such as the GDCM test data,
+/// compiled in behind its own `manifest-*` feature
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Collection {
+    /// directory of the test files of the collection, such as `gdcm`,
+    /// which their names start with
+    pub name: &'static str,
+    /// default base URL of the files,
+    /// to which their names without the directory are appended
+    pub base_url: &'static str,
 }
 
 /// Test file descriptor
@@ -17,31 +337,235 @@ pub struct TestFile {
     pub name: &'static str,
     /// whether the file was subjected to compression
     pub compression: Compression,
-    /// SHA-256 hash of the file's data (post-compression)
+    /// hash of the file's data (post-compression),
+    /// with the algorithm of `digest`
     pub hash: &'static str,
+    /// algorithm of `hash`,
+    /// SHA-256 for all the test files of this project
+    pub digest: DigestAlgorithm,
+    /// size of the file's data in bytes (post-compression),
+    /// as downloaded
+    pub size: u64,
+    /// SHA-256 hash of the file's contents once decompressed,
+    /// if it is stored with compression and the hash is known
+    pub decompressed_hash: Option<&'static str>,
+    /// the defect of the file, if it is deliberately malformed
+    pub defect: Option<Defect>,
+    /// expected values of the file
+    pub info: TestFileInfo,
+    /// base URL of the data source of the file,
+    /// if it is not the default one
+    pub base_url: Option<&'static str>,
+    /// names of the groups the file belongs to,
+    /// see [`group`](crate::group)
+    pub groups: &'static [&'static str],
+    /// where the file comes from, if known
+    pub provenance: Option<Provenance>,
+    /// how the file is known to be free of patient data, if it is
+    pub anonymization: Option<Anonymization>,
+    /// the collection hosting the file, if it is hosted outside of this project
+    pub collection: Option<&'static Collection>,
+    /// URL of the file at the canonical host it originates from, if known,
+    /// without the compression of its stored copy,
+    /// tried when its data source fails
+    pub upstream_url: Option<&'static str>,
+    /// name of the multi-file fixture the file is a member of, if any,
+    /// see [`fetch_set`](crate::fetch_set)
+    pub set: Option<&'static str>,
+    /// instance number of the file, which orders the members of its set
+    pub instance_number: Option<u32>,
+    /// whether the data source holds a pointer record of the file
+    /// instead of its data, which is hosted on a CDN,
+    /// see the [crate documentation](crate#large-files)
+    pub pointer: bool,
 }
 
 impl TestFile {
-    pub const fn new(name: &'static str, compression: Compression, hash: &'static str) -> Self {
+    pub(crate) const fn new(
+        name: &'static str,
+        compression: Compression,
+        hash: &'static str,
+    ) -> Self {
         Self {
             name,
             compression,
             hash,
+            digest: DigestAlgorithm::Sha256,
+            size: 0,
+            decompressed_hash: None,
+            defect: None,
+            info: TestFileInfo::EMPTY,
+            base_url: None,
+            groups: &[],
+            provenance: None,
+            anonymization: None,
+            collection: None,
+            upstream_url: None,
+            set: None,
+            instance_number: None,
+            pointer: false,
         }
     }
 
-    pub const fn none(name: &'static str, hash: &'static str) -> Self {
+    // used by the entries of all manifests
+    #[cfg_attr(
+        not(any(
+            feature = "manifest-pydicom",
+            feature = "manifest-wg04",
+            feature = "manifest-invalid"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) const fn none(name: &'static str, hash: &'static str) -> Self {
         Self::new(name, Compression::None, hash)
     }
 
-    pub const fn zstd(name: &'static str, hash: &'static str) -> Self {
+    // only used by the WG04 entries
+    #[cfg_attr(not(feature = "manifest-wg04"), allow(dead_code))]
+    pub(crate) const fn zstd(name: &'static str, hash: &'static str) -> Self {
         Self::new(name, Compression::Zstd, hash)
     }
 
-    pub fn real_file_name(&self) -> Cow<'static, str> {
+    // used by the entries of gzip compressed files
+    #[allow(dead_code)]
+    pub(crate) const fn gzip(name: &'static str, hash: &'static str) -> Self {
+        Self::new(name, Compression::Gzip, hash)
+    }
+
+    // used by the entries of xz compressed files
+    #[allow(dead_code)]
+    pub(crate) const fn xz(name: &'static str, hash: &'static str) -> Self {
+        Self::new(name, Compression::Xz, hash)
+    }
+
+    pub(crate) const fn size(self, size: u64) -> Self {
+        Self { size, ..self }
+    }
+
+    // only used by the WG04 entries
+    #[cfg_attr(not(feature = "manifest-wg04"), allow(dead_code))]
+    pub(crate) const fn decompressed(self, hash: &'static str) -> Self {
+        Self {
+            decompressed_hash: Some(hash),
+            ..self
+        }
+    }
+
+    pub(crate) const fn digest(self, digest: DigestAlgorithm) -> Self {
+        Self { digest, ..self }
+    }
+
+    // only used by the malformed entries
+    #[cfg_attr(not(feature = "manifest-invalid"), allow(dead_code))]
+    pub(crate) const fn defect(self, defect: Defect) -> Self {
+        Self {
+            defect: Some(defect),
+            ..self
+        }
+    }
+
+    // only used by the pydicom and WG04 entries
+    #[cfg_attr(
+        not(any(feature = "manifest-pydicom", feature = "manifest-wg04")),
+        allow(dead_code)
+    )]
+    pub(crate) const fn info(self, info: TestFileInfo) -> Self {
+        Self { info, ..self }
+    }
+
+    // used by the entries of all manifests
+    #[cfg_attr(
+        not(any(
+            feature = "manifest-pydicom",
+            feature = "manifest-wg04",
+            feature = "manifest-invalid"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) const fn groups(self, groups: &'static [&'static str]) -> Self {
+        Self { groups, ..self }
+    }
+
+    // used by the entries of all manifests
+    #[cfg_attr(
+        not(any(
+            feature = "manifest-pydicom",
+            feature = "manifest-wg04",
+            feature = "manifest-invalid"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) const fn provenance(self, provenance: Provenance) -> Self {
+        Self {
+            provenance: Some(provenance),
+            ..self
+        }
+    }
+
+    // used by the entries of all manifests
+    #[cfg_attr(
+        not(any(
+            feature = "manifest-pydicom",
+            feature = "manifest-wg04",
+            feature = "manifest-invalid"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) const fn anonymization(self, anonymization: Anonymization) -> Self {
+        Self {
+            anonymization: Some(anonymization),
+            ..self
+        }
+    }
+
+    // only used by the entries of hosted collections
+    #[allow(dead_code)]
+    pub(crate) const fn collection(self, collection: &'static Collection) -> Self {
+        Self {
+            collection: Some(collection),
+            ..self
+        }
+    }
+
+    // only used by the pydicom entries
+    #[cfg_attr(not(feature = "manifest-pydicom"), allow(dead_code))]
+    pub(crate) const fn upstream(self, url: &'static str) -> Self {
+        Self {
+            upstream_url: Some(url),
+            ..self
+        }
+    }
+
+    /// The size tier of the file, see [`tier`](crate::tier)
+    pub fn tier(&self) -> Tier {
+        Tier::of_size(self.size)
+    }
+
+    /// The name of the file as stored in the data repository
+    pub(crate) fn real_file_name(&self) -> Cow<'static, str> {
         match self.compression {
             Compression::None => Cow::Borrowed(self.name),
             Compression::Zstd => Cow::Owned(format!("{}.zst", self.name)),
+            Compression::Gzip => Cow::Owned(format!("{}.gz", self.name)),
+            Compression::Xz => Cow::Owned(format!("{}.xz", self.name)),
+            Compression::ZipMember { archive, .. } => Cow::Borrowed(archive),
+            Compression::DicomWeb { .. } => Cow::Borrowed(self.name),
         }
     }
 }
+
+/// An archive of several stored test files of this project,
+/// downloaded at once by [`prefetch`](crate::prefetch),
+/// see ./generate/bundles.py
+#[derive(Debug)]
+#[cfg_attr(not(feature = "bundles"), allow(dead_code))]
+pub(crate) struct Bundle {
+    /// stored name of the `.tar.zst` archive
+    pub name: &'static str,
+    /// SHA-256 hash of the archive
+    pub hash: &'static str,
+    /// size of the archive in bytes
+    pub size: u64,
+    /// common prefix of the stored names of its members
+    pub prefix: &'static str,
+}
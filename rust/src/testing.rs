@@ -0,0 +1,393 @@
+//! A local HTTP server for tests of the download path,
+//! serving fixture blobs with scripted failures.
+//!
+//! Requires the `testing` feature outside of the tests of this crate.
+//! The files served are registered like those of any other
+//! [registry](crate::register_registry),
+//! or the whole server is used as the data source
+//! through `DICOM_TEST_FILES_URL`.
+//!
+//! ```no_run
+//! use dicom_test_files::testing::{Fault, MockServer};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = MockServer::start()?;
+//! server.blob("mock/flaky.dcm", &b"DICM"[..]);
+//! // the first request fails, the next one succeeds
+//! server.fault("mock/flaky.dcm", Fault::Status(503));
+//! dicom_test_files::register_registry(server.registry())?;
+//!
+//! assert!(dicom_test_files::path("mock/flaky.dcm").is_err());
+//! assert!(dicom_test_files::path("mock/flaky.dcm").is_ok());
+//! assert_eq!(server.requests("mock/flaky.dcm"), 2);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{Registry, RegistryEntry};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// A failure of a response of the [`MockServer`],
+/// see [`MockServer::fault`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// respond with the given status code and no body,
+    /// such as 404 or 503
+    Status(u16),
+    /// wait for the given duration before responding
+    Delay(Duration),
+    /// send the body with its first byte changed,
+    /// so that it does not match its hash
+    Corrupt,
+    /// announce the whole body,
+    /// but close the connection after the given number of bytes
+    Truncate(usize),
+    /// send the body one byte at a time,
+    /// waiting for the given duration before each,
+    /// until the client goes away
+    Throttle(Duration),
+}
+
+/// A request received by the [`MockServer`],
+/// see [`MockServer::received`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Request {
+    /// the method, such as `GET`
+    pub method: String,
+    /// the headers, with their names as sent
+    pub headers: Vec<(String, String)>,
+    /// the body, empty without a `Content-Length`
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// The value of the first header of the given name, in any case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// The blobs, headers and faults of a server
+#[derive(Default)]
+struct State {
+    blobs: BTreeMap<String, Vec<u8>>,
+    headers: HashMap<String, Vec<(String, String)>>,
+    faults: HashMap<String, VecDeque<Fault>>,
+    received: HashMap<String, Vec<Request>>,
+}
+
+/// A local HTTP server serving fixture blobs,
+/// stopped when dropped.
+///
+/// Paths which were not given a blob are answered with `404 Not Found`.
+pub struct MockServer {
+    addr: SocketAddr,
+    base_url: String,
+    state: Arc<Mutex<State>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl MockServer {
+    /// Start a server on a free port of the loopback interface.
+    pub fn start() -> io::Result<MockServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(State::default()));
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let (server_state, server_stopped) = (Arc::clone(&state), Arc::clone(&stopped));
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if server_stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+                if let Ok(stream) = stream {
+                    let state = Arc::clone(&server_state);
+                    // failures only concern the client
+                    thread::spawn(move || respond(stream, &state).ok());
+                }
+            }
+        });
+        Ok(MockServer {
+            addr,
+            base_url: format!("http://{}/", addr),
+            state,
+            stopped,
+        })
+    }
+
+    /// The base URL of the server, with a trailing slash.
+    pub fn url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Serve the given contents at the given path,
+    /// returning the entry of a registry for them.
+    pub fn blob(&self, path: &str, contents: impl Into<Vec<u8>>) -> RegistryEntry {
+        let contents = contents.into();
+        let entry = registry_entry(path, &contents);
+        self.lock().blobs.insert(path.to_string(), contents);
+        entry
+    }
+
+    /// Send the given header with the responses for the given path,
+    /// such as a `Content-Type`.
+    pub fn header(&self, path: &str, name: &str, value: &str) {
+        self.lock()
+            .headers
+            .entry(path.to_string())
+            .or_default()
+            .push((name.to_string(), value.to_string()));
+    }
+
+    /// Fail the next request for the given path with the given fault.
+    ///
+    /// Faults of the same path apply to successive requests in the order given,
+    /// after which requests succeed again.
+    pub fn fault(&self, path: &str, fault: Fault) {
+        self.lock()
+            .faults
+            .entry(path.to_string())
+            .or_default()
+            .push_back(fault);
+    }
+
+    /// The number of requests received so far for the given path.
+    pub fn requests(&self, path: &str) -> usize {
+        self.lock().received.get(path).map_or(0, Vec::len)
+    }
+
+    /// The requests received so far for the given path, in order.
+    pub fn received(&self, path: &str) -> Vec<Request> {
+        self.lock().received.get(path).cloned().unwrap_or_default()
+    }
+
+    /// A registry of all blobs served so far,
+    /// to pass to [`register_registry`](crate::register_registry).
+    pub fn registry(&self) -> Registry {
+        let entries = self
+            .lock()
+            .blobs
+            .iter()
+            .map(|(path, contents)| registry_entry(path, contents))
+            .collect();
+        Registry {
+            base_url: self.base_url.clone(),
+            entries,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // wake up the accepting thread
+        let _ = TcpStream::connect(self.addr);
+    }
+}
+
+/// The entry of a registry for a blob
+fn registry_entry(path: &str, contents: &[u8]) -> RegistryEntry {
+    RegistryEntry::new(path, format!("{:x}", Sha256::digest(contents))).size(contents.len() as u64)
+}
+
+/// Answer the request of a connection, then close it
+fn respond(mut stream: TcpStream, state: &Mutex<State>) -> io::Result<()> {
+    let mut request_line = String::new();
+    let mut reader = BufReader::new(&stream);
+    reader.read_line(&mut request_line)?;
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.to_string(), value.trim().to_string()));
+        }
+    }
+    let mut request = Request {
+        method: request_line
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+        headers,
+        body: Vec::new(),
+    };
+    let length = request
+        .header("content-length")
+        .and_then(|v| v.parse().ok());
+    request.body = vec![0; length.unwrap_or(0)];
+    reader.read_exact(&mut request.body)?;
+    let target = request_line.split_whitespace().nth(1).unwrap_or_default();
+    let path = target.trim_start_matches('/').to_string();
+
+    let (contents, headers, fault) = {
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        state
+            .received
+            .entry(path.clone())
+            .or_default()
+            .push(request);
+        let fault = state.faults.get_mut(&path).and_then(VecDeque::pop_front);
+        let headers = state.headers.get(&path).cloned().unwrap_or_default();
+        (state.blobs.get(&path).cloned(), headers, fault)
+    };
+    let mut contents = match (contents, &fault) {
+        (_, Some(Fault::Status(status))) => return write_status(&mut stream, *status),
+        (Some(contents), _) => contents,
+        (None, _) => return write_status(&mut stream, 404),
+    };
+    let mut sent = contents.len();
+    let mut throttle = None;
+    match fault {
+        Some(Fault::Delay(delay)) => thread::sleep(delay),
+        Some(Fault::Corrupt) => {
+            if let Some(byte) = contents.first_mut() {
+                *byte = !*byte;
+            }
+        }
+        Some(Fault::Truncate(bytes)) => sent = bytes.min(sent),
+        Some(Fault::Throttle(delay)) => throttle = Some(delay),
+        _ => {}
+    }
+    write!(stream, "HTTP/1.1 200 OK\r\n")?;
+    for (name, value) in headers {
+        write!(stream, "{}: {}\r\n", name, value)?;
+    }
+    write!(
+        stream,
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        contents.len()
+    )?;
+    match throttle {
+        Some(delay) => {
+            for byte in &contents[..sent] {
+                stream.flush()?;
+                thread::sleep(delay);
+                stream.write_all(&[*byte])?;
+            }
+        }
+        None => stream.write_all(&contents[..sent])?,
+    }
+    stream.flush()
+}
+
+fn write_status(stream: &mut TcpStream, status: u16) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {} Mock\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        status
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{download, Compression, Error, TestFile};
+    use std::time::Instant;
+
+    /// An entry of a blob of the server
+    fn entry(server: &MockServer, path: &str, contents: &[u8]) -> TestFile {
+        server.blob(path, contents);
+        let hash = format!("{:x}", Sha256::digest(contents));
+        let mut entry = TestFile::new(
+            Box::leak(path.to_string().into_boxed_str()),
+            Compression::None,
+            Box::leak(hash.into_boxed_str()),
+        );
+        entry.base_url = Some(Box::leak(server.url().to_string().into_boxed_str()));
+        entry
+    }
+
+    #[test]
+    fn scripted_failures() {
+        const BODY: &[u8] = b"contents of the mock file";
+        let server = MockServer::start().unwrap();
+        let entry = entry(&server, "mock/file.dcm", BODY);
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("file.dcm");
+
+        server.fault("mock/file.dcm", Fault::Status(503));
+        server.fault("mock/file.dcm", Fault::Corrupt);
+        server.fault("mock/file.dcm", Fault::Truncate(4));
+        server.fault("mock/file.dcm", Fault::Delay(Duration::from_millis(200)));
+        let error = download(&entry, &cached_path, None).unwrap_err();
+        assert!(error.is_transient());
+        assert!(matches!(
+            download(&entry, &cached_path, None),
+            Err(Error::InvalidHash { .. })
+        ));
+        assert!(matches!(
+            download(&entry, &cached_path, None),
+            Err(Error::Truncated { name, url, .. })
+                if name == "mock/file.dcm" && url.starts_with(server.url())
+        ));
+        assert!(!cached_path.exists());
+
+        let start = Instant::now();
+        assert!(download(&entry, &cached_path, None).unwrap());
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(std::fs::read(&cached_path).unwrap(), BODY);
+        assert_eq!(server.requests("mock/file.dcm"), 4);
+
+        let missing = TestFile {
+            name: "mock/missing.dcm",
+            ..entry
+        };
+        assert!(matches!(
+            download(&missing, &cached_path, None),
+            Err(Error::Download(_))
+        ));
+    }
+
+    #[test]
+    fn received_requests() {
+        let server = MockServer::start().unwrap();
+        let entry = entry(&server, "mock/typed.dcm", b"DICM");
+        server.header("mock/typed.dcm", "Content-Type", "application/dicom");
+        let dir = tempfile::tempdir().unwrap();
+        assert!(download(&entry, &dir.path().join("typed.dcm"), None).unwrap());
+
+        let received = server.received("mock/typed.dcm");
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].method, "GET");
+        assert!(received[0].header("HOST").is_some());
+        assert!(received[0].body.is_empty());
+        assert!(server.received("mock/other.dcm").is_empty());
+    }
+
+    #[test]
+    fn registry_of_blobs() {
+        let server = MockServer::start().unwrap();
+        let b = server.blob("mock/b.dcm", &b"b"[..]);
+        let a = server.blob("mock/a.dcm", &b"a"[..]);
+        let registry = server.registry();
+        assert_eq!(registry.base_url, server.url());
+        assert_eq!(registry.entries, vec![a, b]);
+    }
+}
@@ -0,0 +1,138 @@
+//! Blocking operations as futures of any async executor
+
+use crate::{cancel, CancelToken};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+/// The shared state of a blocking operation and its future
+struct State<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// The future of a blocking operation,
+/// which runs on a thread of its own once polled,
+/// see [`unblock`].
+/// The downloads of the operation are cancelled when dropped.
+pub(crate) struct Unblock<T> {
+    operation: Option<Box<dyn FnOnce() -> T + Send>>,
+    state: Arc<Mutex<State<T>>>,
+    token: CancelToken,
+}
+
+/// Run the given blocking operation on a thread of its own,
+/// so that it does not block the executor of the awaiting task.
+///
+/// Only the standard library is involved,
+/// the future can be awaited from tokio, async-std, smol or any other executor.
+pub(crate) fn unblock<T: Send + 'static>(
+    operation: impl FnOnce() -> T + Send + 'static,
+) -> Unblock<T> {
+    Unblock {
+        operation: Some(Box::new(operation)),
+        state: Arc::new(Mutex::new(State {
+            result: None,
+            waker: None,
+        })),
+        token: CancelToken::new(),
+    }
+}
+
+impl<T: Send + 'static> Future for Unblock<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(result) = state.result.take() {
+            return Poll::Ready(result);
+        }
+        state.waker = Some(cx.waker().clone());
+        drop(state);
+
+        if let Some(operation) = self.operation.take() {
+            let state = Arc::clone(&self.state);
+            let token = self.token.clone();
+            thread::spawn(move || {
+                let result = cancel::within(Some(token), operation);
+                let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+                state.result = Some(result);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Unblock<T> {
+    fn drop(&mut self) {
+        // an operation which completed has nothing left to cancel
+        self.token.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::mpsc, task::Wake, time::Duration};
+
+    /// Wakes a thread parked in `block_on`
+    struct Unpark(thread::Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// A minimal executor, as in the documentation of `Wake`
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn unblocked_operation() {
+        let caller = thread::current().id();
+        let ran_on = block_on(unblock(|| thread::current().id()));
+        assert_ne!(ran_on, caller);
+
+        let path = block_on(crate::path_async("pydicom/CT_small.dcm")).unwrap();
+        assert_eq!(path, crate::path("pydicom/CT_small.dcm").unwrap());
+        assert!(matches!(
+            block_on(crate::path_async("pydicom/nope.dcm")),
+            Err(crate::Error::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn dropped_future_cancels() {
+        let (sender, receiver) = mpsc::channel();
+        let mut future = Box::pin(unblock(move || {
+            while cancel::check().is_ok() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            sender.send(()).unwrap();
+        }));
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        assert!(future
+            .as_mut()
+            .poll(&mut Context::from_waker(&waker))
+            .is_pending());
+        drop(future);
+        receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+//! Downloads from the canonical upstream hosts of test files,
+//! when their data source fails
+
+use crate::{
+    chaos, check_digest, encoding, http, report, write_temp_hashed, Compression, DigestAlgorithm,
+    Error, Result, TestFile,
+};
+use std::{fs, path::Path};
+
+/// The hash which the file at the upstream URL of the given entry
+/// is verified against, with its algorithm.
+///
+/// Upstream hosts serve files without the compression of their stored copy,
+/// only files with a known hash of their contents can be fetched from there.
+fn upstream_hash(entry: &TestFile) -> Option<(&'static str, DigestAlgorithm)> {
+    match entry.compression {
+        Compression::None => Some((entry.hash, entry.digest)),
+        Compression::ZipMember { .. } | Compression::DicomWeb { .. } => None,
+        _ => entry
+            .decompressed_hash
+            .map(|hash| (hash, DigestAlgorithm::Sha256)),
+    }
+}
+
+/// Whether the given entry can be downloaded from its upstream host
+pub(crate) fn available(entry: &TestFile) -> bool {
+    entry.upstream_url.is_some() && upstream_hash(entry).is_some()
+}
+
+/// Download the given entry from its upstream host into its cached path,
+/// verifying it against the hash of its contents.
+/// Returns whether the file was downloaded, like [`download`](crate::download).
+pub(crate) fn download(entry: &TestFile, cached_path: &Path) -> Result<bool> {
+    let mut attempt = report::Attempt::start(entry.name);
+    let downloaded = try_download(entry, cached_path, &mut attempt).map(|()| true);
+    attempt.finish(&downloaded);
+    downloaded
+}
+
+fn try_download(entry: &TestFile, cached_path: &Path, attempt: &mut report::Attempt) -> Result<()> {
+    let (url, (hash, digest)) = match (entry.upstream_url, upstream_hash(entry)) {
+        (Some(url), Some(hash)) => (url, hash),
+        _ => {
+            return Err(Error::Download(format!(
+                "{} has no upstream host",
+                entry.name
+            )))
+        }
+    };
+    #[cfg(feature = "tracing")]
+    tracing::info!(name = entry.name, url, "downloading from the upstream host");
+    attempt.url = Some(url.to_string());
+    chaos::inject(url)?;
+    let resp =
+        http::get(url, &[("Accept-Encoding", encoding::ACCEPT_ENCODING)]).map_err(|failure| {
+            attempt.status = failure.status;
+            failure.error
+        })?;
+    attempt.status = Some(resp.status);
+
+    let parent_dir = cached_path.parent().unwrap();
+    fs::create_dir_all(parent_dir)?;
+    let (tempfile_path, (actual, bytes)) =
+        write_temp_hashed(&mut encoding::decode(resp, url)?, parent_dir, digest)?;
+    attempt.bytes = bytes;
+    check_digest(&tempfile_path, entry.name, hash, actual, bytes)
+        .map_err(|e| e.downloaded_from(url))?;
+    fs::rename(tempfile_path, cached_path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{base_url, lookup};
+
+    #[test]
+    fn fall_back_to_upstream() {
+        let ct = lookup("pydicom/CT_small.dcm").unwrap();
+        let upstream = format!("{}pydicom/CT_small.dcm", base_url().unwrap());
+        let mut entry = TestFile::none("upstream/CT_small.dcm", ct.hash)
+            .size(ct.size)
+            .upstream(Box::leak(upstream.into_boxed_str()));
+        // a data source which cannot be reached
+        entry.base_url = Some("http://127.0.0.1:1/");
+        assert!(available(&entry));
+
+        let dir = tempfile::tempdir().unwrap();
+        let cached_path = dir.path().join("upstream/CT_small.dcm");
+        assert!(crate::download(&entry, &cached_path, None).unwrap());
+        crate::check_hash(&cached_path, &entry).unwrap();
+
+        // not the contents of the stored copy
+        let compressed = lookup("WG04/REF/CT1_UNC").unwrap();
+        let entry =
+            TestFile::zstd("upstream/CT1_UNC", compressed.hash).upstream("http://127.0.0.1:1/");
+        assert_eq!(upstream_hash(&entry), None);
+        let entry = entry.decompressed(compressed.decompressed_hash.unwrap());
+        assert_eq!(
+            upstream_hash(&entry),
+            Some((
+                compressed.decompressed_hash.unwrap(),
+                DigestAlgorithm::Sha256
+            ))
+        );
+    }
+}
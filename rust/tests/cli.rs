@@ -0,0 +1,282 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_dicom-test-files"))
+}
+
+#[test]
+fn fetch_prints_paths() {
+    let output = cli()
+        .args(["fetch", "pydicom/liver.dcm", "pydicom/CT_small.dcm"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let paths: Vec<_> = stdout.lines().collect();
+    assert_eq!(paths.len(), 2);
+    assert!(paths[0].ends_with("liver.dcm"));
+    assert!(paths[1].ends_with("CT_small.dcm"));
+    assert!(std::path::Path::new(paths[0]).exists());
+}
+
+#[test]
+fn fetch_fails_on_unknown_names() {
+    let output = cli()
+        .args(["fetch", "pydicom/liver.dcm", "pydicom/livr.dcm"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("did you mean \"pydicom/liver.dcm\""));
+
+    let output = cli().arg("fetch").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn list_entries() {
+    cli().args(["fetch", "pydicom/liver.dcm"]).output().unwrap();
+    let output = cli()
+        .args(["list", "--glob", "pydicom/*"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let liver = stdout
+        .lines()
+        .find(|line| line.starts_with("pydicom/liver.dcm "))
+        .unwrap();
+    assert!(liver.ends_with("  cached"));
+    assert!(stdout.lines().all(|line| line.starts_with("pydicom/")));
+
+    let output = cli()
+        .args(["list", "--transfer-syntax", "1.2.840.10008.1.2.5", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let listed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let listed = listed.as_array().unwrap();
+    assert!(listed
+        .iter()
+        .any(|entry| entry["name"] == "WG04/RLE/CT1_RLE"));
+    assert!(listed
+        .iter()
+        .all(|entry| entry["transfer_syntax_uid"] == "1.2.840.10008.1.2.5"));
+
+    let output = cli()
+        .args(["list", "--sop-class", "1.2.840.10008.5.1.4.1.1.2", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let listed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let listed = listed.as_array().unwrap();
+    assert!(listed
+        .iter()
+        .any(|entry| entry["name"] == "WG04/REF/CT1_UNC"));
+    assert!(listed
+        .iter()
+        .all(|entry| entry["sop_class_uid"] == "1.2.840.10008.5.1.4.1.1.2"));
+}
+
+#[test]
+fn verify_and_clean_stale() {
+    cli()
+        .args(["fetch", "pydicom/CT_small.dcm"])
+        .output()
+        .unwrap();
+    let output = cli().arg("verify").output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains(" corrupted, "));
+
+    let output = cli().args(["fetch", "pydicom/liver.dcm"]).output().unwrap();
+    let liver = std::path::PathBuf::from(String::from_utf8(output.stdout).unwrap().trim());
+    let stale = liver.with_file_name("liver_renamed.dcm");
+    std::fs::copy(&liver, &stale).unwrap();
+    let output = cli().args(["clean", "--stale"]).output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("liver_renamed.dcm"));
+    assert!(!stale.exists());
+    assert!(liver.exists());
+}
+
+#[test]
+fn prefetch_with_limits() {
+    let output = cli()
+        .args([
+            "prefetch",
+            "--glob",
+            "pydicom/liver.dcm",
+            "--glob",
+            "WG04/REF/MG1_UNC",
+        ])
+        .args(["--jobs", "8", "--max-size", "1M"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.lines().any(|line| line.starts_with("[2/2] ")));
+    // MG1_UNC has 16 MiB, so it is only there if it was cached before
+    assert!(
+        stderr.contains("WG04/REF/MG1_UNC: skipped") || stderr.contains("WG04/REF/MG1_UNC: cached")
+    );
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains(" fetched, "));
+
+    let output = cli().args(["prefetch", "--jobs", "0"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn stats_report() {
+    dicom_test_files::path("pydicom/liver.dcm").unwrap();
+    let output = cli().args(["stats", "--top", "1000"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("cache: "));
+    assert!(stdout.lines().any(|line| line.starts_with("pydicom ")));
+    assert!(stdout.contains("  pydicom/liver.dcm\n"));
+
+    let output = cli().args(["stats", "--json"]).output().unwrap();
+    assert!(output.status.success());
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(stats["bytes"].as_u64().unwrap() > 0);
+
+    let output = cli().args(["stats", "--top", "many"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[cfg(feature = "tar")]
+#[test]
+fn export_and_import_bundle() {
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("corpus.tar");
+    let output = cli()
+        .args(["export-bundle", "--glob", "pydicom/liver.dcm", "--out"])
+        .arg(&out)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .starts_with("1 test files written to "));
+    assert!(out.exists());
+
+    let output = cli().arg("import-bundle").arg(&out).output().unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .starts_with("1 test files unpacked into "));
+
+    let output = cli()
+        .args(["export-bundle", "--glob", "pydicom/*"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let missing = dir.path().join("missing.tar");
+    let output = cli().arg("import-bundle").arg(&missing).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn serve_data_directory() {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("pydicom")).unwrap();
+    std::fs::write(dir.path().join("pydicom/served.dcm"), b"DICM").unwrap();
+    let mut server = cli()
+        .args(["serve", "--port", "0", "--bind", "127.0.0.1", "--dir"])
+        .arg(dir.path())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    // kept open, the server still writes to it
+    let mut stdout = BufReader::new(server.stdout.take().unwrap());
+    let mut line = String::new();
+    stdout.read_line(&mut line).unwrap();
+    let addr = line
+        .trim_end()
+        .rsplit("http://")
+        .next()
+        .unwrap()
+        .trim_end_matches('/');
+
+    let get = |target: &str| {
+        let mut stream = std::net::TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+            target, addr
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+    let response = get("/pydicom/served.dcm");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("Content-Length: 4\r\n"));
+    assert!(response.ends_with("\r\n\r\nDICM"));
+    assert!(get("/pydicom/%73erved.dcm").starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(get("/pydicom/missing.dcm").starts_with("HTTP/1.1 404 "));
+    assert!(get("/pydicom/../../etc/passwd").starts_with("HTTP/1.1 404 "));
+    server.kill().unwrap();
+    server.wait().unwrap();
+}
+
+#[test]
+fn add_to_data_repository() {
+    let repository = tempfile::tempdir().unwrap();
+    let root = repository.path();
+    std::fs::create_dir_all(root.join("data/pydicom")).unwrap();
+    std::fs::create_dir_all(root.join("rust/src")).unwrap();
+    std::fs::write(
+        root.join("rust/src/entries.rs"),
+        "pub static FILE_ENTRIES: &[TestFile] = &[\n    \
+         #[cfg(feature = \"manifest-pydicom\")]\n    TestFile::none(\"pydicom/a.dcm\", \"0\"),\n    \
+         #[cfg(feature = \"manifest-pydicom\")]\n    TestFile::none(\"pydicom/c.dcm\", \"0\"),\n];\n",
+    )
+    .unwrap();
+    let source = root.join("b.dcm");
+    std::fs::write(&source, vec![0; 4096]).unwrap();
+
+    let output = cli()
+        .current_dir(root.join("rust"))
+        .arg("add")
+        .arg(&source)
+        .args(["--category", "pydicom"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    let entries = std::fs::read_to_string(root.join("rust/src/entries.rs")).unwrap();
+    let (a, b, c) = (
+        entries.find("pydicom/a.dcm").unwrap(),
+        entries.find("pydicom/b.dcm").unwrap(),
+        entries.find("pydicom/c.dcm").unwrap(),
+    );
+    assert!(a < b && b < c);
+    // zeros are worth compressing
+    #[cfg(feature = "zstd")]
+    assert!(root.join("data/pydicom/b.dcm.zst").exists());
+    #[cfg(not(feature = "zstd"))]
+    assert!(root.join("data/pydicom/b.dcm").exists());
+
+    // already added, or in a new category
+    for category in ["pydicom", "mine"] {
+        let output = cli()
+            .current_dir(root)
+            .arg("add")
+            .arg(&source)
+            .args(["--category", category])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2));
+    }
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "macros")]
+
+use dicom_test_files::dicom_test;
+use std::path::PathBuf;
+
+#[dicom_test(file = "pydicom/liver.dcm")]
+fn receives_one_file(liver: PathBuf) {
+    assert_eq!(liver.file_name().unwrap(), "liver.dcm");
+    assert!(liver.exists());
+}
+
+#[dicom_test(file = "pydicom/CT_small.dcm", file = "WG04/JPLY/NM1_JPLY")]
+fn receives_files_in_order(ct: PathBuf, nm: PathBuf) -> Result<(), std::io::Error> {
+    assert_eq!(ct.file_name().unwrap(), "CT_small.dcm");
+    assert_eq!(std::fs::metadata(nm)?.len(), 9844);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Multi-file fixtures of an external manifest, in a process of its own
+//! as the configuration is process-wide
+#![cfg(feature = "toml")]
+
+use dicom_test_files::{fetch_set, set_names, Config, Error, Filter};
+use std::fs;
+
+#[test]
+fn fetch_ordered_set() {
+    // existing test files, declared as a set in reverse order
+    let dir = tempfile::tempdir().unwrap();
+    let manifest_path = dir.path().join("set.toml");
+    let mut manifest = String::new();
+    for (name, number) in &[("WG04/JPLY/NM1_JPLY", 2), ("WG04/JPLY/MR1_JPLY", 1)] {
+        let entry = dicom_test_files::entries(Filter::glob(*name)).unwrap()[0];
+        manifest.push_str(&format!(
+            "[[files]]\nname = \"{}\"\nhash = \"{}\"\n\
+             set = \"wg04/nm_mr\"\ninstance_number = {}\n\n",
+            name, entry.hash, number
+        ));
+    }
+    fs::write(&manifest_path, manifest).unwrap();
+    Config::new().manifest(&manifest_path).apply().unwrap();
+
+    assert_eq!(set_names().unwrap(), ["wg04/nm_mr"]);
+    let paths = fetch_set("wg04/nm_mr").unwrap();
+    assert_eq!(paths.len(), 2);
+    assert!(paths[0].ends_with("WG04/JPLY/MR1_JPLY"));
+    assert!(paths[1].ends_with("WG04/JPLY/NM1_JPLY"));
+    assert!(paths.iter().all(|path| path.exists()));
+
+    assert!(matches!(
+        fetch_set("wg04/nm_ct"),
+        Err(Error::NotFound { suggestions, .. }) if suggestions == ["wg04/nm_mr"]
+    ));
+}
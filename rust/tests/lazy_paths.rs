@@ -0,0 +1,31 @@
+//! Lazy iteration over the paths of test files, in a process of its own
+//! as the registries are process-wide
+
+use dicom_test_files::{
+    download_report, iter_paths, path, register_registry, Registry, RegistryEntry,
+};
+use std::path::PathBuf;
+
+#[test]
+fn iter_paths_lazily() {
+    let paths: Vec<PathBuf> = iter_paths("pydicom/CT_small.dcm")
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(paths, [path("pydicom/CT_small.dcm").unwrap()]);
+
+    // files which cannot be downloaded, attempted one at a time
+    register_registry(Registry {
+        base_url: "http://127.0.0.1:1/".to_string(),
+        entries: vec![
+            RegistryEntry::new("lazy/first.dcm", "0".repeat(64)),
+            RegistryEntry::new("lazy/second.dcm", "0".repeat(64)),
+        ],
+    })
+    .unwrap();
+    let attempted = |name| download_report().iter().any(|attempt| attempt.name == name);
+    let mut paths = iter_paths("lazy/*");
+    assert!(!attempted("lazy/first.dcm"));
+    assert!(paths.next().unwrap().is_err());
+    assert!(attempted("lazy/first.dcm"));
+    assert!(!attempted("lazy/second.dcm"));
+}
@@ -0,0 +1,35 @@
+//! Queries over the entries of an external manifest, in a process of its own
+//! as the configuration is process-wide
+#![cfg(feature = "toml")]
+
+use dicom_test_files::{by_sop_class, info, query, Config};
+use std::fs;
+
+/// CT Image Storage
+const CT_IMAGE: &str = "1.2.840.10008.5.1.4.1.1.2";
+
+#[test]
+fn select_manifest_entry_by_sop_class() {
+    let dir = tempfile::tempdir().unwrap();
+    let manifest_path = dir.path().join("ct.toml");
+    fs::write(
+        &manifest_path,
+        format!(
+            "[[files]]\nname = \"myorg/ct.dcm\"\nhash = \"{}\"\nsop_class_uid = \"{}\"\n",
+            "0".repeat(64),
+            CT_IMAGE
+        ),
+    )
+    .unwrap();
+    Config::new().manifest(&manifest_path).apply().unwrap();
+
+    let ct = by_sop_class(CT_IMAGE).unwrap();
+    assert!(ct.iter().any(|entry| entry.name == "myorg/ct.dcm"));
+    assert!(ct.iter().any(|entry| entry.name == "WG04/REF/CT1_UNC"));
+    assert_eq!(info("myorg/ct.dcm").unwrap().sop_class_uid, Some(CT_IMAGE));
+    assert!(query()
+        .run()
+        .unwrap()
+        .iter()
+        .any(|entry| entry.name == "myorg/ct.dcm"));
+}
@@ -0,0 +1,24 @@
+//! The require-signatures mode, in a process of its own
+//! as the configuration is process-wide
+
+use dicom_test_files::{register_registry, Config, Error, Registry, RegistryEntry};
+
+#[test]
+fn require_signatures() {
+    assert!(matches!(
+        Config::new().trusted_key("not a key").apply(),
+        Err(Error::Manifest(_))
+    ));
+    Config::new().require_signatures(true).apply().unwrap();
+
+    let registry = Registry {
+        base_url: "http://127.0.0.1:1/".to_string(),
+        entries: vec![RegistryEntry::new("unsigned/file.dcm", "0".repeat(64))],
+    };
+    assert!(matches!(
+        register_registry(registry),
+        Err(Error::Manifest(_))
+    ));
+    // the entries compiled in are trusted
+    assert!(dicom_test_files::path("pydicom/liver.dcm").is_ok());
+}